@@ -7,7 +7,6 @@
  * 3. buy - Buy tokens (first buy and subsequent buys)
  * 4. sell - Sell tokens back to the curve
  * 5. updateGlobalConfig - Update the global configuration
- * 6. withdraw - Withdraw funds after graduation
  */
 
 import { createUmi } from "@metaplex-foundation/umi-bundle-defaults";
@@ -35,10 +34,18 @@ import {
   buy,
   sell,
   updateGlobalConfig,
-  withdraw,
   withdrawReserve,
   depositToReserve,
 } from "./generated/umi/src/instructions";
+import {
+  MigrationTarget,
+  ReservePolicy,
+  SurplusTokenPolicy,
+  curveKind,
+  graduationMode,
+  lpPolicy,
+  tradingStart,
+} from "./generated/umi/src/types";
 import * as fs from "fs";
 import * as os from "os";
 import * as path from "path";
@@ -57,7 +64,7 @@ const umi = createUmi("http://127.0.0.1:8899", { commitment: "processed" });
 // The program ID should match the deployed program address
 const COINFUN_PROGRAM_ID =
   process.env.COINFUN_PROGRAM_ID ||
-  "6xHUH1pJ2sLCm8YwyF8DBkUyBjDvoJKWgaXiBLaDdgsp"; // Default from generated client
+  "ihC7UqkLYWxQKVuYLiWNGqGvQCZb2ih4DXMLfyM6F68"; // Default from generated client
 console.log(`Program id: ${COINFUN_PROGRAM_ID}`);
 
 // Register the program with UMI so all instructions use this program ID
@@ -82,9 +89,6 @@ const authority = createSignerFromKeypair(umi, authorityKeypair);
 const creator = generateSigner(umi);
 const buyer = generateSigner(umi);
 
-// Fee recipient is the same as authority
-const feeRecipient = authority;
-
 umi.use(keypairIdentity(authority));
 
 const options: TransactionBuilderSendAndConfirmOptions = {
@@ -99,6 +103,24 @@ const tokenTotalSupply = BigInt(1_000_000_000_000_000); // 1_000_000_000 * 1e6
 const platformTradeFeeBps = BigInt(100); // 1%
 const reserveTradeFeeBps = BigInt(400); // 4%
 const graduationThreshold = BigInt(85) * LAMPORTS_PER_SOL;
+const keeperRewardBps = BigInt(50); // 0.5% of a curve's SOL to whoever migrates it
+const migrationFeeLamports = LAMPORTS_PER_SOL / BigInt(10); // Platform's cut at migration
+const creatorTradeFeeBps = BigInt(0);
+const referralFeeBps = BigInt(2000); // 20% of the platform fee to a trade's referrer
+const curveKinds = [true, true, false]; // Constant product and linear launches
+// Creators may pick their own launch economics between half and twice the defaults
+const minCurveParams = {
+  initialVirtualTokenReserves: initialVirtualTokenReserves / BigInt(2),
+  initialVirtualSolReserves: initialVirtualSolReserves / BigInt(2),
+  tokenTotalSupply: tokenTotalSupply / BigInt(2),
+  graduationThreshold: graduationThreshold / BigInt(2),
+};
+const maxCurveParams = {
+  initialVirtualTokenReserves: initialVirtualTokenReserves * BigInt(2),
+  initialVirtualSolReserves: initialVirtualSolReserves * BigInt(2),
+  tokenTotalSupply: tokenTotalSupply * BigInt(2),
+  graduationThreshold: graduationThreshold * BigInt(2),
+};
 
 // Token program address
 const TOKEN_PROGRAM_ID = publicKey(
@@ -156,11 +178,7 @@ async function main() {
 
     await umi.rpc.airdrop(buyer.publicKey, sol(10), options.confirm);
     console.log(`   ✅ Airdropped 10 SOL to buyer: ${buyer.publicKey}`);
-
-    // Fee recipient is the same as authority, no need to airdrop separately
-    console.log(
-      `   ✅ Fee recipient is authority: ${feeRecipient.publicKey}\n`
-    );
+    console.log("");
   } catch (error) {
     console.error("   ❌ Error airdropping funds:", error);
     throw error;
@@ -187,8 +205,16 @@ async function main() {
       tokenTotalSupply,
       platformTradeFeeBps,
       reserveTradeFeeBps,
-      platformFeeRecipient: feeRecipient.publicKey,
       graduationThreshold,
+      lpPolicy: lpPolicy("PermanentLock"),
+      keeperRewardBps,
+      surplusTokenPolicy: SurplusTokenPolicy.Burn,
+      migrationFeeLamports,
+      curveKinds,
+      minCurveParams,
+      maxCurveParams,
+      creatorTradeFeeBps,
+      referralFeeBps,
     }).sendAndConfirm(umi, options);
 
     console.log(`   ✅ Initialize successful!\n`);
@@ -230,6 +256,14 @@ async function main() {
       tokenName: "Test Token",
       tokenSymbol: "TEST",
       tokenUri: "https://test.com/token.json",
+      migrationTarget: MigrationTarget.DammV2,
+      curveKind: curveKind("ConstantProduct"),
+      curveParams: null,
+      graduationMode: graduationMode("SolThreshold"),
+      launchWindow: null,
+      tradingStartsAt: tradingStart("Immediately"),
+      launchFee: null,
+      reservePolicy: ReservePolicy.Accumulate,
     }).sendAndConfirm(umi, options);
 
     console.log(`   ✅ Create successful!`);
@@ -280,7 +314,6 @@ async function main() {
       userAta: buyerAta,
       mint: mint.publicKey,
       global: globalPda,
-      platformFeeRecipient: feeRecipient.publicKey,
       globalReserve: globalReservePda,
       reserveAta,
       tokenProgram: TOKEN_PROGRAM_ID,
@@ -328,7 +361,6 @@ async function main() {
       userAta: buyerAta,
       mint: mint.publicKey,
       global: globalPda,
-      platformFeeRecipient: feeRecipient.publicKey,
       globalReserve: globalReservePda,
       reserveAta,
       tokenProgram: TOKEN_PROGRAM_ID,
@@ -384,7 +416,6 @@ async function main() {
       userAta: buyerAta,
       mint: mint.publicKey,
       global: globalPda,
-      platformFeeRecipient: feeRecipient.publicKey,
       globalReserve: globalReservePda,
       reserveAta,
       tokenProgram: TOKEN_PROGRAM_ID,
@@ -414,13 +445,21 @@ async function main() {
     await updateGlobalConfig(umi, {
       authority,
       newAuthority: authority.publicKey,
-      newPlatformFeeRecipient: feeRecipient.publicKey,
       newPlatformTradeFeeBps: newPlatformFeeBps,
       newReserveTradeFeeBps: newReserveFeeBps,
       newInitialVirtualTokenReserves: initialVirtualTokenReserves,
       newInitialVirtualSolReserves: initialVirtualSolReserves,
       newTokenTotalSupply: tokenTotalSupply,
       newGraduationThreshold: graduationThreshold,
+      newLpPolicy: lpPolicy("PermanentLock"),
+      newKeeperRewardBps: keeperRewardBps,
+      newSurplusTokenPolicy: SurplusTokenPolicy.Burn,
+      newMigrationFeeLamports: migrationFeeLamports,
+      newCurveKinds: curveKinds,
+      newMinCurveParams: minCurveParams,
+      newMaxCurveParams: maxCurveParams,
+      newCreatorTradeFeeBps: creatorTradeFeeBps,
+      newReferralFeeBps: referralFeeBps,
     }).sendAndConfirm(umi, options);
 
     console.log(`   ✅ Update global config successful!\n`);
@@ -430,160 +469,10 @@ async function main() {
   }
 
   //// ========================================
-  //// 8. WITHDRAW (after graduation)
-  //// ========================================
-  console.log("7️⃣  Testing withdraw instruction...");
-  try {
-    // Create a new token for withdrawal test
-    const withdrawMint = generateSigner(umi);
-    const globalPda = findPda([
-      string({ size: "variable" }).serialize("global"),
-    ]);
-    const globalReservePda = findPda([
-      string({ size: "variable" }).serialize("reserve"),
-    ]);
-
-    const withdrawBondingCurvePda = umi.eddsa.findPda(
-      getCoinfunProgramId(umi),
-      [
-        string({ size: "variable" }).serialize("bonding_curve"),
-        publicKeySerializer().serialize(withdrawMint.publicKey),
-      ]
-    )[0];
-
-    const withdrawBondingCurveAta = getAssociatedTokenAddress(
-      withdrawMint.publicKey,
-      withdrawBondingCurvePda
-    );
-
-    const withdrawMetadataPda = getMetadataPda(withdrawMint.publicKey);
-
-    // Create the token
-    await create(umi, {
-      signer: authority,
-      creator: creator.publicKey,
-      mint: withdrawMint,
-      global: globalPda,
-      bondingCurve: withdrawBondingCurvePda,
-      bondingCurveAta: withdrawBondingCurveAta,
-      metadataAccount: withdrawMetadataPda,
-      tokenProgram: TOKEN_PROGRAM_ID,
-      tokenName: "Withdraw Test Token",
-      tokenSymbol: "WTH",
-      tokenUri: "https://test.com/withdraw.json",
-    }).sendAndConfirm(umi, options);
-
-    // Graduate the curve by buying enough SOL
-    const graduateBuyer = generateSigner(umi);
-    await umi.rpc.airdrop(graduateBuyer.publicKey, sol(100), options.confirm);
-    umi.use(keypairIdentity(graduateBuyer));
-
-    const graduateBuyerAta = getAssociatedTokenAddress(
-      withdrawMint.publicKey,
-      graduateBuyer.publicKey
-    );
-    const withdrawReserveAta = getAssociatedTokenAddress(
-      withdrawMint.publicKey,
-      globalReservePda
-    );
-
-    const graduateAmount = BigInt(90) * LAMPORTS_PER_SOL;
-
-    await buy(umi, {
-      signer: graduateBuyer,
-      bondingCurve: withdrawBondingCurvePda,
-      bondingCurveAta: withdrawBondingCurveAta,
-      userAta: graduateBuyerAta,
-      mint: withdrawMint.publicKey,
-      global: globalPda,
-      platformFeeRecipient: feeRecipient.publicKey,
-      globalReserve: globalReservePda,
-      reserveAta: withdrawReserveAta,
-      tokenProgram: TOKEN_PROGRAM_ID,
-      solAmount: graduateAmount,
-      minTokenOutput: BigInt(0),
-    }).sendAndConfirm(umi, options);
-
-    console.log(`   ✅ Curve graduated`);
-
-    // No cooldown needed anymore - withdraw immediately
-    umi.use(keypairIdentity(authority));
-
-    const authorityAta = getAssociatedTokenAddress(
-      withdrawMint.publicKey,
-      authority.publicKey
-    );
-
-    // Check if authority ATA exists, create it if not
-    const authorityAtaAccount = await umi.rpc.getAccount(authorityAta);
-    if (!authorityAtaAccount.exists) {
-      console.log("   Creating authority ATA...");
-      // Create associated token account instruction
-      // The createAssociatedTokenAccount instruction has no data and uses these accounts:
-      // 0. Payer (signer, writable)
-      // 1. ATA (writable)
-      // 2. Owner (readonly)
-      // 3. Mint (readonly)
-      // 4. System Program (readonly)
-      // 5. Token Program (readonly)
-      const SYSTEM_PROGRAM_ID = publicKey("11111111111111111111111111111111");
-
-      const createAtaTx = transactionBuilder([
-        {
-          instruction: {
-            programId: ASSOCIATED_TOKEN_PROGRAM_ID,
-            keys: [
-              { pubkey: authority.publicKey, isSigner: true, isWritable: true },
-              { pubkey: authorityAta, isSigner: false, isWritable: true },
-              {
-                pubkey: authority.publicKey,
-                isSigner: false,
-                isWritable: false,
-              },
-              {
-                pubkey: withdrawMint.publicKey,
-                isSigner: false,
-                isWritable: false,
-              },
-              { pubkey: SYSTEM_PROGRAM_ID, isSigner: false, isWritable: false },
-              { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
-            ],
-            data: new Uint8Array(),
-          },
-          signers: [authority],
-          bytesCreatedOnChain: 0,
-        },
-      ]);
-
-      await createAtaTx.sendAndConfirm(umi, options);
-      console.log("   ✅ Authority ATA created");
-    }
-
-    // Build withdraw transaction
-    const withdrawTx = withdraw(umi, {
-      authority,
-      global: globalPda,
-      mint: withdrawMint.publicKey,
-      bondingCurve: withdrawBondingCurvePda,
-      bondingCurveAta: withdrawBondingCurveAta,
-      authorityAta,
-      tokenProgram: TOKEN_PROGRAM_ID,
-    });
-
-    await withdrawTx.sendAndConfirm(umi, options);
-
-    console.log(`   ✅ Withdraw successful!\n`);
-  } catch (error) {
-    console.error(`   ❌ Withdraw failed:`, error);
-    throw error;
-  }
-
-  //// ========================================
-  //// 9. WITHDRAW RESERVE
+  //// 8. WITHDRAW RESERVE
   //// ========================================
-  console.log("8️⃣  Testing withdrawReserve instruction...");
+  console.log("7️⃣  Testing withdrawReserve instruction...");
   try {
-    // Use the withdraw mint's reserve ATA
     const withdrawMint = (global as any).testMint; // Can reuse the first test mint
     const globalPda = findPda([
       string({ size: "variable" }).serialize("global"),
@@ -648,6 +537,9 @@ async function main() {
       globalReserve: globalReservePda,
       mint: withdrawMint.publicKey,
       bondingCurve: bondingCurvePda,
+      // The test mint has no stake pool; the program ID stands in for the
+      // absent account instead of the derived pool address
+      stakePool: getCoinfunProgramId(umi),
       reserveAta,
       authorityAta,
       tokenProgram: TOKEN_PROGRAM_ID,
@@ -661,9 +553,9 @@ async function main() {
   }
 
   //// ========================================
-  //// 10. DEPOSIT TO RESERVE
+  //// 9. DEPOSIT TO RESERVE
   //// ========================================
-  console.log("9️⃣  Testing depositToReserve instruction...");
+  console.log("8️⃣  Testing depositToReserve instruction...");
   try {
     // This simulates the off-chain process after Meteora DAMMV2 graduation:
     // 1. Liquidity moves to Meteora DAMMV2 pool (5% fee)
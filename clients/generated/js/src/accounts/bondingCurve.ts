@@ -17,8 +17,6 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
-  getBooleanDecoder,
-  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
@@ -28,16 +26,54 @@ import {
   transformEncoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
   type ReadonlyUint8Array,
 } from '@solana/kit';
+import {
+  getCurveKindDecoder,
+  getCurveKindEncoder,
+  getCurveStatusDecoder,
+  getCurveStatusEncoder,
+  getGraduationModeDecoder,
+  getGraduationModeEncoder,
+  getLaunchFeeDecoder,
+  getLaunchFeeEncoder,
+  getLaunchWindowDecoder,
+  getLaunchWindowEncoder,
+  getLpPolicyDecoder,
+  getLpPolicyEncoder,
+  getMigrationTargetDecoder,
+  getMigrationTargetEncoder,
+  getReservePolicyDecoder,
+  getReservePolicyEncoder,
+  getTradingStartDecoder,
+  getTradingStartEncoder,
+  type CurveKind,
+  type CurveKindArgs,
+  type CurveStatus,
+  type CurveStatusArgs,
+  type GraduationMode,
+  type GraduationModeArgs,
+  type LaunchFee,
+  type LaunchFeeArgs,
+  type LaunchWindow,
+  type LaunchWindowArgs,
+  type LpPolicy,
+  type LpPolicyArgs,
+  type MigrationTarget,
+  type MigrationTargetArgs,
+  type ReservePolicy,
+  type ReservePolicyArgs,
+  type TradingStart,
+  type TradingStartArgs,
+} from '../types';
 
 export const BONDING_CURVE_DISCRIMINATOR = new Uint8Array([
   23, 183, 248, 55, 96, 216, 172, 96,
@@ -58,7 +94,23 @@ export type BondingCurve = {
   realTokenReserves: bigint;
   realSolReserves: bigint;
   tokenTotalSupply: bigint;
-  complete: boolean;
+  status: CurveStatus;
+  migrationTarget: MigrationTarget;
+  lpPolicy: LpPolicy;
+  lpUnlockSlot: bigint;
+  lpMint: Address;
+  curveKind: CurveKind;
+  graduationThreshold: bigint;
+  platformTradeFeeBps: bigint;
+  reserveTradeFeeBps: bigint;
+  graduationMode: GraduationMode;
+  createdSlot: bigint;
+  launchWindow: LaunchWindow;
+  tradingStartsAt: TradingStart;
+  tradingOpenedSlot: bigint;
+  launchFee: LaunchFee;
+  creatorTradeFeeBps: bigint;
+  reservePolicy: ReservePolicy;
 };
 
 export type BondingCurveArgs = {
@@ -69,10 +121,26 @@ export type BondingCurveArgs = {
   realTokenReserves: number | bigint;
   realSolReserves: number | bigint;
   tokenTotalSupply: number | bigint;
-  complete: boolean;
+  status: CurveStatusArgs;
+  migrationTarget: MigrationTargetArgs;
+  lpPolicy: LpPolicyArgs;
+  lpUnlockSlot: number | bigint;
+  lpMint: Address;
+  curveKind: CurveKindArgs;
+  graduationThreshold: number | bigint;
+  platformTradeFeeBps: number | bigint;
+  reserveTradeFeeBps: number | bigint;
+  graduationMode: GraduationModeArgs;
+  createdSlot: number | bigint;
+  launchWindow: LaunchWindowArgs;
+  tradingStartsAt: TradingStartArgs;
+  tradingOpenedSlot: number | bigint;
+  launchFee: LaunchFeeArgs;
+  creatorTradeFeeBps: number | bigint;
+  reservePolicy: ReservePolicyArgs;
 };
 
-export function getBondingCurveEncoder(): FixedSizeEncoder<BondingCurveArgs> {
+export function getBondingCurveEncoder(): Encoder<BondingCurveArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
@@ -83,13 +151,29 @@ export function getBondingCurveEncoder(): FixedSizeEncoder<BondingCurveArgs> {
       ['realTokenReserves', getU64Encoder()],
       ['realSolReserves', getU64Encoder()],
       ['tokenTotalSupply', getU64Encoder()],
-      ['complete', getBooleanEncoder()],
+      ['status', getCurveStatusEncoder()],
+      ['migrationTarget', getMigrationTargetEncoder()],
+      ['lpPolicy', getLpPolicyEncoder()],
+      ['lpUnlockSlot', getU64Encoder()],
+      ['lpMint', getAddressEncoder()],
+      ['curveKind', getCurveKindEncoder()],
+      ['graduationThreshold', getU64Encoder()],
+      ['platformTradeFeeBps', getU64Encoder()],
+      ['reserveTradeFeeBps', getU64Encoder()],
+      ['graduationMode', getGraduationModeEncoder()],
+      ['createdSlot', getU64Encoder()],
+      ['launchWindow', getLaunchWindowEncoder()],
+      ['tradingStartsAt', getTradingStartEncoder()],
+      ['tradingOpenedSlot', getU64Encoder()],
+      ['launchFee', getLaunchFeeEncoder()],
+      ['creatorTradeFeeBps', getU64Encoder()],
+      ['reservePolicy', getReservePolicyEncoder()],
     ]),
     (value) => ({ ...value, discriminator: BONDING_CURVE_DISCRIMINATOR })
   );
 }
 
-export function getBondingCurveDecoder(): FixedSizeDecoder<BondingCurve> {
+export function getBondingCurveDecoder(): Decoder<BondingCurve> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['mint', getAddressDecoder()],
@@ -99,14 +183,27 @@ export function getBondingCurveDecoder(): FixedSizeDecoder<BondingCurve> {
     ['realTokenReserves', getU64Decoder()],
     ['realSolReserves', getU64Decoder()],
     ['tokenTotalSupply', getU64Decoder()],
-    ['complete', getBooleanDecoder()],
+    ['status', getCurveStatusDecoder()],
+    ['migrationTarget', getMigrationTargetDecoder()],
+    ['lpPolicy', getLpPolicyDecoder()],
+    ['lpUnlockSlot', getU64Decoder()],
+    ['lpMint', getAddressDecoder()],
+    ['curveKind', getCurveKindDecoder()],
+    ['graduationThreshold', getU64Decoder()],
+    ['platformTradeFeeBps', getU64Decoder()],
+    ['reserveTradeFeeBps', getU64Decoder()],
+    ['graduationMode', getGraduationModeDecoder()],
+    ['createdSlot', getU64Decoder()],
+    ['launchWindow', getLaunchWindowDecoder()],
+    ['tradingStartsAt', getTradingStartDecoder()],
+    ['tradingOpenedSlot', getU64Decoder()],
+    ['launchFee', getLaunchFeeDecoder()],
+    ['creatorTradeFeeBps', getU64Decoder()],
+    ['reservePolicy', getReservePolicyDecoder()],
   ]);
 }
 
-export function getBondingCurveCodec(): FixedSizeCodec<
-  BondingCurveArgs,
-  BondingCurve
-> {
+export function getBondingCurveCodec(): Codec<BondingCurveArgs, BondingCurve> {
   return combineCodec(getBondingCurveEncoder(), getBondingCurveDecoder());
 }
 
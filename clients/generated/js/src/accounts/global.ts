@@ -17,6 +17,10 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
@@ -26,16 +30,34 @@ import {
   transformEncoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
   type ReadonlyUint8Array,
 } from '@solana/kit';
+import {
+  getCurveParamsDecoder,
+  getCurveParamsEncoder,
+  getLpPolicyDecoder,
+  getLpPolicyEncoder,
+  getPlatformFeeRecipientDecoder,
+  getPlatformFeeRecipientEncoder,
+  getSurplusTokenPolicyDecoder,
+  getSurplusTokenPolicyEncoder,
+  type CurveParams,
+  type CurveParamsArgs,
+  type LpPolicy,
+  type LpPolicyArgs,
+  type PlatformFeeRecipient,
+  type PlatformFeeRecipientArgs,
+  type SurplusTokenPolicy,
+  type SurplusTokenPolicyArgs,
+} from '../types';
 
 export const GLOBAL_DISCRIMINATOR = new Uint8Array([
   167, 232, 232, 177, 200, 108, 114, 127,
@@ -48,7 +70,6 @@ export function getGlobalDiscriminatorBytes() {
 export type Global = {
   discriminator: ReadonlyUint8Array;
   authority: Address;
-  platformFeeRecipient: Address;
   reserve: Address;
   initialVirtualTokenReserves: bigint;
   initialVirtualSolReserves: bigint;
@@ -56,11 +77,25 @@ export type Global = {
   platformTradeFeeBps: bigint;
   reserveTradeFeeBps: bigint;
   graduationThreshold: bigint;
+  migrationPrograms: Array<Address>;
+  lpPolicy: LpPolicy;
+  keeperRewardBps: bigint;
+  migrationConfigs: Array<Address>;
+  surplusTokenPolicy: SurplusTokenPolicy;
+  migrationFeeLamports: bigint;
+  curveKinds: Array<boolean>;
+  minCurveParams: CurveParams;
+  maxCurveParams: CurveParams;
+  solUsdPriceFeed: Address;
+  maxPriceAgeSecs: bigint;
+  maxPriceConfidenceBps: bigint;
+  creatorTradeFeeBps: bigint;
+  referralFeeBps: bigint;
+  platformFeeRecipients: Array<PlatformFeeRecipient>;
 };
 
 export type GlobalArgs = {
   authority: Address;
-  platformFeeRecipient: Address;
   reserve: Address;
   initialVirtualTokenReserves: number | bigint;
   initialVirtualSolReserves: number | bigint;
@@ -68,14 +103,28 @@ export type GlobalArgs = {
   platformTradeFeeBps: number | bigint;
   reserveTradeFeeBps: number | bigint;
   graduationThreshold: number | bigint;
+  migrationPrograms: Array<Address>;
+  lpPolicy: LpPolicyArgs;
+  keeperRewardBps: number | bigint;
+  migrationConfigs: Array<Address>;
+  surplusTokenPolicy: SurplusTokenPolicyArgs;
+  migrationFeeLamports: number | bigint;
+  curveKinds: Array<boolean>;
+  minCurveParams: CurveParamsArgs;
+  maxCurveParams: CurveParamsArgs;
+  solUsdPriceFeed: Address;
+  maxPriceAgeSecs: number | bigint;
+  maxPriceConfidenceBps: number | bigint;
+  creatorTradeFeeBps: number | bigint;
+  referralFeeBps: number | bigint;
+  platformFeeRecipients: Array<PlatformFeeRecipientArgs>;
 };
 
-export function getGlobalEncoder(): FixedSizeEncoder<GlobalArgs> {
+export function getGlobalEncoder(): Encoder<GlobalArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['authority', getAddressEncoder()],
-      ['platformFeeRecipient', getAddressEncoder()],
       ['reserve', getAddressEncoder()],
       ['initialVirtualTokenReserves', getU64Encoder()],
       ['initialVirtualSolReserves', getU64Encoder()],
@@ -83,16 +132,33 @@ export function getGlobalEncoder(): FixedSizeEncoder<GlobalArgs> {
       ['platformTradeFeeBps', getU64Encoder()],
       ['reserveTradeFeeBps', getU64Encoder()],
       ['graduationThreshold', getU64Encoder()],
+      ['migrationPrograms', getArrayEncoder(getAddressEncoder(), { size: 3 })],
+      ['lpPolicy', getLpPolicyEncoder()],
+      ['keeperRewardBps', getU64Encoder()],
+      ['migrationConfigs', getArrayEncoder(getAddressEncoder(), { size: 3 })],
+      ['surplusTokenPolicy', getSurplusTokenPolicyEncoder()],
+      ['migrationFeeLamports', getU64Encoder()],
+      ['curveKinds', getArrayEncoder(getBooleanEncoder(), { size: 3 })],
+      ['minCurveParams', getCurveParamsEncoder()],
+      ['maxCurveParams', getCurveParamsEncoder()],
+      ['solUsdPriceFeed', getAddressEncoder()],
+      ['maxPriceAgeSecs', getU64Encoder()],
+      ['maxPriceConfidenceBps', getU64Encoder()],
+      ['creatorTradeFeeBps', getU64Encoder()],
+      ['referralFeeBps', getU64Encoder()],
+      [
+        'platformFeeRecipients',
+        getArrayEncoder(getPlatformFeeRecipientEncoder(), { size: 5 }),
+      ],
     ]),
     (value) => ({ ...value, discriminator: GLOBAL_DISCRIMINATOR })
   );
 }
 
-export function getGlobalDecoder(): FixedSizeDecoder<Global> {
+export function getGlobalDecoder(): Decoder<Global> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['authority', getAddressDecoder()],
-    ['platformFeeRecipient', getAddressDecoder()],
     ['reserve', getAddressDecoder()],
     ['initialVirtualTokenReserves', getU64Decoder()],
     ['initialVirtualSolReserves', getU64Decoder()],
@@ -100,10 +166,28 @@ export function getGlobalDecoder(): FixedSizeDecoder<Global> {
     ['platformTradeFeeBps', getU64Decoder()],
     ['reserveTradeFeeBps', getU64Decoder()],
     ['graduationThreshold', getU64Decoder()],
+    ['migrationPrograms', getArrayDecoder(getAddressDecoder(), { size: 3 })],
+    ['lpPolicy', getLpPolicyDecoder()],
+    ['keeperRewardBps', getU64Decoder()],
+    ['migrationConfigs', getArrayDecoder(getAddressDecoder(), { size: 3 })],
+    ['surplusTokenPolicy', getSurplusTokenPolicyDecoder()],
+    ['migrationFeeLamports', getU64Decoder()],
+    ['curveKinds', getArrayDecoder(getBooleanDecoder(), { size: 3 })],
+    ['minCurveParams', getCurveParamsDecoder()],
+    ['maxCurveParams', getCurveParamsDecoder()],
+    ['solUsdPriceFeed', getAddressDecoder()],
+    ['maxPriceAgeSecs', getU64Decoder()],
+    ['maxPriceConfidenceBps', getU64Decoder()],
+    ['creatorTradeFeeBps', getU64Decoder()],
+    ['referralFeeBps', getU64Decoder()],
+    [
+      'platformFeeRecipients',
+      getArrayDecoder(getPlatformFeeRecipientDecoder(), { size: 5 }),
+    ],
   ]);
 }
 
-export function getGlobalCodec(): FixedSizeCodec<GlobalArgs, Global> {
+export function getGlobalCodec(): Codec<GlobalArgs, Global> {
   return combineCodec(getGlobalEncoder(), getGlobalDecoder());
 }
 
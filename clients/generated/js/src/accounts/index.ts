@@ -8,3 +8,7 @@
 
 export * from './bondingCurve';
 export * from './global';
+export * from './launchAllocation';
+export * from './referrer';
+export * from './stakePool';
+export * from './stakePosition';
@@ -24,15 +24,114 @@ export const COINFUN_ERROR__NOTHING_TO_WITHDRAW = 0x1772; // 6002
 export const COINFUN_ERROR__BONDING_CURVE_NOT_COMPLETE = 0x1773; // 6003
 /** FeeTooHigh: Fee basis points cannot exceed 3000 (30%) */
 export const COINFUN_ERROR__FEE_TOO_HIGH = 0x1774; // 6004
-/** InvalidTokenReserveConfiguration: Total supply must be greater than the initial real token reserves. */
+/** InvalidTokenReserveConfiguration: Initial virtual token reserves must be greater than the total supply */
 export const COINFUN_ERROR__INVALID_TOKEN_RESERVE_CONFIGURATION = 0x1775; // 6005
+/** NothingToMigrate: Bonding curve has nothing left to migrate */
+export const COINFUN_ERROR__NOTHING_TO_MIGRATE = 0x1776; // 6006
+/** PoolPriceOutOfRange: Pool price derived from the curve is out of range */
+export const COINFUN_ERROR__POOL_PRICE_OUT_OF_RANGE = 0x1777; // 6007
+/** InvalidCurveStatusTransition: Bonding curve cannot move to that status */
+export const COINFUN_ERROR__INVALID_CURVE_STATUS_TRANSITION = 0x1778; // 6008
+/** CurveNotRefunding: Bonding curve is not refunding */
+export const COINFUN_ERROR__CURVE_NOT_REFUNDING = 0x1779; // 6009
+/** MigrationTargetNotAllowed: Migration target is not enabled */
+export const COINFUN_ERROR__MIGRATION_TARGET_NOT_ALLOWED = 0x177a; // 6010
+/** MissingMigrationAccounts: Not enough remaining accounts for the migration target */
+export const COINFUN_ERROR__MISSING_MIGRATION_ACCOUNTS = 0x177b; // 6011
+/** InvalidMigrationAccount: Unexpected account passed for the migration target */
+export const COINFUN_ERROR__INVALID_MIGRATION_ACCOUNT = 0x177c; // 6012
+/** LpPolicyNotSupported: LP policy is not supported by the migration target */
+export const COINFUN_ERROR__LP_POLICY_NOT_SUPPORTED = 0x177d; // 6013
+/** LpFeeClaimNotSupported: Migration target has no LP fees to claim */
+export const COINFUN_ERROR__LP_FEE_CLAIM_NOT_SUPPORTED = 0x177e; // 6014
+/** LpLocked: LP position is locked */
+export const COINFUN_ERROR__LP_LOCKED = 0x177f; // 6015
+/** CurveNotMigrated: Bonding curve has not been migrated */
+export const COINFUN_ERROR__CURVE_NOT_MIGRATED = 0x1780; // 6016
+/** PoolPriceMismatch: The curve's DEX pool already exists at another price */
+export const COINFUN_ERROR__POOL_PRICE_MISMATCH = 0x1781; // 6017
+/** TokenAmountUnavailable: Bonding curve cannot fill that token amount before graduating */
+export const COINFUN_ERROR__TOKEN_AMOUNT_UNAVAILABLE = 0x1782; // 6018
+/** SolAmountUnavailable: Bonding curve cannot pay out that SOL amount */
+export const COINFUN_ERROR__SOL_AMOUNT_UNAVAILABLE = 0x1783; // 6019
+/** CurveKindNotAllowed: Curve kind not allowed */
+export const COINFUN_ERROR__CURVE_KIND_NOT_ALLOWED = 0x1784; // 6020
+/** InvalidCurveParams: Invalid curve parameters */
+export const COINFUN_ERROR__INVALID_CURVE_PARAMS = 0x1785; // 6021
+/** CurveParamsOutOfBounds: Curve parameters outside the allowed bounds */
+export const COINFUN_ERROR__CURVE_PARAMS_OUT_OF_BOUNDS = 0x1786; // 6022
+/** MissingPriceFeed: SOL/USD price feed is not configured or not passed */
+export const COINFUN_ERROR__MISSING_PRICE_FEED = 0x1787; // 6023
+/** InvalidPriceFeed: Invalid SOL/USD price feed account */
+export const COINFUN_ERROR__INVALID_PRICE_FEED = 0x1788; // 6024
+/** PriceFeedStale: SOL/USD price is too old */
+export const COINFUN_ERROR__PRICE_FEED_STALE = 0x1789; // 6025
+/** PriceFeedUncertain: SOL/USD price confidence interval is too wide */
+export const COINFUN_ERROR__PRICE_FEED_UNCERTAIN = 0x178a; // 6026
+/** InvalidLaunchWindow: Invalid launch window */
+export const COINFUN_ERROR__INVALID_LAUNCH_WINDOW = 0x178b; // 6027
+/** LaunchBuyTooLarge: Buy exceeds the launch window's SOL cap */
+export const COINFUN_ERROR__LAUNCH_BUY_TOO_LARGE = 0x178c; // 6028
+/** LaunchWalletCapExceeded: Wallet exceeds the launch window's token cap */
+export const COINFUN_ERROR__LAUNCH_WALLET_CAP_EXCEEDED = 0x178d; // 6029
+/** MissingLaunchAllocation: Launch window buys need the wallet's launch allocation account */
+export const COINFUN_ERROR__MISSING_LAUNCH_ALLOCATION = 0x178e; // 6030
+/** TradingNotStarted: Trading on this curve has not started yet */
+export const COINFUN_ERROR__TRADING_NOT_STARTED = 0x178f; // 6031
+/** InvalidLaunchFee: Invalid launch fee */
+export const COINFUN_ERROR__INVALID_LAUNCH_FEE = 0x1790; // 6032
+/** LaunchFeeTooHigh: Launch fee basis points cannot exceed 5000 (50%) */
+export const COINFUN_ERROR__LAUNCH_FEE_TOO_HIGH = 0x1791; // 6033
+/** SelfReferral: Traders cannot refer themselves */
+export const COINFUN_ERROR__SELF_REFERRAL = 0x1792; // 6034
+/** InvalidFeeRecipients: Invalid platform fee recipients */
+export const COINFUN_ERROR__INVALID_FEE_RECIPIENTS = 0x1793; // 6035
+/** InsufficientReserve: Amount exceeds what the reserve holds */
+export const COINFUN_ERROR__INSUFFICIENT_RESERVE = 0x1794; // 6036
+/** InsufficientStake: Unstake exceeds the staked amount */
+export const COINFUN_ERROR__INSUFFICIENT_STAKE = 0x1795; // 6037
+/** ReserveStaked: The mint's reserve belongs to its stakers */
+export const COINFUN_ERROR__RESERVE_STAKED = 0x1796; // 6038
 
 export type CoinfunError =
   | typeof COINFUN_ERROR__BONDING_CURVE_COMPLETE
   | typeof COINFUN_ERROR__BONDING_CURVE_NOT_COMPLETE
+  | typeof COINFUN_ERROR__CURVE_KIND_NOT_ALLOWED
+  | typeof COINFUN_ERROR__CURVE_NOT_MIGRATED
+  | typeof COINFUN_ERROR__CURVE_NOT_REFUNDING
+  | typeof COINFUN_ERROR__CURVE_PARAMS_OUT_OF_BOUNDS
   | typeof COINFUN_ERROR__FEE_TOO_HIGH
+  | typeof COINFUN_ERROR__INSUFFICIENT_RESERVE
+  | typeof COINFUN_ERROR__INSUFFICIENT_STAKE
+  | typeof COINFUN_ERROR__INVALID_CURVE_PARAMS
+  | typeof COINFUN_ERROR__INVALID_CURVE_STATUS_TRANSITION
+  | typeof COINFUN_ERROR__INVALID_FEE_RECIPIENTS
+  | typeof COINFUN_ERROR__INVALID_LAUNCH_FEE
+  | typeof COINFUN_ERROR__INVALID_LAUNCH_WINDOW
+  | typeof COINFUN_ERROR__INVALID_MIGRATION_ACCOUNT
+  | typeof COINFUN_ERROR__INVALID_PRICE_FEED
   | typeof COINFUN_ERROR__INVALID_TOKEN_RESERVE_CONFIGURATION
+  | typeof COINFUN_ERROR__LAUNCH_BUY_TOO_LARGE
+  | typeof COINFUN_ERROR__LAUNCH_FEE_TOO_HIGH
+  | typeof COINFUN_ERROR__LAUNCH_WALLET_CAP_EXCEEDED
+  | typeof COINFUN_ERROR__LP_FEE_CLAIM_NOT_SUPPORTED
+  | typeof COINFUN_ERROR__LP_LOCKED
+  | typeof COINFUN_ERROR__LP_POLICY_NOT_SUPPORTED
+  | typeof COINFUN_ERROR__MIGRATION_TARGET_NOT_ALLOWED
+  | typeof COINFUN_ERROR__MISSING_LAUNCH_ALLOCATION
+  | typeof COINFUN_ERROR__MISSING_MIGRATION_ACCOUNTS
+  | typeof COINFUN_ERROR__MISSING_PRICE_FEED
+  | typeof COINFUN_ERROR__NOTHING_TO_MIGRATE
   | typeof COINFUN_ERROR__NOTHING_TO_WITHDRAW
+  | typeof COINFUN_ERROR__POOL_PRICE_MISMATCH
+  | typeof COINFUN_ERROR__POOL_PRICE_OUT_OF_RANGE
+  | typeof COINFUN_ERROR__PRICE_FEED_STALE
+  | typeof COINFUN_ERROR__PRICE_FEED_UNCERTAIN
+  | typeof COINFUN_ERROR__RESERVE_STAKED
+  | typeof COINFUN_ERROR__SELF_REFERRAL
+  | typeof COINFUN_ERROR__SOL_AMOUNT_UNAVAILABLE
+  | typeof COINFUN_ERROR__TOKEN_AMOUNT_UNAVAILABLE
+  | typeof COINFUN_ERROR__TRADING_NOT_STARTED
   | typeof COINFUN_ERROR__UNAUTHORIZED;
 
 let coinfunErrorMessages: Record<CoinfunError, string> | undefined;
@@ -40,9 +139,42 @@ if (process.env.NODE_ENV !== 'production') {
   coinfunErrorMessages = {
     [COINFUN_ERROR__BONDING_CURVE_COMPLETE]: `Bonding curve is complete`,
     [COINFUN_ERROR__BONDING_CURVE_NOT_COMPLETE]: `Bonding curve is not complete`,
+    [COINFUN_ERROR__CURVE_KIND_NOT_ALLOWED]: `Curve kind not allowed`,
+    [COINFUN_ERROR__CURVE_NOT_MIGRATED]: `Bonding curve has not been migrated`,
+    [COINFUN_ERROR__CURVE_NOT_REFUNDING]: `Bonding curve is not refunding`,
+    [COINFUN_ERROR__CURVE_PARAMS_OUT_OF_BOUNDS]: `Curve parameters outside the allowed bounds`,
     [COINFUN_ERROR__FEE_TOO_HIGH]: `Fee basis points cannot exceed 3000 (30%)`,
-    [COINFUN_ERROR__INVALID_TOKEN_RESERVE_CONFIGURATION]: `Total supply must be greater than the initial real token reserves.`,
+    [COINFUN_ERROR__INSUFFICIENT_RESERVE]: `Amount exceeds what the reserve holds`,
+    [COINFUN_ERROR__INSUFFICIENT_STAKE]: `Unstake exceeds the staked amount`,
+    [COINFUN_ERROR__INVALID_CURVE_PARAMS]: `Invalid curve parameters`,
+    [COINFUN_ERROR__INVALID_CURVE_STATUS_TRANSITION]: `Bonding curve cannot move to that status`,
+    [COINFUN_ERROR__INVALID_FEE_RECIPIENTS]: `Invalid platform fee recipients`,
+    [COINFUN_ERROR__INVALID_LAUNCH_FEE]: `Invalid launch fee`,
+    [COINFUN_ERROR__INVALID_LAUNCH_WINDOW]: `Invalid launch window`,
+    [COINFUN_ERROR__INVALID_MIGRATION_ACCOUNT]: `Unexpected account passed for the migration target`,
+    [COINFUN_ERROR__INVALID_PRICE_FEED]: `Invalid SOL/USD price feed account`,
+    [COINFUN_ERROR__INVALID_TOKEN_RESERVE_CONFIGURATION]: `Initial virtual token reserves must be greater than the total supply`,
+    [COINFUN_ERROR__LAUNCH_BUY_TOO_LARGE]: `Buy exceeds the launch window's SOL cap`,
+    [COINFUN_ERROR__LAUNCH_FEE_TOO_HIGH]: `Launch fee basis points cannot exceed 5000 (50%)`,
+    [COINFUN_ERROR__LAUNCH_WALLET_CAP_EXCEEDED]: `Wallet exceeds the launch window's token cap`,
+    [COINFUN_ERROR__LP_FEE_CLAIM_NOT_SUPPORTED]: `Migration target has no LP fees to claim`,
+    [COINFUN_ERROR__LP_LOCKED]: `LP position is locked`,
+    [COINFUN_ERROR__LP_POLICY_NOT_SUPPORTED]: `LP policy is not supported by the migration target`,
+    [COINFUN_ERROR__MIGRATION_TARGET_NOT_ALLOWED]: `Migration target is not enabled`,
+    [COINFUN_ERROR__MISSING_LAUNCH_ALLOCATION]: `Launch window buys need the wallet's launch allocation account`,
+    [COINFUN_ERROR__MISSING_MIGRATION_ACCOUNTS]: `Not enough remaining accounts for the migration target`,
+    [COINFUN_ERROR__MISSING_PRICE_FEED]: `SOL/USD price feed is not configured or not passed`,
+    [COINFUN_ERROR__NOTHING_TO_MIGRATE]: `Bonding curve has nothing left to migrate`,
     [COINFUN_ERROR__NOTHING_TO_WITHDRAW]: `Nothing to withdraw`,
+    [COINFUN_ERROR__POOL_PRICE_MISMATCH]: `The curve's DEX pool already exists at another price`,
+    [COINFUN_ERROR__POOL_PRICE_OUT_OF_RANGE]: `Pool price derived from the curve is out of range`,
+    [COINFUN_ERROR__PRICE_FEED_STALE]: `SOL/USD price is too old`,
+    [COINFUN_ERROR__PRICE_FEED_UNCERTAIN]: `SOL/USD price confidence interval is too wide`,
+    [COINFUN_ERROR__RESERVE_STAKED]: `The mint's reserve belongs to its stakers`,
+    [COINFUN_ERROR__SELF_REFERRAL]: `Traders cannot refer themselves`,
+    [COINFUN_ERROR__SOL_AMOUNT_UNAVAILABLE]: `Bonding curve cannot pay out that SOL amount`,
+    [COINFUN_ERROR__TOKEN_AMOUNT_UNAVAILABLE]: `Bonding curve cannot fill that token amount before graduating`,
+    [COINFUN_ERROR__TRADING_NOT_STARTED]: `Trading on this curve has not started yet`,
     [COINFUN_ERROR__UNAUTHORIZED]: `You are not the vault owner`,
   };
 }
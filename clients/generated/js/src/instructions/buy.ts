@@ -57,9 +57,11 @@ export type BuyInstruction<
   TAccountUserAta extends string | AccountMeta<string> = string,
   TAccountMint extends string | AccountMeta<string> = string,
   TAccountGlobal extends string | AccountMeta<string> = string,
-  TAccountPlatformFeeRecipient extends string | AccountMeta<string> = string,
+  TAccountPlatformFeeVault extends string | AccountMeta<string> = string,
   TAccountGlobalReserve extends string | AccountMeta<string> = string,
   TAccountReserveAta extends string | AccountMeta<string> = string,
+  TAccountCreatorVault extends string | AccountMeta<string> = string,
+  TAccountReserveSolVault extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -67,6 +69,9 @@ export type BuyInstruction<
   TAccountAssociatedTokenProgram extends
     | string
     | AccountMeta<string> = 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
+  TAccountPriceFeed extends string | AccountMeta<string> = string,
+  TAccountLaunchAllocation extends string | AccountMeta<string> = string,
+  TAccountReferrer extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -86,20 +91,26 @@ export type BuyInstruction<
         ? WritableAccount<TAccountUserAta>
         : TAccountUserAta,
       TAccountMint extends string
-        ? ReadonlyAccount<TAccountMint>
+        ? WritableAccount<TAccountMint>
         : TAccountMint,
       TAccountGlobal extends string
         ? ReadonlyAccount<TAccountGlobal>
         : TAccountGlobal,
-      TAccountPlatformFeeRecipient extends string
-        ? WritableAccount<TAccountPlatformFeeRecipient>
-        : TAccountPlatformFeeRecipient,
+      TAccountPlatformFeeVault extends string
+        ? WritableAccount<TAccountPlatformFeeVault>
+        : TAccountPlatformFeeVault,
       TAccountGlobalReserve extends string
         ? ReadonlyAccount<TAccountGlobalReserve>
         : TAccountGlobalReserve,
       TAccountReserveAta extends string
         ? WritableAccount<TAccountReserveAta>
         : TAccountReserveAta,
+      TAccountCreatorVault extends string
+        ? WritableAccount<TAccountCreatorVault>
+        : TAccountCreatorVault,
+      TAccountReserveSolVault extends string
+        ? WritableAccount<TAccountReserveSolVault>
+        : TAccountReserveSolVault,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -109,6 +120,15 @@ export type BuyInstruction<
       TAccountAssociatedTokenProgram extends string
         ? ReadonlyAccount<TAccountAssociatedTokenProgram>
         : TAccountAssociatedTokenProgram,
+      TAccountPriceFeed extends string
+        ? ReadonlyAccount<TAccountPriceFeed>
+        : TAccountPriceFeed,
+      TAccountLaunchAllocation extends string
+        ? WritableAccount<TAccountLaunchAllocation>
+        : TAccountLaunchAllocation,
+      TAccountReferrer extends string
+        ? WritableAccount<TAccountReferrer>
+        : TAccountReferrer,
       ...TRemainingAccounts,
     ]
   >;
@@ -160,12 +180,17 @@ export type BuyAsyncInput<
   TAccountUserAta extends string = string,
   TAccountMint extends string = string,
   TAccountGlobal extends string = string,
-  TAccountPlatformFeeRecipient extends string = string,
+  TAccountPlatformFeeVault extends string = string,
   TAccountGlobalReserve extends string = string,
   TAccountReserveAta extends string = string,
+  TAccountCreatorVault extends string = string,
+  TAccountReserveSolVault extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
+  TAccountPriceFeed extends string = string,
+  TAccountLaunchAllocation extends string = string,
+  TAccountReferrer extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   bondingCurve?: Address<TAccountBondingCurve>;
@@ -173,12 +198,18 @@ export type BuyAsyncInput<
   userAta?: Address<TAccountUserAta>;
   mint: Address<TAccountMint>;
   global?: Address<TAccountGlobal>;
-  platformFeeRecipient: Address<TAccountPlatformFeeRecipient>;
+  platformFeeVault?: Address<TAccountPlatformFeeVault>;
   globalReserve?: Address<TAccountGlobalReserve>;
   reserveAta?: Address<TAccountReserveAta>;
+  creatorVault?: Address<TAccountCreatorVault>;
+  reserveSolVault?: Address<TAccountReserveSolVault>;
   systemProgram?: Address<TAccountSystemProgram>;
   tokenProgram: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
+  /** `Global.sol_usd_price_feed` and decoded in `Global::sol_usd_price` */
+  priceFeed?: Address<TAccountPriceFeed>;
+  launchAllocation?: Address<TAccountLaunchAllocation>;
+  referrer?: Address<TAccountReferrer>;
   solAmount: BuyInstructionDataArgs['solAmount'];
   minTokenOutput: BuyInstructionDataArgs['minTokenOutput'];
 };
@@ -190,12 +221,17 @@ export async function getBuyInstructionAsync<
   TAccountUserAta extends string,
   TAccountMint extends string,
   TAccountGlobal extends string,
-  TAccountPlatformFeeRecipient extends string,
+  TAccountPlatformFeeVault extends string,
   TAccountGlobalReserve extends string,
   TAccountReserveAta extends string,
+  TAccountCreatorVault extends string,
+  TAccountReserveSolVault extends string,
   TAccountSystemProgram extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
+  TAccountPriceFeed extends string,
+  TAccountLaunchAllocation extends string,
+  TAccountReferrer extends string,
   TProgramAddress extends Address = typeof COINFUN_PROGRAM_ADDRESS,
 >(
   input: BuyAsyncInput<
@@ -205,12 +241,17 @@ export async function getBuyInstructionAsync<
     TAccountUserAta,
     TAccountMint,
     TAccountGlobal,
-    TAccountPlatformFeeRecipient,
+    TAccountPlatformFeeVault,
     TAccountGlobalReserve,
     TAccountReserveAta,
+    TAccountCreatorVault,
+    TAccountReserveSolVault,
     TAccountSystemProgram,
     TAccountTokenProgram,
-    TAccountAssociatedTokenProgram
+    TAccountAssociatedTokenProgram,
+    TAccountPriceFeed,
+    TAccountLaunchAllocation,
+    TAccountReferrer
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -222,12 +263,17 @@ export async function getBuyInstructionAsync<
     TAccountUserAta,
     TAccountMint,
     TAccountGlobal,
-    TAccountPlatformFeeRecipient,
+    TAccountPlatformFeeVault,
     TAccountGlobalReserve,
     TAccountReserveAta,
+    TAccountCreatorVault,
+    TAccountReserveSolVault,
     TAccountSystemProgram,
     TAccountTokenProgram,
-    TAccountAssociatedTokenProgram
+    TAccountAssociatedTokenProgram,
+    TAccountPriceFeed,
+    TAccountLaunchAllocation,
+    TAccountReferrer
   >
 > {
   // Program address.
@@ -239,20 +285,28 @@ export async function getBuyInstructionAsync<
     bondingCurve: { value: input.bondingCurve ?? null, isWritable: true },
     bondingCurveAta: { value: input.bondingCurveAta ?? null, isWritable: true },
     userAta: { value: input.userAta ?? null, isWritable: true },
-    mint: { value: input.mint ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: true },
     global: { value: input.global ?? null, isWritable: false },
-    platformFeeRecipient: {
-      value: input.platformFeeRecipient ?? null,
+    platformFeeVault: {
+      value: input.platformFeeVault ?? null,
       isWritable: true,
     },
     globalReserve: { value: input.globalReserve ?? null, isWritable: false },
     reserveAta: { value: input.reserveAta ?? null, isWritable: true },
+    creatorVault: { value: input.creatorVault ?? null, isWritable: true },
+    reserveSolVault: { value: input.reserveSolVault ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
       isWritable: false,
     },
+    priceFeed: { value: input.priceFeed ?? null, isWritable: false },
+    launchAllocation: {
+      value: input.launchAllocation ?? null,
+      isWritable: true,
+    },
+    referrer: { value: input.referrer ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -318,6 +372,19 @@ export async function getBuyInstructionAsync<
       ],
     });
   }
+  if (!accounts.platformFeeVault.value) {
+    accounts.platformFeeVault.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            112, 108, 97, 116, 102, 111, 114, 109, 95, 102, 101, 101, 95, 118,
+            97, 117, 108, 116,
+          ])
+        ),
+      ],
+    });
+  }
   if (!accounts.globalReserve.value) {
     accounts.globalReserve.value = await getProgramDerivedAddress({
       programAddress,
@@ -339,6 +406,32 @@ export async function getBuyInstructionAsync<
       ],
     });
   }
+  if (!accounts.creatorVault.value) {
+    accounts.creatorVault.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            99, 114, 101, 97, 116, 111, 114, 95, 118, 97, 117, 108, 116,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.bondingCurve.value)),
+      ],
+    });
+  }
+  if (!accounts.reserveSolVault.value) {
+    accounts.reserveSolVault.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            114, 101, 115, 101, 114, 118, 101, 95, 115, 111, 108, 95, 118, 97,
+            117, 108, 116,
+          ])
+        ),
+      ],
+    });
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -347,6 +440,21 @@ export async function getBuyInstructionAsync<
     accounts.associatedTokenProgram.value =
       'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
   }
+  if (!accounts.launchAllocation.value) {
+    accounts.launchAllocation.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            108, 97, 117, 110, 99, 104, 95, 97, 108, 108, 111, 99, 97, 116, 105,
+            111, 110,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.bondingCurve.value)),
+        getAddressEncoder().encode(expectAddress(accounts.signer.value)),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -357,12 +465,17 @@ export async function getBuyInstructionAsync<
       getAccountMeta(accounts.userAta),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.global),
-      getAccountMeta(accounts.platformFeeRecipient),
+      getAccountMeta(accounts.platformFeeVault),
       getAccountMeta(accounts.globalReserve),
       getAccountMeta(accounts.reserveAta),
+      getAccountMeta(accounts.creatorVault),
+      getAccountMeta(accounts.reserveSolVault),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
+      getAccountMeta(accounts.priceFeed),
+      getAccountMeta(accounts.launchAllocation),
+      getAccountMeta(accounts.referrer),
     ],
     data: getBuyInstructionDataEncoder().encode(args as BuyInstructionDataArgs),
     programAddress,
@@ -374,12 +487,17 @@ export async function getBuyInstructionAsync<
     TAccountUserAta,
     TAccountMint,
     TAccountGlobal,
-    TAccountPlatformFeeRecipient,
+    TAccountPlatformFeeVault,
     TAccountGlobalReserve,
     TAccountReserveAta,
+    TAccountCreatorVault,
+    TAccountReserveSolVault,
     TAccountSystemProgram,
     TAccountTokenProgram,
-    TAccountAssociatedTokenProgram
+    TAccountAssociatedTokenProgram,
+    TAccountPriceFeed,
+    TAccountLaunchAllocation,
+    TAccountReferrer
   >);
 }
 
@@ -390,12 +508,17 @@ export type BuyInput<
   TAccountUserAta extends string = string,
   TAccountMint extends string = string,
   TAccountGlobal extends string = string,
-  TAccountPlatformFeeRecipient extends string = string,
+  TAccountPlatformFeeVault extends string = string,
   TAccountGlobalReserve extends string = string,
   TAccountReserveAta extends string = string,
+  TAccountCreatorVault extends string = string,
+  TAccountReserveSolVault extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
+  TAccountPriceFeed extends string = string,
+  TAccountLaunchAllocation extends string = string,
+  TAccountReferrer extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   bondingCurve: Address<TAccountBondingCurve>;
@@ -403,12 +526,18 @@ export type BuyInput<
   userAta: Address<TAccountUserAta>;
   mint: Address<TAccountMint>;
   global: Address<TAccountGlobal>;
-  platformFeeRecipient: Address<TAccountPlatformFeeRecipient>;
+  platformFeeVault: Address<TAccountPlatformFeeVault>;
   globalReserve: Address<TAccountGlobalReserve>;
   reserveAta: Address<TAccountReserveAta>;
+  creatorVault: Address<TAccountCreatorVault>;
+  reserveSolVault: Address<TAccountReserveSolVault>;
   systemProgram?: Address<TAccountSystemProgram>;
   tokenProgram: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
+  /** `Global.sol_usd_price_feed` and decoded in `Global::sol_usd_price` */
+  priceFeed?: Address<TAccountPriceFeed>;
+  launchAllocation?: Address<TAccountLaunchAllocation>;
+  referrer?: Address<TAccountReferrer>;
   solAmount: BuyInstructionDataArgs['solAmount'];
   minTokenOutput: BuyInstructionDataArgs['minTokenOutput'];
 };
@@ -420,12 +549,17 @@ export function getBuyInstruction<
   TAccountUserAta extends string,
   TAccountMint extends string,
   TAccountGlobal extends string,
-  TAccountPlatformFeeRecipient extends string,
+  TAccountPlatformFeeVault extends string,
   TAccountGlobalReserve extends string,
   TAccountReserveAta extends string,
+  TAccountCreatorVault extends string,
+  TAccountReserveSolVault extends string,
   TAccountSystemProgram extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
+  TAccountPriceFeed extends string,
+  TAccountLaunchAllocation extends string,
+  TAccountReferrer extends string,
   TProgramAddress extends Address = typeof COINFUN_PROGRAM_ADDRESS,
 >(
   input: BuyInput<
@@ -435,12 +569,17 @@ export function getBuyInstruction<
     TAccountUserAta,
     TAccountMint,
     TAccountGlobal,
-    TAccountPlatformFeeRecipient,
+    TAccountPlatformFeeVault,
     TAccountGlobalReserve,
     TAccountReserveAta,
+    TAccountCreatorVault,
+    TAccountReserveSolVault,
     TAccountSystemProgram,
     TAccountTokenProgram,
-    TAccountAssociatedTokenProgram
+    TAccountAssociatedTokenProgram,
+    TAccountPriceFeed,
+    TAccountLaunchAllocation,
+    TAccountReferrer
   >,
   config?: { programAddress?: TProgramAddress }
 ): BuyInstruction<
@@ -451,12 +590,17 @@ export function getBuyInstruction<
   TAccountUserAta,
   TAccountMint,
   TAccountGlobal,
-  TAccountPlatformFeeRecipient,
+  TAccountPlatformFeeVault,
   TAccountGlobalReserve,
   TAccountReserveAta,
+  TAccountCreatorVault,
+  TAccountReserveSolVault,
   TAccountSystemProgram,
   TAccountTokenProgram,
-  TAccountAssociatedTokenProgram
+  TAccountAssociatedTokenProgram,
+  TAccountPriceFeed,
+  TAccountLaunchAllocation,
+  TAccountReferrer
 > {
   // Program address.
   const programAddress = config?.programAddress ?? COINFUN_PROGRAM_ADDRESS;
@@ -467,20 +611,28 @@ export function getBuyInstruction<
     bondingCurve: { value: input.bondingCurve ?? null, isWritable: true },
     bondingCurveAta: { value: input.bondingCurveAta ?? null, isWritable: true },
     userAta: { value: input.userAta ?? null, isWritable: true },
-    mint: { value: input.mint ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: true },
     global: { value: input.global ?? null, isWritable: false },
-    platformFeeRecipient: {
-      value: input.platformFeeRecipient ?? null,
+    platformFeeVault: {
+      value: input.platformFeeVault ?? null,
       isWritable: true,
     },
     globalReserve: { value: input.globalReserve ?? null, isWritable: false },
     reserveAta: { value: input.reserveAta ?? null, isWritable: true },
+    creatorVault: { value: input.creatorVault ?? null, isWritable: true },
+    reserveSolVault: { value: input.reserveSolVault ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
       isWritable: false,
     },
+    priceFeed: { value: input.priceFeed ?? null, isWritable: false },
+    launchAllocation: {
+      value: input.launchAllocation ?? null,
+      isWritable: true,
+    },
+    referrer: { value: input.referrer ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -509,12 +661,17 @@ export function getBuyInstruction<
       getAccountMeta(accounts.userAta),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.global),
-      getAccountMeta(accounts.platformFeeRecipient),
+      getAccountMeta(accounts.platformFeeVault),
       getAccountMeta(accounts.globalReserve),
       getAccountMeta(accounts.reserveAta),
+      getAccountMeta(accounts.creatorVault),
+      getAccountMeta(accounts.reserveSolVault),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
+      getAccountMeta(accounts.priceFeed),
+      getAccountMeta(accounts.launchAllocation),
+      getAccountMeta(accounts.referrer),
     ],
     data: getBuyInstructionDataEncoder().encode(args as BuyInstructionDataArgs),
     programAddress,
@@ -526,12 +683,17 @@ export function getBuyInstruction<
     TAccountUserAta,
     TAccountMint,
     TAccountGlobal,
-    TAccountPlatformFeeRecipient,
+    TAccountPlatformFeeVault,
     TAccountGlobalReserve,
     TAccountReserveAta,
+    TAccountCreatorVault,
+    TAccountReserveSolVault,
     TAccountSystemProgram,
     TAccountTokenProgram,
-    TAccountAssociatedTokenProgram
+    TAccountAssociatedTokenProgram,
+    TAccountPriceFeed,
+    TAccountLaunchAllocation,
+    TAccountReferrer
   >);
 }
 
@@ -547,12 +709,18 @@ export type ParsedBuyInstruction<
     userAta: TAccountMetas[3];
     mint: TAccountMetas[4];
     global: TAccountMetas[5];
-    platformFeeRecipient: TAccountMetas[6];
+    platformFeeVault: TAccountMetas[6];
     globalReserve: TAccountMetas[7];
     reserveAta: TAccountMetas[8];
-    systemProgram: TAccountMetas[9];
-    tokenProgram: TAccountMetas[10];
-    associatedTokenProgram: TAccountMetas[11];
+    creatorVault: TAccountMetas[9];
+    reserveSolVault: TAccountMetas[10];
+    systemProgram: TAccountMetas[11];
+    tokenProgram: TAccountMetas[12];
+    associatedTokenProgram: TAccountMetas[13];
+    /** `Global.sol_usd_price_feed` and decoded in `Global::sol_usd_price` */
+    priceFeed?: TAccountMetas[14] | undefined;
+    launchAllocation?: TAccountMetas[15] | undefined;
+    referrer?: TAccountMetas[16] | undefined;
   };
   data: BuyInstructionData;
 };
@@ -565,7 +733,7 @@ export function parseBuyInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedBuyInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 12) {
+  if (instruction.accounts.length < 17) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -575,6 +743,12 @@ export function parseBuyInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === COINFUN_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -584,12 +758,17 @@ export function parseBuyInstruction<
       userAta: getNextAccount(),
       mint: getNextAccount(),
       global: getNextAccount(),
-      platformFeeRecipient: getNextAccount(),
+      platformFeeVault: getNextAccount(),
       globalReserve: getNextAccount(),
       reserveAta: getNextAccount(),
+      creatorVault: getNextAccount(),
+      reserveSolVault: getNextAccount(),
       systemProgram: getNextAccount(),
       tokenProgram: getNextAccount(),
       associatedTokenProgram: getNextAccount(),
+      priceFeed: getNextOptionalAccount(),
+      launchAllocation: getNextOptionalAccount(),
+      referrer: getNextOptionalAccount(),
     },
     data: getBuyInstructionDataDecoder().decode(instruction.data),
   };
@@ -15,6 +15,8 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
@@ -32,6 +34,8 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -44,6 +48,40 @@ import {
   getAccountMetaFactory,
   type ResolvedAccount,
 } from '../shared';
+import {
+  getCurveKindDecoder,
+  getCurveKindEncoder,
+  getCurveParamsDecoder,
+  getCurveParamsEncoder,
+  getGraduationModeDecoder,
+  getGraduationModeEncoder,
+  getLaunchFeeDecoder,
+  getLaunchFeeEncoder,
+  getLaunchWindowDecoder,
+  getLaunchWindowEncoder,
+  getMigrationTargetDecoder,
+  getMigrationTargetEncoder,
+  getReservePolicyDecoder,
+  getReservePolicyEncoder,
+  getTradingStartDecoder,
+  getTradingStartEncoder,
+  type CurveKind,
+  type CurveKindArgs,
+  type CurveParams,
+  type CurveParamsArgs,
+  type GraduationMode,
+  type GraduationModeArgs,
+  type LaunchFee,
+  type LaunchFeeArgs,
+  type LaunchWindow,
+  type LaunchWindowArgs,
+  type MigrationTarget,
+  type MigrationTargetArgs,
+  type ReservePolicy,
+  type ReservePolicyArgs,
+  type TradingStart,
+  type TradingStartArgs,
+} from '../types';
 
 export const CREATE_DISCRIMINATOR = new Uint8Array([
   24, 30, 200, 40, 5, 28, 7, 119,
@@ -61,6 +99,7 @@ export type CreateInstruction<
   TAccountGlobal extends string | AccountMeta<string> = string,
   TAccountBondingCurve extends string | AccountMeta<string> = string,
   TAccountBondingCurveAta extends string | AccountMeta<string> = string,
+  TAccountCreatorVault extends string | AccountMeta<string> = string,
   TAccountMetadataAccount extends string | AccountMeta<string> = string,
   TAccountTokenMetadataProgram extends
     | string
@@ -99,6 +138,9 @@ export type CreateInstruction<
       TAccountBondingCurveAta extends string
         ? WritableAccount<TAccountBondingCurveAta>
         : TAccountBondingCurveAta,
+      TAccountCreatorVault extends string
+        ? WritableAccount<TAccountCreatorVault>
+        : TAccountCreatorVault,
       TAccountMetadataAccount extends string
         ? WritableAccount<TAccountMetadataAccount>
         : TAccountMetadataAccount,
@@ -126,12 +168,28 @@ export type CreateInstructionData = {
   tokenName: string;
   tokenSymbol: string;
   tokenUri: string;
+  migrationTarget: MigrationTarget;
+  curveKind: CurveKind;
+  curveParams: Option<CurveParams>;
+  graduationMode: GraduationMode;
+  launchWindow: Option<LaunchWindow>;
+  tradingStartsAt: TradingStart;
+  launchFee: Option<LaunchFee>;
+  reservePolicy: ReservePolicy;
 };
 
 export type CreateInstructionDataArgs = {
   tokenName: string;
   tokenSymbol: string;
   tokenUri: string;
+  migrationTarget: MigrationTargetArgs;
+  curveKind: CurveKindArgs;
+  curveParams: OptionOrNullable<CurveParamsArgs>;
+  graduationMode: GraduationModeArgs;
+  launchWindow: OptionOrNullable<LaunchWindowArgs>;
+  tradingStartsAt: TradingStartArgs;
+  launchFee: OptionOrNullable<LaunchFeeArgs>;
+  reservePolicy: ReservePolicyArgs;
 };
 
 export function getCreateInstructionDataEncoder(): Encoder<CreateInstructionDataArgs> {
@@ -141,6 +199,14 @@ export function getCreateInstructionDataEncoder(): Encoder<CreateInstructionData
       ['tokenName', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['tokenSymbol', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['tokenUri', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['migrationTarget', getMigrationTargetEncoder()],
+      ['curveKind', getCurveKindEncoder()],
+      ['curveParams', getOptionEncoder(getCurveParamsEncoder())],
+      ['graduationMode', getGraduationModeEncoder()],
+      ['launchWindow', getOptionEncoder(getLaunchWindowEncoder())],
+      ['tradingStartsAt', getTradingStartEncoder()],
+      ['launchFee', getOptionEncoder(getLaunchFeeEncoder())],
+      ['reservePolicy', getReservePolicyEncoder()],
     ]),
     (value) => ({ ...value, discriminator: CREATE_DISCRIMINATOR })
   );
@@ -152,6 +218,14 @@ export function getCreateInstructionDataDecoder(): Decoder<CreateInstructionData
     ['tokenName', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['tokenSymbol', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['tokenUri', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['migrationTarget', getMigrationTargetDecoder()],
+    ['curveKind', getCurveKindDecoder()],
+    ['curveParams', getOptionDecoder(getCurveParamsDecoder())],
+    ['graduationMode', getGraduationModeDecoder()],
+    ['launchWindow', getOptionDecoder(getLaunchWindowDecoder())],
+    ['tradingStartsAt', getTradingStartDecoder()],
+    ['launchFee', getOptionDecoder(getLaunchFeeDecoder())],
+    ['reservePolicy', getReservePolicyDecoder()],
   ]);
 }
 
@@ -172,6 +246,7 @@ export type CreateAsyncInput<
   TAccountGlobal extends string = string,
   TAccountBondingCurve extends string = string,
   TAccountBondingCurveAta extends string = string,
+  TAccountCreatorVault extends string = string,
   TAccountMetadataAccount extends string = string,
   TAccountTokenMetadataProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
@@ -185,6 +260,7 @@ export type CreateAsyncInput<
   global?: Address<TAccountGlobal>;
   bondingCurve?: Address<TAccountBondingCurve>;
   bondingCurveAta?: Address<TAccountBondingCurveAta>;
+  creatorVault?: Address<TAccountCreatorVault>;
   metadataAccount?: Address<TAccountMetadataAccount>;
   tokenMetadataProgram?: Address<TAccountTokenMetadataProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
@@ -194,6 +270,14 @@ export type CreateAsyncInput<
   tokenName: CreateInstructionDataArgs['tokenName'];
   tokenSymbol: CreateInstructionDataArgs['tokenSymbol'];
   tokenUri: CreateInstructionDataArgs['tokenUri'];
+  migrationTarget: CreateInstructionDataArgs['migrationTarget'];
+  curveKind: CreateInstructionDataArgs['curveKind'];
+  curveParams: CreateInstructionDataArgs['curveParams'];
+  graduationMode: CreateInstructionDataArgs['graduationMode'];
+  launchWindow: CreateInstructionDataArgs['launchWindow'];
+  tradingStartsAt: CreateInstructionDataArgs['tradingStartsAt'];
+  launchFee: CreateInstructionDataArgs['launchFee'];
+  reservePolicy: CreateInstructionDataArgs['reservePolicy'];
 };
 
 export async function getCreateInstructionAsync<
@@ -203,6 +287,7 @@ export async function getCreateInstructionAsync<
   TAccountGlobal extends string,
   TAccountBondingCurve extends string,
   TAccountBondingCurveAta extends string,
+  TAccountCreatorVault extends string,
   TAccountMetadataAccount extends string,
   TAccountTokenMetadataProgram extends string,
   TAccountAssociatedTokenProgram extends string,
@@ -218,6 +303,7 @@ export async function getCreateInstructionAsync<
     TAccountGlobal,
     TAccountBondingCurve,
     TAccountBondingCurveAta,
+    TAccountCreatorVault,
     TAccountMetadataAccount,
     TAccountTokenMetadataProgram,
     TAccountAssociatedTokenProgram,
@@ -235,6 +321,7 @@ export async function getCreateInstructionAsync<
     TAccountGlobal,
     TAccountBondingCurve,
     TAccountBondingCurveAta,
+    TAccountCreatorVault,
     TAccountMetadataAccount,
     TAccountTokenMetadataProgram,
     TAccountAssociatedTokenProgram,
@@ -254,6 +341,7 @@ export async function getCreateInstructionAsync<
     global: { value: input.global ?? null, isWritable: false },
     bondingCurve: { value: input.bondingCurve ?? null, isWritable: true },
     bondingCurveAta: { value: input.bondingCurveAta ?? null, isWritable: true },
+    creatorVault: { value: input.creatorVault ?? null, isWritable: true },
     metadataAccount: { value: input.metadataAccount ?? null, isWritable: true },
     tokenMetadataProgram: {
       value: input.tokenMetadataProgram ?? null,
@@ -308,6 +396,19 @@ export async function getCreateInstructionAsync<
       ],
     });
   }
+  if (!accounts.creatorVault.value) {
+    accounts.creatorVault.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            99, 114, 101, 97, 116, 111, 114, 95, 118, 97, 117, 108, 116,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.bondingCurve.value)),
+      ],
+    });
+  }
   if (!accounts.tokenMetadataProgram.value) {
     accounts.tokenMetadataProgram.value =
       'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s' as Address<'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s'>;
@@ -349,6 +450,7 @@ export async function getCreateInstructionAsync<
       getAccountMeta(accounts.global),
       getAccountMeta(accounts.bondingCurve),
       getAccountMeta(accounts.bondingCurveAta),
+      getAccountMeta(accounts.creatorVault),
       getAccountMeta(accounts.metadataAccount),
       getAccountMeta(accounts.tokenMetadataProgram),
       getAccountMeta(accounts.associatedTokenProgram),
@@ -368,6 +470,7 @@ export async function getCreateInstructionAsync<
     TAccountGlobal,
     TAccountBondingCurve,
     TAccountBondingCurveAta,
+    TAccountCreatorVault,
     TAccountMetadataAccount,
     TAccountTokenMetadataProgram,
     TAccountAssociatedTokenProgram,
@@ -384,6 +487,7 @@ export type CreateInput<
   TAccountGlobal extends string = string,
   TAccountBondingCurve extends string = string,
   TAccountBondingCurveAta extends string = string,
+  TAccountCreatorVault extends string = string,
   TAccountMetadataAccount extends string = string,
   TAccountTokenMetadataProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
@@ -397,6 +501,7 @@ export type CreateInput<
   global: Address<TAccountGlobal>;
   bondingCurve: Address<TAccountBondingCurve>;
   bondingCurveAta: Address<TAccountBondingCurveAta>;
+  creatorVault: Address<TAccountCreatorVault>;
   metadataAccount: Address<TAccountMetadataAccount>;
   tokenMetadataProgram?: Address<TAccountTokenMetadataProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
@@ -406,6 +511,14 @@ export type CreateInput<
   tokenName: CreateInstructionDataArgs['tokenName'];
   tokenSymbol: CreateInstructionDataArgs['tokenSymbol'];
   tokenUri: CreateInstructionDataArgs['tokenUri'];
+  migrationTarget: CreateInstructionDataArgs['migrationTarget'];
+  curveKind: CreateInstructionDataArgs['curveKind'];
+  curveParams: CreateInstructionDataArgs['curveParams'];
+  graduationMode: CreateInstructionDataArgs['graduationMode'];
+  launchWindow: CreateInstructionDataArgs['launchWindow'];
+  tradingStartsAt: CreateInstructionDataArgs['tradingStartsAt'];
+  launchFee: CreateInstructionDataArgs['launchFee'];
+  reservePolicy: CreateInstructionDataArgs['reservePolicy'];
 };
 
 export function getCreateInstruction<
@@ -415,6 +528,7 @@ export function getCreateInstruction<
   TAccountGlobal extends string,
   TAccountBondingCurve extends string,
   TAccountBondingCurveAta extends string,
+  TAccountCreatorVault extends string,
   TAccountMetadataAccount extends string,
   TAccountTokenMetadataProgram extends string,
   TAccountAssociatedTokenProgram extends string,
@@ -430,6 +544,7 @@ export function getCreateInstruction<
     TAccountGlobal,
     TAccountBondingCurve,
     TAccountBondingCurveAta,
+    TAccountCreatorVault,
     TAccountMetadataAccount,
     TAccountTokenMetadataProgram,
     TAccountAssociatedTokenProgram,
@@ -446,6 +561,7 @@ export function getCreateInstruction<
   TAccountGlobal,
   TAccountBondingCurve,
   TAccountBondingCurveAta,
+  TAccountCreatorVault,
   TAccountMetadataAccount,
   TAccountTokenMetadataProgram,
   TAccountAssociatedTokenProgram,
@@ -464,6 +580,7 @@ export function getCreateInstruction<
     global: { value: input.global ?? null, isWritable: false },
     bondingCurve: { value: input.bondingCurve ?? null, isWritable: true },
     bondingCurveAta: { value: input.bondingCurveAta ?? null, isWritable: true },
+    creatorVault: { value: input.creatorVault ?? null, isWritable: true },
     metadataAccount: { value: input.metadataAccount ?? null, isWritable: true },
     tokenMetadataProgram: {
       value: input.tokenMetadataProgram ?? null,
@@ -512,6 +629,7 @@ export function getCreateInstruction<
       getAccountMeta(accounts.global),
       getAccountMeta(accounts.bondingCurve),
       getAccountMeta(accounts.bondingCurveAta),
+      getAccountMeta(accounts.creatorVault),
       getAccountMeta(accounts.metadataAccount),
       getAccountMeta(accounts.tokenMetadataProgram),
       getAccountMeta(accounts.associatedTokenProgram),
@@ -531,6 +649,7 @@ export function getCreateInstruction<
     TAccountGlobal,
     TAccountBondingCurve,
     TAccountBondingCurveAta,
+    TAccountCreatorVault,
     TAccountMetadataAccount,
     TAccountTokenMetadataProgram,
     TAccountAssociatedTokenProgram,
@@ -552,12 +671,13 @@ export type ParsedCreateInstruction<
     global: TAccountMetas[3];
     bondingCurve: TAccountMetas[4];
     bondingCurveAta: TAccountMetas[5];
-    metadataAccount: TAccountMetas[6];
-    tokenMetadataProgram: TAccountMetas[7];
-    associatedTokenProgram: TAccountMetas[8];
-    tokenProgram: TAccountMetas[9];
-    systemProgram: TAccountMetas[10];
-    rent: TAccountMetas[11];
+    creatorVault: TAccountMetas[6];
+    metadataAccount: TAccountMetas[7];
+    tokenMetadataProgram: TAccountMetas[8];
+    associatedTokenProgram: TAccountMetas[9];
+    tokenProgram: TAccountMetas[10];
+    systemProgram: TAccountMetas[11];
+    rent: TAccountMetas[12];
   };
   data: CreateInstructionData;
 };
@@ -570,7 +690,7 @@ export function parseCreateInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCreateInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 12) {
+  if (instruction.accounts.length < 13) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -589,6 +709,7 @@ export function parseCreateInstruction<
       global: getNextAccount(),
       bondingCurve: getNextAccount(),
       bondingCurveAta: getNextAccount(),
+      creatorVault: getNextAccount(),
       metadataAccount: getNextAccount(),
       tokenMetadataProgram: getNextAccount(),
       associatedTokenProgram: getNextAccount(),
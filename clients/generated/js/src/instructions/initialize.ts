@@ -10,8 +10,10 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
-  getAddressDecoder,
-  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getProgramDerivedAddress,
@@ -23,9 +25,9 @@ import {
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
@@ -37,6 +39,20 @@ import {
 } from '@solana/kit';
 import { COINFUN_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  getCurveParamsDecoder,
+  getCurveParamsEncoder,
+  getLpPolicyDecoder,
+  getLpPolicyEncoder,
+  getSurplusTokenPolicyDecoder,
+  getSurplusTokenPolicyEncoder,
+  type CurveParams,
+  type CurveParamsArgs,
+  type LpPolicy,
+  type LpPolicyArgs,
+  type SurplusTokenPolicy,
+  type SurplusTokenPolicyArgs,
+} from '../types';
 
 export const INITIALIZE_DISCRIMINATOR = new Uint8Array([
   175, 175, 109, 31, 13, 152, 155, 237,
@@ -51,6 +67,8 @@ export type InitializeInstruction<
   TAccountAuthority extends string | AccountMeta<string> = string,
   TAccountGlobal extends string | AccountMeta<string> = string,
   TAccountGlobalReserve extends string | AccountMeta<string> = string,
+  TAccountPlatformFeeVault extends string | AccountMeta<string> = string,
+  TAccountReserveSolVault extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -69,6 +87,12 @@ export type InitializeInstruction<
       TAccountGlobalReserve extends string
         ? WritableAccount<TAccountGlobalReserve>
         : TAccountGlobalReserve,
+      TAccountPlatformFeeVault extends string
+        ? WritableAccount<TAccountPlatformFeeVault>
+        : TAccountPlatformFeeVault,
+      TAccountReserveSolVault extends string
+        ? WritableAccount<TAccountReserveSolVault>
+        : TAccountReserveSolVault,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -83,8 +107,16 @@ export type InitializeInstructionData = {
   tokenTotalSupply: bigint;
   platformTradeFeeBps: bigint;
   reserveTradeFeeBps: bigint;
-  platformFeeRecipient: Address;
   graduationThreshold: bigint;
+  lpPolicy: LpPolicy;
+  keeperRewardBps: bigint;
+  surplusTokenPolicy: SurplusTokenPolicy;
+  migrationFeeLamports: bigint;
+  curveKinds: Array<boolean>;
+  minCurveParams: CurveParams;
+  maxCurveParams: CurveParams;
+  creatorTradeFeeBps: bigint;
+  referralFeeBps: bigint;
 };
 
 export type InitializeInstructionDataArgs = {
@@ -93,11 +125,19 @@ export type InitializeInstructionDataArgs = {
   tokenTotalSupply: number | bigint;
   platformTradeFeeBps: number | bigint;
   reserveTradeFeeBps: number | bigint;
-  platformFeeRecipient: Address;
   graduationThreshold: number | bigint;
+  lpPolicy: LpPolicyArgs;
+  keeperRewardBps: number | bigint;
+  surplusTokenPolicy: SurplusTokenPolicyArgs;
+  migrationFeeLamports: number | bigint;
+  curveKinds: Array<boolean>;
+  minCurveParams: CurveParamsArgs;
+  maxCurveParams: CurveParamsArgs;
+  creatorTradeFeeBps: number | bigint;
+  referralFeeBps: number | bigint;
 };
 
-export function getInitializeInstructionDataEncoder(): FixedSizeEncoder<InitializeInstructionDataArgs> {
+export function getInitializeInstructionDataEncoder(): Encoder<InitializeInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
@@ -106,14 +146,22 @@ export function getInitializeInstructionDataEncoder(): FixedSizeEncoder<Initiali
       ['tokenTotalSupply', getU64Encoder()],
       ['platformTradeFeeBps', getU64Encoder()],
       ['reserveTradeFeeBps', getU64Encoder()],
-      ['platformFeeRecipient', getAddressEncoder()],
       ['graduationThreshold', getU64Encoder()],
+      ['lpPolicy', getLpPolicyEncoder()],
+      ['keeperRewardBps', getU64Encoder()],
+      ['surplusTokenPolicy', getSurplusTokenPolicyEncoder()],
+      ['migrationFeeLamports', getU64Encoder()],
+      ['curveKinds', getArrayEncoder(getBooleanEncoder(), { size: 3 })],
+      ['minCurveParams', getCurveParamsEncoder()],
+      ['maxCurveParams', getCurveParamsEncoder()],
+      ['creatorTradeFeeBps', getU64Encoder()],
+      ['referralFeeBps', getU64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: INITIALIZE_DISCRIMINATOR })
   );
 }
 
-export function getInitializeInstructionDataDecoder(): FixedSizeDecoder<InitializeInstructionData> {
+export function getInitializeInstructionDataDecoder(): Decoder<InitializeInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['initialVirtualTokenReserves', getU64Decoder()],
@@ -121,12 +169,20 @@ export function getInitializeInstructionDataDecoder(): FixedSizeDecoder<Initiali
     ['tokenTotalSupply', getU64Decoder()],
     ['platformTradeFeeBps', getU64Decoder()],
     ['reserveTradeFeeBps', getU64Decoder()],
-    ['platformFeeRecipient', getAddressDecoder()],
     ['graduationThreshold', getU64Decoder()],
+    ['lpPolicy', getLpPolicyDecoder()],
+    ['keeperRewardBps', getU64Decoder()],
+    ['surplusTokenPolicy', getSurplusTokenPolicyDecoder()],
+    ['migrationFeeLamports', getU64Decoder()],
+    ['curveKinds', getArrayDecoder(getBooleanDecoder(), { size: 3 })],
+    ['minCurveParams', getCurveParamsDecoder()],
+    ['maxCurveParams', getCurveParamsDecoder()],
+    ['creatorTradeFeeBps', getU64Decoder()],
+    ['referralFeeBps', getU64Decoder()],
   ]);
 }
 
-export function getInitializeInstructionDataCodec(): FixedSizeCodec<
+export function getInitializeInstructionDataCodec(): Codec<
   InitializeInstructionDataArgs,
   InitializeInstructionData
 > {
@@ -140,25 +196,39 @@ export type InitializeAsyncInput<
   TAccountAuthority extends string = string,
   TAccountGlobal extends string = string,
   TAccountGlobalReserve extends string = string,
+  TAccountPlatformFeeVault extends string = string,
+  TAccountReserveSolVault extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   authority: TransactionSigner<TAccountAuthority>;
   global?: Address<TAccountGlobal>;
   globalReserve?: Address<TAccountGlobalReserve>;
+  platformFeeVault?: Address<TAccountPlatformFeeVault>;
+  reserveSolVault?: Address<TAccountReserveSolVault>;
   systemProgram?: Address<TAccountSystemProgram>;
   initialVirtualTokenReserves: InitializeInstructionDataArgs['initialVirtualTokenReserves'];
   initialVirtualSolReserves: InitializeInstructionDataArgs['initialVirtualSolReserves'];
   tokenTotalSupply: InitializeInstructionDataArgs['tokenTotalSupply'];
   platformTradeFeeBps: InitializeInstructionDataArgs['platformTradeFeeBps'];
   reserveTradeFeeBps: InitializeInstructionDataArgs['reserveTradeFeeBps'];
-  platformFeeRecipient: InitializeInstructionDataArgs['platformFeeRecipient'];
   graduationThreshold: InitializeInstructionDataArgs['graduationThreshold'];
+  lpPolicy: InitializeInstructionDataArgs['lpPolicy'];
+  keeperRewardBps: InitializeInstructionDataArgs['keeperRewardBps'];
+  surplusTokenPolicy: InitializeInstructionDataArgs['surplusTokenPolicy'];
+  migrationFeeLamports: InitializeInstructionDataArgs['migrationFeeLamports'];
+  curveKinds: InitializeInstructionDataArgs['curveKinds'];
+  minCurveParams: InitializeInstructionDataArgs['minCurveParams'];
+  maxCurveParams: InitializeInstructionDataArgs['maxCurveParams'];
+  creatorTradeFeeBps: InitializeInstructionDataArgs['creatorTradeFeeBps'];
+  referralFeeBps: InitializeInstructionDataArgs['referralFeeBps'];
 };
 
 export async function getInitializeInstructionAsync<
   TAccountAuthority extends string,
   TAccountGlobal extends string,
   TAccountGlobalReserve extends string,
+  TAccountPlatformFeeVault extends string,
+  TAccountReserveSolVault extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof COINFUN_PROGRAM_ADDRESS,
 >(
@@ -166,6 +236,8 @@ export async function getInitializeInstructionAsync<
     TAccountAuthority,
     TAccountGlobal,
     TAccountGlobalReserve,
+    TAccountPlatformFeeVault,
+    TAccountReserveSolVault,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
@@ -175,6 +247,8 @@ export async function getInitializeInstructionAsync<
     TAccountAuthority,
     TAccountGlobal,
     TAccountGlobalReserve,
+    TAccountPlatformFeeVault,
+    TAccountReserveSolVault,
     TAccountSystemProgram
   >
 > {
@@ -186,6 +260,11 @@ export async function getInitializeInstructionAsync<
     authority: { value: input.authority ?? null, isWritable: true },
     global: { value: input.global ?? null, isWritable: true },
     globalReserve: { value: input.globalReserve ?? null, isWritable: true },
+    platformFeeVault: {
+      value: input.platformFeeVault ?? null,
+      isWritable: true,
+    },
+    reserveSolVault: { value: input.reserveSolVault ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -215,6 +294,32 @@ export async function getInitializeInstructionAsync<
       ],
     });
   }
+  if (!accounts.platformFeeVault.value) {
+    accounts.platformFeeVault.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            112, 108, 97, 116, 102, 111, 114, 109, 95, 102, 101, 101, 95, 118,
+            97, 117, 108, 116,
+          ])
+        ),
+      ],
+    });
+  }
+  if (!accounts.reserveSolVault.value) {
+    accounts.reserveSolVault.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            114, 101, 115, 101, 114, 118, 101, 95, 115, 111, 108, 95, 118, 97,
+            117, 108, 116,
+          ])
+        ),
+      ],
+    });
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -226,6 +331,8 @@ export async function getInitializeInstructionAsync<
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.global),
       getAccountMeta(accounts.globalReserve),
+      getAccountMeta(accounts.platformFeeVault),
+      getAccountMeta(accounts.reserveSolVault),
       getAccountMeta(accounts.systemProgram),
     ],
     data: getInitializeInstructionDataEncoder().encode(
@@ -237,6 +344,8 @@ export async function getInitializeInstructionAsync<
     TAccountAuthority,
     TAccountGlobal,
     TAccountGlobalReserve,
+    TAccountPlatformFeeVault,
+    TAccountReserveSolVault,
     TAccountSystemProgram
   >);
 }
@@ -245,25 +354,39 @@ export type InitializeInput<
   TAccountAuthority extends string = string,
   TAccountGlobal extends string = string,
   TAccountGlobalReserve extends string = string,
+  TAccountPlatformFeeVault extends string = string,
+  TAccountReserveSolVault extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   authority: TransactionSigner<TAccountAuthority>;
   global: Address<TAccountGlobal>;
   globalReserve: Address<TAccountGlobalReserve>;
+  platformFeeVault: Address<TAccountPlatformFeeVault>;
+  reserveSolVault: Address<TAccountReserveSolVault>;
   systemProgram?: Address<TAccountSystemProgram>;
   initialVirtualTokenReserves: InitializeInstructionDataArgs['initialVirtualTokenReserves'];
   initialVirtualSolReserves: InitializeInstructionDataArgs['initialVirtualSolReserves'];
   tokenTotalSupply: InitializeInstructionDataArgs['tokenTotalSupply'];
   platformTradeFeeBps: InitializeInstructionDataArgs['platformTradeFeeBps'];
   reserveTradeFeeBps: InitializeInstructionDataArgs['reserveTradeFeeBps'];
-  platformFeeRecipient: InitializeInstructionDataArgs['platformFeeRecipient'];
   graduationThreshold: InitializeInstructionDataArgs['graduationThreshold'];
+  lpPolicy: InitializeInstructionDataArgs['lpPolicy'];
+  keeperRewardBps: InitializeInstructionDataArgs['keeperRewardBps'];
+  surplusTokenPolicy: InitializeInstructionDataArgs['surplusTokenPolicy'];
+  migrationFeeLamports: InitializeInstructionDataArgs['migrationFeeLamports'];
+  curveKinds: InitializeInstructionDataArgs['curveKinds'];
+  minCurveParams: InitializeInstructionDataArgs['minCurveParams'];
+  maxCurveParams: InitializeInstructionDataArgs['maxCurveParams'];
+  creatorTradeFeeBps: InitializeInstructionDataArgs['creatorTradeFeeBps'];
+  referralFeeBps: InitializeInstructionDataArgs['referralFeeBps'];
 };
 
 export function getInitializeInstruction<
   TAccountAuthority extends string,
   TAccountGlobal extends string,
   TAccountGlobalReserve extends string,
+  TAccountPlatformFeeVault extends string,
+  TAccountReserveSolVault extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof COINFUN_PROGRAM_ADDRESS,
 >(
@@ -271,6 +394,8 @@ export function getInitializeInstruction<
     TAccountAuthority,
     TAccountGlobal,
     TAccountGlobalReserve,
+    TAccountPlatformFeeVault,
+    TAccountReserveSolVault,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
@@ -279,6 +404,8 @@ export function getInitializeInstruction<
   TAccountAuthority,
   TAccountGlobal,
   TAccountGlobalReserve,
+  TAccountPlatformFeeVault,
+  TAccountReserveSolVault,
   TAccountSystemProgram
 > {
   // Program address.
@@ -289,6 +416,11 @@ export function getInitializeInstruction<
     authority: { value: input.authority ?? null, isWritable: true },
     global: { value: input.global ?? null, isWritable: true },
     globalReserve: { value: input.globalReserve ?? null, isWritable: true },
+    platformFeeVault: {
+      value: input.platformFeeVault ?? null,
+      isWritable: true,
+    },
+    reserveSolVault: { value: input.reserveSolVault ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -311,6 +443,8 @@ export function getInitializeInstruction<
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.global),
       getAccountMeta(accounts.globalReserve),
+      getAccountMeta(accounts.platformFeeVault),
+      getAccountMeta(accounts.reserveSolVault),
       getAccountMeta(accounts.systemProgram),
     ],
     data: getInitializeInstructionDataEncoder().encode(
@@ -322,6 +456,8 @@ export function getInitializeInstruction<
     TAccountAuthority,
     TAccountGlobal,
     TAccountGlobalReserve,
+    TAccountPlatformFeeVault,
+    TAccountReserveSolVault,
     TAccountSystemProgram
   >);
 }
@@ -335,7 +471,9 @@ export type ParsedInitializeInstruction<
     authority: TAccountMetas[0];
     global: TAccountMetas[1];
     globalReserve: TAccountMetas[2];
-    systemProgram: TAccountMetas[3];
+    platformFeeVault: TAccountMetas[3];
+    reserveSolVault: TAccountMetas[4];
+    systemProgram: TAccountMetas[5];
   };
   data: InitializeInstructionData;
 };
@@ -348,7 +486,7 @@ export function parseInitializeInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedInitializeInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -364,6 +502,8 @@ export function parseInitializeInstruction<
       authority: getNextAccount(),
       global: getNextAccount(),
       globalReserve: getNextAccount(),
+      platformFeeVault: getNextAccount(),
+      reserveSolVault: getNextAccount(),
       systemProgram: getNextAccount(),
     },
     data: getInitializeInstructionDataDecoder().decode(instruction.data),
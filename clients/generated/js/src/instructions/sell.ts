@@ -57,9 +57,11 @@ export type SellInstruction<
   TAccountUserAta extends string | AccountMeta<string> = string,
   TAccountMint extends string | AccountMeta<string> = string,
   TAccountGlobal extends string | AccountMeta<string> = string,
-  TAccountPlatformFeeRecipient extends string | AccountMeta<string> = string,
+  TAccountPlatformFeeVault extends string | AccountMeta<string> = string,
   TAccountGlobalReserve extends string | AccountMeta<string> = string,
   TAccountReserveAta extends string | AccountMeta<string> = string,
+  TAccountCreatorVault extends string | AccountMeta<string> = string,
+  TAccountReserveSolVault extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -67,6 +69,7 @@ export type SellInstruction<
   TAccountAssociatedTokenProgram extends
     | string
     | AccountMeta<string> = 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
+  TAccountReferrer extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -86,20 +89,26 @@ export type SellInstruction<
         ? WritableAccount<TAccountUserAta>
         : TAccountUserAta,
       TAccountMint extends string
-        ? ReadonlyAccount<TAccountMint>
+        ? WritableAccount<TAccountMint>
         : TAccountMint,
       TAccountGlobal extends string
         ? ReadonlyAccount<TAccountGlobal>
         : TAccountGlobal,
-      TAccountPlatformFeeRecipient extends string
-        ? WritableAccount<TAccountPlatformFeeRecipient>
-        : TAccountPlatformFeeRecipient,
+      TAccountPlatformFeeVault extends string
+        ? WritableAccount<TAccountPlatformFeeVault>
+        : TAccountPlatformFeeVault,
       TAccountGlobalReserve extends string
         ? ReadonlyAccount<TAccountGlobalReserve>
         : TAccountGlobalReserve,
       TAccountReserveAta extends string
         ? WritableAccount<TAccountReserveAta>
         : TAccountReserveAta,
+      TAccountCreatorVault extends string
+        ? WritableAccount<TAccountCreatorVault>
+        : TAccountCreatorVault,
+      TAccountReserveSolVault extends string
+        ? WritableAccount<TAccountReserveSolVault>
+        : TAccountReserveSolVault,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -109,6 +118,9 @@ export type SellInstruction<
       TAccountAssociatedTokenProgram extends string
         ? ReadonlyAccount<TAccountAssociatedTokenProgram>
         : TAccountAssociatedTokenProgram,
+      TAccountReferrer extends string
+        ? WritableAccount<TAccountReferrer>
+        : TAccountReferrer,
       ...TRemainingAccounts,
     ]
   >;
@@ -160,12 +172,15 @@ export type SellAsyncInput<
   TAccountUserAta extends string = string,
   TAccountMint extends string = string,
   TAccountGlobal extends string = string,
-  TAccountPlatformFeeRecipient extends string = string,
+  TAccountPlatformFeeVault extends string = string,
   TAccountGlobalReserve extends string = string,
   TAccountReserveAta extends string = string,
+  TAccountCreatorVault extends string = string,
+  TAccountReserveSolVault extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
+  TAccountReferrer extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   bondingCurve?: Address<TAccountBondingCurve>;
@@ -173,12 +188,15 @@ export type SellAsyncInput<
   userAta?: Address<TAccountUserAta>;
   mint: Address<TAccountMint>;
   global?: Address<TAccountGlobal>;
-  platformFeeRecipient: Address<TAccountPlatformFeeRecipient>;
+  platformFeeVault?: Address<TAccountPlatformFeeVault>;
   globalReserve?: Address<TAccountGlobalReserve>;
   reserveAta?: Address<TAccountReserveAta>;
+  creatorVault?: Address<TAccountCreatorVault>;
+  reserveSolVault?: Address<TAccountReserveSolVault>;
   systemProgram?: Address<TAccountSystemProgram>;
   tokenProgram: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
+  referrer?: Address<TAccountReferrer>;
   tokenAmount: SellInstructionDataArgs['tokenAmount'];
   minSolOutput: SellInstructionDataArgs['minSolOutput'];
 };
@@ -190,12 +208,15 @@ export async function getSellInstructionAsync<
   TAccountUserAta extends string,
   TAccountMint extends string,
   TAccountGlobal extends string,
-  TAccountPlatformFeeRecipient extends string,
+  TAccountPlatformFeeVault extends string,
   TAccountGlobalReserve extends string,
   TAccountReserveAta extends string,
+  TAccountCreatorVault extends string,
+  TAccountReserveSolVault extends string,
   TAccountSystemProgram extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
+  TAccountReferrer extends string,
   TProgramAddress extends Address = typeof COINFUN_PROGRAM_ADDRESS,
 >(
   input: SellAsyncInput<
@@ -205,12 +226,15 @@ export async function getSellInstructionAsync<
     TAccountUserAta,
     TAccountMint,
     TAccountGlobal,
-    TAccountPlatformFeeRecipient,
+    TAccountPlatformFeeVault,
     TAccountGlobalReserve,
     TAccountReserveAta,
+    TAccountCreatorVault,
+    TAccountReserveSolVault,
     TAccountSystemProgram,
     TAccountTokenProgram,
-    TAccountAssociatedTokenProgram
+    TAccountAssociatedTokenProgram,
+    TAccountReferrer
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -222,12 +246,15 @@ export async function getSellInstructionAsync<
     TAccountUserAta,
     TAccountMint,
     TAccountGlobal,
-    TAccountPlatformFeeRecipient,
+    TAccountPlatformFeeVault,
     TAccountGlobalReserve,
     TAccountReserveAta,
+    TAccountCreatorVault,
+    TAccountReserveSolVault,
     TAccountSystemProgram,
     TAccountTokenProgram,
-    TAccountAssociatedTokenProgram
+    TAccountAssociatedTokenProgram,
+    TAccountReferrer
   >
 > {
   // Program address.
@@ -239,20 +266,23 @@ export async function getSellInstructionAsync<
     bondingCurve: { value: input.bondingCurve ?? null, isWritable: true },
     bondingCurveAta: { value: input.bondingCurveAta ?? null, isWritable: true },
     userAta: { value: input.userAta ?? null, isWritable: true },
-    mint: { value: input.mint ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: true },
     global: { value: input.global ?? null, isWritable: false },
-    platformFeeRecipient: {
-      value: input.platformFeeRecipient ?? null,
+    platformFeeVault: {
+      value: input.platformFeeVault ?? null,
       isWritable: true,
     },
     globalReserve: { value: input.globalReserve ?? null, isWritable: false },
     reserveAta: { value: input.reserveAta ?? null, isWritable: true },
+    creatorVault: { value: input.creatorVault ?? null, isWritable: true },
+    reserveSolVault: { value: input.reserveSolVault ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
       isWritable: false,
     },
+    referrer: { value: input.referrer ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -318,6 +348,19 @@ export async function getSellInstructionAsync<
       ],
     });
   }
+  if (!accounts.platformFeeVault.value) {
+    accounts.platformFeeVault.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            112, 108, 97, 116, 102, 111, 114, 109, 95, 102, 101, 101, 95, 118,
+            97, 117, 108, 116,
+          ])
+        ),
+      ],
+    });
+  }
   if (!accounts.globalReserve.value) {
     accounts.globalReserve.value = await getProgramDerivedAddress({
       programAddress,
@@ -339,6 +382,32 @@ export async function getSellInstructionAsync<
       ],
     });
   }
+  if (!accounts.creatorVault.value) {
+    accounts.creatorVault.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            99, 114, 101, 97, 116, 111, 114, 95, 118, 97, 117, 108, 116,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.bondingCurve.value)),
+      ],
+    });
+  }
+  if (!accounts.reserveSolVault.value) {
+    accounts.reserveSolVault.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            114, 101, 115, 101, 114, 118, 101, 95, 115, 111, 108, 95, 118, 97,
+            117, 108, 116,
+          ])
+        ),
+      ],
+    });
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -357,12 +426,15 @@ export async function getSellInstructionAsync<
       getAccountMeta(accounts.userAta),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.global),
-      getAccountMeta(accounts.platformFeeRecipient),
+      getAccountMeta(accounts.platformFeeVault),
       getAccountMeta(accounts.globalReserve),
       getAccountMeta(accounts.reserveAta),
+      getAccountMeta(accounts.creatorVault),
+      getAccountMeta(accounts.reserveSolVault),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
+      getAccountMeta(accounts.referrer),
     ],
     data: getSellInstructionDataEncoder().encode(
       args as SellInstructionDataArgs
@@ -376,12 +448,15 @@ export async function getSellInstructionAsync<
     TAccountUserAta,
     TAccountMint,
     TAccountGlobal,
-    TAccountPlatformFeeRecipient,
+    TAccountPlatformFeeVault,
     TAccountGlobalReserve,
     TAccountReserveAta,
+    TAccountCreatorVault,
+    TAccountReserveSolVault,
     TAccountSystemProgram,
     TAccountTokenProgram,
-    TAccountAssociatedTokenProgram
+    TAccountAssociatedTokenProgram,
+    TAccountReferrer
   >);
 }
 
@@ -392,12 +467,15 @@ export type SellInput<
   TAccountUserAta extends string = string,
   TAccountMint extends string = string,
   TAccountGlobal extends string = string,
-  TAccountPlatformFeeRecipient extends string = string,
+  TAccountPlatformFeeVault extends string = string,
   TAccountGlobalReserve extends string = string,
   TAccountReserveAta extends string = string,
+  TAccountCreatorVault extends string = string,
+  TAccountReserveSolVault extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
+  TAccountReferrer extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   bondingCurve: Address<TAccountBondingCurve>;
@@ -405,12 +483,15 @@ export type SellInput<
   userAta: Address<TAccountUserAta>;
   mint: Address<TAccountMint>;
   global: Address<TAccountGlobal>;
-  platformFeeRecipient: Address<TAccountPlatformFeeRecipient>;
+  platformFeeVault: Address<TAccountPlatformFeeVault>;
   globalReserve: Address<TAccountGlobalReserve>;
   reserveAta: Address<TAccountReserveAta>;
+  creatorVault: Address<TAccountCreatorVault>;
+  reserveSolVault: Address<TAccountReserveSolVault>;
   systemProgram?: Address<TAccountSystemProgram>;
   tokenProgram: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
+  referrer?: Address<TAccountReferrer>;
   tokenAmount: SellInstructionDataArgs['tokenAmount'];
   minSolOutput: SellInstructionDataArgs['minSolOutput'];
 };
@@ -422,12 +503,15 @@ export function getSellInstruction<
   TAccountUserAta extends string,
   TAccountMint extends string,
   TAccountGlobal extends string,
-  TAccountPlatformFeeRecipient extends string,
+  TAccountPlatformFeeVault extends string,
   TAccountGlobalReserve extends string,
   TAccountReserveAta extends string,
+  TAccountCreatorVault extends string,
+  TAccountReserveSolVault extends string,
   TAccountSystemProgram extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
+  TAccountReferrer extends string,
   TProgramAddress extends Address = typeof COINFUN_PROGRAM_ADDRESS,
 >(
   input: SellInput<
@@ -437,12 +521,15 @@ export function getSellInstruction<
     TAccountUserAta,
     TAccountMint,
     TAccountGlobal,
-    TAccountPlatformFeeRecipient,
+    TAccountPlatformFeeVault,
     TAccountGlobalReserve,
     TAccountReserveAta,
+    TAccountCreatorVault,
+    TAccountReserveSolVault,
     TAccountSystemProgram,
     TAccountTokenProgram,
-    TAccountAssociatedTokenProgram
+    TAccountAssociatedTokenProgram,
+    TAccountReferrer
   >,
   config?: { programAddress?: TProgramAddress }
 ): SellInstruction<
@@ -453,12 +540,15 @@ export function getSellInstruction<
   TAccountUserAta,
   TAccountMint,
   TAccountGlobal,
-  TAccountPlatformFeeRecipient,
+  TAccountPlatformFeeVault,
   TAccountGlobalReserve,
   TAccountReserveAta,
+  TAccountCreatorVault,
+  TAccountReserveSolVault,
   TAccountSystemProgram,
   TAccountTokenProgram,
-  TAccountAssociatedTokenProgram
+  TAccountAssociatedTokenProgram,
+  TAccountReferrer
 > {
   // Program address.
   const programAddress = config?.programAddress ?? COINFUN_PROGRAM_ADDRESS;
@@ -469,20 +559,23 @@ export function getSellInstruction<
     bondingCurve: { value: input.bondingCurve ?? null, isWritable: true },
     bondingCurveAta: { value: input.bondingCurveAta ?? null, isWritable: true },
     userAta: { value: input.userAta ?? null, isWritable: true },
-    mint: { value: input.mint ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: true },
     global: { value: input.global ?? null, isWritable: false },
-    platformFeeRecipient: {
-      value: input.platformFeeRecipient ?? null,
+    platformFeeVault: {
+      value: input.platformFeeVault ?? null,
       isWritable: true,
     },
     globalReserve: { value: input.globalReserve ?? null, isWritable: false },
     reserveAta: { value: input.reserveAta ?? null, isWritable: true },
+    creatorVault: { value: input.creatorVault ?? null, isWritable: true },
+    reserveSolVault: { value: input.reserveSolVault ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
       isWritable: false,
     },
+    referrer: { value: input.referrer ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -511,12 +604,15 @@ export function getSellInstruction<
       getAccountMeta(accounts.userAta),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.global),
-      getAccountMeta(accounts.platformFeeRecipient),
+      getAccountMeta(accounts.platformFeeVault),
       getAccountMeta(accounts.globalReserve),
       getAccountMeta(accounts.reserveAta),
+      getAccountMeta(accounts.creatorVault),
+      getAccountMeta(accounts.reserveSolVault),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
+      getAccountMeta(accounts.referrer),
     ],
     data: getSellInstructionDataEncoder().encode(
       args as SellInstructionDataArgs
@@ -530,12 +626,15 @@ export function getSellInstruction<
     TAccountUserAta,
     TAccountMint,
     TAccountGlobal,
-    TAccountPlatformFeeRecipient,
+    TAccountPlatformFeeVault,
     TAccountGlobalReserve,
     TAccountReserveAta,
+    TAccountCreatorVault,
+    TAccountReserveSolVault,
     TAccountSystemProgram,
     TAccountTokenProgram,
-    TAccountAssociatedTokenProgram
+    TAccountAssociatedTokenProgram,
+    TAccountReferrer
   >);
 }
 
@@ -551,12 +650,15 @@ export type ParsedSellInstruction<
     userAta: TAccountMetas[3];
     mint: TAccountMetas[4];
     global: TAccountMetas[5];
-    platformFeeRecipient: TAccountMetas[6];
+    platformFeeVault: TAccountMetas[6];
     globalReserve: TAccountMetas[7];
     reserveAta: TAccountMetas[8];
-    systemProgram: TAccountMetas[9];
-    tokenProgram: TAccountMetas[10];
-    associatedTokenProgram: TAccountMetas[11];
+    creatorVault: TAccountMetas[9];
+    reserveSolVault: TAccountMetas[10];
+    systemProgram: TAccountMetas[11];
+    tokenProgram: TAccountMetas[12];
+    associatedTokenProgram: TAccountMetas[13];
+    referrer?: TAccountMetas[14] | undefined;
   };
   data: SellInstructionData;
 };
@@ -569,7 +671,7 @@ export function parseSellInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedSellInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 12) {
+  if (instruction.accounts.length < 15) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -579,6 +681,12 @@ export function parseSellInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === COINFUN_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -588,12 +696,15 @@ export function parseSellInstruction<
       userAta: getNextAccount(),
       mint: getNextAccount(),
       global: getNextAccount(),
-      platformFeeRecipient: getNextAccount(),
+      platformFeeVault: getNextAccount(),
       globalReserve: getNextAccount(),
       reserveAta: getNextAccount(),
+      creatorVault: getNextAccount(),
+      reserveSolVault: getNextAccount(),
       systemProgram: getNextAccount(),
       tokenProgram: getNextAccount(),
       associatedTokenProgram: getNextAccount(),
+      referrer: getNextOptionalAccount(),
     },
     data: getSellInstructionDataDecoder().decode(instruction.data),
   };
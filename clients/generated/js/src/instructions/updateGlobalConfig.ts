@@ -12,6 +12,10 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getProgramDerivedAddress,
@@ -23,9 +27,9 @@ import {
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
@@ -36,6 +40,20 @@ import {
 } from '@solana/kit';
 import { COINFUN_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  getCurveParamsDecoder,
+  getCurveParamsEncoder,
+  getLpPolicyDecoder,
+  getLpPolicyEncoder,
+  getSurplusTokenPolicyDecoder,
+  getSurplusTokenPolicyEncoder,
+  type CurveParams,
+  type CurveParamsArgs,
+  type LpPolicy,
+  type LpPolicyArgs,
+  type SurplusTokenPolicy,
+  type SurplusTokenPolicyArgs,
+} from '../types';
 
 export const UPDATE_GLOBAL_CONFIG_DISCRIMINATOR = new Uint8Array([
   164, 84, 130, 189, 111, 58, 250, 200,
@@ -70,58 +88,90 @@ export type UpdateGlobalConfigInstruction<
 export type UpdateGlobalConfigInstructionData = {
   discriminator: ReadonlyUint8Array;
   newAuthority: Address;
-  newPlatformFeeRecipient: Address;
   newPlatformTradeFeeBps: bigint;
   newReserveTradeFeeBps: bigint;
   newInitialVirtualTokenReserves: bigint;
   newInitialVirtualSolReserves: bigint;
   newTokenTotalSupply: bigint;
   newGraduationThreshold: bigint;
+  newLpPolicy: LpPolicy;
+  newKeeperRewardBps: bigint;
+  newSurplusTokenPolicy: SurplusTokenPolicy;
+  newMigrationFeeLamports: bigint;
+  newCurveKinds: Array<boolean>;
+  newMinCurveParams: CurveParams;
+  newMaxCurveParams: CurveParams;
+  newCreatorTradeFeeBps: bigint;
+  newReferralFeeBps: bigint;
 };
 
 export type UpdateGlobalConfigInstructionDataArgs = {
   newAuthority: Address;
-  newPlatformFeeRecipient: Address;
   newPlatformTradeFeeBps: number | bigint;
   newReserveTradeFeeBps: number | bigint;
   newInitialVirtualTokenReserves: number | bigint;
   newInitialVirtualSolReserves: number | bigint;
   newTokenTotalSupply: number | bigint;
   newGraduationThreshold: number | bigint;
+  newLpPolicy: LpPolicyArgs;
+  newKeeperRewardBps: number | bigint;
+  newSurplusTokenPolicy: SurplusTokenPolicyArgs;
+  newMigrationFeeLamports: number | bigint;
+  newCurveKinds: Array<boolean>;
+  newMinCurveParams: CurveParamsArgs;
+  newMaxCurveParams: CurveParamsArgs;
+  newCreatorTradeFeeBps: number | bigint;
+  newReferralFeeBps: number | bigint;
 };
 
-export function getUpdateGlobalConfigInstructionDataEncoder(): FixedSizeEncoder<UpdateGlobalConfigInstructionDataArgs> {
+export function getUpdateGlobalConfigInstructionDataEncoder(): Encoder<UpdateGlobalConfigInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['newAuthority', getAddressEncoder()],
-      ['newPlatformFeeRecipient', getAddressEncoder()],
       ['newPlatformTradeFeeBps', getU64Encoder()],
       ['newReserveTradeFeeBps', getU64Encoder()],
       ['newInitialVirtualTokenReserves', getU64Encoder()],
       ['newInitialVirtualSolReserves', getU64Encoder()],
       ['newTokenTotalSupply', getU64Encoder()],
       ['newGraduationThreshold', getU64Encoder()],
+      ['newLpPolicy', getLpPolicyEncoder()],
+      ['newKeeperRewardBps', getU64Encoder()],
+      ['newSurplusTokenPolicy', getSurplusTokenPolicyEncoder()],
+      ['newMigrationFeeLamports', getU64Encoder()],
+      ['newCurveKinds', getArrayEncoder(getBooleanEncoder(), { size: 3 })],
+      ['newMinCurveParams', getCurveParamsEncoder()],
+      ['newMaxCurveParams', getCurveParamsEncoder()],
+      ['newCreatorTradeFeeBps', getU64Encoder()],
+      ['newReferralFeeBps', getU64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: UPDATE_GLOBAL_CONFIG_DISCRIMINATOR })
   );
 }
 
-export function getUpdateGlobalConfigInstructionDataDecoder(): FixedSizeDecoder<UpdateGlobalConfigInstructionData> {
+export function getUpdateGlobalConfigInstructionDataDecoder(): Decoder<UpdateGlobalConfigInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['newAuthority', getAddressDecoder()],
-    ['newPlatformFeeRecipient', getAddressDecoder()],
     ['newPlatformTradeFeeBps', getU64Decoder()],
     ['newReserveTradeFeeBps', getU64Decoder()],
     ['newInitialVirtualTokenReserves', getU64Decoder()],
     ['newInitialVirtualSolReserves', getU64Decoder()],
     ['newTokenTotalSupply', getU64Decoder()],
     ['newGraduationThreshold', getU64Decoder()],
+    ['newLpPolicy', getLpPolicyDecoder()],
+    ['newKeeperRewardBps', getU64Decoder()],
+    ['newSurplusTokenPolicy', getSurplusTokenPolicyDecoder()],
+    ['newMigrationFeeLamports', getU64Decoder()],
+    ['newCurveKinds', getArrayDecoder(getBooleanDecoder(), { size: 3 })],
+    ['newMinCurveParams', getCurveParamsDecoder()],
+    ['newMaxCurveParams', getCurveParamsDecoder()],
+    ['newCreatorTradeFeeBps', getU64Decoder()],
+    ['newReferralFeeBps', getU64Decoder()],
   ]);
 }
 
-export function getUpdateGlobalConfigInstructionDataCodec(): FixedSizeCodec<
+export function getUpdateGlobalConfigInstructionDataCodec(): Codec<
   UpdateGlobalConfigInstructionDataArgs,
   UpdateGlobalConfigInstructionData
 > {
@@ -138,13 +188,21 @@ export type UpdateGlobalConfigAsyncInput<
   authority: TransactionSigner<TAccountAuthority>;
   global?: Address<TAccountGlobal>;
   newAuthority: UpdateGlobalConfigInstructionDataArgs['newAuthority'];
-  newPlatformFeeRecipient: UpdateGlobalConfigInstructionDataArgs['newPlatformFeeRecipient'];
   newPlatformTradeFeeBps: UpdateGlobalConfigInstructionDataArgs['newPlatformTradeFeeBps'];
   newReserveTradeFeeBps: UpdateGlobalConfigInstructionDataArgs['newReserveTradeFeeBps'];
   newInitialVirtualTokenReserves: UpdateGlobalConfigInstructionDataArgs['newInitialVirtualTokenReserves'];
   newInitialVirtualSolReserves: UpdateGlobalConfigInstructionDataArgs['newInitialVirtualSolReserves'];
   newTokenTotalSupply: UpdateGlobalConfigInstructionDataArgs['newTokenTotalSupply'];
   newGraduationThreshold: UpdateGlobalConfigInstructionDataArgs['newGraduationThreshold'];
+  newLpPolicy: UpdateGlobalConfigInstructionDataArgs['newLpPolicy'];
+  newKeeperRewardBps: UpdateGlobalConfigInstructionDataArgs['newKeeperRewardBps'];
+  newSurplusTokenPolicy: UpdateGlobalConfigInstructionDataArgs['newSurplusTokenPolicy'];
+  newMigrationFeeLamports: UpdateGlobalConfigInstructionDataArgs['newMigrationFeeLamports'];
+  newCurveKinds: UpdateGlobalConfigInstructionDataArgs['newCurveKinds'];
+  newMinCurveParams: UpdateGlobalConfigInstructionDataArgs['newMinCurveParams'];
+  newMaxCurveParams: UpdateGlobalConfigInstructionDataArgs['newMaxCurveParams'];
+  newCreatorTradeFeeBps: UpdateGlobalConfigInstructionDataArgs['newCreatorTradeFeeBps'];
+  newReferralFeeBps: UpdateGlobalConfigInstructionDataArgs['newReferralFeeBps'];
 };
 
 export async function getUpdateGlobalConfigInstructionAsync<
@@ -211,13 +269,21 @@ export type UpdateGlobalConfigInput<
   authority: TransactionSigner<TAccountAuthority>;
   global: Address<TAccountGlobal>;
   newAuthority: UpdateGlobalConfigInstructionDataArgs['newAuthority'];
-  newPlatformFeeRecipient: UpdateGlobalConfigInstructionDataArgs['newPlatformFeeRecipient'];
   newPlatformTradeFeeBps: UpdateGlobalConfigInstructionDataArgs['newPlatformTradeFeeBps'];
   newReserveTradeFeeBps: UpdateGlobalConfigInstructionDataArgs['newReserveTradeFeeBps'];
   newInitialVirtualTokenReserves: UpdateGlobalConfigInstructionDataArgs['newInitialVirtualTokenReserves'];
   newInitialVirtualSolReserves: UpdateGlobalConfigInstructionDataArgs['newInitialVirtualSolReserves'];
   newTokenTotalSupply: UpdateGlobalConfigInstructionDataArgs['newTokenTotalSupply'];
   newGraduationThreshold: UpdateGlobalConfigInstructionDataArgs['newGraduationThreshold'];
+  newLpPolicy: UpdateGlobalConfigInstructionDataArgs['newLpPolicy'];
+  newKeeperRewardBps: UpdateGlobalConfigInstructionDataArgs['newKeeperRewardBps'];
+  newSurplusTokenPolicy: UpdateGlobalConfigInstructionDataArgs['newSurplusTokenPolicy'];
+  newMigrationFeeLamports: UpdateGlobalConfigInstructionDataArgs['newMigrationFeeLamports'];
+  newCurveKinds: UpdateGlobalConfigInstructionDataArgs['newCurveKinds'];
+  newMinCurveParams: UpdateGlobalConfigInstructionDataArgs['newMinCurveParams'];
+  newMaxCurveParams: UpdateGlobalConfigInstructionDataArgs['newMaxCurveParams'];
+  newCreatorTradeFeeBps: UpdateGlobalConfigInstructionDataArgs['newCreatorTradeFeeBps'];
+  newReferralFeeBps: UpdateGlobalConfigInstructionDataArgs['newReferralFeeBps'];
 };
 
 export function getUpdateGlobalConfigInstruction<
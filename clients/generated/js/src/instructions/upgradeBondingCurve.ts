@@ -39,32 +39,28 @@ import {
   type ResolvedAccount,
 } from '../shared';
 
-export const WITHDRAW_DISCRIMINATOR = new Uint8Array([
-  183, 18, 70, 156, 148, 109, 161, 34,
+export const UPGRADE_BONDING_CURVE_DISCRIMINATOR = new Uint8Array([
+  12, 187, 75, 31, 125, 9, 204, 9,
 ]);
 
-export function getWithdrawDiscriminatorBytes() {
-  return fixEncoderSize(getBytesEncoder(), 8).encode(WITHDRAW_DISCRIMINATOR);
+export function getUpgradeBondingCurveDiscriminatorBytes() {
+  return fixEncoderSize(getBytesEncoder(), 8).encode(
+    UPGRADE_BONDING_CURVE_DISCRIMINATOR
+  );
 }
 
-export type WithdrawInstruction<
+export type UpgradeBondingCurveInstruction<
   TProgram extends string = typeof COINFUN_PROGRAM_ADDRESS,
   TAccountAuthority extends string | AccountMeta<string> = string,
   TAccountGlobal extends string | AccountMeta<string> = string,
   TAccountMint extends string | AccountMeta<string> = string,
   TAccountBondingCurve extends string | AccountMeta<string> = string,
   TAccountBondingCurveAta extends string | AccountMeta<string> = string,
-  TAccountAuthorityAta extends string | AccountMeta<string> = string,
+  TAccountCreatorVault extends string | AccountMeta<string> = string,
+  TAccountTokenProgram extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
-  TAccountTokenProgram extends string | AccountMeta<string> = string,
-  TAccountAssociatedTokenProgram extends
-    | string
-    | AccountMeta<string> = 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
-  TAccountRent extends
-    | string
-    | AccountMeta<string> = 'SysvarRent111111111111111111111111111111111',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -84,117 +80,106 @@ export type WithdrawInstruction<
         ? WritableAccount<TAccountBondingCurve>
         : TAccountBondingCurve,
       TAccountBondingCurveAta extends string
-        ? WritableAccount<TAccountBondingCurveAta>
+        ? ReadonlyAccount<TAccountBondingCurveAta>
         : TAccountBondingCurveAta,
-      TAccountAuthorityAta extends string
-        ? WritableAccount<TAccountAuthorityAta>
-        : TAccountAuthorityAta,
-      TAccountSystemProgram extends string
-        ? ReadonlyAccount<TAccountSystemProgram>
-        : TAccountSystemProgram,
+      TAccountCreatorVault extends string
+        ? WritableAccount<TAccountCreatorVault>
+        : TAccountCreatorVault,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
-      TAccountAssociatedTokenProgram extends string
-        ? ReadonlyAccount<TAccountAssociatedTokenProgram>
-        : TAccountAssociatedTokenProgram,
-      TAccountRent extends string
-        ? ReadonlyAccount<TAccountRent>
-        : TAccountRent,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
       ...TRemainingAccounts,
     ]
   >;
 
-export type WithdrawInstructionData = { discriminator: ReadonlyUint8Array };
+export type UpgradeBondingCurveInstructionData = {
+  discriminator: ReadonlyUint8Array;
+};
 
-export type WithdrawInstructionDataArgs = {};
+export type UpgradeBondingCurveInstructionDataArgs = {};
 
-export function getWithdrawInstructionDataEncoder(): FixedSizeEncoder<WithdrawInstructionDataArgs> {
+export function getUpgradeBondingCurveInstructionDataEncoder(): FixedSizeEncoder<UpgradeBondingCurveInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)]]),
-    (value) => ({ ...value, discriminator: WITHDRAW_DISCRIMINATOR })
+    (value) => ({
+      ...value,
+      discriminator: UPGRADE_BONDING_CURVE_DISCRIMINATOR,
+    })
   );
 }
 
-export function getWithdrawInstructionDataDecoder(): FixedSizeDecoder<WithdrawInstructionData> {
+export function getUpgradeBondingCurveInstructionDataDecoder(): FixedSizeDecoder<UpgradeBondingCurveInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
   ]);
 }
 
-export function getWithdrawInstructionDataCodec(): FixedSizeCodec<
-  WithdrawInstructionDataArgs,
-  WithdrawInstructionData
+export function getUpgradeBondingCurveInstructionDataCodec(): FixedSizeCodec<
+  UpgradeBondingCurveInstructionDataArgs,
+  UpgradeBondingCurveInstructionData
 > {
   return combineCodec(
-    getWithdrawInstructionDataEncoder(),
-    getWithdrawInstructionDataDecoder()
+    getUpgradeBondingCurveInstructionDataEncoder(),
+    getUpgradeBondingCurveInstructionDataDecoder()
   );
 }
 
-export type WithdrawAsyncInput<
+export type UpgradeBondingCurveAsyncInput<
   TAccountAuthority extends string = string,
   TAccountGlobal extends string = string,
   TAccountMint extends string = string,
   TAccountBondingCurve extends string = string,
   TAccountBondingCurveAta extends string = string,
-  TAccountAuthorityAta extends string = string,
-  TAccountSystemProgram extends string = string,
+  TAccountCreatorVault extends string = string,
   TAccountTokenProgram extends string = string,
-  TAccountAssociatedTokenProgram extends string = string,
-  TAccountRent extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
   authority: TransactionSigner<TAccountAuthority>;
   global?: Address<TAccountGlobal>;
   mint: Address<TAccountMint>;
   bondingCurve?: Address<TAccountBondingCurve>;
   bondingCurveAta?: Address<TAccountBondingCurveAta>;
-  authorityAta?: Address<TAccountAuthorityAta>;
-  systemProgram?: Address<TAccountSystemProgram>;
+  creatorVault?: Address<TAccountCreatorVault>;
   tokenProgram: Address<TAccountTokenProgram>;
-  associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
-  rent?: Address<TAccountRent>;
+  systemProgram?: Address<TAccountSystemProgram>;
 };
 
-export async function getWithdrawInstructionAsync<
+export async function getUpgradeBondingCurveInstructionAsync<
   TAccountAuthority extends string,
   TAccountGlobal extends string,
   TAccountMint extends string,
   TAccountBondingCurve extends string,
   TAccountBondingCurveAta extends string,
-  TAccountAuthorityAta extends string,
-  TAccountSystemProgram extends string,
+  TAccountCreatorVault extends string,
   TAccountTokenProgram extends string,
-  TAccountAssociatedTokenProgram extends string,
-  TAccountRent extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof COINFUN_PROGRAM_ADDRESS,
 >(
-  input: WithdrawAsyncInput<
+  input: UpgradeBondingCurveAsyncInput<
     TAccountAuthority,
     TAccountGlobal,
     TAccountMint,
     TAccountBondingCurve,
     TAccountBondingCurveAta,
-    TAccountAuthorityAta,
-    TAccountSystemProgram,
+    TAccountCreatorVault,
     TAccountTokenProgram,
-    TAccountAssociatedTokenProgram,
-    TAccountRent
+    TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
-  WithdrawInstruction<
+  UpgradeBondingCurveInstruction<
     TProgramAddress,
     TAccountAuthority,
     TAccountGlobal,
     TAccountMint,
     TAccountBondingCurve,
     TAccountBondingCurveAta,
-    TAccountAuthorityAta,
-    TAccountSystemProgram,
+    TAccountCreatorVault,
     TAccountTokenProgram,
-    TAccountAssociatedTokenProgram,
-    TAccountRent
+    TAccountSystemProgram
   >
 > {
   // Program address.
@@ -206,15 +191,13 @@ export async function getWithdrawInstructionAsync<
     global: { value: input.global ?? null, isWritable: false },
     mint: { value: input.mint ?? null, isWritable: false },
     bondingCurve: { value: input.bondingCurve ?? null, isWritable: true },
-    bondingCurveAta: { value: input.bondingCurveAta ?? null, isWritable: true },
-    authorityAta: { value: input.authorityAta ?? null, isWritable: true },
-    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
-    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
-    associatedTokenProgram: {
-      value: input.associatedTokenProgram ?? null,
+    bondingCurveAta: {
+      value: input.bondingCurveAta ?? null,
       isWritable: false,
     },
-    rent: { value: input.rent ?? null, isWritable: false },
+    creatorVault: { value: input.creatorVault ?? null, isWritable: true },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -249,31 +232,21 @@ export async function getWithdrawInstructionAsync<
         'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
       seeds: [
         getAddressEncoder().encode(expectAddress(accounts.bondingCurve.value)),
-        getBytesEncoder().encode(
-          new Uint8Array([
-            6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
-            121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133,
-            126, 255, 0, 169,
-          ])
-        ),
+        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
         getAddressEncoder().encode(expectAddress(accounts.mint.value)),
       ],
     });
   }
-  if (!accounts.authorityAta.value) {
-    accounts.authorityAta.value = await getProgramDerivedAddress({
-      programAddress:
-        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
+  if (!accounts.creatorVault.value) {
+    accounts.creatorVault.value = await getProgramDerivedAddress({
+      programAddress,
       seeds: [
-        getAddressEncoder().encode(expectAddress(accounts.authority.value)),
         getBytesEncoder().encode(
           new Uint8Array([
-            6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
-            121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133,
-            126, 255, 0, 169,
+            99, 114, 101, 97, 116, 111, 114, 95, 118, 97, 117, 108, 116,
           ])
         ),
-        getAddressEncoder().encode(expectAddress(accounts.mint.value)),
+        getAddressEncoder().encode(expectAddress(accounts.bondingCurve.value)),
       ],
     });
   }
@@ -281,14 +254,6 @@ export async function getWithdrawInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
-  if (!accounts.associatedTokenProgram.value) {
-    accounts.associatedTokenProgram.value =
-      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
-  }
-  if (!accounts.rent.value) {
-    accounts.rent.value =
-      'SysvarRent111111111111111111111111111111111' as Address<'SysvarRent111111111111111111111111111111111'>;
-  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -298,91 +263,77 @@ export async function getWithdrawInstructionAsync<
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.bondingCurve),
       getAccountMeta(accounts.bondingCurveAta),
-      getAccountMeta(accounts.authorityAta),
-      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.creatorVault),
       getAccountMeta(accounts.tokenProgram),
-      getAccountMeta(accounts.associatedTokenProgram),
-      getAccountMeta(accounts.rent),
+      getAccountMeta(accounts.systemProgram),
     ],
-    data: getWithdrawInstructionDataEncoder().encode({}),
+    data: getUpgradeBondingCurveInstructionDataEncoder().encode({}),
     programAddress,
-  } as WithdrawInstruction<
+  } as UpgradeBondingCurveInstruction<
     TProgramAddress,
     TAccountAuthority,
     TAccountGlobal,
     TAccountMint,
     TAccountBondingCurve,
     TAccountBondingCurveAta,
-    TAccountAuthorityAta,
-    TAccountSystemProgram,
+    TAccountCreatorVault,
     TAccountTokenProgram,
-    TAccountAssociatedTokenProgram,
-    TAccountRent
+    TAccountSystemProgram
   >);
 }
 
-export type WithdrawInput<
+export type UpgradeBondingCurveInput<
   TAccountAuthority extends string = string,
   TAccountGlobal extends string = string,
   TAccountMint extends string = string,
   TAccountBondingCurve extends string = string,
   TAccountBondingCurveAta extends string = string,
-  TAccountAuthorityAta extends string = string,
-  TAccountSystemProgram extends string = string,
+  TAccountCreatorVault extends string = string,
   TAccountTokenProgram extends string = string,
-  TAccountAssociatedTokenProgram extends string = string,
-  TAccountRent extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
   authority: TransactionSigner<TAccountAuthority>;
   global: Address<TAccountGlobal>;
   mint: Address<TAccountMint>;
   bondingCurve: Address<TAccountBondingCurve>;
   bondingCurveAta: Address<TAccountBondingCurveAta>;
-  authorityAta: Address<TAccountAuthorityAta>;
-  systemProgram?: Address<TAccountSystemProgram>;
+  creatorVault: Address<TAccountCreatorVault>;
   tokenProgram: Address<TAccountTokenProgram>;
-  associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
-  rent?: Address<TAccountRent>;
+  systemProgram?: Address<TAccountSystemProgram>;
 };
 
-export function getWithdrawInstruction<
+export function getUpgradeBondingCurveInstruction<
   TAccountAuthority extends string,
   TAccountGlobal extends string,
   TAccountMint extends string,
   TAccountBondingCurve extends string,
   TAccountBondingCurveAta extends string,
-  TAccountAuthorityAta extends string,
-  TAccountSystemProgram extends string,
+  TAccountCreatorVault extends string,
   TAccountTokenProgram extends string,
-  TAccountAssociatedTokenProgram extends string,
-  TAccountRent extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof COINFUN_PROGRAM_ADDRESS,
 >(
-  input: WithdrawInput<
+  input: UpgradeBondingCurveInput<
     TAccountAuthority,
     TAccountGlobal,
     TAccountMint,
     TAccountBondingCurve,
     TAccountBondingCurveAta,
-    TAccountAuthorityAta,
-    TAccountSystemProgram,
+    TAccountCreatorVault,
     TAccountTokenProgram,
-    TAccountAssociatedTokenProgram,
-    TAccountRent
+    TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
-): WithdrawInstruction<
+): UpgradeBondingCurveInstruction<
   TProgramAddress,
   TAccountAuthority,
   TAccountGlobal,
   TAccountMint,
   TAccountBondingCurve,
   TAccountBondingCurveAta,
-  TAccountAuthorityAta,
-  TAccountSystemProgram,
+  TAccountCreatorVault,
   TAccountTokenProgram,
-  TAccountAssociatedTokenProgram,
-  TAccountRent
+  TAccountSystemProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? COINFUN_PROGRAM_ADDRESS;
@@ -393,15 +344,13 @@ export function getWithdrawInstruction<
     global: { value: input.global ?? null, isWritable: false },
     mint: { value: input.mint ?? null, isWritable: false },
     bondingCurve: { value: input.bondingCurve ?? null, isWritable: true },
-    bondingCurveAta: { value: input.bondingCurveAta ?? null, isWritable: true },
-    authorityAta: { value: input.authorityAta ?? null, isWritable: true },
-    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
-    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
-    associatedTokenProgram: {
-      value: input.associatedTokenProgram ?? null,
+    bondingCurveAta: {
+      value: input.bondingCurveAta ?? null,
       isWritable: false,
     },
-    rent: { value: input.rent ?? null, isWritable: false },
+    creatorVault: { value: input.creatorVault ?? null, isWritable: true },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -413,14 +362,6 @@ export function getWithdrawInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
-  if (!accounts.associatedTokenProgram.value) {
-    accounts.associatedTokenProgram.value =
-      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
-  }
-  if (!accounts.rent.value) {
-    accounts.rent.value =
-      'SysvarRent111111111111111111111111111111111' as Address<'SysvarRent111111111111111111111111111111111'>;
-  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -430,30 +371,26 @@ export function getWithdrawInstruction<
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.bondingCurve),
       getAccountMeta(accounts.bondingCurveAta),
-      getAccountMeta(accounts.authorityAta),
-      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.creatorVault),
       getAccountMeta(accounts.tokenProgram),
-      getAccountMeta(accounts.associatedTokenProgram),
-      getAccountMeta(accounts.rent),
+      getAccountMeta(accounts.systemProgram),
     ],
-    data: getWithdrawInstructionDataEncoder().encode({}),
+    data: getUpgradeBondingCurveInstructionDataEncoder().encode({}),
     programAddress,
-  } as WithdrawInstruction<
+  } as UpgradeBondingCurveInstruction<
     TProgramAddress,
     TAccountAuthority,
     TAccountGlobal,
     TAccountMint,
     TAccountBondingCurve,
     TAccountBondingCurveAta,
-    TAccountAuthorityAta,
-    TAccountSystemProgram,
+    TAccountCreatorVault,
     TAccountTokenProgram,
-    TAccountAssociatedTokenProgram,
-    TAccountRent
+    TAccountSystemProgram
   >);
 }
 
-export type ParsedWithdrawInstruction<
+export type ParsedUpgradeBondingCurveInstruction<
   TProgram extends string = typeof COINFUN_PROGRAM_ADDRESS,
   TAccountMetas extends readonly AccountMeta[] = readonly AccountMeta[],
 > = {
@@ -464,24 +401,22 @@ export type ParsedWithdrawInstruction<
     mint: TAccountMetas[2];
     bondingCurve: TAccountMetas[3];
     bondingCurveAta: TAccountMetas[4];
-    authorityAta: TAccountMetas[5];
-    systemProgram: TAccountMetas[6];
-    tokenProgram: TAccountMetas[7];
-    associatedTokenProgram: TAccountMetas[8];
-    rent: TAccountMetas[9];
+    creatorVault: TAccountMetas[5];
+    tokenProgram: TAccountMetas[6];
+    systemProgram: TAccountMetas[7];
   };
-  data: WithdrawInstructionData;
+  data: UpgradeBondingCurveInstructionData;
 };
 
-export function parseWithdrawInstruction<
+export function parseUpgradeBondingCurveInstruction<
   TProgram extends string,
   TAccountMetas extends readonly AccountMeta[],
 >(
   instruction: Instruction<TProgram> &
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
-): ParsedWithdrawInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 10) {
+): ParsedUpgradeBondingCurveInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -499,12 +434,12 @@ export function parseWithdrawInstruction<
       mint: getNextAccount(),
       bondingCurve: getNextAccount(),
       bondingCurveAta: getNextAccount(),
-      authorityAta: getNextAccount(),
-      systemProgram: getNextAccount(),
+      creatorVault: getNextAccount(),
       tokenProgram: getNextAccount(),
-      associatedTokenProgram: getNextAccount(),
-      rent: getNextAccount(),
+      systemProgram: getNextAccount(),
     },
-    data: getWithdrawInstructionDataDecoder().decode(instruction.data),
+    data: getUpgradeBondingCurveInstructionDataDecoder().decode(
+      instruction.data
+    ),
   };
 }
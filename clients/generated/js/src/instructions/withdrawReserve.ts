@@ -58,6 +58,7 @@ export type WithdrawReserveInstruction<
   TAccountGlobalReserve extends string | AccountMeta<string> = string,
   TAccountMint extends string | AccountMeta<string> = string,
   TAccountBondingCurve extends string | AccountMeta<string> = string,
+  TAccountStakePool extends string | AccountMeta<string> = string,
   TAccountReserveAta extends string | AccountMeta<string> = string,
   TAccountAuthorityAta extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
@@ -88,6 +89,9 @@ export type WithdrawReserveInstruction<
       TAccountBondingCurve extends string
         ? ReadonlyAccount<TAccountBondingCurve>
         : TAccountBondingCurve,
+      TAccountStakePool extends string
+        ? ReadonlyAccount<TAccountStakePool>
+        : TAccountStakePool,
       TAccountReserveAta extends string
         ? WritableAccount<TAccountReserveAta>
         : TAccountReserveAta,
@@ -147,6 +151,7 @@ export type WithdrawReserveAsyncInput<
   TAccountGlobalReserve extends string = string,
   TAccountMint extends string = string,
   TAccountBondingCurve extends string = string,
+  TAccountStakePool extends string = string,
   TAccountReserveAta extends string = string,
   TAccountAuthorityAta extends string = string,
   TAccountSystemProgram extends string = string,
@@ -158,6 +163,7 @@ export type WithdrawReserveAsyncInput<
   globalReserve?: Address<TAccountGlobalReserve>;
   mint: Address<TAccountMint>;
   bondingCurve?: Address<TAccountBondingCurve>;
+  stakePool?: Address<TAccountStakePool>;
   reserveAta?: Address<TAccountReserveAta>;
   authorityAta?: Address<TAccountAuthorityAta>;
   systemProgram?: Address<TAccountSystemProgram>;
@@ -172,6 +178,7 @@ export async function getWithdrawReserveInstructionAsync<
   TAccountGlobalReserve extends string,
   TAccountMint extends string,
   TAccountBondingCurve extends string,
+  TAccountStakePool extends string,
   TAccountReserveAta extends string,
   TAccountAuthorityAta extends string,
   TAccountSystemProgram extends string,
@@ -185,6 +192,7 @@ export async function getWithdrawReserveInstructionAsync<
     TAccountGlobalReserve,
     TAccountMint,
     TAccountBondingCurve,
+    TAccountStakePool,
     TAccountReserveAta,
     TAccountAuthorityAta,
     TAccountSystemProgram,
@@ -200,6 +208,7 @@ export async function getWithdrawReserveInstructionAsync<
     TAccountGlobalReserve,
     TAccountMint,
     TAccountBondingCurve,
+    TAccountStakePool,
     TAccountReserveAta,
     TAccountAuthorityAta,
     TAccountSystemProgram,
@@ -217,6 +226,7 @@ export async function getWithdrawReserveInstructionAsync<
     globalReserve: { value: input.globalReserve ?? null, isWritable: false },
     mint: { value: input.mint ?? null, isWritable: false },
     bondingCurve: { value: input.bondingCurve ?? null, isWritable: false },
+    stakePool: { value: input.stakePool ?? null, isWritable: false },
     reserveAta: { value: input.reserveAta ?? null, isWritable: true },
     authorityAta: { value: input.authorityAta ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
@@ -266,6 +276,17 @@ export async function getWithdrawReserveInstructionAsync<
       ],
     });
   }
+  if (!accounts.stakePool.value) {
+    accounts.stakePool.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([115, 116, 97, 107, 101, 95, 112, 111, 111, 108])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.mint.value)),
+      ],
+    });
+  }
   if (!accounts.reserveAta.value) {
     accounts.reserveAta.value = await getProgramDerivedAddress({
       programAddress:
@@ -317,6 +338,7 @@ export async function getWithdrawReserveInstructionAsync<
       getAccountMeta(accounts.globalReserve),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.bondingCurve),
+      getAccountMeta(accounts.stakePool),
       getAccountMeta(accounts.reserveAta),
       getAccountMeta(accounts.authorityAta),
       getAccountMeta(accounts.systemProgram),
@@ -334,6 +356,7 @@ export async function getWithdrawReserveInstructionAsync<
     TAccountGlobalReserve,
     TAccountMint,
     TAccountBondingCurve,
+    TAccountStakePool,
     TAccountReserveAta,
     TAccountAuthorityAta,
     TAccountSystemProgram,
@@ -348,6 +371,7 @@ export type WithdrawReserveInput<
   TAccountGlobalReserve extends string = string,
   TAccountMint extends string = string,
   TAccountBondingCurve extends string = string,
+  TAccountStakePool extends string = string,
   TAccountReserveAta extends string = string,
   TAccountAuthorityAta extends string = string,
   TAccountSystemProgram extends string = string,
@@ -359,6 +383,7 @@ export type WithdrawReserveInput<
   globalReserve: Address<TAccountGlobalReserve>;
   mint: Address<TAccountMint>;
   bondingCurve: Address<TAccountBondingCurve>;
+  stakePool?: Address<TAccountStakePool>;
   reserveAta: Address<TAccountReserveAta>;
   authorityAta: Address<TAccountAuthorityAta>;
   systemProgram?: Address<TAccountSystemProgram>;
@@ -373,6 +398,7 @@ export function getWithdrawReserveInstruction<
   TAccountGlobalReserve extends string,
   TAccountMint extends string,
   TAccountBondingCurve extends string,
+  TAccountStakePool extends string,
   TAccountReserveAta extends string,
   TAccountAuthorityAta extends string,
   TAccountSystemProgram extends string,
@@ -386,6 +412,7 @@ export function getWithdrawReserveInstruction<
     TAccountGlobalReserve,
     TAccountMint,
     TAccountBondingCurve,
+    TAccountStakePool,
     TAccountReserveAta,
     TAccountAuthorityAta,
     TAccountSystemProgram,
@@ -400,6 +427,7 @@ export function getWithdrawReserveInstruction<
   TAccountGlobalReserve,
   TAccountMint,
   TAccountBondingCurve,
+  TAccountStakePool,
   TAccountReserveAta,
   TAccountAuthorityAta,
   TAccountSystemProgram,
@@ -416,6 +444,7 @@ export function getWithdrawReserveInstruction<
     globalReserve: { value: input.globalReserve ?? null, isWritable: false },
     mint: { value: input.mint ?? null, isWritable: false },
     bondingCurve: { value: input.bondingCurve ?? null, isWritable: false },
+    stakePool: { value: input.stakePool ?? null, isWritable: false },
     reserveAta: { value: input.reserveAta ?? null, isWritable: true },
     authorityAta: { value: input.authorityAta ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
@@ -451,6 +480,7 @@ export function getWithdrawReserveInstruction<
       getAccountMeta(accounts.globalReserve),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.bondingCurve),
+      getAccountMeta(accounts.stakePool),
       getAccountMeta(accounts.reserveAta),
       getAccountMeta(accounts.authorityAta),
       getAccountMeta(accounts.systemProgram),
@@ -468,6 +498,7 @@ export function getWithdrawReserveInstruction<
     TAccountGlobalReserve,
     TAccountMint,
     TAccountBondingCurve,
+    TAccountStakePool,
     TAccountReserveAta,
     TAccountAuthorityAta,
     TAccountSystemProgram,
@@ -487,11 +518,12 @@ export type ParsedWithdrawReserveInstruction<
     globalReserve: TAccountMetas[2];
     mint: TAccountMetas[3];
     bondingCurve: TAccountMetas[4];
-    reserveAta: TAccountMetas[5];
-    authorityAta: TAccountMetas[6];
-    systemProgram: TAccountMetas[7];
-    tokenProgram: TAccountMetas[8];
-    associatedTokenProgram: TAccountMetas[9];
+    stakePool?: TAccountMetas[5] | undefined;
+    reserveAta: TAccountMetas[6];
+    authorityAta: TAccountMetas[7];
+    systemProgram: TAccountMetas[8];
+    tokenProgram: TAccountMetas[9];
+    associatedTokenProgram: TAccountMetas[10];
   };
   data: WithdrawReserveInstructionData;
 };
@@ -504,7 +536,7 @@ export function parseWithdrawReserveInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedWithdrawReserveInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 10) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -514,6 +546,12 @@ export function parseWithdrawReserveInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === COINFUN_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -522,6 +560,7 @@ export function parseWithdrawReserveInstruction<
       globalReserve: getNextAccount(),
       mint: getNextAccount(),
       bondingCurve: getNextAccount(),
+      stakePool: getNextOptionalAccount(),
       reserveAta: getNextAccount(),
       authorityAta: getNextAccount(),
       systemProgram: getNextAccount(),
@@ -14,22 +14,47 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedBuyExactOutInstruction,
   type ParsedBuyInstruction,
+  type ParsedClaimCreatorFeesInstruction,
+  type ParsedClaimReferralFeesInstruction,
+  type ParsedClaimStakingRewardsInstruction,
   type ParsedCreateInstruction,
+  type ParsedCreateStakePoolInstruction,
   type ParsedDepositToReserveInstruction,
+  type ParsedDistributePlatformFeesInstruction,
+  type ParsedHarvestPoolFeesInstruction,
   type ParsedInitializeInstruction,
+  type ParsedMigrateInstruction,
+  type ParsedRefundInstruction,
+  type ParsedRegisterReferrerInstruction,
+  type ParsedReleaseLpInstruction,
+  type ParsedRepriceCurveInstruction,
+  type ParsedSellForSolInstruction,
   type ParsedSellInstruction,
+  type ParsedSetCurveStatusInstruction,
+  type ParsedSetMigrationProgramInstruction,
+  type ParsedSetPlatformFeeRecipientsInstruction,
+  type ParsedSetPriceFeedInstruction,
+  type ParsedStakeInstruction,
+  type ParsedUnstakeInstruction,
   type ParsedUpdateGlobalConfigInstruction,
-  type ParsedWithdrawInstruction,
+  type ParsedUpgradeBondingCurveInstruction,
+  type ParsedUpgradeGlobalInstruction,
   type ParsedWithdrawReserveInstruction,
+  type ParsedWithdrawReserveSolInstruction,
 } from '../instructions';
 
 export const COINFUN_PROGRAM_ADDRESS =
-  '6xHUH1pJ2sLCm8YwyF8DBkUyBjDvoJKWgaXiBLaDdgsp' as Address<'6xHUH1pJ2sLCm8YwyF8DBkUyBjDvoJKWgaXiBLaDdgsp'>;
+  'ihC7UqkLYWxQKVuYLiWNGqGvQCZb2ih4DXMLfyM6F68' as Address<'ihC7UqkLYWxQKVuYLiWNGqGvQCZb2ih4DXMLfyM6F68'>;
 
 export enum CoinfunAccount {
   BondingCurve,
   Global,
+  LaunchAllocation,
+  Referrer,
+  StakePool,
+  StakePosition,
 }
 
 export function identifyCoinfunAccount(
@@ -58,6 +83,50 @@ export function identifyCoinfunAccount(
   ) {
     return CoinfunAccount.Global;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([125, 237, 117, 135, 71, 180, 187, 14])
+      ),
+      0
+    )
+  ) {
+    return CoinfunAccount.LaunchAllocation;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([99, 150, 214, 66, 111, 120, 49, 126])
+      ),
+      0
+    )
+  ) {
+    return CoinfunAccount.Referrer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([121, 34, 206, 21, 79, 127, 255, 28])
+      ),
+      0
+    )
+  ) {
+    return CoinfunAccount.StakePool;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([78, 165, 30, 111, 171, 125, 11, 220])
+      ),
+      0
+    )
+  ) {
+    return CoinfunAccount.StakePosition;
+  }
   throw new Error(
     'The provided account could not be identified as a coinfun account.'
   );
@@ -65,13 +134,34 @@ export function identifyCoinfunAccount(
 
 export enum CoinfunInstruction {
   Buy,
+  BuyExactOut,
+  ClaimCreatorFees,
+  ClaimReferralFees,
+  ClaimStakingRewards,
   Create,
+  CreateStakePool,
   DepositToReserve,
+  DistributePlatformFees,
+  HarvestPoolFees,
   Initialize,
+  Migrate,
+  Refund,
+  RegisterReferrer,
+  ReleaseLp,
+  RepriceCurve,
   Sell,
+  SellForSol,
+  SetCurveStatus,
+  SetMigrationProgram,
+  SetPlatformFeeRecipients,
+  SetPriceFeed,
+  Stake,
+  Unstake,
   UpdateGlobalConfig,
-  Withdraw,
+  UpgradeBondingCurve,
+  UpgradeGlobal,
   WithdrawReserve,
+  WithdrawReserveSol,
 }
 
 export function identifyCoinfunInstruction(
@@ -89,6 +179,50 @@ export function identifyCoinfunInstruction(
   ) {
     return CoinfunInstruction.Buy;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([24, 211, 116, 40, 105, 3, 153, 56])
+      ),
+      0
+    )
+  ) {
+    return CoinfunInstruction.BuyExactOut;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([0, 23, 125, 234, 156, 118, 134, 89])
+      ),
+      0
+    )
+  ) {
+    return CoinfunInstruction.ClaimCreatorFees;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([208, 216, 137, 78, 36, 103, 162, 49])
+      ),
+      0
+    )
+  ) {
+    return CoinfunInstruction.ClaimReferralFees;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([229, 141, 170, 69, 111, 94, 6, 72])
+      ),
+      0
+    )
+  ) {
+    return CoinfunInstruction.ClaimStakingRewards;
+  }
   if (
     containsBytes(
       data,
@@ -100,6 +234,17 @@ export function identifyCoinfunInstruction(
   ) {
     return CoinfunInstruction.Create;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([198, 175, 88, 63, 128, 43, 8, 214])
+      ),
+      0
+    )
+  ) {
+    return CoinfunInstruction.CreateStakePool;
+  }
   if (
     containsBytes(
       data,
@@ -111,6 +256,28 @@ export function identifyCoinfunInstruction(
   ) {
     return CoinfunInstruction.DepositToReserve;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([222, 221, 191, 28, 254, 90, 138, 248])
+      ),
+      0
+    )
+  ) {
+    return CoinfunInstruction.DistributePlatformFees;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([220, 111, 249, 100, 4, 231, 179, 83])
+      ),
+      0
+    )
+  ) {
+    return CoinfunInstruction.HarvestPoolFees;
+  }
   if (
     containsBytes(
       data,
@@ -122,6 +289,61 @@ export function identifyCoinfunInstruction(
   ) {
     return CoinfunInstruction.Initialize;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([155, 234, 231, 146, 236, 158, 162, 30])
+      ),
+      0
+    )
+  ) {
+    return CoinfunInstruction.Migrate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([2, 96, 183, 251, 63, 208, 46, 46])
+      ),
+      0
+    )
+  ) {
+    return CoinfunInstruction.Refund;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([122, 229, 215, 169, 100, 145, 198, 120])
+      ),
+      0
+    )
+  ) {
+    return CoinfunInstruction.RegisterReferrer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([207, 177, 27, 201, 0, 12, 111, 135])
+      ),
+      0
+    )
+  ) {
+    return CoinfunInstruction.ReleaseLp;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([59, 132, 32, 218, 118, 47, 245, 230])
+      ),
+      0
+    )
+  ) {
+    return CoinfunInstruction.RepriceCurve;
+  }
   if (
     containsBytes(
       data,
@@ -133,6 +355,83 @@ export function identifyCoinfunInstruction(
   ) {
     return CoinfunInstruction.Sell;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([104, 187, 114, 235, 177, 46, 96, 208])
+      ),
+      0
+    )
+  ) {
+    return CoinfunInstruction.SellForSol;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([109, 112, 77, 242, 244, 143, 61, 208])
+      ),
+      0
+    )
+  ) {
+    return CoinfunInstruction.SetCurveStatus;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([164, 62, 128, 74, 231, 47, 241, 42])
+      ),
+      0
+    )
+  ) {
+    return CoinfunInstruction.SetMigrationProgram;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([113, 240, 18, 65, 103, 74, 0, 77])
+      ),
+      0
+    )
+  ) {
+    return CoinfunInstruction.SetPlatformFeeRecipients;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([13, 15, 231, 129, 61, 7, 28, 122])
+      ),
+      0
+    )
+  ) {
+    return CoinfunInstruction.SetPriceFeed;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([206, 176, 202, 18, 200, 209, 179, 108])
+      ),
+      0
+    )
+  ) {
+    return CoinfunInstruction.Stake;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([90, 95, 107, 42, 205, 124, 50, 225])
+      ),
+      0
+    )
+  ) {
+    return CoinfunInstruction.Unstake;
+  }
   if (
     containsBytes(
       data,
@@ -148,12 +447,23 @@ export function identifyCoinfunInstruction(
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([183, 18, 70, 156, 148, 109, 161, 34])
+        new Uint8Array([12, 187, 75, 31, 125, 9, 204, 9])
+      ),
+      0
+    )
+  ) {
+    return CoinfunInstruction.UpgradeBondingCurve;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([64, 253, 189, 174, 158, 41, 8, 139])
       ),
       0
     )
   ) {
-    return CoinfunInstruction.Withdraw;
+    return CoinfunInstruction.UpgradeGlobal;
   }
   if (
     containsBytes(
@@ -166,35 +476,109 @@ export function identifyCoinfunInstruction(
   ) {
     return CoinfunInstruction.WithdrawReserve;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([182, 3, 52, 148, 104, 68, 46, 130])
+      ),
+      0
+    )
+  ) {
+    return CoinfunInstruction.WithdrawReserveSol;
+  }
   throw new Error(
     'The provided instruction could not be identified as a coinfun instruction.'
   );
 }
 
 export type ParsedCoinfunInstruction<
-  TProgram extends string = '6xHUH1pJ2sLCm8YwyF8DBkUyBjDvoJKWgaXiBLaDdgsp',
+  TProgram extends string = 'ihC7UqkLYWxQKVuYLiWNGqGvQCZb2ih4DXMLfyM6F68',
 > =
   | ({
       instructionType: CoinfunInstruction.Buy;
     } & ParsedBuyInstruction<TProgram>)
+  | ({
+      instructionType: CoinfunInstruction.BuyExactOut;
+    } & ParsedBuyExactOutInstruction<TProgram>)
+  | ({
+      instructionType: CoinfunInstruction.ClaimCreatorFees;
+    } & ParsedClaimCreatorFeesInstruction<TProgram>)
+  | ({
+      instructionType: CoinfunInstruction.ClaimReferralFees;
+    } & ParsedClaimReferralFeesInstruction<TProgram>)
+  | ({
+      instructionType: CoinfunInstruction.ClaimStakingRewards;
+    } & ParsedClaimStakingRewardsInstruction<TProgram>)
   | ({
       instructionType: CoinfunInstruction.Create;
     } & ParsedCreateInstruction<TProgram>)
+  | ({
+      instructionType: CoinfunInstruction.CreateStakePool;
+    } & ParsedCreateStakePoolInstruction<TProgram>)
   | ({
       instructionType: CoinfunInstruction.DepositToReserve;
     } & ParsedDepositToReserveInstruction<TProgram>)
+  | ({
+      instructionType: CoinfunInstruction.DistributePlatformFees;
+    } & ParsedDistributePlatformFeesInstruction<TProgram>)
+  | ({
+      instructionType: CoinfunInstruction.HarvestPoolFees;
+    } & ParsedHarvestPoolFeesInstruction<TProgram>)
   | ({
       instructionType: CoinfunInstruction.Initialize;
     } & ParsedInitializeInstruction<TProgram>)
+  | ({
+      instructionType: CoinfunInstruction.Migrate;
+    } & ParsedMigrateInstruction<TProgram>)
+  | ({
+      instructionType: CoinfunInstruction.Refund;
+    } & ParsedRefundInstruction<TProgram>)
+  | ({
+      instructionType: CoinfunInstruction.RegisterReferrer;
+    } & ParsedRegisterReferrerInstruction<TProgram>)
+  | ({
+      instructionType: CoinfunInstruction.ReleaseLp;
+    } & ParsedReleaseLpInstruction<TProgram>)
+  | ({
+      instructionType: CoinfunInstruction.RepriceCurve;
+    } & ParsedRepriceCurveInstruction<TProgram>)
   | ({
       instructionType: CoinfunInstruction.Sell;
     } & ParsedSellInstruction<TProgram>)
+  | ({
+      instructionType: CoinfunInstruction.SellForSol;
+    } & ParsedSellForSolInstruction<TProgram>)
+  | ({
+      instructionType: CoinfunInstruction.SetCurveStatus;
+    } & ParsedSetCurveStatusInstruction<TProgram>)
+  | ({
+      instructionType: CoinfunInstruction.SetMigrationProgram;
+    } & ParsedSetMigrationProgramInstruction<TProgram>)
+  | ({
+      instructionType: CoinfunInstruction.SetPlatformFeeRecipients;
+    } & ParsedSetPlatformFeeRecipientsInstruction<TProgram>)
+  | ({
+      instructionType: CoinfunInstruction.SetPriceFeed;
+    } & ParsedSetPriceFeedInstruction<TProgram>)
+  | ({
+      instructionType: CoinfunInstruction.Stake;
+    } & ParsedStakeInstruction<TProgram>)
+  | ({
+      instructionType: CoinfunInstruction.Unstake;
+    } & ParsedUnstakeInstruction<TProgram>)
   | ({
       instructionType: CoinfunInstruction.UpdateGlobalConfig;
     } & ParsedUpdateGlobalConfigInstruction<TProgram>)
   | ({
-      instructionType: CoinfunInstruction.Withdraw;
-    } & ParsedWithdrawInstruction<TProgram>)
+      instructionType: CoinfunInstruction.UpgradeBondingCurve;
+    } & ParsedUpgradeBondingCurveInstruction<TProgram>)
+  | ({
+      instructionType: CoinfunInstruction.UpgradeGlobal;
+    } & ParsedUpgradeGlobalInstruction<TProgram>)
   | ({
       instructionType: CoinfunInstruction.WithdrawReserve;
-    } & ParsedWithdrawReserveInstruction<TProgram>);
+    } & ParsedWithdrawReserveInstruction<TProgram>)
+  | ({
+      instructionType: CoinfunInstruction.WithdrawReserveSol;
+    } & ParsedWithdrawReserveSolInstruction<TProgram>);
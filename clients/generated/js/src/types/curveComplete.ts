@@ -17,15 +17,30 @@ import {
   type FixedSizeDecoder,
   type FixedSizeEncoder,
 } from '@solana/kit';
+import {
+  getGraduationCauseDecoder,
+  getGraduationCauseEncoder,
+  type GraduationCause,
+  type GraduationCauseArgs,
+} from '.';
 
-export type CurveComplete = { mint: Address; bondingCurve: Address };
+export type CurveComplete = {
+  mint: Address;
+  bondingCurve: Address;
+  cause: GraduationCause;
+};
 
-export type CurveCompleteArgs = CurveComplete;
+export type CurveCompleteArgs = {
+  mint: Address;
+  bondingCurve: Address;
+  cause: GraduationCauseArgs;
+};
 
 export function getCurveCompleteEncoder(): FixedSizeEncoder<CurveCompleteArgs> {
   return getStructEncoder([
     ['mint', getAddressEncoder()],
     ['bondingCurve', getAddressEncoder()],
+    ['cause', getGraduationCauseEncoder()],
   ]);
 }
 
@@ -33,6 +48,7 @@ export function getCurveCompleteDecoder(): FixedSizeDecoder<CurveComplete> {
   return getStructDecoder([
     ['mint', getAddressDecoder()],
     ['bondingCurve', getAddressDecoder()],
+    ['cause', getGraduationCauseDecoder()],
   ]);
 }
 
@@ -6,7 +6,40 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './creatorFeesClaimed';
 export * from './curveComplete';
+export * from './curveKind';
+export * from './curveMigrated';
+export * from './curveParams';
+export * from './curveRepriced';
+export * from './curveStatus';
+export * from './curveStatusChanged';
+export * from './feeDecay';
+export * from './graduationCause';
+export * from './graduationMode';
+export * from './launchFee';
+export * from './launchWindow';
+export * from './lpPolicy';
+export * from './lpReleased';
+export * from './migrationProgramSet';
+export * from './migrationTarget';
+export * from './platformFeeRecipient';
+export * from './platformFeeRecipientsSet';
+export * from './platformFeesDistributed';
+export * from './poolFeesHarvested';
+export * from './priceFeedSet';
+export * from './referralFeesClaimed';
+export * from './refunded';
+export * from './reservePolicy';
+export * from './reserveSolWithdrawn';
+export * from './stakePoolCreated';
+export * from './staked';
+export * from './stakingRewardsAccrued';
+export * from './stakingRewardsClaimed';
+export * from './surplusTokenPolicy';
 export * from './tokenCreated';
 export * from './trade';
 export * from './tradeSide';
+export * from './tradingOpened';
+export * from './tradingStart';
+export * from './unstaked';
@@ -10,18 +10,26 @@ import {
   combineCodec,
   getAddressDecoder,
   getAddressEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type Option,
+  type OptionOrNullable,
 } from '@solana/kit';
 import {
+  getReservePolicyDecoder,
+  getReservePolicyEncoder,
   getTradeSideDecoder,
   getTradeSideEncoder,
+  type ReservePolicy,
+  type ReservePolicyArgs,
   type TradeSide,
   type TradeSideArgs,
 } from '.';
@@ -32,6 +40,12 @@ export type Trade = {
   side: TradeSide;
   solAmount: bigint;
   tokenAmount: bigint;
+  solRefunded: bigint;
+  referrer: Option<Address>;
+  referralFee: bigint;
+  reservePolicy: ReservePolicy;
+  reserveFee: bigint;
+  reserveTokenAmount: bigint;
 };
 
 export type TradeArgs = {
@@ -40,28 +54,46 @@ export type TradeArgs = {
   side: TradeSideArgs;
   solAmount: number | bigint;
   tokenAmount: number | bigint;
+  solRefunded: number | bigint;
+  referrer: OptionOrNullable<Address>;
+  referralFee: number | bigint;
+  reservePolicy: ReservePolicyArgs;
+  reserveFee: number | bigint;
+  reserveTokenAmount: number | bigint;
 };
 
-export function getTradeEncoder(): FixedSizeEncoder<TradeArgs> {
+export function getTradeEncoder(): Encoder<TradeArgs> {
   return getStructEncoder([
     ['mint', getAddressEncoder()],
     ['trader', getAddressEncoder()],
     ['side', getTradeSideEncoder()],
     ['solAmount', getU64Encoder()],
     ['tokenAmount', getU64Encoder()],
+    ['solRefunded', getU64Encoder()],
+    ['referrer', getOptionEncoder(getAddressEncoder())],
+    ['referralFee', getU64Encoder()],
+    ['reservePolicy', getReservePolicyEncoder()],
+    ['reserveFee', getU64Encoder()],
+    ['reserveTokenAmount', getU64Encoder()],
   ]);
 }
 
-export function getTradeDecoder(): FixedSizeDecoder<Trade> {
+export function getTradeDecoder(): Decoder<Trade> {
   return getStructDecoder([
     ['mint', getAddressDecoder()],
     ['trader', getAddressDecoder()],
     ['side', getTradeSideDecoder()],
     ['solAmount', getU64Decoder()],
     ['tokenAmount', getU64Decoder()],
+    ['solRefunded', getU64Decoder()],
+    ['referrer', getOptionDecoder(getAddressDecoder())],
+    ['referralFee', getU64Decoder()],
+    ['reservePolicy', getReservePolicyDecoder()],
+    ['reserveFee', getU64Decoder()],
+    ['reserveTokenAmount', getU64Decoder()],
   ]);
 }
 
-export function getTradeCodec(): FixedSizeCodec<TradeArgs, Trade> {
+export function getTradeCodec(): Codec<TradeArgs, Trade> {
   return combineCodec(getTradeEncoder(), getTradeDecoder());
 }
@@ -6,6 +6,15 @@
 //!
 
 use solana_pubkey::Pubkey;
+use crate::generated::types::CurveStatus;
+use crate::generated::types::MigrationTarget;
+use crate::generated::types::LpPolicy;
+use crate::generated::types::CurveKind;
+use crate::generated::types::GraduationMode;
+use crate::generated::types::LaunchWindow;
+use crate::generated::types::TradingStart;
+use crate::generated::types::LaunchFee;
+use crate::generated::types::ReservePolicy;
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
 
@@ -23,7 +32,24 @@ pub virtual_sol_reserves: u64,
 pub real_token_reserves: u64,
 pub real_sol_reserves: u64,
 pub token_total_supply: u64,
-pub complete: bool,
+pub status: CurveStatus,
+pub migration_target: MigrationTarget,
+pub lp_policy: LpPolicy,
+pub lp_unlock_slot: u64,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub lp_mint: Pubkey,
+pub curve_kind: CurveKind,
+pub graduation_threshold: u64,
+pub platform_trade_fee_bps: u64,
+pub reserve_trade_fee_bps: u64,
+pub graduation_mode: GraduationMode,
+pub created_slot: u64,
+pub launch_window: LaunchWindow,
+pub trading_starts_at: TradingStart,
+pub trading_opened_slot: u64,
+pub launch_fee: LaunchFee,
+pub creator_trade_fee_bps: u64,
+pub reserve_policy: ReservePolicy,
 }
 
 
@@ -6,6 +6,10 @@
 //!
 
 use solana_pubkey::Pubkey;
+use crate::generated::types::LpPolicy;
+use crate::generated::types::SurplusTokenPolicy;
+use crate::generated::types::CurveParams;
+use crate::generated::types::PlatformFeeRecipient;
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
 
@@ -17,8 +21,6 @@ pub discriminator: [u8; 8],
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub authority: Pubkey,
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
-pub platform_fee_recipient: Pubkey,
-#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub reserve: Pubkey,
 pub initial_virtual_token_reserves: u64,
 pub initial_virtual_sol_reserves: u64,
@@ -26,6 +28,24 @@ pub token_total_supply: u64,
 pub platform_trade_fee_bps: u64,
 pub reserve_trade_fee_bps: u64,
 pub graduation_threshold: u64,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<[serde_with::DisplayFromStr; 3]>"))]
+pub migration_programs: [Pubkey; 3],
+pub lp_policy: LpPolicy,
+pub keeper_reward_bps: u64,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<[serde_with::DisplayFromStr; 3]>"))]
+pub migration_configs: [Pubkey; 3],
+pub surplus_token_policy: SurplusTokenPolicy,
+pub migration_fee_lamports: u64,
+pub curve_kinds: [bool; 3],
+pub min_curve_params: CurveParams,
+pub max_curve_params: CurveParams,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub sol_usd_price_feed: Pubkey,
+pub max_price_age_secs: u64,
+pub max_price_confidence_bps: u64,
+pub creator_trade_fee_bps: u64,
+pub referral_fee_bps: u64,
+pub platform_fee_recipients: [PlatformFeeRecipient; 5],
 }
 
 
@@ -7,7 +7,15 @@
 
   pub(crate) mod r#bonding_curve;
   pub(crate) mod r#global;
+  pub(crate) mod r#launch_allocation;
+  pub(crate) mod r#referrer;
+  pub(crate) mod r#stake_pool;
+  pub(crate) mod r#stake_position;
 
   pub use self::r#bonding_curve::*;
   pub use self::r#global::*;
+  pub use self::r#launch_allocation::*;
+  pub use self::r#referrer::*;
+  pub use self::r#stake_pool::*;
+  pub use self::r#stake_position::*;
 
@@ -25,9 +25,108 @@ pub enum CoinfunError {
     /// 6004 - Fee basis points cannot exceed 3000 (30%)
     #[error("Fee basis points cannot exceed 3000 (30%)")]
     FeeTooHigh = 0x1774,
-    /// 6005 - Total supply must be greater than the initial real token reserves.
-    #[error("Total supply must be greater than the initial real token reserves.")]
+    /// 6005 - Initial virtual token reserves must be greater than the total supply
+    #[error("Initial virtual token reserves must be greater than the total supply")]
     InvalidTokenReserveConfiguration = 0x1775,
+    /// 6006 - Bonding curve has nothing left to migrate
+    #[error("Bonding curve has nothing left to migrate")]
+    NothingToMigrate = 0x1776,
+    /// 6007 - Pool price derived from the curve is out of range
+    #[error("Pool price derived from the curve is out of range")]
+    PoolPriceOutOfRange = 0x1777,
+    /// 6008 - Bonding curve cannot move to that status
+    #[error("Bonding curve cannot move to that status")]
+    InvalidCurveStatusTransition = 0x1778,
+    /// 6009 - Bonding curve is not refunding
+    #[error("Bonding curve is not refunding")]
+    CurveNotRefunding = 0x1779,
+    /// 6010 - Migration target is not enabled
+    #[error("Migration target is not enabled")]
+    MigrationTargetNotAllowed = 0x177a,
+    /// 6011 - Not enough remaining accounts for the migration target
+    #[error("Not enough remaining accounts for the migration target")]
+    MissingMigrationAccounts = 0x177b,
+    /// 6012 - Unexpected account passed for the migration target
+    #[error("Unexpected account passed for the migration target")]
+    InvalidMigrationAccount = 0x177c,
+    /// 6013 - LP policy is not supported by the migration target
+    #[error("LP policy is not supported by the migration target")]
+    LpPolicyNotSupported = 0x177d,
+    /// 6014 - Migration target has no LP fees to claim
+    #[error("Migration target has no LP fees to claim")]
+    LpFeeClaimNotSupported = 0x177e,
+    /// 6015 - LP position is locked
+    #[error("LP position is locked")]
+    LpLocked = 0x177f,
+    /// 6016 - Bonding curve has not been migrated
+    #[error("Bonding curve has not been migrated")]
+    CurveNotMigrated = 0x1780,
+    /// 6017 - The curve's DEX pool already exists at another price
+    #[error("The curve's DEX pool already exists at another price")]
+    PoolPriceMismatch = 0x1781,
+    /// 6018 - Bonding curve cannot fill that token amount before graduating
+    #[error("Bonding curve cannot fill that token amount before graduating")]
+    TokenAmountUnavailable = 0x1782,
+    /// 6019 - Bonding curve cannot pay out that SOL amount
+    #[error("Bonding curve cannot pay out that SOL amount")]
+    SolAmountUnavailable = 0x1783,
+    /// 6020 - Curve kind not allowed
+    #[error("Curve kind not allowed")]
+    CurveKindNotAllowed = 0x1784,
+    /// 6021 - Invalid curve parameters
+    #[error("Invalid curve parameters")]
+    InvalidCurveParams = 0x1785,
+    /// 6022 - Curve parameters outside the allowed bounds
+    #[error("Curve parameters outside the allowed bounds")]
+    CurveParamsOutOfBounds = 0x1786,
+    /// 6023 - SOL/USD price feed is not configured or not passed
+    #[error("SOL/USD price feed is not configured or not passed")]
+    MissingPriceFeed = 0x1787,
+    /// 6024 - Invalid SOL/USD price feed account
+    #[error("Invalid SOL/USD price feed account")]
+    InvalidPriceFeed = 0x1788,
+    /// 6025 - SOL/USD price is too old
+    #[error("SOL/USD price is too old")]
+    PriceFeedStale = 0x1789,
+    /// 6026 - SOL/USD price confidence interval is too wide
+    #[error("SOL/USD price confidence interval is too wide")]
+    PriceFeedUncertain = 0x178a,
+    /// 6027 - Invalid launch window
+    #[error("Invalid launch window")]
+    InvalidLaunchWindow = 0x178b,
+    /// 6028 - Buy exceeds the launch window's SOL cap
+    #[error("Buy exceeds the launch window's SOL cap")]
+    LaunchBuyTooLarge = 0x178c,
+    /// 6029 - Wallet exceeds the launch window's token cap
+    #[error("Wallet exceeds the launch window's token cap")]
+    LaunchWalletCapExceeded = 0x178d,
+    /// 6030 - Launch window buys need the wallet's launch allocation account
+    #[error("Launch window buys need the wallet's launch allocation account")]
+    MissingLaunchAllocation = 0x178e,
+    /// 6031 - Trading on this curve has not started yet
+    #[error("Trading on this curve has not started yet")]
+    TradingNotStarted = 0x178f,
+    /// 6032 - Invalid launch fee
+    #[error("Invalid launch fee")]
+    InvalidLaunchFee = 0x1790,
+    /// 6033 - Launch fee basis points cannot exceed 5000 (50%)
+    #[error("Launch fee basis points cannot exceed 5000 (50%)")]
+    LaunchFeeTooHigh = 0x1791,
+    /// 6034 - Traders cannot refer themselves
+    #[error("Traders cannot refer themselves")]
+    SelfReferral = 0x1792,
+    /// 6035 - Invalid platform fee recipients
+    #[error("Invalid platform fee recipients")]
+    InvalidFeeRecipients = 0x1793,
+    /// 6036 - Amount exceeds what the reserve holds
+    #[error("Amount exceeds what the reserve holds")]
+    InsufficientReserve = 0x1794,
+    /// 6037 - Unstake exceeds the staked amount
+    #[error("Unstake exceeds the staked amount")]
+    InsufficientStake = 0x1795,
+    /// 6038 - The mint's reserve belongs to its stakers
+    #[error("The mint's reserve belongs to its stakers")]
+    ReserveStaked = 0x1796,
 }
 
 impl From<CoinfunError> for solana_program_error::ProgramError {
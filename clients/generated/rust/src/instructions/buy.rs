@@ -33,7 +33,7 @@ pub struct Buy {
           pub global: solana_pubkey::Pubkey,
           
               
-          pub platform_fee_recipient: solana_pubkey::Pubkey,
+          pub platform_fee_vault: solana_pubkey::Pubkey,
           
               
           pub global_reserve: solana_pubkey::Pubkey,
@@ -42,6 +42,12 @@ pub struct Buy {
           pub reserve_ata: solana_pubkey::Pubkey,
           
               
+          pub creator_vault: solana_pubkey::Pubkey,
+          
+              
+          pub reserve_sol_vault: solana_pubkey::Pubkey,
+          
+              
           pub system_program: solana_pubkey::Pubkey,
           
               
@@ -49,6 +55,16 @@ pub struct Buy {
           
               
           pub associated_token_program: solana_pubkey::Pubkey,
+          
+              
+          /// `Global.sol_usd_price_feed` and decoded in `Global::sol_usd_price`
+          pub price_feed: Option<solana_pubkey::Pubkey>,
+          
+              
+          pub launch_allocation: Option<solana_pubkey::Pubkey>,
+          
+              
+          pub referrer: Option<solana_pubkey::Pubkey>,
       }
 
 impl Buy {
@@ -58,7 +74,7 @@ impl Buy {
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, args: BuyInstructionArgs, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(12+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(17+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             self.signer,
             true
@@ -75,7 +91,7 @@ impl Buy {
             self.user_ata,
             false
           ));
-                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+                                          accounts.push(solana_instruction::AccountMeta::new(
             self.mint,
             false
           ));
@@ -84,7 +100,7 @@ impl Buy {
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
-            self.platform_fee_recipient,
+            self.platform_fee_vault,
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
@@ -94,6 +110,14 @@ impl Buy {
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.reserve_ata,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.creator_vault,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.reserve_sol_vault,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
@@ -107,6 +131,39 @@ impl Buy {
             self.associated_token_program,
             false
           ));
+                                          if let Some(price_feed) = self.price_feed {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                price_feed,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::COINFUN_ID,
+                false,
+              ));
+            }
+                                          if let Some(launch_allocation) = self.launch_allocation {
+              accounts.push(solana_instruction::AccountMeta::new(
+                launch_allocation,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::COINFUN_ID,
+                false,
+              ));
+            }
+                                          if let Some(referrer) = self.referrer {
+              accounts.push(solana_instruction::AccountMeta::new(
+                referrer,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::COINFUN_ID,
+                false,
+              ));
+            }
                       accounts.extend_from_slice(remaining_accounts);
     let mut data = borsh::to_vec(&BuyInstructionData::new()).unwrap();
           let mut args = borsh::to_vec(&args).unwrap();
@@ -156,14 +213,19 @@ impl Default for BuyInstructionData {
                 ///   1. `[writable]` bonding_curve
                 ///   2. `[writable]` bonding_curve_ata
                 ///   3. `[writable]` user_ata
-          ///   4. `[]` mint
+                ///   4. `[writable]` mint
           ///   5. `[]` global
-                ///   6. `[writable]` platform_fee_recipient
+                ///   6. `[writable]` platform_fee_vault
           ///   7. `[]` global_reserve
                 ///   8. `[writable]` reserve_ata
-                ///   9. `[optional]` system_program (default to `11111111111111111111111111111111`)
-          ///   10. `[]` token_program
-                ///   11. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+                ///   9. `[writable]` creator_vault
+                ///   10. `[writable]` reserve_sol_vault
+                ///   11. `[optional]` system_program (default to `11111111111111111111111111111111`)
+          ///   12. `[]` token_program
+                ///   13. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+                ///   14. `[optional]` price_feed
+                      ///   15. `[writable, optional]` launch_allocation
+                      ///   16. `[writable, optional]` referrer
 #[derive(Clone, Debug, Default)]
 pub struct BuyBuilder {
             signer: Option<solana_pubkey::Pubkey>,
@@ -172,12 +234,17 @@ pub struct BuyBuilder {
                 user_ata: Option<solana_pubkey::Pubkey>,
                 mint: Option<solana_pubkey::Pubkey>,
                 global: Option<solana_pubkey::Pubkey>,
-                platform_fee_recipient: Option<solana_pubkey::Pubkey>,
+                platform_fee_vault: Option<solana_pubkey::Pubkey>,
                 global_reserve: Option<solana_pubkey::Pubkey>,
                 reserve_ata: Option<solana_pubkey::Pubkey>,
+                creator_vault: Option<solana_pubkey::Pubkey>,
+                reserve_sol_vault: Option<solana_pubkey::Pubkey>,
                 system_program: Option<solana_pubkey::Pubkey>,
                 token_program: Option<solana_pubkey::Pubkey>,
                 associated_token_program: Option<solana_pubkey::Pubkey>,
+                price_feed: Option<solana_pubkey::Pubkey>,
+                launch_allocation: Option<solana_pubkey::Pubkey>,
+                referrer: Option<solana_pubkey::Pubkey>,
                         sol_amount: Option<u64>,
                 min_token_output: Option<u64>,
         __remaining_accounts: Vec<solana_instruction::AccountMeta>,
@@ -218,8 +285,8 @@ impl BuyBuilder {
                     self
     }
             #[inline(always)]
-    pub fn platform_fee_recipient(&mut self, platform_fee_recipient: solana_pubkey::Pubkey) -> &mut Self {
-                        self.platform_fee_recipient = Some(platform_fee_recipient);
+    pub fn platform_fee_vault(&mut self, platform_fee_vault: solana_pubkey::Pubkey) -> &mut Self {
+                        self.platform_fee_vault = Some(platform_fee_vault);
                     self
     }
             #[inline(always)]
@@ -231,6 +298,16 @@ impl BuyBuilder {
     pub fn reserve_ata(&mut self, reserve_ata: solana_pubkey::Pubkey) -> &mut Self {
                         self.reserve_ata = Some(reserve_ata);
                     self
+    }
+            #[inline(always)]
+    pub fn creator_vault(&mut self, creator_vault: solana_pubkey::Pubkey) -> &mut Self {
+                        self.creator_vault = Some(creator_vault);
+                    self
+    }
+            #[inline(always)]
+    pub fn reserve_sol_vault(&mut self, reserve_sol_vault: solana_pubkey::Pubkey) -> &mut Self {
+                        self.reserve_sol_vault = Some(reserve_sol_vault);
+                    self
     }
             /// `[optional account, default to '11111111111111111111111111111111']`
 #[inline(always)]
@@ -248,6 +325,24 @@ impl BuyBuilder {
     pub fn associated_token_program(&mut self, associated_token_program: solana_pubkey::Pubkey) -> &mut Self {
                         self.associated_token_program = Some(associated_token_program);
                     self
+    }
+            /// `[optional account]`
+#[inline(always)]
+    pub fn price_feed(&mut self, price_feed: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.price_feed = price_feed;
+                    self
+    }
+            /// `[optional account]`
+#[inline(always)]
+    pub fn launch_allocation(&mut self, launch_allocation: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.launch_allocation = launch_allocation;
+                    self
+    }
+            /// `[optional account]`
+#[inline(always)]
+    pub fn referrer(&mut self, referrer: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.referrer = referrer;
+                    self
     }
                     #[inline(always)]
       pub fn sol_amount(&mut self, sol_amount: u64) -> &mut Self {
@@ -280,12 +375,17 @@ impl BuyBuilder {
                                         user_ata: self.user_ata.expect("user_ata is not set"),
                                         mint: self.mint.expect("mint is not set"),
                                         global: self.global.expect("global is not set"),
-                                        platform_fee_recipient: self.platform_fee_recipient.expect("platform_fee_recipient is not set"),
+                                        platform_fee_vault: self.platform_fee_vault.expect("platform_fee_vault is not set"),
                                         global_reserve: self.global_reserve.expect("global_reserve is not set"),
                                         reserve_ata: self.reserve_ata.expect("reserve_ata is not set"),
+                                        creator_vault: self.creator_vault.expect("creator_vault is not set"),
+                                        reserve_sol_vault: self.reserve_sol_vault.expect("reserve_sol_vault is not set"),
                                         system_program: self.system_program.unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
                                         token_program: self.token_program.expect("token_program is not set"),
                                         associated_token_program: self.associated_token_program.unwrap_or(solana_pubkey::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")),
+                                        price_feed: self.price_feed,
+                                        launch_allocation: self.launch_allocation,
+                                        referrer: self.referrer,
                       };
           let args = BuyInstructionArgs {
                                                               sol_amount: self.sol_amount.clone().expect("sol_amount is not set"),
@@ -318,7 +418,7 @@ impl BuyBuilder {
               pub global: &'b solana_account_info::AccountInfo<'a>,
                 
                     
-              pub platform_fee_recipient: &'b solana_account_info::AccountInfo<'a>,
+              pub platform_fee_vault: &'b solana_account_info::AccountInfo<'a>,
                 
                     
               pub global_reserve: &'b solana_account_info::AccountInfo<'a>,
@@ -327,6 +427,12 @@ impl BuyBuilder {
               pub reserve_ata: &'b solana_account_info::AccountInfo<'a>,
                 
                     
+              pub creator_vault: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
+              pub reserve_sol_vault: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
               pub system_program: &'b solana_account_info::AccountInfo<'a>,
                 
                     
@@ -334,6 +440,16 @@ impl BuyBuilder {
                 
                     
               pub associated_token_program: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
+              /// `Global.sol_usd_price_feed` and decoded in `Global::sol_usd_price`
+              pub price_feed: Option<&'b solana_account_info::AccountInfo<'a>>,
+                
+                    
+              pub launch_allocation: Option<&'b solana_account_info::AccountInfo<'a>>,
+                
+                    
+              pub referrer: Option<&'b solana_account_info::AccountInfo<'a>>,
             }
 
 /// `buy` CPI instruction.
@@ -360,7 +476,7 @@ pub struct BuyCpi<'a, 'b> {
           pub global: &'b solana_account_info::AccountInfo<'a>,
           
               
-          pub platform_fee_recipient: &'b solana_account_info::AccountInfo<'a>,
+          pub platform_fee_vault: &'b solana_account_info::AccountInfo<'a>,
           
               
           pub global_reserve: &'b solana_account_info::AccountInfo<'a>,
@@ -369,6 +485,12 @@ pub struct BuyCpi<'a, 'b> {
           pub reserve_ata: &'b solana_account_info::AccountInfo<'a>,
           
               
+          pub creator_vault: &'b solana_account_info::AccountInfo<'a>,
+          
+              
+          pub reserve_sol_vault: &'b solana_account_info::AccountInfo<'a>,
+          
+              
           pub system_program: &'b solana_account_info::AccountInfo<'a>,
           
               
@@ -376,6 +498,16 @@ pub struct BuyCpi<'a, 'b> {
           
               
           pub associated_token_program: &'b solana_account_info::AccountInfo<'a>,
+          
+              
+          /// `Global.sol_usd_price_feed` and decoded in `Global::sol_usd_price`
+          pub price_feed: Option<&'b solana_account_info::AccountInfo<'a>>,
+          
+              
+          pub launch_allocation: Option<&'b solana_account_info::AccountInfo<'a>>,
+          
+              
+          pub referrer: Option<&'b solana_account_info::AccountInfo<'a>>,
             /// The arguments for the instruction.
     pub __args: BuyInstructionArgs,
   }
@@ -394,12 +526,17 @@ impl<'a, 'b> BuyCpi<'a, 'b> {
               user_ata: accounts.user_ata,
               mint: accounts.mint,
               global: accounts.global,
-              platform_fee_recipient: accounts.platform_fee_recipient,
+              platform_fee_vault: accounts.platform_fee_vault,
               global_reserve: accounts.global_reserve,
               reserve_ata: accounts.reserve_ata,
+              creator_vault: accounts.creator_vault,
+              reserve_sol_vault: accounts.reserve_sol_vault,
               system_program: accounts.system_program,
               token_program: accounts.token_program,
               associated_token_program: accounts.associated_token_program,
+              price_feed: accounts.price_feed,
+              launch_allocation: accounts.launch_allocation,
+              referrer: accounts.referrer,
                     __args: args,
           }
   }
@@ -423,7 +560,7 @@ impl<'a, 'b> BuyCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_error::ProgramResult {
-    let mut accounts = Vec::with_capacity(12+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(17+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             *self.signer.key,
             true
@@ -440,7 +577,7 @@ impl<'a, 'b> BuyCpi<'a, 'b> {
             *self.user_ata.key,
             false
           ));
-                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+                                          accounts.push(solana_instruction::AccountMeta::new(
             *self.mint.key,
             false
           ));
@@ -449,7 +586,7 @@ impl<'a, 'b> BuyCpi<'a, 'b> {
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
-            *self.platform_fee_recipient.key,
+            *self.platform_fee_vault.key,
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
@@ -459,6 +596,14 @@ impl<'a, 'b> BuyCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.reserve_ata.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.creator_vault.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.reserve_sol_vault.key,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
@@ -472,6 +617,39 @@ impl<'a, 'b> BuyCpi<'a, 'b> {
             *self.associated_token_program.key,
             false
           ));
+                                          if let Some(price_feed) = self.price_feed {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *price_feed.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::COINFUN_ID,
+                false,
+              ));
+            }
+                                          if let Some(launch_allocation) = self.launch_allocation {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *launch_allocation.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::COINFUN_ID,
+                false,
+              ));
+            }
+                                          if let Some(referrer) = self.referrer {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *referrer.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::COINFUN_ID,
+                false,
+              ));
+            }
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_instruction::AccountMeta {
           pubkey: *remaining_account.0.key,
@@ -488,7 +666,7 @@ impl<'a, 'b> BuyCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(13 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(18 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.signer.clone());
                         account_infos.push(self.bonding_curve.clone());
@@ -496,12 +674,23 @@ impl<'a, 'b> BuyCpi<'a, 'b> {
                         account_infos.push(self.user_ata.clone());
                         account_infos.push(self.mint.clone());
                         account_infos.push(self.global.clone());
-                        account_infos.push(self.platform_fee_recipient.clone());
+                        account_infos.push(self.platform_fee_vault.clone());
                         account_infos.push(self.global_reserve.clone());
                         account_infos.push(self.reserve_ata.clone());
+                        account_infos.push(self.creator_vault.clone());
+                        account_infos.push(self.reserve_sol_vault.clone());
                         account_infos.push(self.system_program.clone());
                         account_infos.push(self.token_program.clone());
                         account_infos.push(self.associated_token_program.clone());
+                        if let Some(price_feed) = self.price_feed {
+          account_infos.push(price_feed.clone());
+        }
+                        if let Some(launch_allocation) = self.launch_allocation {
+          account_infos.push(launch_allocation.clone());
+        }
+                        if let Some(referrer) = self.referrer {
+          account_infos.push(referrer.clone());
+        }
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -520,14 +709,19 @@ impl<'a, 'b> BuyCpi<'a, 'b> {
                 ///   1. `[writable]` bonding_curve
                 ///   2. `[writable]` bonding_curve_ata
                 ///   3. `[writable]` user_ata
-          ///   4. `[]` mint
+                ///   4. `[writable]` mint
           ///   5. `[]` global
-                ///   6. `[writable]` platform_fee_recipient
+                ///   6. `[writable]` platform_fee_vault
           ///   7. `[]` global_reserve
                 ///   8. `[writable]` reserve_ata
-          ///   9. `[]` system_program
-          ///   10. `[]` token_program
-          ///   11. `[]` associated_token_program
+                ///   9. `[writable]` creator_vault
+                ///   10. `[writable]` reserve_sol_vault
+          ///   11. `[]` system_program
+          ///   12. `[]` token_program
+          ///   13. `[]` associated_token_program
+                ///   14. `[optional]` price_feed
+                      ///   15. `[writable, optional]` launch_allocation
+                      ///   16. `[writable, optional]` referrer
 #[derive(Clone, Debug)]
 pub struct BuyCpiBuilder<'a, 'b> {
   instruction: Box<BuyCpiBuilderInstruction<'a, 'b>>,
@@ -543,12 +737,17 @@ impl<'a, 'b> BuyCpiBuilder<'a, 'b> {
               user_ata: None,
               mint: None,
               global: None,
-              platform_fee_recipient: None,
+              platform_fee_vault: None,
               global_reserve: None,
               reserve_ata: None,
+              creator_vault: None,
+              reserve_sol_vault: None,
               system_program: None,
               token_program: None,
               associated_token_program: None,
+              price_feed: None,
+              launch_allocation: None,
+              referrer: None,
                                             sol_amount: None,
                                 min_token_output: None,
                     __remaining_accounts: Vec::new(),
@@ -586,8 +785,8 @@ impl<'a, 'b> BuyCpiBuilder<'a, 'b> {
                     self
     }
       #[inline(always)]
-    pub fn platform_fee_recipient(&mut self, platform_fee_recipient: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.platform_fee_recipient = Some(platform_fee_recipient);
+    pub fn platform_fee_vault(&mut self, platform_fee_vault: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.platform_fee_vault = Some(platform_fee_vault);
                     self
     }
       #[inline(always)]
@@ -601,6 +800,16 @@ impl<'a, 'b> BuyCpiBuilder<'a, 'b> {
                     self
     }
       #[inline(always)]
+    pub fn creator_vault(&mut self, creator_vault: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.creator_vault = Some(creator_vault);
+                    self
+    }
+      #[inline(always)]
+    pub fn reserve_sol_vault(&mut self, reserve_sol_vault: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.reserve_sol_vault = Some(reserve_sol_vault);
+                    self
+    }
+      #[inline(always)]
     pub fn system_program(&mut self, system_program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.system_program = Some(system_program);
                     self
@@ -614,6 +823,24 @@ impl<'a, 'b> BuyCpiBuilder<'a, 'b> {
     pub fn associated_token_program(&mut self, associated_token_program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.associated_token_program = Some(associated_token_program);
                     self
+    }
+      /// `[optional account]`
+#[inline(always)]
+    pub fn price_feed(&mut self, price_feed: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.price_feed = price_feed;
+                    self
+    }
+      /// `[optional account]`
+#[inline(always)]
+    pub fn launch_allocation(&mut self, launch_allocation: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.launch_allocation = launch_allocation;
+                    self
+    }
+      /// `[optional account]`
+#[inline(always)]
+    pub fn referrer(&mut self, referrer: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.referrer = referrer;
+                    self
     }
                     #[inline(always)]
       pub fn sol_amount(&mut self, sol_amount: u64) -> &mut Self {
@@ -666,17 +893,27 @@ impl<'a, 'b> BuyCpiBuilder<'a, 'b> {
                   
           global: self.instruction.global.expect("global is not set"),
                   
-          platform_fee_recipient: self.instruction.platform_fee_recipient.expect("platform_fee_recipient is not set"),
+          platform_fee_vault: self.instruction.platform_fee_vault.expect("platform_fee_vault is not set"),
                   
           global_reserve: self.instruction.global_reserve.expect("global_reserve is not set"),
                   
           reserve_ata: self.instruction.reserve_ata.expect("reserve_ata is not set"),
                   
+          creator_vault: self.instruction.creator_vault.expect("creator_vault is not set"),
+                  
+          reserve_sol_vault: self.instruction.reserve_sol_vault.expect("reserve_sol_vault is not set"),
+                  
           system_program: self.instruction.system_program.expect("system_program is not set"),
                   
           token_program: self.instruction.token_program.expect("token_program is not set"),
                   
           associated_token_program: self.instruction.associated_token_program.expect("associated_token_program is not set"),
+                  
+          price_feed: self.instruction.price_feed,
+                  
+          launch_allocation: self.instruction.launch_allocation,
+                  
+          referrer: self.instruction.referrer,
                           __args: args,
             };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
@@ -692,12 +929,17 @@ struct BuyCpiBuilderInstruction<'a, 'b> {
                 user_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
                 mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 global: Option<&'b solana_account_info::AccountInfo<'a>>,
-                platform_fee_recipient: Option<&'b solana_account_info::AccountInfo<'a>>,
+                platform_fee_vault: Option<&'b solana_account_info::AccountInfo<'a>>,
                 global_reserve: Option<&'b solana_account_info::AccountInfo<'a>>,
                 reserve_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                creator_vault: Option<&'b solana_account_info::AccountInfo<'a>>,
+                reserve_sol_vault: Option<&'b solana_account_info::AccountInfo<'a>>,
                 system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 associated_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+                price_feed: Option<&'b solana_account_info::AccountInfo<'a>>,
+                launch_allocation: Option<&'b solana_account_info::AccountInfo<'a>>,
+                referrer: Option<&'b solana_account_info::AccountInfo<'a>>,
                         sol_amount: Option<u64>,
                 min_token_output: Option<u64>,
         /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
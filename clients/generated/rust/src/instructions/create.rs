@@ -5,6 +5,14 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::MigrationTarget;
+use crate::generated::types::CurveKind;
+use crate::generated::types::CurveParams;
+use crate::generated::types::GraduationMode;
+use crate::generated::types::LaunchWindow;
+use crate::generated::types::TradingStart;
+use crate::generated::types::LaunchFee;
+use crate::generated::types::ReservePolicy;
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
 
@@ -33,6 +41,9 @@ pub struct Create {
           pub bonding_curve_ata: solana_pubkey::Pubkey,
           
               
+          pub creator_vault: solana_pubkey::Pubkey,
+          
+              
           pub metadata_account: solana_pubkey::Pubkey,
           
               
@@ -58,7 +69,7 @@ impl Create {
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, args: CreateInstructionArgs, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(12+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(13+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             self.signer,
             true
@@ -82,6 +93,10 @@ impl Create {
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.bonding_curve_ata,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.creator_vault,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.metadata_account,
@@ -124,13 +139,13 @@ impl Create {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
  pub struct CreateInstructionData {
             discriminator: [u8; 8],
-                        }
+                                                                        }
 
 impl CreateInstructionData {
   pub fn new() -> Self {
     Self {
                         discriminator: [24, 30, 200, 40, 5, 28, 7, 119],
-                                                            }
+                                                                                                                                                                            }
   }
 }
 
@@ -146,6 +161,14 @@ impl Default for CreateInstructionData {
                   pub token_name: String,
                 pub token_symbol: String,
                 pub token_uri: String,
+                pub migration_target: MigrationTarget,
+                pub curve_kind: CurveKind,
+                pub curve_params: Option<CurveParams>,
+                pub graduation_mode: GraduationMode,
+                pub launch_window: Option<LaunchWindow>,
+                pub trading_starts_at: TradingStart,
+                pub launch_fee: Option<LaunchFee>,
+                pub reserve_policy: ReservePolicy,
       }
 
 
@@ -159,12 +182,13 @@ impl Default for CreateInstructionData {
           ///   3. `[]` global
                 ///   4. `[writable]` bonding_curve
                 ///   5. `[writable]` bonding_curve_ata
-                ///   6. `[writable]` metadata_account
-                ///   7. `[optional]` token_metadata_program (default to `metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s`)
-                ///   8. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
-          ///   9. `[]` token_program
-                ///   10. `[optional]` system_program (default to `11111111111111111111111111111111`)
-                ///   11. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
+                ///   6. `[writable]` creator_vault
+                ///   7. `[writable]` metadata_account
+                ///   8. `[optional]` token_metadata_program (default to `metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s`)
+                ///   9. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+          ///   10. `[]` token_program
+                ///   11. `[optional]` system_program (default to `11111111111111111111111111111111`)
+                ///   12. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct CreateBuilder {
             signer: Option<solana_pubkey::Pubkey>,
@@ -173,6 +197,7 @@ pub struct CreateBuilder {
                 global: Option<solana_pubkey::Pubkey>,
                 bonding_curve: Option<solana_pubkey::Pubkey>,
                 bonding_curve_ata: Option<solana_pubkey::Pubkey>,
+                creator_vault: Option<solana_pubkey::Pubkey>,
                 metadata_account: Option<solana_pubkey::Pubkey>,
                 token_metadata_program: Option<solana_pubkey::Pubkey>,
                 associated_token_program: Option<solana_pubkey::Pubkey>,
@@ -182,6 +207,14 @@ pub struct CreateBuilder {
                         token_name: Option<String>,
                 token_symbol: Option<String>,
                 token_uri: Option<String>,
+                migration_target: Option<MigrationTarget>,
+                curve_kind: Option<CurveKind>,
+                curve_params: Option<CurveParams>,
+                graduation_mode: Option<GraduationMode>,
+                launch_window: Option<LaunchWindow>,
+                trading_starts_at: Option<TradingStart>,
+                launch_fee: Option<LaunchFee>,
+                reserve_policy: Option<ReservePolicy>,
         __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -220,6 +253,11 @@ impl CreateBuilder {
                     self
     }
             #[inline(always)]
+    pub fn creator_vault(&mut self, creator_vault: solana_pubkey::Pubkey) -> &mut Self {
+                        self.creator_vault = Some(creator_vault);
+                    self
+    }
+            #[inline(always)]
     pub fn metadata_account(&mut self, metadata_account: solana_pubkey::Pubkey) -> &mut Self {
                         self.metadata_account = Some(metadata_account);
                     self
@@ -267,6 +305,49 @@ impl CreateBuilder {
       pub fn token_uri(&mut self, token_uri: String) -> &mut Self {
         self.token_uri = Some(token_uri);
         self
+      }
+                #[inline(always)]
+      pub fn migration_target(&mut self, migration_target: MigrationTarget) -> &mut Self {
+        self.migration_target = Some(migration_target);
+        self
+      }
+                #[inline(always)]
+      pub fn curve_kind(&mut self, curve_kind: CurveKind) -> &mut Self {
+        self.curve_kind = Some(curve_kind);
+        self
+      }
+                /// `[optional argument]`
+#[inline(always)]
+      pub fn curve_params(&mut self, curve_params: CurveParams) -> &mut Self {
+        self.curve_params = Some(curve_params);
+        self
+      }
+                #[inline(always)]
+      pub fn graduation_mode(&mut self, graduation_mode: GraduationMode) -> &mut Self {
+        self.graduation_mode = Some(graduation_mode);
+        self
+      }
+                /// `[optional argument]`
+#[inline(always)]
+      pub fn launch_window(&mut self, launch_window: LaunchWindow) -> &mut Self {
+        self.launch_window = Some(launch_window);
+        self
+      }
+                #[inline(always)]
+      pub fn trading_starts_at(&mut self, trading_starts_at: TradingStart) -> &mut Self {
+        self.trading_starts_at = Some(trading_starts_at);
+        self
+      }
+                /// `[optional argument]`
+#[inline(always)]
+      pub fn launch_fee(&mut self, launch_fee: LaunchFee) -> &mut Self {
+        self.launch_fee = Some(launch_fee);
+        self
+      }
+                #[inline(always)]
+      pub fn reserve_policy(&mut self, reserve_policy: ReservePolicy) -> &mut Self {
+        self.reserve_policy = Some(reserve_policy);
+        self
       }
         /// Add an additional account to the instruction.
   #[inline(always)]
@@ -289,6 +370,7 @@ impl CreateBuilder {
                                         global: self.global.expect("global is not set"),
                                         bonding_curve: self.bonding_curve.expect("bonding_curve is not set"),
                                         bonding_curve_ata: self.bonding_curve_ata.expect("bonding_curve_ata is not set"),
+                                        creator_vault: self.creator_vault.expect("creator_vault is not set"),
                                         metadata_account: self.metadata_account.expect("metadata_account is not set"),
                                         token_metadata_program: self.token_metadata_program.unwrap_or(solana_pubkey::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s")),
                                         associated_token_program: self.associated_token_program.unwrap_or(solana_pubkey::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")),
@@ -300,6 +382,14 @@ impl CreateBuilder {
                                                               token_name: self.token_name.clone().expect("token_name is not set"),
                                                                   token_symbol: self.token_symbol.clone().expect("token_symbol is not set"),
                                                                   token_uri: self.token_uri.clone().expect("token_uri is not set"),
+                                                                  migration_target: self.migration_target.clone().expect("migration_target is not set"),
+                                                                  curve_kind: self.curve_kind.clone().expect("curve_kind is not set"),
+                                                                  curve_params: self.curve_params.clone(),
+                                                                  graduation_mode: self.graduation_mode.clone().expect("graduation_mode is not set"),
+                                                                  launch_window: self.launch_window.clone(),
+                                                                  trading_starts_at: self.trading_starts_at.clone().expect("trading_starts_at is not set"),
+                                                                  launch_fee: self.launch_fee.clone(),
+                                                                  reserve_policy: self.reserve_policy.clone().expect("reserve_policy is not set"),
                                     };
     
     accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -328,6 +418,9 @@ impl CreateBuilder {
               pub bonding_curve_ata: &'b solana_account_info::AccountInfo<'a>,
                 
                     
+              pub creator_vault: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
               pub metadata_account: &'b solana_account_info::AccountInfo<'a>,
                 
                     
@@ -370,6 +463,9 @@ pub struct CreateCpi<'a, 'b> {
           pub bonding_curve_ata: &'b solana_account_info::AccountInfo<'a>,
           
               
+          pub creator_vault: &'b solana_account_info::AccountInfo<'a>,
+          
+              
           pub metadata_account: &'b solana_account_info::AccountInfo<'a>,
           
               
@@ -404,6 +500,7 @@ impl<'a, 'b> CreateCpi<'a, 'b> {
               global: accounts.global,
               bonding_curve: accounts.bonding_curve,
               bonding_curve_ata: accounts.bonding_curve_ata,
+              creator_vault: accounts.creator_vault,
               metadata_account: accounts.metadata_account,
               token_metadata_program: accounts.token_metadata_program,
               associated_token_program: accounts.associated_token_program,
@@ -433,7 +530,7 @@ impl<'a, 'b> CreateCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_error::ProgramResult {
-    let mut accounts = Vec::with_capacity(12+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(13+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             *self.signer.key,
             true
@@ -457,6 +554,10 @@ impl<'a, 'b> CreateCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.bonding_curve_ata.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.creator_vault.key,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.metadata_account.key,
@@ -498,7 +599,7 @@ impl<'a, 'b> CreateCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(13 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(14 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.signer.clone());
                         account_infos.push(self.creator.clone());
@@ -506,6 +607,7 @@ impl<'a, 'b> CreateCpi<'a, 'b> {
                         account_infos.push(self.global.clone());
                         account_infos.push(self.bonding_curve.clone());
                         account_infos.push(self.bonding_curve_ata.clone());
+                        account_infos.push(self.creator_vault.clone());
                         account_infos.push(self.metadata_account.clone());
                         account_infos.push(self.token_metadata_program.clone());
                         account_infos.push(self.associated_token_program.clone());
@@ -532,12 +634,13 @@ impl<'a, 'b> CreateCpi<'a, 'b> {
           ///   3. `[]` global
                 ///   4. `[writable]` bonding_curve
                 ///   5. `[writable]` bonding_curve_ata
-                ///   6. `[writable]` metadata_account
-          ///   7. `[]` token_metadata_program
-          ///   8. `[]` associated_token_program
-          ///   9. `[]` token_program
-          ///   10. `[]` system_program
-          ///   11. `[]` rent
+                ///   6. `[writable]` creator_vault
+                ///   7. `[writable]` metadata_account
+          ///   8. `[]` token_metadata_program
+          ///   9. `[]` associated_token_program
+          ///   10. `[]` token_program
+          ///   11. `[]` system_program
+          ///   12. `[]` rent
 #[derive(Clone, Debug)]
 pub struct CreateCpiBuilder<'a, 'b> {
   instruction: Box<CreateCpiBuilderInstruction<'a, 'b>>,
@@ -553,6 +656,7 @@ impl<'a, 'b> CreateCpiBuilder<'a, 'b> {
               global: None,
               bonding_curve: None,
               bonding_curve_ata: None,
+              creator_vault: None,
               metadata_account: None,
               token_metadata_program: None,
               associated_token_program: None,
@@ -562,6 +666,14 @@ impl<'a, 'b> CreateCpiBuilder<'a, 'b> {
                                             token_name: None,
                                 token_symbol: None,
                                 token_uri: None,
+                                migration_target: None,
+                                curve_kind: None,
+                                curve_params: None,
+                                graduation_mode: None,
+                                launch_window: None,
+                                trading_starts_at: None,
+                                launch_fee: None,
+                                reserve_policy: None,
                     __remaining_accounts: Vec::new(),
     });
     Self { instruction }
@@ -597,6 +709,11 @@ impl<'a, 'b> CreateCpiBuilder<'a, 'b> {
                     self
     }
       #[inline(always)]
+    pub fn creator_vault(&mut self, creator_vault: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.creator_vault = Some(creator_vault);
+                    self
+    }
+      #[inline(always)]
     pub fn metadata_account(&mut self, metadata_account: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.metadata_account = Some(metadata_account);
                     self
@@ -640,6 +757,49 @@ impl<'a, 'b> CreateCpiBuilder<'a, 'b> {
       pub fn token_uri(&mut self, token_uri: String) -> &mut Self {
         self.instruction.token_uri = Some(token_uri);
         self
+      }
+                #[inline(always)]
+      pub fn migration_target(&mut self, migration_target: MigrationTarget) -> &mut Self {
+        self.instruction.migration_target = Some(migration_target);
+        self
+      }
+                #[inline(always)]
+      pub fn curve_kind(&mut self, curve_kind: CurveKind) -> &mut Self {
+        self.instruction.curve_kind = Some(curve_kind);
+        self
+      }
+                /// `[optional argument]`
+#[inline(always)]
+      pub fn curve_params(&mut self, curve_params: CurveParams) -> &mut Self {
+        self.instruction.curve_params = Some(curve_params);
+        self
+      }
+                #[inline(always)]
+      pub fn graduation_mode(&mut self, graduation_mode: GraduationMode) -> &mut Self {
+        self.instruction.graduation_mode = Some(graduation_mode);
+        self
+      }
+                /// `[optional argument]`
+#[inline(always)]
+      pub fn launch_window(&mut self, launch_window: LaunchWindow) -> &mut Self {
+        self.instruction.launch_window = Some(launch_window);
+        self
+      }
+                #[inline(always)]
+      pub fn trading_starts_at(&mut self, trading_starts_at: TradingStart) -> &mut Self {
+        self.instruction.trading_starts_at = Some(trading_starts_at);
+        self
+      }
+                /// `[optional argument]`
+#[inline(always)]
+      pub fn launch_fee(&mut self, launch_fee: LaunchFee) -> &mut Self {
+        self.instruction.launch_fee = Some(launch_fee);
+        self
+      }
+                #[inline(always)]
+      pub fn reserve_policy(&mut self, reserve_policy: ReservePolicy) -> &mut Self {
+        self.instruction.reserve_policy = Some(reserve_policy);
+        self
       }
         /// Add an additional account to the instruction.
   #[inline(always)]
@@ -667,6 +827,14 @@ impl<'a, 'b> CreateCpiBuilder<'a, 'b> {
                                                               token_name: self.instruction.token_name.clone().expect("token_name is not set"),
                                                                   token_symbol: self.instruction.token_symbol.clone().expect("token_symbol is not set"),
                                                                   token_uri: self.instruction.token_uri.clone().expect("token_uri is not set"),
+                                                                  migration_target: self.instruction.migration_target.clone().expect("migration_target is not set"),
+                                                                  curve_kind: self.instruction.curve_kind.clone().expect("curve_kind is not set"),
+                                                                  curve_params: self.instruction.curve_params.clone(),
+                                                                  graduation_mode: self.instruction.graduation_mode.clone().expect("graduation_mode is not set"),
+                                                                  launch_window: self.instruction.launch_window.clone(),
+                                                                  trading_starts_at: self.instruction.trading_starts_at.clone().expect("trading_starts_at is not set"),
+                                                                  launch_fee: self.instruction.launch_fee.clone(),
+                                                                  reserve_policy: self.instruction.reserve_policy.clone().expect("reserve_policy is not set"),
                                     };
         let instruction = CreateCpi {
         __program: self.instruction.__program,
@@ -683,6 +851,8 @@ impl<'a, 'b> CreateCpiBuilder<'a, 'b> {
                   
           bonding_curve_ata: self.instruction.bonding_curve_ata.expect("bonding_curve_ata is not set"),
                   
+          creator_vault: self.instruction.creator_vault.expect("creator_vault is not set"),
+                  
           metadata_account: self.instruction.metadata_account.expect("metadata_account is not set"),
                   
           token_metadata_program: self.instruction.token_metadata_program.expect("token_metadata_program is not set"),
@@ -709,6 +879,7 @@ struct CreateCpiBuilderInstruction<'a, 'b> {
                 global: Option<&'b solana_account_info::AccountInfo<'a>>,
                 bonding_curve: Option<&'b solana_account_info::AccountInfo<'a>>,
                 bonding_curve_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                creator_vault: Option<&'b solana_account_info::AccountInfo<'a>>,
                 metadata_account: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_metadata_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 associated_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
@@ -718,6 +889,14 @@ struct CreateCpiBuilderInstruction<'a, 'b> {
                         token_name: Option<String>,
                 token_symbol: Option<String>,
                 token_uri: Option<String>,
+                migration_target: Option<MigrationTarget>,
+                curve_kind: Option<CurveKind>,
+                curve_params: Option<CurveParams>,
+                graduation_mode: Option<GraduationMode>,
+                launch_window: Option<LaunchWindow>,
+                trading_starts_at: Option<TradingStart>,
+                launch_fee: Option<LaunchFee>,
+                reserve_policy: Option<ReservePolicy>,
         /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
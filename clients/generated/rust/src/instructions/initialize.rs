@@ -5,7 +5,9 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
-use solana_pubkey::Pubkey;
+use crate::generated::types::LpPolicy;
+use crate::generated::types::SurplusTokenPolicy;
+use crate::generated::types::CurveParams;
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
 
@@ -25,6 +27,12 @@ pub struct Initialize {
           pub global_reserve: solana_pubkey::Pubkey,
           
               
+          pub platform_fee_vault: solana_pubkey::Pubkey,
+          
+              
+          pub reserve_sol_vault: solana_pubkey::Pubkey,
+          
+              
           pub system_program: solana_pubkey::Pubkey,
       }
 
@@ -35,7 +43,7 @@ impl Initialize {
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, args: InitializeInstructionArgs, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(4+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(6+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             self.authority,
             true
@@ -47,6 +55,14 @@ impl Initialize {
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.global_reserve,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.platform_fee_vault,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.reserve_sol_vault,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
@@ -69,13 +85,13 @@ impl Initialize {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
  pub struct InitializeInstructionData {
             discriminator: [u8; 8],
-                                                }
+                                                                                                }
 
 impl InitializeInstructionData {
   pub fn new() -> Self {
     Self {
                         discriminator: [175, 175, 109, 31, 13, 152, 155, 237],
-                                                                                                                    }
+                                                                                                                                                                                                                                    }
   }
 }
 
@@ -93,8 +109,16 @@ impl Default for InitializeInstructionData {
                 pub token_total_supply: u64,
                 pub platform_trade_fee_bps: u64,
                 pub reserve_trade_fee_bps: u64,
-                pub platform_fee_recipient: Pubkey,
                 pub graduation_threshold: u64,
+                pub lp_policy: LpPolicy,
+                pub keeper_reward_bps: u64,
+                pub surplus_token_policy: SurplusTokenPolicy,
+                pub migration_fee_lamports: u64,
+                pub curve_kinds: [bool; 3],
+                pub min_curve_params: CurveParams,
+                pub max_curve_params: CurveParams,
+                pub creator_trade_fee_bps: u64,
+                pub referral_fee_bps: u64,
       }
 
 
@@ -105,20 +129,32 @@ impl Default for InitializeInstructionData {
                       ///   0. `[writable, signer]` authority
                 ///   1. `[writable]` global
                 ///   2. `[writable]` global_reserve
-                ///   3. `[optional]` system_program (default to `11111111111111111111111111111111`)
+                ///   3. `[writable]` platform_fee_vault
+                ///   4. `[writable]` reserve_sol_vault
+                ///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct InitializeBuilder {
             authority: Option<solana_pubkey::Pubkey>,
                 global: Option<solana_pubkey::Pubkey>,
                 global_reserve: Option<solana_pubkey::Pubkey>,
+                platform_fee_vault: Option<solana_pubkey::Pubkey>,
+                reserve_sol_vault: Option<solana_pubkey::Pubkey>,
                 system_program: Option<solana_pubkey::Pubkey>,
                         initial_virtual_token_reserves: Option<u64>,
                 initial_virtual_sol_reserves: Option<u64>,
                 token_total_supply: Option<u64>,
                 platform_trade_fee_bps: Option<u64>,
                 reserve_trade_fee_bps: Option<u64>,
-                platform_fee_recipient: Option<Pubkey>,
                 graduation_threshold: Option<u64>,
+                lp_policy: Option<LpPolicy>,
+                keeper_reward_bps: Option<u64>,
+                surplus_token_policy: Option<SurplusTokenPolicy>,
+                migration_fee_lamports: Option<u64>,
+                curve_kinds: Option<[bool; 3]>,
+                min_curve_params: Option<CurveParams>,
+                max_curve_params: Option<CurveParams>,
+                creator_trade_fee_bps: Option<u64>,
+                referral_fee_bps: Option<u64>,
         __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -140,6 +176,16 @@ impl InitializeBuilder {
     pub fn global_reserve(&mut self, global_reserve: solana_pubkey::Pubkey) -> &mut Self {
                         self.global_reserve = Some(global_reserve);
                     self
+    }
+            #[inline(always)]
+    pub fn platform_fee_vault(&mut self, platform_fee_vault: solana_pubkey::Pubkey) -> &mut Self {
+                        self.platform_fee_vault = Some(platform_fee_vault);
+                    self
+    }
+            #[inline(always)]
+    pub fn reserve_sol_vault(&mut self, reserve_sol_vault: solana_pubkey::Pubkey) -> &mut Self {
+                        self.reserve_sol_vault = Some(reserve_sol_vault);
+                    self
     }
             /// `[optional account, default to '11111111111111111111111111111111']`
 #[inline(always)]
@@ -173,13 +219,53 @@ impl InitializeBuilder {
         self
       }
                 #[inline(always)]
-      pub fn platform_fee_recipient(&mut self, platform_fee_recipient: Pubkey) -> &mut Self {
-        self.platform_fee_recipient = Some(platform_fee_recipient);
+      pub fn graduation_threshold(&mut self, graduation_threshold: u64) -> &mut Self {
+        self.graduation_threshold = Some(graduation_threshold);
+        self
+      }
+                #[inline(always)]
+      pub fn lp_policy(&mut self, lp_policy: LpPolicy) -> &mut Self {
+        self.lp_policy = Some(lp_policy);
         self
       }
                 #[inline(always)]
-      pub fn graduation_threshold(&mut self, graduation_threshold: u64) -> &mut Self {
-        self.graduation_threshold = Some(graduation_threshold);
+      pub fn keeper_reward_bps(&mut self, keeper_reward_bps: u64) -> &mut Self {
+        self.keeper_reward_bps = Some(keeper_reward_bps);
+        self
+      }
+                #[inline(always)]
+      pub fn surplus_token_policy(&mut self, surplus_token_policy: SurplusTokenPolicy) -> &mut Self {
+        self.surplus_token_policy = Some(surplus_token_policy);
+        self
+      }
+                #[inline(always)]
+      pub fn migration_fee_lamports(&mut self, migration_fee_lamports: u64) -> &mut Self {
+        self.migration_fee_lamports = Some(migration_fee_lamports);
+        self
+      }
+                #[inline(always)]
+      pub fn curve_kinds(&mut self, curve_kinds: [bool; 3]) -> &mut Self {
+        self.curve_kinds = Some(curve_kinds);
+        self
+      }
+                #[inline(always)]
+      pub fn min_curve_params(&mut self, min_curve_params: CurveParams) -> &mut Self {
+        self.min_curve_params = Some(min_curve_params);
+        self
+      }
+                #[inline(always)]
+      pub fn max_curve_params(&mut self, max_curve_params: CurveParams) -> &mut Self {
+        self.max_curve_params = Some(max_curve_params);
+        self
+      }
+                #[inline(always)]
+      pub fn creator_trade_fee_bps(&mut self, creator_trade_fee_bps: u64) -> &mut Self {
+        self.creator_trade_fee_bps = Some(creator_trade_fee_bps);
+        self
+      }
+                #[inline(always)]
+      pub fn referral_fee_bps(&mut self, referral_fee_bps: u64) -> &mut Self {
+        self.referral_fee_bps = Some(referral_fee_bps);
         self
       }
         /// Add an additional account to the instruction.
@@ -200,6 +286,8 @@ impl InitializeBuilder {
                               authority: self.authority.expect("authority is not set"),
                                         global: self.global.expect("global is not set"),
                                         global_reserve: self.global_reserve.expect("global_reserve is not set"),
+                                        platform_fee_vault: self.platform_fee_vault.expect("platform_fee_vault is not set"),
+                                        reserve_sol_vault: self.reserve_sol_vault.expect("reserve_sol_vault is not set"),
                                         system_program: self.system_program.unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
                       };
           let args = InitializeInstructionArgs {
@@ -208,8 +296,16 @@ impl InitializeBuilder {
                                                                   token_total_supply: self.token_total_supply.clone().expect("token_total_supply is not set"),
                                                                   platform_trade_fee_bps: self.platform_trade_fee_bps.clone().expect("platform_trade_fee_bps is not set"),
                                                                   reserve_trade_fee_bps: self.reserve_trade_fee_bps.clone().expect("reserve_trade_fee_bps is not set"),
-                                                                  platform_fee_recipient: self.platform_fee_recipient.clone().expect("platform_fee_recipient is not set"),
                                                                   graduation_threshold: self.graduation_threshold.clone().expect("graduation_threshold is not set"),
+                                                                  lp_policy: self.lp_policy.clone().expect("lp_policy is not set"),
+                                                                  keeper_reward_bps: self.keeper_reward_bps.clone().expect("keeper_reward_bps is not set"),
+                                                                  surplus_token_policy: self.surplus_token_policy.clone().expect("surplus_token_policy is not set"),
+                                                                  migration_fee_lamports: self.migration_fee_lamports.clone().expect("migration_fee_lamports is not set"),
+                                                                  curve_kinds: self.curve_kinds.clone().expect("curve_kinds is not set"),
+                                                                  min_curve_params: self.min_curve_params.clone().expect("min_curve_params is not set"),
+                                                                  max_curve_params: self.max_curve_params.clone().expect("max_curve_params is not set"),
+                                                                  creator_trade_fee_bps: self.creator_trade_fee_bps.clone().expect("creator_trade_fee_bps is not set"),
+                                                                  referral_fee_bps: self.referral_fee_bps.clone().expect("referral_fee_bps is not set"),
                                     };
     
     accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -229,6 +325,12 @@ impl InitializeBuilder {
               pub global_reserve: &'b solana_account_info::AccountInfo<'a>,
                 
                     
+              pub platform_fee_vault: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
+              pub reserve_sol_vault: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
               pub system_program: &'b solana_account_info::AccountInfo<'a>,
             }
 
@@ -247,6 +349,12 @@ pub struct InitializeCpi<'a, 'b> {
           pub global_reserve: &'b solana_account_info::AccountInfo<'a>,
           
               
+          pub platform_fee_vault: &'b solana_account_info::AccountInfo<'a>,
+          
+              
+          pub reserve_sol_vault: &'b solana_account_info::AccountInfo<'a>,
+          
+              
           pub system_program: &'b solana_account_info::AccountInfo<'a>,
             /// The arguments for the instruction.
     pub __args: InitializeInstructionArgs,
@@ -263,6 +371,8 @@ impl<'a, 'b> InitializeCpi<'a, 'b> {
               authority: accounts.authority,
               global: accounts.global,
               global_reserve: accounts.global_reserve,
+              platform_fee_vault: accounts.platform_fee_vault,
+              reserve_sol_vault: accounts.reserve_sol_vault,
               system_program: accounts.system_program,
                     __args: args,
           }
@@ -287,7 +397,7 @@ impl<'a, 'b> InitializeCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_error::ProgramResult {
-    let mut accounts = Vec::with_capacity(4+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(6+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             *self.authority.key,
             true
@@ -299,6 +409,14 @@ impl<'a, 'b> InitializeCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.global_reserve.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.platform_fee_vault.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.reserve_sol_vault.key,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
@@ -320,11 +438,13 @@ impl<'a, 'b> InitializeCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(5 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.authority.clone());
                         account_infos.push(self.global.clone());
                         account_infos.push(self.global_reserve.clone());
+                        account_infos.push(self.platform_fee_vault.clone());
+                        account_infos.push(self.reserve_sol_vault.clone());
                         account_infos.push(self.system_program.clone());
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
@@ -343,7 +463,9 @@ impl<'a, 'b> InitializeCpi<'a, 'b> {
                       ///   0. `[writable, signer]` authority
                 ///   1. `[writable]` global
                 ///   2. `[writable]` global_reserve
-          ///   3. `[]` system_program
+                ///   3. `[writable]` platform_fee_vault
+                ///   4. `[writable]` reserve_sol_vault
+          ///   5. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct InitializeCpiBuilder<'a, 'b> {
   instruction: Box<InitializeCpiBuilderInstruction<'a, 'b>>,
@@ -356,14 +478,24 @@ impl<'a, 'b> InitializeCpiBuilder<'a, 'b> {
               authority: None,
               global: None,
               global_reserve: None,
+              platform_fee_vault: None,
+              reserve_sol_vault: None,
               system_program: None,
                                             initial_virtual_token_reserves: None,
                                 initial_virtual_sol_reserves: None,
                                 token_total_supply: None,
                                 platform_trade_fee_bps: None,
                                 reserve_trade_fee_bps: None,
-                                platform_fee_recipient: None,
                                 graduation_threshold: None,
+                                lp_policy: None,
+                                keeper_reward_bps: None,
+                                surplus_token_policy: None,
+                                migration_fee_lamports: None,
+                                curve_kinds: None,
+                                min_curve_params: None,
+                                max_curve_params: None,
+                                creator_trade_fee_bps: None,
+                                referral_fee_bps: None,
                     __remaining_accounts: Vec::new(),
     });
     Self { instruction }
@@ -384,6 +516,16 @@ impl<'a, 'b> InitializeCpiBuilder<'a, 'b> {
                     self
     }
       #[inline(always)]
+    pub fn platform_fee_vault(&mut self, platform_fee_vault: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.platform_fee_vault = Some(platform_fee_vault);
+                    self
+    }
+      #[inline(always)]
+    pub fn reserve_sol_vault(&mut self, reserve_sol_vault: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.reserve_sol_vault = Some(reserve_sol_vault);
+                    self
+    }
+      #[inline(always)]
     pub fn system_program(&mut self, system_program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.system_program = Some(system_program);
                     self
@@ -414,13 +556,53 @@ impl<'a, 'b> InitializeCpiBuilder<'a, 'b> {
         self
       }
                 #[inline(always)]
-      pub fn platform_fee_recipient(&mut self, platform_fee_recipient: Pubkey) -> &mut Self {
-        self.instruction.platform_fee_recipient = Some(platform_fee_recipient);
+      pub fn graduation_threshold(&mut self, graduation_threshold: u64) -> &mut Self {
+        self.instruction.graduation_threshold = Some(graduation_threshold);
+        self
+      }
+                #[inline(always)]
+      pub fn lp_policy(&mut self, lp_policy: LpPolicy) -> &mut Self {
+        self.instruction.lp_policy = Some(lp_policy);
         self
       }
                 #[inline(always)]
-      pub fn graduation_threshold(&mut self, graduation_threshold: u64) -> &mut Self {
-        self.instruction.graduation_threshold = Some(graduation_threshold);
+      pub fn keeper_reward_bps(&mut self, keeper_reward_bps: u64) -> &mut Self {
+        self.instruction.keeper_reward_bps = Some(keeper_reward_bps);
+        self
+      }
+                #[inline(always)]
+      pub fn surplus_token_policy(&mut self, surplus_token_policy: SurplusTokenPolicy) -> &mut Self {
+        self.instruction.surplus_token_policy = Some(surplus_token_policy);
+        self
+      }
+                #[inline(always)]
+      pub fn migration_fee_lamports(&mut self, migration_fee_lamports: u64) -> &mut Self {
+        self.instruction.migration_fee_lamports = Some(migration_fee_lamports);
+        self
+      }
+                #[inline(always)]
+      pub fn curve_kinds(&mut self, curve_kinds: [bool; 3]) -> &mut Self {
+        self.instruction.curve_kinds = Some(curve_kinds);
+        self
+      }
+                #[inline(always)]
+      pub fn min_curve_params(&mut self, min_curve_params: CurveParams) -> &mut Self {
+        self.instruction.min_curve_params = Some(min_curve_params);
+        self
+      }
+                #[inline(always)]
+      pub fn max_curve_params(&mut self, max_curve_params: CurveParams) -> &mut Self {
+        self.instruction.max_curve_params = Some(max_curve_params);
+        self
+      }
+                #[inline(always)]
+      pub fn creator_trade_fee_bps(&mut self, creator_trade_fee_bps: u64) -> &mut Self {
+        self.instruction.creator_trade_fee_bps = Some(creator_trade_fee_bps);
+        self
+      }
+                #[inline(always)]
+      pub fn referral_fee_bps(&mut self, referral_fee_bps: u64) -> &mut Self {
+        self.instruction.referral_fee_bps = Some(referral_fee_bps);
         self
       }
         /// Add an additional account to the instruction.
@@ -451,8 +633,16 @@ impl<'a, 'b> InitializeCpiBuilder<'a, 'b> {
                                                                   token_total_supply: self.instruction.token_total_supply.clone().expect("token_total_supply is not set"),
                                                                   platform_trade_fee_bps: self.instruction.platform_trade_fee_bps.clone().expect("platform_trade_fee_bps is not set"),
                                                                   reserve_trade_fee_bps: self.instruction.reserve_trade_fee_bps.clone().expect("reserve_trade_fee_bps is not set"),
-                                                                  platform_fee_recipient: self.instruction.platform_fee_recipient.clone().expect("platform_fee_recipient is not set"),
                                                                   graduation_threshold: self.instruction.graduation_threshold.clone().expect("graduation_threshold is not set"),
+                                                                  lp_policy: self.instruction.lp_policy.clone().expect("lp_policy is not set"),
+                                                                  keeper_reward_bps: self.instruction.keeper_reward_bps.clone().expect("keeper_reward_bps is not set"),
+                                                                  surplus_token_policy: self.instruction.surplus_token_policy.clone().expect("surplus_token_policy is not set"),
+                                                                  migration_fee_lamports: self.instruction.migration_fee_lamports.clone().expect("migration_fee_lamports is not set"),
+                                                                  curve_kinds: self.instruction.curve_kinds.clone().expect("curve_kinds is not set"),
+                                                                  min_curve_params: self.instruction.min_curve_params.clone().expect("min_curve_params is not set"),
+                                                                  max_curve_params: self.instruction.max_curve_params.clone().expect("max_curve_params is not set"),
+                                                                  creator_trade_fee_bps: self.instruction.creator_trade_fee_bps.clone().expect("creator_trade_fee_bps is not set"),
+                                                                  referral_fee_bps: self.instruction.referral_fee_bps.clone().expect("referral_fee_bps is not set"),
                                     };
         let instruction = InitializeCpi {
         __program: self.instruction.__program,
@@ -463,6 +653,10 @@ impl<'a, 'b> InitializeCpiBuilder<'a, 'b> {
                   
           global_reserve: self.instruction.global_reserve.expect("global_reserve is not set"),
                   
+          platform_fee_vault: self.instruction.platform_fee_vault.expect("platform_fee_vault is not set"),
+                  
+          reserve_sol_vault: self.instruction.reserve_sol_vault.expect("reserve_sol_vault is not set"),
+                  
           system_program: self.instruction.system_program.expect("system_program is not set"),
                           __args: args,
             };
@@ -476,14 +670,24 @@ struct InitializeCpiBuilderInstruction<'a, 'b> {
             authority: Option<&'b solana_account_info::AccountInfo<'a>>,
                 global: Option<&'b solana_account_info::AccountInfo<'a>>,
                 global_reserve: Option<&'b solana_account_info::AccountInfo<'a>>,
+                platform_fee_vault: Option<&'b solana_account_info::AccountInfo<'a>>,
+                reserve_sol_vault: Option<&'b solana_account_info::AccountInfo<'a>>,
                 system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                         initial_virtual_token_reserves: Option<u64>,
                 initial_virtual_sol_reserves: Option<u64>,
                 token_total_supply: Option<u64>,
                 platform_trade_fee_bps: Option<u64>,
                 reserve_trade_fee_bps: Option<u64>,
-                platform_fee_recipient: Option<Pubkey>,
                 graduation_threshold: Option<u64>,
+                lp_policy: Option<LpPolicy>,
+                keeper_reward_bps: Option<u64>,
+                surplus_token_policy: Option<SurplusTokenPolicy>,
+                migration_fee_lamports: Option<u64>,
+                curve_kinds: Option<[bool; 3]>,
+                min_curve_params: Option<CurveParams>,
+                max_curve_params: Option<CurveParams>,
+                creator_trade_fee_bps: Option<u64>,
+                referral_fee_bps: Option<u64>,
         /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
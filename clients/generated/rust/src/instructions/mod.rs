@@ -6,20 +6,62 @@
 //!
 
   pub(crate) mod r#buy;
+  pub(crate) mod r#buy_exact_out;
+  pub(crate) mod r#claim_creator_fees;
+  pub(crate) mod r#claim_referral_fees;
+  pub(crate) mod r#claim_staking_rewards;
   pub(crate) mod r#create;
+  pub(crate) mod r#create_stake_pool;
   pub(crate) mod r#deposit_to_reserve;
+  pub(crate) mod r#distribute_platform_fees;
+  pub(crate) mod r#harvest_pool_fees;
   pub(crate) mod r#initialize;
+  pub(crate) mod r#migrate;
+  pub(crate) mod r#refund;
+  pub(crate) mod r#register_referrer;
+  pub(crate) mod r#release_lp;
+  pub(crate) mod r#reprice_curve;
   pub(crate) mod r#sell;
+  pub(crate) mod r#sell_for_sol;
+  pub(crate) mod r#set_curve_status;
+  pub(crate) mod r#set_migration_program;
+  pub(crate) mod r#set_platform_fee_recipients;
+  pub(crate) mod r#set_price_feed;
+  pub(crate) mod r#stake;
+  pub(crate) mod r#unstake;
   pub(crate) mod r#update_global_config;
-  pub(crate) mod r#withdraw;
+  pub(crate) mod r#upgrade_bonding_curve;
+  pub(crate) mod r#upgrade_global;
   pub(crate) mod r#withdraw_reserve;
+  pub(crate) mod r#withdraw_reserve_sol;
 
   pub use self::r#buy::*;
+  pub use self::r#buy_exact_out::*;
+  pub use self::r#claim_creator_fees::*;
+  pub use self::r#claim_referral_fees::*;
+  pub use self::r#claim_staking_rewards::*;
   pub use self::r#create::*;
+  pub use self::r#create_stake_pool::*;
   pub use self::r#deposit_to_reserve::*;
+  pub use self::r#distribute_platform_fees::*;
+  pub use self::r#harvest_pool_fees::*;
   pub use self::r#initialize::*;
+  pub use self::r#migrate::*;
+  pub use self::r#refund::*;
+  pub use self::r#register_referrer::*;
+  pub use self::r#release_lp::*;
+  pub use self::r#reprice_curve::*;
   pub use self::r#sell::*;
+  pub use self::r#sell_for_sol::*;
+  pub use self::r#set_curve_status::*;
+  pub use self::r#set_migration_program::*;
+  pub use self::r#set_platform_fee_recipients::*;
+  pub use self::r#set_price_feed::*;
+  pub use self::r#stake::*;
+  pub use self::r#unstake::*;
   pub use self::r#update_global_config::*;
-  pub use self::r#withdraw::*;
+  pub use self::r#upgrade_bonding_curve::*;
+  pub use self::r#upgrade_global::*;
   pub use self::r#withdraw_reserve::*;
+  pub use self::r#withdraw_reserve_sol::*;
 
@@ -6,6 +6,9 @@
 //!
 
 use solana_pubkey::Pubkey;
+use crate::generated::types::LpPolicy;
+use crate::generated::types::SurplusTokenPolicy;
+use crate::generated::types::CurveParams;
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
 
@@ -55,13 +58,13 @@ impl UpdateGlobalConfig {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
  pub struct UpdateGlobalConfigInstructionData {
             discriminator: [u8; 8],
-                                                      }
+                                                                                                      }
 
 impl UpdateGlobalConfigInstructionData {
   pub fn new() -> Self {
     Self {
                         discriminator: [164, 84, 130, 189, 111, 58, 250, 200],
-                                                                                                                                  }
+                                                                                                                                                                                                                                                  }
   }
 }
 
@@ -75,13 +78,21 @@ impl Default for UpdateGlobalConfigInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
  pub struct UpdateGlobalConfigInstructionArgs {
                   pub new_authority: Pubkey,
-                pub new_platform_fee_recipient: Pubkey,
                 pub new_platform_trade_fee_bps: u64,
                 pub new_reserve_trade_fee_bps: u64,
                 pub new_initial_virtual_token_reserves: u64,
                 pub new_initial_virtual_sol_reserves: u64,
                 pub new_token_total_supply: u64,
                 pub new_graduation_threshold: u64,
+                pub new_lp_policy: LpPolicy,
+                pub new_keeper_reward_bps: u64,
+                pub new_surplus_token_policy: SurplusTokenPolicy,
+                pub new_migration_fee_lamports: u64,
+                pub new_curve_kinds: [bool; 3],
+                pub new_min_curve_params: CurveParams,
+                pub new_max_curve_params: CurveParams,
+                pub new_creator_trade_fee_bps: u64,
+                pub new_referral_fee_bps: u64,
       }
 
 
@@ -96,13 +107,21 @@ pub struct UpdateGlobalConfigBuilder {
             authority: Option<solana_pubkey::Pubkey>,
                 global: Option<solana_pubkey::Pubkey>,
                         new_authority: Option<Pubkey>,
-                new_platform_fee_recipient: Option<Pubkey>,
                 new_platform_trade_fee_bps: Option<u64>,
                 new_reserve_trade_fee_bps: Option<u64>,
                 new_initial_virtual_token_reserves: Option<u64>,
                 new_initial_virtual_sol_reserves: Option<u64>,
                 new_token_total_supply: Option<u64>,
                 new_graduation_threshold: Option<u64>,
+                new_lp_policy: Option<LpPolicy>,
+                new_keeper_reward_bps: Option<u64>,
+                new_surplus_token_policy: Option<SurplusTokenPolicy>,
+                new_migration_fee_lamports: Option<u64>,
+                new_curve_kinds: Option<[bool; 3]>,
+                new_min_curve_params: Option<CurveParams>,
+                new_max_curve_params: Option<CurveParams>,
+                new_creator_trade_fee_bps: Option<u64>,
+                new_referral_fee_bps: Option<u64>,
         __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -126,11 +145,6 @@ impl UpdateGlobalConfigBuilder {
         self
       }
                 #[inline(always)]
-      pub fn new_platform_fee_recipient(&mut self, new_platform_fee_recipient: Pubkey) -> &mut Self {
-        self.new_platform_fee_recipient = Some(new_platform_fee_recipient);
-        self
-      }
-                #[inline(always)]
       pub fn new_platform_trade_fee_bps(&mut self, new_platform_trade_fee_bps: u64) -> &mut Self {
         self.new_platform_trade_fee_bps = Some(new_platform_trade_fee_bps);
         self
@@ -159,6 +173,51 @@ impl UpdateGlobalConfigBuilder {
       pub fn new_graduation_threshold(&mut self, new_graduation_threshold: u64) -> &mut Self {
         self.new_graduation_threshold = Some(new_graduation_threshold);
         self
+      }
+                #[inline(always)]
+      pub fn new_lp_policy(&mut self, new_lp_policy: LpPolicy) -> &mut Self {
+        self.new_lp_policy = Some(new_lp_policy);
+        self
+      }
+                #[inline(always)]
+      pub fn new_keeper_reward_bps(&mut self, new_keeper_reward_bps: u64) -> &mut Self {
+        self.new_keeper_reward_bps = Some(new_keeper_reward_bps);
+        self
+      }
+                #[inline(always)]
+      pub fn new_surplus_token_policy(&mut self, new_surplus_token_policy: SurplusTokenPolicy) -> &mut Self {
+        self.new_surplus_token_policy = Some(new_surplus_token_policy);
+        self
+      }
+                #[inline(always)]
+      pub fn new_migration_fee_lamports(&mut self, new_migration_fee_lamports: u64) -> &mut Self {
+        self.new_migration_fee_lamports = Some(new_migration_fee_lamports);
+        self
+      }
+                #[inline(always)]
+      pub fn new_curve_kinds(&mut self, new_curve_kinds: [bool; 3]) -> &mut Self {
+        self.new_curve_kinds = Some(new_curve_kinds);
+        self
+      }
+                #[inline(always)]
+      pub fn new_min_curve_params(&mut self, new_min_curve_params: CurveParams) -> &mut Self {
+        self.new_min_curve_params = Some(new_min_curve_params);
+        self
+      }
+                #[inline(always)]
+      pub fn new_max_curve_params(&mut self, new_max_curve_params: CurveParams) -> &mut Self {
+        self.new_max_curve_params = Some(new_max_curve_params);
+        self
+      }
+                #[inline(always)]
+      pub fn new_creator_trade_fee_bps(&mut self, new_creator_trade_fee_bps: u64) -> &mut Self {
+        self.new_creator_trade_fee_bps = Some(new_creator_trade_fee_bps);
+        self
+      }
+                #[inline(always)]
+      pub fn new_referral_fee_bps(&mut self, new_referral_fee_bps: u64) -> &mut Self {
+        self.new_referral_fee_bps = Some(new_referral_fee_bps);
+        self
       }
         /// Add an additional account to the instruction.
   #[inline(always)]
@@ -180,13 +239,21 @@ impl UpdateGlobalConfigBuilder {
                       };
           let args = UpdateGlobalConfigInstructionArgs {
                                                               new_authority: self.new_authority.clone().expect("new_authority is not set"),
-                                                                  new_platform_fee_recipient: self.new_platform_fee_recipient.clone().expect("new_platform_fee_recipient is not set"),
                                                                   new_platform_trade_fee_bps: self.new_platform_trade_fee_bps.clone().expect("new_platform_trade_fee_bps is not set"),
                                                                   new_reserve_trade_fee_bps: self.new_reserve_trade_fee_bps.clone().expect("new_reserve_trade_fee_bps is not set"),
                                                                   new_initial_virtual_token_reserves: self.new_initial_virtual_token_reserves.clone().expect("new_initial_virtual_token_reserves is not set"),
                                                                   new_initial_virtual_sol_reserves: self.new_initial_virtual_sol_reserves.clone().expect("new_initial_virtual_sol_reserves is not set"),
                                                                   new_token_total_supply: self.new_token_total_supply.clone().expect("new_token_total_supply is not set"),
                                                                   new_graduation_threshold: self.new_graduation_threshold.clone().expect("new_graduation_threshold is not set"),
+                                                                  new_lp_policy: self.new_lp_policy.clone().expect("new_lp_policy is not set"),
+                                                                  new_keeper_reward_bps: self.new_keeper_reward_bps.clone().expect("new_keeper_reward_bps is not set"),
+                                                                  new_surplus_token_policy: self.new_surplus_token_policy.clone().expect("new_surplus_token_policy is not set"),
+                                                                  new_migration_fee_lamports: self.new_migration_fee_lamports.clone().expect("new_migration_fee_lamports is not set"),
+                                                                  new_curve_kinds: self.new_curve_kinds.clone().expect("new_curve_kinds is not set"),
+                                                                  new_min_curve_params: self.new_min_curve_params.clone().expect("new_min_curve_params is not set"),
+                                                                  new_max_curve_params: self.new_max_curve_params.clone().expect("new_max_curve_params is not set"),
+                                                                  new_creator_trade_fee_bps: self.new_creator_trade_fee_bps.clone().expect("new_creator_trade_fee_bps is not set"),
+                                                                  new_referral_fee_bps: self.new_referral_fee_bps.clone().expect("new_referral_fee_bps is not set"),
                                     };
     
     accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -307,13 +374,21 @@ impl<'a, 'b> UpdateGlobalConfigCpiBuilder<'a, 'b> {
               authority: None,
               global: None,
                                             new_authority: None,
-                                new_platform_fee_recipient: None,
                                 new_platform_trade_fee_bps: None,
                                 new_reserve_trade_fee_bps: None,
                                 new_initial_virtual_token_reserves: None,
                                 new_initial_virtual_sol_reserves: None,
                                 new_token_total_supply: None,
                                 new_graduation_threshold: None,
+                                new_lp_policy: None,
+                                new_keeper_reward_bps: None,
+                                new_surplus_token_policy: None,
+                                new_migration_fee_lamports: None,
+                                new_curve_kinds: None,
+                                new_min_curve_params: None,
+                                new_max_curve_params: None,
+                                new_creator_trade_fee_bps: None,
+                                new_referral_fee_bps: None,
                     __remaining_accounts: Vec::new(),
     });
     Self { instruction }
@@ -334,11 +409,6 @@ impl<'a, 'b> UpdateGlobalConfigCpiBuilder<'a, 'b> {
         self
       }
                 #[inline(always)]
-      pub fn new_platform_fee_recipient(&mut self, new_platform_fee_recipient: Pubkey) -> &mut Self {
-        self.instruction.new_platform_fee_recipient = Some(new_platform_fee_recipient);
-        self
-      }
-                #[inline(always)]
       pub fn new_platform_trade_fee_bps(&mut self, new_platform_trade_fee_bps: u64) -> &mut Self {
         self.instruction.new_platform_trade_fee_bps = Some(new_platform_trade_fee_bps);
         self
@@ -367,6 +437,51 @@ impl<'a, 'b> UpdateGlobalConfigCpiBuilder<'a, 'b> {
       pub fn new_graduation_threshold(&mut self, new_graduation_threshold: u64) -> &mut Self {
         self.instruction.new_graduation_threshold = Some(new_graduation_threshold);
         self
+      }
+                #[inline(always)]
+      pub fn new_lp_policy(&mut self, new_lp_policy: LpPolicy) -> &mut Self {
+        self.instruction.new_lp_policy = Some(new_lp_policy);
+        self
+      }
+                #[inline(always)]
+      pub fn new_keeper_reward_bps(&mut self, new_keeper_reward_bps: u64) -> &mut Self {
+        self.instruction.new_keeper_reward_bps = Some(new_keeper_reward_bps);
+        self
+      }
+                #[inline(always)]
+      pub fn new_surplus_token_policy(&mut self, new_surplus_token_policy: SurplusTokenPolicy) -> &mut Self {
+        self.instruction.new_surplus_token_policy = Some(new_surplus_token_policy);
+        self
+      }
+                #[inline(always)]
+      pub fn new_migration_fee_lamports(&mut self, new_migration_fee_lamports: u64) -> &mut Self {
+        self.instruction.new_migration_fee_lamports = Some(new_migration_fee_lamports);
+        self
+      }
+                #[inline(always)]
+      pub fn new_curve_kinds(&mut self, new_curve_kinds: [bool; 3]) -> &mut Self {
+        self.instruction.new_curve_kinds = Some(new_curve_kinds);
+        self
+      }
+                #[inline(always)]
+      pub fn new_min_curve_params(&mut self, new_min_curve_params: CurveParams) -> &mut Self {
+        self.instruction.new_min_curve_params = Some(new_min_curve_params);
+        self
+      }
+                #[inline(always)]
+      pub fn new_max_curve_params(&mut self, new_max_curve_params: CurveParams) -> &mut Self {
+        self.instruction.new_max_curve_params = Some(new_max_curve_params);
+        self
+      }
+                #[inline(always)]
+      pub fn new_creator_trade_fee_bps(&mut self, new_creator_trade_fee_bps: u64) -> &mut Self {
+        self.instruction.new_creator_trade_fee_bps = Some(new_creator_trade_fee_bps);
+        self
+      }
+                #[inline(always)]
+      pub fn new_referral_fee_bps(&mut self, new_referral_fee_bps: u64) -> &mut Self {
+        self.instruction.new_referral_fee_bps = Some(new_referral_fee_bps);
+        self
       }
         /// Add an additional account to the instruction.
   #[inline(always)]
@@ -392,13 +507,21 @@ impl<'a, 'b> UpdateGlobalConfigCpiBuilder<'a, 'b> {
   pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program_error::ProgramResult {
           let args = UpdateGlobalConfigInstructionArgs {
                                                               new_authority: self.instruction.new_authority.clone().expect("new_authority is not set"),
-                                                                  new_platform_fee_recipient: self.instruction.new_platform_fee_recipient.clone().expect("new_platform_fee_recipient is not set"),
                                                                   new_platform_trade_fee_bps: self.instruction.new_platform_trade_fee_bps.clone().expect("new_platform_trade_fee_bps is not set"),
                                                                   new_reserve_trade_fee_bps: self.instruction.new_reserve_trade_fee_bps.clone().expect("new_reserve_trade_fee_bps is not set"),
                                                                   new_initial_virtual_token_reserves: self.instruction.new_initial_virtual_token_reserves.clone().expect("new_initial_virtual_token_reserves is not set"),
                                                                   new_initial_virtual_sol_reserves: self.instruction.new_initial_virtual_sol_reserves.clone().expect("new_initial_virtual_sol_reserves is not set"),
                                                                   new_token_total_supply: self.instruction.new_token_total_supply.clone().expect("new_token_total_supply is not set"),
                                                                   new_graduation_threshold: self.instruction.new_graduation_threshold.clone().expect("new_graduation_threshold is not set"),
+                                                                  new_lp_policy: self.instruction.new_lp_policy.clone().expect("new_lp_policy is not set"),
+                                                                  new_keeper_reward_bps: self.instruction.new_keeper_reward_bps.clone().expect("new_keeper_reward_bps is not set"),
+                                                                  new_surplus_token_policy: self.instruction.new_surplus_token_policy.clone().expect("new_surplus_token_policy is not set"),
+                                                                  new_migration_fee_lamports: self.instruction.new_migration_fee_lamports.clone().expect("new_migration_fee_lamports is not set"),
+                                                                  new_curve_kinds: self.instruction.new_curve_kinds.clone().expect("new_curve_kinds is not set"),
+                                                                  new_min_curve_params: self.instruction.new_min_curve_params.clone().expect("new_min_curve_params is not set"),
+                                                                  new_max_curve_params: self.instruction.new_max_curve_params.clone().expect("new_max_curve_params is not set"),
+                                                                  new_creator_trade_fee_bps: self.instruction.new_creator_trade_fee_bps.clone().expect("new_creator_trade_fee_bps is not set"),
+                                                                  new_referral_fee_bps: self.instruction.new_referral_fee_bps.clone().expect("new_referral_fee_bps is not set"),
                                     };
         let instruction = UpdateGlobalConfigCpi {
         __program: self.instruction.__program,
@@ -418,13 +541,21 @@ struct UpdateGlobalConfigCpiBuilderInstruction<'a, 'b> {
             authority: Option<&'b solana_account_info::AccountInfo<'a>>,
                 global: Option<&'b solana_account_info::AccountInfo<'a>>,
                         new_authority: Option<Pubkey>,
-                new_platform_fee_recipient: Option<Pubkey>,
                 new_platform_trade_fee_bps: Option<u64>,
                 new_reserve_trade_fee_bps: Option<u64>,
                 new_initial_virtual_token_reserves: Option<u64>,
                 new_initial_virtual_sol_reserves: Option<u64>,
                 new_token_total_supply: Option<u64>,
                 new_graduation_threshold: Option<u64>,
+                new_lp_policy: Option<LpPolicy>,
+                new_keeper_reward_bps: Option<u64>,
+                new_surplus_token_policy: Option<SurplusTokenPolicy>,
+                new_migration_fee_lamports: Option<u64>,
+                new_curve_kinds: Option<[bool; 3]>,
+                new_min_curve_params: Option<CurveParams>,
+                new_max_curve_params: Option<CurveParams>,
+                new_creator_trade_fee_bps: Option<u64>,
+                new_referral_fee_bps: Option<u64>,
         /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
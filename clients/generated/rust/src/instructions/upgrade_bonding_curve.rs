@@ -8,11 +8,11 @@
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
 
-pub const WITHDRAW_DISCRIMINATOR: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
+pub const UPGRADE_BONDING_CURVE_DISCRIMINATOR: [u8; 8] = [12, 187, 75, 31, 125, 9, 204, 9];
 
 /// Accounts.
 #[derive(Debug)]
-pub struct Withdraw {
+pub struct UpgradeBondingCurve {
       
               
           pub authority: solana_pubkey::Pubkey,
@@ -30,29 +30,23 @@ pub struct Withdraw {
           pub bonding_curve_ata: solana_pubkey::Pubkey,
           
               
-          pub authority_ata: solana_pubkey::Pubkey,
-          
-              
-          pub system_program: solana_pubkey::Pubkey,
+          pub creator_vault: solana_pubkey::Pubkey,
           
               
           pub token_program: solana_pubkey::Pubkey,
           
               
-          pub associated_token_program: solana_pubkey::Pubkey,
-          
-              
-          pub rent: solana_pubkey::Pubkey,
+          pub system_program: solana_pubkey::Pubkey,
       }
 
-impl Withdraw {
+impl UpgradeBondingCurve {
   pub fn instruction(&self) -> solana_instruction::Instruction {
     self.instruction_with_remaining_accounts(&[])
   }
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(10+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(8+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             self.authority,
             true
@@ -69,16 +63,12 @@ impl Withdraw {
             self.bonding_curve,
             false
           ));
-                                          accounts.push(solana_instruction::AccountMeta::new(
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.bonding_curve_ata,
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
-            self.authority_ata,
-            false
-          ));
-                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
-            self.system_program,
+            self.creator_vault,
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
@@ -86,15 +76,11 @@ impl Withdraw {
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
-            self.associated_token_program,
-            false
-          ));
-                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
-            self.rent,
+            self.system_program,
             false
           ));
                       accounts.extend_from_slice(remaining_accounts);
-    let data = borsh::to_vec(&WithdrawInstructionData::new()).unwrap();
+    let data = borsh::to_vec(&UpgradeBondingCurveInstructionData::new()).unwrap();
     
     solana_instruction::Instruction {
       program_id: crate::COINFUN_ID,
@@ -106,19 +92,19 @@ impl Withdraw {
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
- pub struct WithdrawInstructionData {
+ pub struct UpgradeBondingCurveInstructionData {
             discriminator: [u8; 8],
       }
 
-impl WithdrawInstructionData {
+impl UpgradeBondingCurveInstructionData {
   pub fn new() -> Self {
     Self {
-                        discriminator: [183, 18, 70, 156, 148, 109, 161, 34],
+                        discriminator: [12, 187, 75, 31, 125, 9, 204, 9],
                   }
   }
 }
 
-impl Default for WithdrawInstructionData {
+impl Default for UpgradeBondingCurveInstructionData {
   fn default() -> Self {
     Self::new()
   }
@@ -126,7 +112,7 @@ impl Default for WithdrawInstructionData {
 
 
 
-/// Instruction builder for `Withdraw`.
+/// Instruction builder for `UpgradeBondingCurve`.
 ///
 /// ### Accounts:
 ///
@@ -134,28 +120,24 @@ impl Default for WithdrawInstructionData {
           ///   1. `[]` global
           ///   2. `[]` mint
                 ///   3. `[writable]` bonding_curve
-                ///   4. `[writable]` bonding_curve_ata
-                ///   5. `[writable]` authority_ata
-                ///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
-          ///   7. `[]` token_program
-                ///   8. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
-                ///   9. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
+          ///   4. `[]` bonding_curve_ata
+                ///   5. `[writable]` creator_vault
+          ///   6. `[]` token_program
+                ///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
-pub struct WithdrawBuilder {
+pub struct UpgradeBondingCurveBuilder {
             authority: Option<solana_pubkey::Pubkey>,
                 global: Option<solana_pubkey::Pubkey>,
                 mint: Option<solana_pubkey::Pubkey>,
                 bonding_curve: Option<solana_pubkey::Pubkey>,
                 bonding_curve_ata: Option<solana_pubkey::Pubkey>,
-                authority_ata: Option<solana_pubkey::Pubkey>,
-                system_program: Option<solana_pubkey::Pubkey>,
+                creator_vault: Option<solana_pubkey::Pubkey>,
                 token_program: Option<solana_pubkey::Pubkey>,
-                associated_token_program: Option<solana_pubkey::Pubkey>,
-                rent: Option<solana_pubkey::Pubkey>,
+                system_program: Option<solana_pubkey::Pubkey>,
                 __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
-impl WithdrawBuilder {
+impl UpgradeBondingCurveBuilder {
   pub fn new() -> Self {
     Self::default()
   }
@@ -185,14 +167,8 @@ impl WithdrawBuilder {
                     self
     }
             #[inline(always)]
-    pub fn authority_ata(&mut self, authority_ata: solana_pubkey::Pubkey) -> &mut Self {
-                        self.authority_ata = Some(authority_ata);
-                    self
-    }
-            /// `[optional account, default to '11111111111111111111111111111111']`
-#[inline(always)]
-    pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
-                        self.system_program = Some(system_program);
+    pub fn creator_vault(&mut self, creator_vault: solana_pubkey::Pubkey) -> &mut Self {
+                        self.creator_vault = Some(creator_vault);
                     self
     }
             #[inline(always)]
@@ -200,16 +176,10 @@ impl WithdrawBuilder {
                         self.token_program = Some(token_program);
                     self
     }
-            /// `[optional account, default to 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL']`
-#[inline(always)]
-    pub fn associated_token_program(&mut self, associated_token_program: solana_pubkey::Pubkey) -> &mut Self {
-                        self.associated_token_program = Some(associated_token_program);
-                    self
-    }
-            /// `[optional account, default to 'SysvarRent111111111111111111111111111111111']`
+            /// `[optional account, default to '11111111111111111111111111111111']`
 #[inline(always)]
-    pub fn rent(&mut self, rent: solana_pubkey::Pubkey) -> &mut Self {
-                        self.rent = Some(rent);
+    pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
+                        self.system_program = Some(system_program);
                     self
     }
             /// Add an additional account to the instruction.
@@ -226,25 +196,23 @@ impl WithdrawBuilder {
   }
   #[allow(clippy::clone_on_copy)]
   pub fn instruction(&self) -> solana_instruction::Instruction {
-    let accounts = Withdraw {
+    let accounts = UpgradeBondingCurve {
                               authority: self.authority.expect("authority is not set"),
                                         global: self.global.expect("global is not set"),
                                         mint: self.mint.expect("mint is not set"),
                                         bonding_curve: self.bonding_curve.expect("bonding_curve is not set"),
                                         bonding_curve_ata: self.bonding_curve_ata.expect("bonding_curve_ata is not set"),
-                                        authority_ata: self.authority_ata.expect("authority_ata is not set"),
-                                        system_program: self.system_program.unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+                                        creator_vault: self.creator_vault.expect("creator_vault is not set"),
                                         token_program: self.token_program.expect("token_program is not set"),
-                                        associated_token_program: self.associated_token_program.unwrap_or(solana_pubkey::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")),
-                                        rent: self.rent.unwrap_or(solana_pubkey::pubkey!("SysvarRent111111111111111111111111111111111")),
+                                        system_program: self.system_program.unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
                       };
     
     accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
   }
 }
 
-  /// `withdraw` CPI accounts.
-  pub struct WithdrawCpiAccounts<'a, 'b> {
+  /// `upgrade_bonding_curve` CPI accounts.
+  pub struct UpgradeBondingCurveCpiAccounts<'a, 'b> {
           
                     
               pub authority: &'b solana_account_info::AccountInfo<'a>,
@@ -262,23 +230,17 @@ impl WithdrawBuilder {
               pub bonding_curve_ata: &'b solana_account_info::AccountInfo<'a>,
                 
                     
-              pub authority_ata: &'b solana_account_info::AccountInfo<'a>,
-                
-                    
-              pub system_program: &'b solana_account_info::AccountInfo<'a>,
+              pub creator_vault: &'b solana_account_info::AccountInfo<'a>,
                 
                     
               pub token_program: &'b solana_account_info::AccountInfo<'a>,
                 
                     
-              pub associated_token_program: &'b solana_account_info::AccountInfo<'a>,
-                
-                    
-              pub rent: &'b solana_account_info::AccountInfo<'a>,
+              pub system_program: &'b solana_account_info::AccountInfo<'a>,
             }
 
-/// `withdraw` CPI instruction.
-pub struct WithdrawCpi<'a, 'b> {
+/// `upgrade_bonding_curve` CPI instruction.
+pub struct UpgradeBondingCurveCpi<'a, 'b> {
   /// The program to invoke.
   pub __program: &'b solana_account_info::AccountInfo<'a>,
       
@@ -298,25 +260,19 @@ pub struct WithdrawCpi<'a, 'b> {
           pub bonding_curve_ata: &'b solana_account_info::AccountInfo<'a>,
           
               
-          pub authority_ata: &'b solana_account_info::AccountInfo<'a>,
-          
-              
-          pub system_program: &'b solana_account_info::AccountInfo<'a>,
+          pub creator_vault: &'b solana_account_info::AccountInfo<'a>,
           
               
           pub token_program: &'b solana_account_info::AccountInfo<'a>,
           
               
-          pub associated_token_program: &'b solana_account_info::AccountInfo<'a>,
-          
-              
-          pub rent: &'b solana_account_info::AccountInfo<'a>,
+          pub system_program: &'b solana_account_info::AccountInfo<'a>,
         }
 
-impl<'a, 'b> WithdrawCpi<'a, 'b> {
+impl<'a, 'b> UpgradeBondingCurveCpi<'a, 'b> {
   pub fn new(
     program: &'b solana_account_info::AccountInfo<'a>,
-          accounts: WithdrawCpiAccounts<'a, 'b>,
+          accounts: UpgradeBondingCurveCpiAccounts<'a, 'b>,
           ) -> Self {
     Self {
       __program: program,
@@ -325,11 +281,9 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
               mint: accounts.mint,
               bonding_curve: accounts.bonding_curve,
               bonding_curve_ata: accounts.bonding_curve_ata,
-              authority_ata: accounts.authority_ata,
-              system_program: accounts.system_program,
+              creator_vault: accounts.creator_vault,
               token_program: accounts.token_program,
-              associated_token_program: accounts.associated_token_program,
-              rent: accounts.rent,
+              system_program: accounts.system_program,
                 }
   }
   #[inline(always)]
@@ -352,7 +306,7 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_error::ProgramResult {
-    let mut accounts = Vec::with_capacity(10+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(8+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             *self.authority.key,
             true
@@ -369,16 +323,12 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
             *self.bonding_curve.key,
             false
           ));
-                                          accounts.push(solana_instruction::AccountMeta::new(
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.bonding_curve_ata.key,
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
-            *self.authority_ata.key,
-            false
-          ));
-                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
-            *self.system_program.key,
+            *self.creator_vault.key,
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
@@ -386,11 +336,7 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
-            *self.associated_token_program.key,
-            false
-          ));
-                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
-            *self.rent.key,
+            *self.system_program.key,
             false
           ));
                       remaining_accounts.iter().for_each(|remaining_account| {
@@ -400,25 +346,23 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
           is_writable: remaining_account.2,
       })
     });
-    let data = borsh::to_vec(&WithdrawInstructionData::new()).unwrap();
+    let data = borsh::to_vec(&UpgradeBondingCurveInstructionData::new()).unwrap();
     
     let instruction = solana_instruction::Instruction {
       program_id: crate::COINFUN_ID,
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(11 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.authority.clone());
                         account_infos.push(self.global.clone());
                         account_infos.push(self.mint.clone());
                         account_infos.push(self.bonding_curve.clone());
                         account_infos.push(self.bonding_curve_ata.clone());
-                        account_infos.push(self.authority_ata.clone());
-                        account_infos.push(self.system_program.clone());
+                        account_infos.push(self.creator_vault.clone());
                         account_infos.push(self.token_program.clone());
-                        account_infos.push(self.associated_token_program.clone());
-                        account_infos.push(self.rent.clone());
+                        account_infos.push(self.system_program.clone());
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -429,7 +373,7 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
   }
 }
 
-/// Instruction builder for `Withdraw` via CPI.
+/// Instruction builder for `UpgradeBondingCurve` via CPI.
 ///
 /// ### Accounts:
 ///
@@ -437,31 +381,27 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
           ///   1. `[]` global
           ///   2. `[]` mint
                 ///   3. `[writable]` bonding_curve
-                ///   4. `[writable]` bonding_curve_ata
-                ///   5. `[writable]` authority_ata
-          ///   6. `[]` system_program
-          ///   7. `[]` token_program
-          ///   8. `[]` associated_token_program
-          ///   9. `[]` rent
+          ///   4. `[]` bonding_curve_ata
+                ///   5. `[writable]` creator_vault
+          ///   6. `[]` token_program
+          ///   7. `[]` system_program
 #[derive(Clone, Debug)]
-pub struct WithdrawCpiBuilder<'a, 'b> {
-  instruction: Box<WithdrawCpiBuilderInstruction<'a, 'b>>,
+pub struct UpgradeBondingCurveCpiBuilder<'a, 'b> {
+  instruction: Box<UpgradeBondingCurveCpiBuilderInstruction<'a, 'b>>,
 }
 
-impl<'a, 'b> WithdrawCpiBuilder<'a, 'b> {
+impl<'a, 'b> UpgradeBondingCurveCpiBuilder<'a, 'b> {
   pub fn new(program: &'b solana_account_info::AccountInfo<'a>) -> Self {
-    let instruction = Box::new(WithdrawCpiBuilderInstruction {
+    let instruction = Box::new(UpgradeBondingCurveCpiBuilderInstruction {
       __program: program,
               authority: None,
               global: None,
               mint: None,
               bonding_curve: None,
               bonding_curve_ata: None,
-              authority_ata: None,
-              system_program: None,
+              creator_vault: None,
               token_program: None,
-              associated_token_program: None,
-              rent: None,
+              system_program: None,
                                 __remaining_accounts: Vec::new(),
     });
     Self { instruction }
@@ -492,13 +432,8 @@ impl<'a, 'b> WithdrawCpiBuilder<'a, 'b> {
                     self
     }
       #[inline(always)]
-    pub fn authority_ata(&mut self, authority_ata: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.authority_ata = Some(authority_ata);
-                    self
-    }
-      #[inline(always)]
-    pub fn system_program(&mut self, system_program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.system_program = Some(system_program);
+    pub fn creator_vault(&mut self, creator_vault: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.creator_vault = Some(creator_vault);
                     self
     }
       #[inline(always)]
@@ -507,13 +442,8 @@ impl<'a, 'b> WithdrawCpiBuilder<'a, 'b> {
                     self
     }
       #[inline(always)]
-    pub fn associated_token_program(&mut self, associated_token_program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.associated_token_program = Some(associated_token_program);
-                    self
-    }
-      #[inline(always)]
-    pub fn rent(&mut self, rent: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.rent = Some(rent);
+    pub fn system_program(&mut self, system_program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.system_program = Some(system_program);
                     self
     }
             /// Add an additional account to the instruction.
@@ -538,7 +468,7 @@ impl<'a, 'b> WithdrawCpiBuilder<'a, 'b> {
   #[allow(clippy::clone_on_copy)]
   #[allow(clippy::vec_init_then_push)]
   pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program_error::ProgramResult {
-        let instruction = WithdrawCpi {
+        let instruction = UpgradeBondingCurveCpi {
         __program: self.instruction.__program,
                   
           authority: self.instruction.authority.expect("authority is not set"),
@@ -551,33 +481,27 @@ impl<'a, 'b> WithdrawCpiBuilder<'a, 'b> {
                   
           bonding_curve_ata: self.instruction.bonding_curve_ata.expect("bonding_curve_ata is not set"),
                   
-          authority_ata: self.instruction.authority_ata.expect("authority_ata is not set"),
-                  
-          system_program: self.instruction.system_program.expect("system_program is not set"),
+          creator_vault: self.instruction.creator_vault.expect("creator_vault is not set"),
                   
           token_program: self.instruction.token_program.expect("token_program is not set"),
                   
-          associated_token_program: self.instruction.associated_token_program.expect("associated_token_program is not set"),
-                  
-          rent: self.instruction.rent.expect("rent is not set"),
+          system_program: self.instruction.system_program.expect("system_program is not set"),
                     };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
   }
 }
 
 #[derive(Clone, Debug)]
-struct WithdrawCpiBuilderInstruction<'a, 'b> {
+struct UpgradeBondingCurveCpiBuilderInstruction<'a, 'b> {
   __program: &'b solana_account_info::AccountInfo<'a>,
             authority: Option<&'b solana_account_info::AccountInfo<'a>>,
                 global: Option<&'b solana_account_info::AccountInfo<'a>>,
                 mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 bonding_curve: Option<&'b solana_account_info::AccountInfo<'a>>,
                 bonding_curve_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
-                authority_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
-                system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+                creator_vault: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
-                associated_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
-                rent: Option<&'b solana_account_info::AccountInfo<'a>>,
+                system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -30,6 +30,9 @@ pub struct WithdrawReserve {
           pub bonding_curve: solana_pubkey::Pubkey,
           
               
+          pub stake_pool: Option<solana_pubkey::Pubkey>,
+          
+              
           pub reserve_ata: solana_pubkey::Pubkey,
           
               
@@ -52,7 +55,7 @@ impl WithdrawReserve {
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, args: WithdrawReserveInstructionArgs, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(10+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(11+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             self.authority,
             true
@@ -73,6 +76,17 @@ impl WithdrawReserve {
             self.bonding_curve,
             false
           ));
+                                          if let Some(stake_pool) = self.stake_pool {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                stake_pool,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::COINFUN_ID,
+                false,
+              ));
+            }
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.reserve_ata,
             false
@@ -142,11 +156,12 @@ impl Default for WithdrawReserveInstructionData {
           ///   2. `[]` global_reserve
           ///   3. `[]` mint
           ///   4. `[]` bonding_curve
-                ///   5. `[writable]` reserve_ata
-                ///   6. `[writable]` authority_ata
-                ///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
-          ///   8. `[]` token_program
-                ///   9. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+                ///   5. `[optional]` stake_pool
+                ///   6. `[writable]` reserve_ata
+                ///   7. `[writable]` authority_ata
+                ///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
+          ///   9. `[]` token_program
+                ///   10. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
 #[derive(Clone, Debug, Default)]
 pub struct WithdrawReserveBuilder {
             authority: Option<solana_pubkey::Pubkey>,
@@ -154,6 +169,7 @@ pub struct WithdrawReserveBuilder {
                 global_reserve: Option<solana_pubkey::Pubkey>,
                 mint: Option<solana_pubkey::Pubkey>,
                 bonding_curve: Option<solana_pubkey::Pubkey>,
+                stake_pool: Option<solana_pubkey::Pubkey>,
                 reserve_ata: Option<solana_pubkey::Pubkey>,
                 authority_ata: Option<solana_pubkey::Pubkey>,
                 system_program: Option<solana_pubkey::Pubkey>,
@@ -191,6 +207,12 @@ impl WithdrawReserveBuilder {
     pub fn bonding_curve(&mut self, bonding_curve: solana_pubkey::Pubkey) -> &mut Self {
                         self.bonding_curve = Some(bonding_curve);
                     self
+    }
+            /// `[optional account]`
+#[inline(always)]
+    pub fn stake_pool(&mut self, stake_pool: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.stake_pool = stake_pool;
+                    self
     }
             #[inline(always)]
     pub fn reserve_ata(&mut self, reserve_ata: solana_pubkey::Pubkey) -> &mut Self {
@@ -244,6 +266,7 @@ impl WithdrawReserveBuilder {
                                         global_reserve: self.global_reserve.expect("global_reserve is not set"),
                                         mint: self.mint.expect("mint is not set"),
                                         bonding_curve: self.bonding_curve.expect("bonding_curve is not set"),
+                                        stake_pool: self.stake_pool,
                                         reserve_ata: self.reserve_ata.expect("reserve_ata is not set"),
                                         authority_ata: self.authority_ata.expect("authority_ata is not set"),
                                         system_program: self.system_program.unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
@@ -277,6 +300,9 @@ impl WithdrawReserveBuilder {
               pub bonding_curve: &'b solana_account_info::AccountInfo<'a>,
                 
                     
+              pub stake_pool: Option<&'b solana_account_info::AccountInfo<'a>>,
+                
+                    
               pub reserve_ata: &'b solana_account_info::AccountInfo<'a>,
                 
                     
@@ -313,6 +339,9 @@ pub struct WithdrawReserveCpi<'a, 'b> {
           pub bonding_curve: &'b solana_account_info::AccountInfo<'a>,
           
               
+          pub stake_pool: Option<&'b solana_account_info::AccountInfo<'a>>,
+          
+              
           pub reserve_ata: &'b solana_account_info::AccountInfo<'a>,
           
               
@@ -343,6 +372,7 @@ impl<'a, 'b> WithdrawReserveCpi<'a, 'b> {
               global_reserve: accounts.global_reserve,
               mint: accounts.mint,
               bonding_curve: accounts.bonding_curve,
+              stake_pool: accounts.stake_pool,
               reserve_ata: accounts.reserve_ata,
               authority_ata: accounts.authority_ata,
               system_program: accounts.system_program,
@@ -371,7 +401,7 @@ impl<'a, 'b> WithdrawReserveCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_error::ProgramResult {
-    let mut accounts = Vec::with_capacity(10+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(11+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             *self.authority.key,
             true
@@ -392,6 +422,17 @@ impl<'a, 'b> WithdrawReserveCpi<'a, 'b> {
             *self.bonding_curve.key,
             false
           ));
+                                          if let Some(stake_pool) = self.stake_pool {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *stake_pool.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::COINFUN_ID,
+                false,
+              ));
+            }
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.reserve_ata.key,
             false
@@ -428,13 +469,16 @@ impl<'a, 'b> WithdrawReserveCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(11 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(12 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.authority.clone());
                         account_infos.push(self.global.clone());
                         account_infos.push(self.global_reserve.clone());
                         account_infos.push(self.mint.clone());
                         account_infos.push(self.bonding_curve.clone());
+                        if let Some(stake_pool) = self.stake_pool {
+          account_infos.push(stake_pool.clone());
+        }
                         account_infos.push(self.reserve_ata.clone());
                         account_infos.push(self.authority_ata.clone());
                         account_infos.push(self.system_program.clone());
@@ -459,11 +503,12 @@ impl<'a, 'b> WithdrawReserveCpi<'a, 'b> {
           ///   2. `[]` global_reserve
           ///   3. `[]` mint
           ///   4. `[]` bonding_curve
-                ///   5. `[writable]` reserve_ata
-                ///   6. `[writable]` authority_ata
-          ///   7. `[]` system_program
-          ///   8. `[]` token_program
-          ///   9. `[]` associated_token_program
+                ///   5. `[optional]` stake_pool
+                ///   6. `[writable]` reserve_ata
+                ///   7. `[writable]` authority_ata
+          ///   8. `[]` system_program
+          ///   9. `[]` token_program
+          ///   10. `[]` associated_token_program
 #[derive(Clone, Debug)]
 pub struct WithdrawReserveCpiBuilder<'a, 'b> {
   instruction: Box<WithdrawReserveCpiBuilderInstruction<'a, 'b>>,
@@ -478,6 +523,7 @@ impl<'a, 'b> WithdrawReserveCpiBuilder<'a, 'b> {
               global_reserve: None,
               mint: None,
               bonding_curve: None,
+              stake_pool: None,
               reserve_ata: None,
               authority_ata: None,
               system_program: None,
@@ -512,6 +558,12 @@ impl<'a, 'b> WithdrawReserveCpiBuilder<'a, 'b> {
     pub fn bonding_curve(&mut self, bonding_curve: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.bonding_curve = Some(bonding_curve);
                     self
+    }
+      /// `[optional account]`
+#[inline(always)]
+    pub fn stake_pool(&mut self, stake_pool: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.stake_pool = stake_pool;
+                    self
     }
       #[inline(always)]
     pub fn reserve_ata(&mut self, reserve_ata: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
@@ -581,6 +633,8 @@ impl<'a, 'b> WithdrawReserveCpiBuilder<'a, 'b> {
                   
           bonding_curve: self.instruction.bonding_curve.expect("bonding_curve is not set"),
                   
+          stake_pool: self.instruction.stake_pool,
+                  
           reserve_ata: self.instruction.reserve_ata.expect("reserve_ata is not set"),
                   
           authority_ata: self.instruction.authority_ata.expect("authority_ata is not set"),
@@ -604,6 +658,7 @@ struct WithdrawReserveCpiBuilderInstruction<'a, 'b> {
                 global_reserve: Option<&'b solana_account_info::AccountInfo<'a>>,
                 mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 bonding_curve: Option<&'b solana_account_info::AccountInfo<'a>>,
+                stake_pool: Option<&'b solana_account_info::AccountInfo<'a>>,
                 reserve_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
                 authority_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
                 system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
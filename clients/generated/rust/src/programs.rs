@@ -9,5 +9,5 @@ use solana_pubkey::{pubkey, Pubkey};
 
 
   /// `coinfun` program ID.
-  pub const COINFUN_ID: Pubkey = pubkey!("6xHUH1pJ2sLCm8YwyF8DBkUyBjDvoJKWgaXiBLaDdgsp");
+  pub const COINFUN_ID: Pubkey = pubkey!("ihC7UqkLYWxQKVuYLiWNGqGvQCZb2ih4DXMLfyM6F68");
 
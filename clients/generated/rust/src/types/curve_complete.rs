@@ -6,6 +6,7 @@
 //!
 
 use solana_pubkey::Pubkey;
+use crate::generated::types::GraduationCause;
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
 
@@ -16,6 +17,7 @@ pub struct CurveComplete {
 pub mint: Pubkey,
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub bonding_curve: Pubkey,
+pub cause: GraduationCause,
 }
 
 
@@ -5,13 +5,79 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+  pub(crate) mod r#creator_fees_claimed;
   pub(crate) mod r#curve_complete;
+  pub(crate) mod r#curve_kind;
+  pub(crate) mod r#curve_migrated;
+  pub(crate) mod r#curve_params;
+  pub(crate) mod r#curve_repriced;
+  pub(crate) mod r#curve_status;
+  pub(crate) mod r#curve_status_changed;
+  pub(crate) mod r#fee_decay;
+  pub(crate) mod r#graduation_cause;
+  pub(crate) mod r#graduation_mode;
+  pub(crate) mod r#launch_fee;
+  pub(crate) mod r#launch_window;
+  pub(crate) mod r#lp_policy;
+  pub(crate) mod r#lp_released;
+  pub(crate) mod r#migration_program_set;
+  pub(crate) mod r#migration_target;
+  pub(crate) mod r#platform_fee_recipient;
+  pub(crate) mod r#platform_fee_recipients_set;
+  pub(crate) mod r#platform_fees_distributed;
+  pub(crate) mod r#pool_fees_harvested;
+  pub(crate) mod r#price_feed_set;
+  pub(crate) mod r#referral_fees_claimed;
+  pub(crate) mod r#refunded;
+  pub(crate) mod r#reserve_policy;
+  pub(crate) mod r#reserve_sol_withdrawn;
+  pub(crate) mod r#stake_pool_created;
+  pub(crate) mod r#staked;
+  pub(crate) mod r#staking_rewards_accrued;
+  pub(crate) mod r#staking_rewards_claimed;
+  pub(crate) mod r#surplus_token_policy;
   pub(crate) mod r#token_created;
   pub(crate) mod r#trade;
   pub(crate) mod r#trade_side;
+  pub(crate) mod r#trading_opened;
+  pub(crate) mod r#trading_start;
+  pub(crate) mod r#unstaked;
 
+  pub use self::r#creator_fees_claimed::*;
   pub use self::r#curve_complete::*;
+  pub use self::r#curve_kind::*;
+  pub use self::r#curve_migrated::*;
+  pub use self::r#curve_params::*;
+  pub use self::r#curve_repriced::*;
+  pub use self::r#curve_status::*;
+  pub use self::r#curve_status_changed::*;
+  pub use self::r#fee_decay::*;
+  pub use self::r#graduation_cause::*;
+  pub use self::r#graduation_mode::*;
+  pub use self::r#launch_fee::*;
+  pub use self::r#launch_window::*;
+  pub use self::r#lp_policy::*;
+  pub use self::r#lp_released::*;
+  pub use self::r#migration_program_set::*;
+  pub use self::r#migration_target::*;
+  pub use self::r#platform_fee_recipient::*;
+  pub use self::r#platform_fee_recipients_set::*;
+  pub use self::r#platform_fees_distributed::*;
+  pub use self::r#pool_fees_harvested::*;
+  pub use self::r#price_feed_set::*;
+  pub use self::r#referral_fees_claimed::*;
+  pub use self::r#refunded::*;
+  pub use self::r#reserve_policy::*;
+  pub use self::r#reserve_sol_withdrawn::*;
+  pub use self::r#stake_pool_created::*;
+  pub use self::r#staked::*;
+  pub use self::r#staking_rewards_accrued::*;
+  pub use self::r#staking_rewards_claimed::*;
+  pub use self::r#surplus_token_policy::*;
   pub use self::r#token_created::*;
   pub use self::r#trade::*;
   pub use self::r#trade_side::*;
+  pub use self::r#trading_opened::*;
+  pub use self::r#trading_start::*;
+  pub use self::r#unstaked::*;
 
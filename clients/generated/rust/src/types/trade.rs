@@ -7,6 +7,7 @@
 
 use solana_pubkey::Pubkey;
 use crate::generated::types::TradeSide;
+use crate::generated::types::ReservePolicy;
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
 
@@ -20,6 +21,13 @@ pub trader: Pubkey,
 pub side: TradeSide,
 pub sol_amount: u64,
 pub token_amount: u64,
+pub sol_refunded: u64,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<Option<serde_with::DisplayFromStr>>"))]
+pub referrer: Option<Pubkey>,
+pub referral_fee: u64,
+pub reserve_policy: ReservePolicy,
+pub reserve_fee: u64,
+pub reserve_token_amount: u64,
 }
 
 
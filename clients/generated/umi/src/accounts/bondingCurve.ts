@@ -21,13 +21,41 @@ import {
 } from '@metaplex-foundation/umi';
 import {
   Serializer,
-  bool,
   bytes,
   mapSerializer,
   publicKey as publicKeySerializer,
   struct,
   u64,
 } from '@metaplex-foundation/umi/serializers';
+import {
+  CurveKind,
+  CurveKindArgs,
+  CurveStatus,
+  CurveStatusArgs,
+  GraduationMode,
+  GraduationModeArgs,
+  LaunchFee,
+  LaunchFeeArgs,
+  LaunchWindow,
+  LaunchWindowArgs,
+  LpPolicy,
+  LpPolicyArgs,
+  MigrationTarget,
+  MigrationTargetArgs,
+  ReservePolicy,
+  ReservePolicyArgs,
+  TradingStart,
+  TradingStartArgs,
+  getCurveKindSerializer,
+  getCurveStatusSerializer,
+  getGraduationModeSerializer,
+  getLaunchFeeSerializer,
+  getLaunchWindowSerializer,
+  getLpPolicySerializer,
+  getMigrationTargetSerializer,
+  getReservePolicySerializer,
+  getTradingStartSerializer,
+} from '../types';
 
 export type BondingCurve = Account<BondingCurveAccountData>;
 
@@ -40,7 +68,23 @@ export type BondingCurveAccountData = {
   realTokenReserves: bigint;
   realSolReserves: bigint;
   tokenTotalSupply: bigint;
-  complete: boolean;
+  status: CurveStatus;
+  migrationTarget: MigrationTarget;
+  lpPolicy: LpPolicy;
+  lpUnlockSlot: bigint;
+  lpMint: PublicKey;
+  curveKind: CurveKind;
+  graduationThreshold: bigint;
+  platformTradeFeeBps: bigint;
+  reserveTradeFeeBps: bigint;
+  graduationMode: GraduationMode;
+  createdSlot: bigint;
+  launchWindow: LaunchWindow;
+  tradingStartsAt: TradingStart;
+  tradingOpenedSlot: bigint;
+  launchFee: LaunchFee;
+  creatorTradeFeeBps: bigint;
+  reservePolicy: ReservePolicy;
 };
 
 export type BondingCurveAccountDataArgs = {
@@ -51,7 +95,23 @@ export type BondingCurveAccountDataArgs = {
   realTokenReserves: number | bigint;
   realSolReserves: number | bigint;
   tokenTotalSupply: number | bigint;
-  complete: boolean;
+  status: CurveStatusArgs;
+  migrationTarget: MigrationTargetArgs;
+  lpPolicy: LpPolicyArgs;
+  lpUnlockSlot: number | bigint;
+  lpMint: PublicKey;
+  curveKind: CurveKindArgs;
+  graduationThreshold: number | bigint;
+  platformTradeFeeBps: number | bigint;
+  reserveTradeFeeBps: number | bigint;
+  graduationMode: GraduationModeArgs;
+  createdSlot: number | bigint;
+  launchWindow: LaunchWindowArgs;
+  tradingStartsAt: TradingStartArgs;
+  tradingOpenedSlot: number | bigint;
+  launchFee: LaunchFeeArgs;
+  creatorTradeFeeBps: number | bigint;
+  reservePolicy: ReservePolicyArgs;
 };
 
 export function getBondingCurveAccountDataSerializer(): Serializer<
@@ -73,7 +133,23 @@ export function getBondingCurveAccountDataSerializer(): Serializer<
         ['realTokenReserves', u64()],
         ['realSolReserves', u64()],
         ['tokenTotalSupply', u64()],
-        ['complete', bool()],
+        ['status', getCurveStatusSerializer()],
+        ['migrationTarget', getMigrationTargetSerializer()],
+        ['lpPolicy', getLpPolicySerializer()],
+        ['lpUnlockSlot', u64()],
+        ['lpMint', publicKeySerializer()],
+        ['curveKind', getCurveKindSerializer()],
+        ['graduationThreshold', u64()],
+        ['platformTradeFeeBps', u64()],
+        ['reserveTradeFeeBps', u64()],
+        ['graduationMode', getGraduationModeSerializer()],
+        ['createdSlot', u64()],
+        ['launchWindow', getLaunchWindowSerializer()],
+        ['tradingStartsAt', getTradingStartSerializer()],
+        ['tradingOpenedSlot', u64()],
+        ['launchFee', getLaunchFeeSerializer()],
+        ['creatorTradeFeeBps', u64()],
+        ['reservePolicy', getReservePolicySerializer()],
       ],
       { description: 'BondingCurveAccountData' }
     ),
@@ -147,7 +223,7 @@ export function getBondingCurveGpaBuilder(
 ) {
   const programId = context.programs.getPublicKey(
     'coinfun',
-    '6xHUH1pJ2sLCm8YwyF8DBkUyBjDvoJKWgaXiBLaDdgsp'
+    'ihC7UqkLYWxQKVuYLiWNGqGvQCZb2ih4DXMLfyM6F68'
   );
   return gpaBuilder(context, programId)
     .registerFields<{
@@ -159,7 +235,23 @@ export function getBondingCurveGpaBuilder(
       realTokenReserves: number | bigint;
       realSolReserves: number | bigint;
       tokenTotalSupply: number | bigint;
-      complete: boolean;
+      status: CurveStatusArgs;
+      migrationTarget: MigrationTargetArgs;
+      lpPolicy: LpPolicyArgs;
+      lpUnlockSlot: number | bigint;
+      lpMint: PublicKey;
+      curveKind: CurveKindArgs;
+      graduationThreshold: number | bigint;
+      platformTradeFeeBps: number | bigint;
+      reserveTradeFeeBps: number | bigint;
+      graduationMode: GraduationModeArgs;
+      createdSlot: number | bigint;
+      launchWindow: LaunchWindowArgs;
+      tradingStartsAt: TradingStartArgs;
+      tradingOpenedSlot: number | bigint;
+      launchFee: LaunchFeeArgs;
+      creatorTradeFeeBps: number | bigint;
+      reservePolicy: ReservePolicyArgs;
     }>({
       discriminator: [0, bytes({ size: 8 })],
       mint: [8, publicKeySerializer()],
@@ -169,7 +261,23 @@ export function getBondingCurveGpaBuilder(
       realTokenReserves: [88, u64()],
       realSolReserves: [96, u64()],
       tokenTotalSupply: [104, u64()],
-      complete: [112, bool()],
+      status: [112, getCurveStatusSerializer()],
+      migrationTarget: [113, getMigrationTargetSerializer()],
+      lpPolicy: [114, getLpPolicySerializer()],
+      lpUnlockSlot: [null, u64()],
+      lpMint: [null, publicKeySerializer()],
+      curveKind: [null, getCurveKindSerializer()],
+      graduationThreshold: [null, u64()],
+      platformTradeFeeBps: [null, u64()],
+      reserveTradeFeeBps: [null, u64()],
+      graduationMode: [null, getGraduationModeSerializer()],
+      createdSlot: [null, u64()],
+      launchWindow: [null, getLaunchWindowSerializer()],
+      tradingStartsAt: [null, getTradingStartSerializer()],
+      tradingOpenedSlot: [null, u64()],
+      launchFee: [null, getLaunchFeeSerializer()],
+      creatorTradeFeeBps: [null, u64()],
+      reservePolicy: [null, getReservePolicySerializer()],
     })
     .deserializeUsing<BondingCurve>((account) =>
       deserializeBondingCurve(account)
@@ -21,19 +21,34 @@ import {
 } from '@metaplex-foundation/umi';
 import {
   Serializer,
+  array,
+  bool,
   bytes,
   mapSerializer,
   publicKey as publicKeySerializer,
   struct,
   u64,
 } from '@metaplex-foundation/umi/serializers';
+import {
+  CurveParams,
+  CurveParamsArgs,
+  LpPolicy,
+  LpPolicyArgs,
+  PlatformFeeRecipient,
+  PlatformFeeRecipientArgs,
+  SurplusTokenPolicy,
+  SurplusTokenPolicyArgs,
+  getCurveParamsSerializer,
+  getLpPolicySerializer,
+  getPlatformFeeRecipientSerializer,
+  getSurplusTokenPolicySerializer,
+} from '../types';
 
 export type Global = Account<GlobalAccountData>;
 
 export type GlobalAccountData = {
   discriminator: Uint8Array;
   authority: PublicKey;
-  platformFeeRecipient: PublicKey;
   reserve: PublicKey;
   initialVirtualTokenReserves: bigint;
   initialVirtualSolReserves: bigint;
@@ -41,11 +56,25 @@ export type GlobalAccountData = {
   platformTradeFeeBps: bigint;
   reserveTradeFeeBps: bigint;
   graduationThreshold: bigint;
+  migrationPrograms: Array<PublicKey>;
+  lpPolicy: LpPolicy;
+  keeperRewardBps: bigint;
+  migrationConfigs: Array<PublicKey>;
+  surplusTokenPolicy: SurplusTokenPolicy;
+  migrationFeeLamports: bigint;
+  curveKinds: Array<boolean>;
+  minCurveParams: CurveParams;
+  maxCurveParams: CurveParams;
+  solUsdPriceFeed: PublicKey;
+  maxPriceAgeSecs: bigint;
+  maxPriceConfidenceBps: bigint;
+  creatorTradeFeeBps: bigint;
+  referralFeeBps: bigint;
+  platformFeeRecipients: Array<PlatformFeeRecipient>;
 };
 
 export type GlobalAccountDataArgs = {
   authority: PublicKey;
-  platformFeeRecipient: PublicKey;
   reserve: PublicKey;
   initialVirtualTokenReserves: number | bigint;
   initialVirtualSolReserves: number | bigint;
@@ -53,6 +82,21 @@ export type GlobalAccountDataArgs = {
   platformTradeFeeBps: number | bigint;
   reserveTradeFeeBps: number | bigint;
   graduationThreshold: number | bigint;
+  migrationPrograms: Array<PublicKey>;
+  lpPolicy: LpPolicyArgs;
+  keeperRewardBps: number | bigint;
+  migrationConfigs: Array<PublicKey>;
+  surplusTokenPolicy: SurplusTokenPolicyArgs;
+  migrationFeeLamports: number | bigint;
+  curveKinds: Array<boolean>;
+  minCurveParams: CurveParamsArgs;
+  maxCurveParams: CurveParamsArgs;
+  solUsdPriceFeed: PublicKey;
+  maxPriceAgeSecs: number | bigint;
+  maxPriceConfidenceBps: number | bigint;
+  creatorTradeFeeBps: number | bigint;
+  referralFeeBps: number | bigint;
+  platformFeeRecipients: Array<PlatformFeeRecipientArgs>;
 };
 
 export function getGlobalAccountDataSerializer(): Serializer<
@@ -64,7 +108,6 @@ export function getGlobalAccountDataSerializer(): Serializer<
       [
         ['discriminator', bytes({ size: 8 })],
         ['authority', publicKeySerializer()],
-        ['platformFeeRecipient', publicKeySerializer()],
         ['reserve', publicKeySerializer()],
         ['initialVirtualTokenReserves', u64()],
         ['initialVirtualSolReserves', u64()],
@@ -72,6 +115,24 @@ export function getGlobalAccountDataSerializer(): Serializer<
         ['platformTradeFeeBps', u64()],
         ['reserveTradeFeeBps', u64()],
         ['graduationThreshold', u64()],
+        ['migrationPrograms', array(publicKeySerializer(), { size: 3 })],
+        ['lpPolicy', getLpPolicySerializer()],
+        ['keeperRewardBps', u64()],
+        ['migrationConfigs', array(publicKeySerializer(), { size: 3 })],
+        ['surplusTokenPolicy', getSurplusTokenPolicySerializer()],
+        ['migrationFeeLamports', u64()],
+        ['curveKinds', array(bool(), { size: 3 })],
+        ['minCurveParams', getCurveParamsSerializer()],
+        ['maxCurveParams', getCurveParamsSerializer()],
+        ['solUsdPriceFeed', publicKeySerializer()],
+        ['maxPriceAgeSecs', u64()],
+        ['maxPriceConfidenceBps', u64()],
+        ['creatorTradeFeeBps', u64()],
+        ['referralFeeBps', u64()],
+        [
+          'platformFeeRecipients',
+          array(getPlatformFeeRecipientSerializer(), { size: 5 }),
+        ],
       ],
       { description: 'GlobalAccountData' }
     ),
@@ -145,13 +206,12 @@ export function getGlobalGpaBuilder(
 ) {
   const programId = context.programs.getPublicKey(
     'coinfun',
-    '6xHUH1pJ2sLCm8YwyF8DBkUyBjDvoJKWgaXiBLaDdgsp'
+    'ihC7UqkLYWxQKVuYLiWNGqGvQCZb2ih4DXMLfyM6F68'
   );
   return gpaBuilder(context, programId)
     .registerFields<{
       discriminator: Uint8Array;
       authority: PublicKey;
-      platformFeeRecipient: PublicKey;
       reserve: PublicKey;
       initialVirtualTokenReserves: number | bigint;
       initialVirtualSolReserves: number | bigint;
@@ -159,17 +219,49 @@ export function getGlobalGpaBuilder(
       platformTradeFeeBps: number | bigint;
       reserveTradeFeeBps: number | bigint;
       graduationThreshold: number | bigint;
+      migrationPrograms: Array<PublicKey>;
+      lpPolicy: LpPolicyArgs;
+      keeperRewardBps: number | bigint;
+      migrationConfigs: Array<PublicKey>;
+      surplusTokenPolicy: SurplusTokenPolicyArgs;
+      migrationFeeLamports: number | bigint;
+      curveKinds: Array<boolean>;
+      minCurveParams: CurveParamsArgs;
+      maxCurveParams: CurveParamsArgs;
+      solUsdPriceFeed: PublicKey;
+      maxPriceAgeSecs: number | bigint;
+      maxPriceConfidenceBps: number | bigint;
+      creatorTradeFeeBps: number | bigint;
+      referralFeeBps: number | bigint;
+      platformFeeRecipients: Array<PlatformFeeRecipientArgs>;
     }>({
       discriminator: [0, bytes({ size: 8 })],
       authority: [8, publicKeySerializer()],
-      platformFeeRecipient: [40, publicKeySerializer()],
-      reserve: [72, publicKeySerializer()],
-      initialVirtualTokenReserves: [104, u64()],
-      initialVirtualSolReserves: [112, u64()],
-      tokenTotalSupply: [120, u64()],
-      platformTradeFeeBps: [128, u64()],
-      reserveTradeFeeBps: [136, u64()],
-      graduationThreshold: [144, u64()],
+      reserve: [40, publicKeySerializer()],
+      initialVirtualTokenReserves: [72, u64()],
+      initialVirtualSolReserves: [80, u64()],
+      tokenTotalSupply: [88, u64()],
+      platformTradeFeeBps: [96, u64()],
+      reserveTradeFeeBps: [104, u64()],
+      graduationThreshold: [112, u64()],
+      migrationPrograms: [120, array(publicKeySerializer(), { size: 3 })],
+      lpPolicy: [216, getLpPolicySerializer()],
+      keeperRewardBps: [null, u64()],
+      migrationConfigs: [null, array(publicKeySerializer(), { size: 3 })],
+      surplusTokenPolicy: [null, getSurplusTokenPolicySerializer()],
+      migrationFeeLamports: [null, u64()],
+      curveKinds: [null, array(bool(), { size: 3 })],
+      minCurveParams: [null, getCurveParamsSerializer()],
+      maxCurveParams: [null, getCurveParamsSerializer()],
+      solUsdPriceFeed: [null, publicKeySerializer()],
+      maxPriceAgeSecs: [null, u64()],
+      maxPriceConfidenceBps: [null, u64()],
+      creatorTradeFeeBps: [null, u64()],
+      referralFeeBps: [null, u64()],
+      platformFeeRecipients: [
+        null,
+        array(getPlatformFeeRecipientSerializer(), { size: 5 }),
+      ],
     })
     .deserializeUsing<Global>((account) => deserializeGlobal(account))
     .whereField(
@@ -80,7 +80,7 @@ export class FeeTooHighError extends ProgramError {
 codeToErrorMap.set(0x1774, FeeTooHighError);
 nameToErrorMap.set('FeeTooHigh', FeeTooHighError);
 
-/** InvalidTokenReserveConfiguration: Total supply must be greater than the initial real token reserves. */
+/** InvalidTokenReserveConfiguration: Initial virtual token reserves must be greater than the total supply */
 export class InvalidTokenReserveConfigurationError extends ProgramError {
   override readonly name: string = 'InvalidTokenReserveConfiguration';
 
@@ -88,7 +88,7 @@ export class InvalidTokenReserveConfigurationError extends ProgramError {
 
   constructor(program: Program, cause?: Error) {
     super(
-      'Total supply must be greater than the initial real token reserves.',
+      'Initial virtual token reserves must be greater than the total supply',
       program,
       cause
     );
@@ -100,6 +100,454 @@ nameToErrorMap.set(
   InvalidTokenReserveConfigurationError
 );
 
+/** NothingToMigrate: Bonding curve has nothing left to migrate */
+export class NothingToMigrateError extends ProgramError {
+  override readonly name: string = 'NothingToMigrate';
+
+  readonly code: number = 0x1776; // 6006
+
+  constructor(program: Program, cause?: Error) {
+    super('Bonding curve has nothing left to migrate', program, cause);
+  }
+}
+codeToErrorMap.set(0x1776, NothingToMigrateError);
+nameToErrorMap.set('NothingToMigrate', NothingToMigrateError);
+
+/** PoolPriceOutOfRange: Pool price derived from the curve is out of range */
+export class PoolPriceOutOfRangeError extends ProgramError {
+  override readonly name: string = 'PoolPriceOutOfRange';
+
+  readonly code: number = 0x1777; // 6007
+
+  constructor(program: Program, cause?: Error) {
+    super('Pool price derived from the curve is out of range', program, cause);
+  }
+}
+codeToErrorMap.set(0x1777, PoolPriceOutOfRangeError);
+nameToErrorMap.set('PoolPriceOutOfRange', PoolPriceOutOfRangeError);
+
+/** InvalidCurveStatusTransition: Bonding curve cannot move to that status */
+export class InvalidCurveStatusTransitionError extends ProgramError {
+  override readonly name: string = 'InvalidCurveStatusTransition';
+
+  readonly code: number = 0x1778; // 6008
+
+  constructor(program: Program, cause?: Error) {
+    super('Bonding curve cannot move to that status', program, cause);
+  }
+}
+codeToErrorMap.set(0x1778, InvalidCurveStatusTransitionError);
+nameToErrorMap.set(
+  'InvalidCurveStatusTransition',
+  InvalidCurveStatusTransitionError
+);
+
+/** CurveNotRefunding: Bonding curve is not refunding */
+export class CurveNotRefundingError extends ProgramError {
+  override readonly name: string = 'CurveNotRefunding';
+
+  readonly code: number = 0x1779; // 6009
+
+  constructor(program: Program, cause?: Error) {
+    super('Bonding curve is not refunding', program, cause);
+  }
+}
+codeToErrorMap.set(0x1779, CurveNotRefundingError);
+nameToErrorMap.set('CurveNotRefunding', CurveNotRefundingError);
+
+/** MigrationTargetNotAllowed: Migration target is not enabled */
+export class MigrationTargetNotAllowedError extends ProgramError {
+  override readonly name: string = 'MigrationTargetNotAllowed';
+
+  readonly code: number = 0x177a; // 6010
+
+  constructor(program: Program, cause?: Error) {
+    super('Migration target is not enabled', program, cause);
+  }
+}
+codeToErrorMap.set(0x177a, MigrationTargetNotAllowedError);
+nameToErrorMap.set('MigrationTargetNotAllowed', MigrationTargetNotAllowedError);
+
+/** MissingMigrationAccounts: Not enough remaining accounts for the migration target */
+export class MissingMigrationAccountsError extends ProgramError {
+  override readonly name: string = 'MissingMigrationAccounts';
+
+  readonly code: number = 0x177b; // 6011
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Not enough remaining accounts for the migration target',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x177b, MissingMigrationAccountsError);
+nameToErrorMap.set('MissingMigrationAccounts', MissingMigrationAccountsError);
+
+/** InvalidMigrationAccount: Unexpected account passed for the migration target */
+export class InvalidMigrationAccountError extends ProgramError {
+  override readonly name: string = 'InvalidMigrationAccount';
+
+  readonly code: number = 0x177c; // 6012
+
+  constructor(program: Program, cause?: Error) {
+    super('Unexpected account passed for the migration target', program, cause);
+  }
+}
+codeToErrorMap.set(0x177c, InvalidMigrationAccountError);
+nameToErrorMap.set('InvalidMigrationAccount', InvalidMigrationAccountError);
+
+/** LpPolicyNotSupported: LP policy is not supported by the migration target */
+export class LpPolicyNotSupportedError extends ProgramError {
+  override readonly name: string = 'LpPolicyNotSupported';
+
+  readonly code: number = 0x177d; // 6013
+
+  constructor(program: Program, cause?: Error) {
+    super('LP policy is not supported by the migration target', program, cause);
+  }
+}
+codeToErrorMap.set(0x177d, LpPolicyNotSupportedError);
+nameToErrorMap.set('LpPolicyNotSupported', LpPolicyNotSupportedError);
+
+/** LpFeeClaimNotSupported: Migration target has no LP fees to claim */
+export class LpFeeClaimNotSupportedError extends ProgramError {
+  override readonly name: string = 'LpFeeClaimNotSupported';
+
+  readonly code: number = 0x177e; // 6014
+
+  constructor(program: Program, cause?: Error) {
+    super('Migration target has no LP fees to claim', program, cause);
+  }
+}
+codeToErrorMap.set(0x177e, LpFeeClaimNotSupportedError);
+nameToErrorMap.set('LpFeeClaimNotSupported', LpFeeClaimNotSupportedError);
+
+/** LpLocked: LP position is locked */
+export class LpLockedError extends ProgramError {
+  override readonly name: string = 'LpLocked';
+
+  readonly code: number = 0x177f; // 6015
+
+  constructor(program: Program, cause?: Error) {
+    super('LP position is locked', program, cause);
+  }
+}
+codeToErrorMap.set(0x177f, LpLockedError);
+nameToErrorMap.set('LpLocked', LpLockedError);
+
+/** CurveNotMigrated: Bonding curve has not been migrated */
+export class CurveNotMigratedError extends ProgramError {
+  override readonly name: string = 'CurveNotMigrated';
+
+  readonly code: number = 0x1780; // 6016
+
+  constructor(program: Program, cause?: Error) {
+    super('Bonding curve has not been migrated', program, cause);
+  }
+}
+codeToErrorMap.set(0x1780, CurveNotMigratedError);
+nameToErrorMap.set('CurveNotMigrated', CurveNotMigratedError);
+
+/** PoolPriceMismatch: The curve's DEX pool already exists at another price */
+export class PoolPriceMismatchError extends ProgramError {
+  override readonly name: string = 'PoolPriceMismatch';
+
+  readonly code: number = 0x1781; // 6017
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      "The curve's DEX pool already exists at another price",
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1781, PoolPriceMismatchError);
+nameToErrorMap.set('PoolPriceMismatch', PoolPriceMismatchError);
+
+/** TokenAmountUnavailable: Bonding curve cannot fill that token amount before graduating */
+export class TokenAmountUnavailableError extends ProgramError {
+  override readonly name: string = 'TokenAmountUnavailable';
+
+  readonly code: number = 0x1782; // 6018
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Bonding curve cannot fill that token amount before graduating',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1782, TokenAmountUnavailableError);
+nameToErrorMap.set('TokenAmountUnavailable', TokenAmountUnavailableError);
+
+/** SolAmountUnavailable: Bonding curve cannot pay out that SOL amount */
+export class SolAmountUnavailableError extends ProgramError {
+  override readonly name: string = 'SolAmountUnavailable';
+
+  readonly code: number = 0x1783; // 6019
+
+  constructor(program: Program, cause?: Error) {
+    super('Bonding curve cannot pay out that SOL amount', program, cause);
+  }
+}
+codeToErrorMap.set(0x1783, SolAmountUnavailableError);
+nameToErrorMap.set('SolAmountUnavailable', SolAmountUnavailableError);
+
+/** CurveKindNotAllowed: Curve kind not allowed */
+export class CurveKindNotAllowedError extends ProgramError {
+  override readonly name: string = 'CurveKindNotAllowed';
+
+  readonly code: number = 0x1784; // 6020
+
+  constructor(program: Program, cause?: Error) {
+    super('Curve kind not allowed', program, cause);
+  }
+}
+codeToErrorMap.set(0x1784, CurveKindNotAllowedError);
+nameToErrorMap.set('CurveKindNotAllowed', CurveKindNotAllowedError);
+
+/** InvalidCurveParams: Invalid curve parameters */
+export class InvalidCurveParamsError extends ProgramError {
+  override readonly name: string = 'InvalidCurveParams';
+
+  readonly code: number = 0x1785; // 6021
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid curve parameters', program, cause);
+  }
+}
+codeToErrorMap.set(0x1785, InvalidCurveParamsError);
+nameToErrorMap.set('InvalidCurveParams', InvalidCurveParamsError);
+
+/** CurveParamsOutOfBounds: Curve parameters outside the allowed bounds */
+export class CurveParamsOutOfBoundsError extends ProgramError {
+  override readonly name: string = 'CurveParamsOutOfBounds';
+
+  readonly code: number = 0x1786; // 6022
+
+  constructor(program: Program, cause?: Error) {
+    super('Curve parameters outside the allowed bounds', program, cause);
+  }
+}
+codeToErrorMap.set(0x1786, CurveParamsOutOfBoundsError);
+nameToErrorMap.set('CurveParamsOutOfBounds', CurveParamsOutOfBoundsError);
+
+/** MissingPriceFeed: SOL/USD price feed is not configured or not passed */
+export class MissingPriceFeedError extends ProgramError {
+  override readonly name: string = 'MissingPriceFeed';
+
+  readonly code: number = 0x1787; // 6023
+
+  constructor(program: Program, cause?: Error) {
+    super('SOL/USD price feed is not configured or not passed', program, cause);
+  }
+}
+codeToErrorMap.set(0x1787, MissingPriceFeedError);
+nameToErrorMap.set('MissingPriceFeed', MissingPriceFeedError);
+
+/** InvalidPriceFeed: Invalid SOL/USD price feed account */
+export class InvalidPriceFeedError extends ProgramError {
+  override readonly name: string = 'InvalidPriceFeed';
+
+  readonly code: number = 0x1788; // 6024
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid SOL/USD price feed account', program, cause);
+  }
+}
+codeToErrorMap.set(0x1788, InvalidPriceFeedError);
+nameToErrorMap.set('InvalidPriceFeed', InvalidPriceFeedError);
+
+/** PriceFeedStale: SOL/USD price is too old */
+export class PriceFeedStaleError extends ProgramError {
+  override readonly name: string = 'PriceFeedStale';
+
+  readonly code: number = 0x1789; // 6025
+
+  constructor(program: Program, cause?: Error) {
+    super('SOL/USD price is too old', program, cause);
+  }
+}
+codeToErrorMap.set(0x1789, PriceFeedStaleError);
+nameToErrorMap.set('PriceFeedStale', PriceFeedStaleError);
+
+/** PriceFeedUncertain: SOL/USD price confidence interval is too wide */
+export class PriceFeedUncertainError extends ProgramError {
+  override readonly name: string = 'PriceFeedUncertain';
+
+  readonly code: number = 0x178a; // 6026
+
+  constructor(program: Program, cause?: Error) {
+    super('SOL/USD price confidence interval is too wide', program, cause);
+  }
+}
+codeToErrorMap.set(0x178a, PriceFeedUncertainError);
+nameToErrorMap.set('PriceFeedUncertain', PriceFeedUncertainError);
+
+/** InvalidLaunchWindow: Invalid launch window */
+export class InvalidLaunchWindowError extends ProgramError {
+  override readonly name: string = 'InvalidLaunchWindow';
+
+  readonly code: number = 0x178b; // 6027
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid launch window', program, cause);
+  }
+}
+codeToErrorMap.set(0x178b, InvalidLaunchWindowError);
+nameToErrorMap.set('InvalidLaunchWindow', InvalidLaunchWindowError);
+
+/** LaunchBuyTooLarge: Buy exceeds the launch window's SOL cap */
+export class LaunchBuyTooLargeError extends ProgramError {
+  override readonly name: string = 'LaunchBuyTooLarge';
+
+  readonly code: number = 0x178c; // 6028
+
+  constructor(program: Program, cause?: Error) {
+    super("Buy exceeds the launch window's SOL cap", program, cause);
+  }
+}
+codeToErrorMap.set(0x178c, LaunchBuyTooLargeError);
+nameToErrorMap.set('LaunchBuyTooLarge', LaunchBuyTooLargeError);
+
+/** LaunchWalletCapExceeded: Wallet exceeds the launch window's token cap */
+export class LaunchWalletCapExceededError extends ProgramError {
+  override readonly name: string = 'LaunchWalletCapExceeded';
+
+  readonly code: number = 0x178d; // 6029
+
+  constructor(program: Program, cause?: Error) {
+    super("Wallet exceeds the launch window's token cap", program, cause);
+  }
+}
+codeToErrorMap.set(0x178d, LaunchWalletCapExceededError);
+nameToErrorMap.set('LaunchWalletCapExceeded', LaunchWalletCapExceededError);
+
+/** MissingLaunchAllocation: Launch window buys need the wallet's launch allocation account */
+export class MissingLaunchAllocationError extends ProgramError {
+  override readonly name: string = 'MissingLaunchAllocation';
+
+  readonly code: number = 0x178e; // 6030
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      "Launch window buys need the wallet's launch allocation account",
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x178e, MissingLaunchAllocationError);
+nameToErrorMap.set('MissingLaunchAllocation', MissingLaunchAllocationError);
+
+/** TradingNotStarted: Trading on this curve has not started yet */
+export class TradingNotStartedError extends ProgramError {
+  override readonly name: string = 'TradingNotStarted';
+
+  readonly code: number = 0x178f; // 6031
+
+  constructor(program: Program, cause?: Error) {
+    super('Trading on this curve has not started yet', program, cause);
+  }
+}
+codeToErrorMap.set(0x178f, TradingNotStartedError);
+nameToErrorMap.set('TradingNotStarted', TradingNotStartedError);
+
+/** InvalidLaunchFee: Invalid launch fee */
+export class InvalidLaunchFeeError extends ProgramError {
+  override readonly name: string = 'InvalidLaunchFee';
+
+  readonly code: number = 0x1790; // 6032
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid launch fee', program, cause);
+  }
+}
+codeToErrorMap.set(0x1790, InvalidLaunchFeeError);
+nameToErrorMap.set('InvalidLaunchFee', InvalidLaunchFeeError);
+
+/** LaunchFeeTooHigh: Launch fee basis points cannot exceed 5000 (50%) */
+export class LaunchFeeTooHighError extends ProgramError {
+  override readonly name: string = 'LaunchFeeTooHigh';
+
+  readonly code: number = 0x1791; // 6033
+
+  constructor(program: Program, cause?: Error) {
+    super('Launch fee basis points cannot exceed 5000 (50%)', program, cause);
+  }
+}
+codeToErrorMap.set(0x1791, LaunchFeeTooHighError);
+nameToErrorMap.set('LaunchFeeTooHigh', LaunchFeeTooHighError);
+
+/** SelfReferral: Traders cannot refer themselves */
+export class SelfReferralError extends ProgramError {
+  override readonly name: string = 'SelfReferral';
+
+  readonly code: number = 0x1792; // 6034
+
+  constructor(program: Program, cause?: Error) {
+    super('Traders cannot refer themselves', program, cause);
+  }
+}
+codeToErrorMap.set(0x1792, SelfReferralError);
+nameToErrorMap.set('SelfReferral', SelfReferralError);
+
+/** InvalidFeeRecipients: Invalid platform fee recipients */
+export class InvalidFeeRecipientsError extends ProgramError {
+  override readonly name: string = 'InvalidFeeRecipients';
+
+  readonly code: number = 0x1793; // 6035
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid platform fee recipients', program, cause);
+  }
+}
+codeToErrorMap.set(0x1793, InvalidFeeRecipientsError);
+nameToErrorMap.set('InvalidFeeRecipients', InvalidFeeRecipientsError);
+
+/** InsufficientReserve: Amount exceeds what the reserve holds */
+export class InsufficientReserveError extends ProgramError {
+  override readonly name: string = 'InsufficientReserve';
+
+  readonly code: number = 0x1794; // 6036
+
+  constructor(program: Program, cause?: Error) {
+    super('Amount exceeds what the reserve holds', program, cause);
+  }
+}
+codeToErrorMap.set(0x1794, InsufficientReserveError);
+nameToErrorMap.set('InsufficientReserve', InsufficientReserveError);
+
+/** InsufficientStake: Unstake exceeds the staked amount */
+export class InsufficientStakeError extends ProgramError {
+  override readonly name: string = 'InsufficientStake';
+
+  readonly code: number = 0x1795; // 6037
+
+  constructor(program: Program, cause?: Error) {
+    super('Unstake exceeds the staked amount', program, cause);
+  }
+}
+codeToErrorMap.set(0x1795, InsufficientStakeError);
+nameToErrorMap.set('InsufficientStake', InsufficientStakeError);
+
+/** ReserveStaked: The mint's reserve belongs to its stakers */
+export class ReserveStakedError extends ProgramError {
+  override readonly name: string = 'ReserveStaked';
+
+  readonly code: number = 0x1796; // 6038
+
+  constructor(program: Program, cause?: Error) {
+    super("The mint's reserve belongs to its stakers", program, cause);
+  }
+}
+codeToErrorMap.set(0x1796, ReserveStakedError);
+nameToErrorMap.set('ReserveStaked', ReserveStakedError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
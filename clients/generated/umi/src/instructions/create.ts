@@ -8,6 +8,8 @@
 
 import {
   Context,
+  Option,
+  OptionOrNullable,
   Pda,
   PublicKey,
   Signer,
@@ -18,6 +20,7 @@ import {
   Serializer,
   bytes,
   mapSerializer,
+  option,
   publicKey as publicKeySerializer,
   string,
   struct,
@@ -28,6 +31,32 @@ import {
   expectPublicKey,
   getAccountMetasAndSigners,
 } from '../shared';
+import {
+  CurveKind,
+  CurveKindArgs,
+  CurveParams,
+  CurveParamsArgs,
+  GraduationMode,
+  GraduationModeArgs,
+  LaunchFee,
+  LaunchFeeArgs,
+  LaunchWindow,
+  LaunchWindowArgs,
+  MigrationTarget,
+  MigrationTargetArgs,
+  ReservePolicy,
+  ReservePolicyArgs,
+  TradingStart,
+  TradingStartArgs,
+  getCurveKindSerializer,
+  getCurveParamsSerializer,
+  getGraduationModeSerializer,
+  getLaunchFeeSerializer,
+  getLaunchWindowSerializer,
+  getMigrationTargetSerializer,
+  getReservePolicySerializer,
+  getTradingStartSerializer,
+} from '../types';
 
 // Accounts.
 export type CreateInstructionAccounts = {
@@ -37,6 +66,7 @@ export type CreateInstructionAccounts = {
   global?: PublicKey | Pda;
   bondingCurve?: PublicKey | Pda;
   bondingCurveAta?: PublicKey | Pda;
+  creatorVault?: PublicKey | Pda;
   metadataAccount?: PublicKey | Pda;
   tokenMetadataProgram?: PublicKey | Pda;
   associatedTokenProgram?: PublicKey | Pda;
@@ -51,12 +81,28 @@ export type CreateInstructionData = {
   tokenName: string;
   tokenSymbol: string;
   tokenUri: string;
+  migrationTarget: MigrationTarget;
+  curveKind: CurveKind;
+  curveParams: Option<CurveParams>;
+  graduationMode: GraduationMode;
+  launchWindow: Option<LaunchWindow>;
+  tradingStartsAt: TradingStart;
+  launchFee: Option<LaunchFee>;
+  reservePolicy: ReservePolicy;
 };
 
 export type CreateInstructionDataArgs = {
   tokenName: string;
   tokenSymbol: string;
   tokenUri: string;
+  migrationTarget: MigrationTargetArgs;
+  curveKind: CurveKindArgs;
+  curveParams: OptionOrNullable<CurveParamsArgs>;
+  graduationMode: GraduationModeArgs;
+  launchWindow: OptionOrNullable<LaunchWindowArgs>;
+  tradingStartsAt: TradingStartArgs;
+  launchFee: OptionOrNullable<LaunchFeeArgs>;
+  reservePolicy: ReservePolicyArgs;
 };
 
 export function getCreateInstructionDataSerializer(): Serializer<
@@ -70,6 +116,14 @@ export function getCreateInstructionDataSerializer(): Serializer<
         ['tokenName', string()],
         ['tokenSymbol', string()],
         ['tokenUri', string()],
+        ['migrationTarget', getMigrationTargetSerializer()],
+        ['curveKind', getCurveKindSerializer()],
+        ['curveParams', option(getCurveParamsSerializer())],
+        ['graduationMode', getGraduationModeSerializer()],
+        ['launchWindow', option(getLaunchWindowSerializer())],
+        ['tradingStartsAt', getTradingStartSerializer()],
+        ['launchFee', option(getLaunchFeeSerializer())],
+        ['reservePolicy', getReservePolicySerializer()],
       ],
       { description: 'CreateInstructionData' }
     ),
@@ -91,7 +145,7 @@ export function create(
   // Program ID.
   const programId = context.programs.getPublicKey(
     'coinfun',
-    '6xHUH1pJ2sLCm8YwyF8DBkUyBjDvoJKWgaXiBLaDdgsp'
+    'ihC7UqkLYWxQKVuYLiWNGqGvQCZb2ih4DXMLfyM6F68'
   );
 
   // Accounts.
@@ -122,33 +176,38 @@ export function create(
       isWritable: true as boolean,
       value: input.bondingCurveAta ?? null,
     },
-    metadataAccount: {
+    creatorVault: {
       index: 6,
       isWritable: true as boolean,
+      value: input.creatorVault ?? null,
+    },
+    metadataAccount: {
+      index: 7,
+      isWritable: true as boolean,
       value: input.metadataAccount ?? null,
     },
     tokenMetadataProgram: {
-      index: 7,
+      index: 8,
       isWritable: false as boolean,
       value: input.tokenMetadataProgram ?? null,
     },
     associatedTokenProgram: {
-      index: 8,
+      index: 9,
       isWritable: false as boolean,
       value: input.associatedTokenProgram ?? null,
     },
     tokenProgram: {
-      index: 9,
+      index: 10,
       isWritable: false as boolean,
       value: input.tokenProgram ?? null,
     },
     systemProgram: {
-      index: 10,
+      index: 11,
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
     rent: {
-      index: 11,
+      index: 12,
       isWritable: false as boolean,
       value: input.rent ?? null,
     },
@@ -194,6 +253,18 @@ export function create(
       ]
     );
   }
+  if (!resolvedAccounts.creatorVault.value) {
+    resolvedAccounts.creatorVault.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([
+          99, 114, 101, 97, 116, 111, 114, 95, 118, 97, 117, 108, 116,
+        ])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.bondingCurve.value)
+      ),
+    ]);
+  }
   if (!resolvedAccounts.tokenMetadataProgram.value) {
     resolvedAccounts.tokenMetadataProgram.value = context.programs.getPublicKey(
       'tokenMetadataProgram',
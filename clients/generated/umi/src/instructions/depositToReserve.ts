@@ -87,7 +87,7 @@ export function depositToReserve(
   // Program ID.
   const programId = context.programs.getPublicKey(
     'coinfun',
-    '6xHUH1pJ2sLCm8YwyF8DBkUyBjDvoJKWgaXiBLaDdgsp'
+    'ihC7UqkLYWxQKVuYLiWNGqGvQCZb2ih4DXMLfyM6F68'
   );
 
   // Accounts.
@@ -7,10 +7,31 @@
  */
 
 export * from './buy';
+export * from './buyExactOut';
+export * from './claimCreatorFees';
+export * from './claimReferralFees';
+export * from './claimStakingRewards';
 export * from './create';
+export * from './createStakePool';
 export * from './depositToReserve';
+export * from './distributePlatformFees';
+export * from './harvestPoolFees';
 export * from './initialize';
+export * from './migrate';
+export * from './refund';
+export * from './registerReferrer';
+export * from './releaseLp';
+export * from './repriceCurve';
 export * from './sell';
+export * from './sellForSol';
+export * from './setCurveStatus';
+export * from './setMigrationProgram';
+export * from './setPlatformFeeRecipients';
+export * from './setPriceFeed';
+export * from './stake';
+export * from './unstake';
 export * from './updateGlobalConfig';
-export * from './withdraw';
+export * from './upgradeBondingCurve';
+export * from './upgradeGlobal';
 export * from './withdrawReserve';
+export * from './withdrawReserveSol';
@@ -16,9 +16,10 @@ import {
 } from '@metaplex-foundation/umi';
 import {
   Serializer,
+  array,
+  bool,
   bytes,
   mapSerializer,
-  publicKey as publicKeySerializer,
   struct,
   u64,
 } from '@metaplex-foundation/umi/serializers';
@@ -27,12 +28,25 @@ import {
   ResolvedAccountsWithIndices,
   getAccountMetasAndSigners,
 } from '../shared';
+import {
+  CurveParams,
+  CurveParamsArgs,
+  LpPolicy,
+  LpPolicyArgs,
+  SurplusTokenPolicy,
+  SurplusTokenPolicyArgs,
+  getCurveParamsSerializer,
+  getLpPolicySerializer,
+  getSurplusTokenPolicySerializer,
+} from '../types';
 
 // Accounts.
 export type InitializeInstructionAccounts = {
   authority: Signer;
   global?: PublicKey | Pda;
   globalReserve?: PublicKey | Pda;
+  platformFeeVault?: PublicKey | Pda;
+  reserveSolVault?: PublicKey | Pda;
   systemProgram?: PublicKey | Pda;
 };
 
@@ -44,8 +58,16 @@ export type InitializeInstructionData = {
   tokenTotalSupply: bigint;
   platformTradeFeeBps: bigint;
   reserveTradeFeeBps: bigint;
-  platformFeeRecipient: PublicKey;
   graduationThreshold: bigint;
+  lpPolicy: LpPolicy;
+  keeperRewardBps: bigint;
+  surplusTokenPolicy: SurplusTokenPolicy;
+  migrationFeeLamports: bigint;
+  curveKinds: Array<boolean>;
+  minCurveParams: CurveParams;
+  maxCurveParams: CurveParams;
+  creatorTradeFeeBps: bigint;
+  referralFeeBps: bigint;
 };
 
 export type InitializeInstructionDataArgs = {
@@ -54,8 +76,16 @@ export type InitializeInstructionDataArgs = {
   tokenTotalSupply: number | bigint;
   platformTradeFeeBps: number | bigint;
   reserveTradeFeeBps: number | bigint;
-  platformFeeRecipient: PublicKey;
   graduationThreshold: number | bigint;
+  lpPolicy: LpPolicyArgs;
+  keeperRewardBps: number | bigint;
+  surplusTokenPolicy: SurplusTokenPolicyArgs;
+  migrationFeeLamports: number | bigint;
+  curveKinds: Array<boolean>;
+  minCurveParams: CurveParamsArgs;
+  maxCurveParams: CurveParamsArgs;
+  creatorTradeFeeBps: number | bigint;
+  referralFeeBps: number | bigint;
 };
 
 export function getInitializeInstructionDataSerializer(): Serializer<
@@ -75,8 +105,16 @@ export function getInitializeInstructionDataSerializer(): Serializer<
         ['tokenTotalSupply', u64()],
         ['platformTradeFeeBps', u64()],
         ['reserveTradeFeeBps', u64()],
-        ['platformFeeRecipient', publicKeySerializer()],
         ['graduationThreshold', u64()],
+        ['lpPolicy', getLpPolicySerializer()],
+        ['keeperRewardBps', u64()],
+        ['surplusTokenPolicy', getSurplusTokenPolicySerializer()],
+        ['migrationFeeLamports', u64()],
+        ['curveKinds', array(bool(), { size: 3 })],
+        ['minCurveParams', getCurveParamsSerializer()],
+        ['maxCurveParams', getCurveParamsSerializer()],
+        ['creatorTradeFeeBps', u64()],
+        ['referralFeeBps', u64()],
       ],
       { description: 'InitializeInstructionData' }
     ),
@@ -98,7 +136,7 @@ export function initialize(
   // Program ID.
   const programId = context.programs.getPublicKey(
     'coinfun',
-    '6xHUH1pJ2sLCm8YwyF8DBkUyBjDvoJKWgaXiBLaDdgsp'
+    'ihC7UqkLYWxQKVuYLiWNGqGvQCZb2ih4DXMLfyM6F68'
   );
 
   // Accounts.
@@ -118,8 +156,18 @@ export function initialize(
       isWritable: true as boolean,
       value: input.globalReserve ?? null,
     },
-    systemProgram: {
+    platformFeeVault: {
       index: 3,
+      isWritable: true as boolean,
+      value: input.platformFeeVault ?? null,
+    },
+    reserveSolVault: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.reserveSolVault ?? null,
+    },
+    systemProgram: {
+      index: 5,
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
@@ -139,6 +187,26 @@ export function initialize(
       bytes().serialize(new Uint8Array([114, 101, 115, 101, 114, 118, 101])),
     ]);
   }
+  if (!resolvedAccounts.platformFeeVault.value) {
+    resolvedAccounts.platformFeeVault.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([
+          112, 108, 97, 116, 102, 111, 114, 109, 95, 102, 101, 101, 95, 118, 97,
+          117, 108, 116,
+        ])
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.reserveSolVault.value) {
+    resolvedAccounts.reserveSolVault.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([
+          114, 101, 115, 101, 114, 118, 101, 95, 115, 111, 108, 95, 118, 97,
+          117, 108, 116,
+        ])
+      ),
+    ]);
+  }
   if (!resolvedAccounts.systemProgram.value) {
     resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
       'systemProgram',
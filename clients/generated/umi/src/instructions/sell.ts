@@ -37,12 +37,15 @@ export type SellInstructionAccounts = {
   userAta?: PublicKey | Pda;
   mint: PublicKey | Pda;
   global?: PublicKey | Pda;
-  platformFeeRecipient: PublicKey | Pda;
+  platformFeeVault?: PublicKey | Pda;
   globalReserve?: PublicKey | Pda;
   reserveAta?: PublicKey | Pda;
+  creatorVault?: PublicKey | Pda;
+  reserveSolVault?: PublicKey | Pda;
   systemProgram?: PublicKey | Pda;
   tokenProgram: PublicKey | Pda;
   associatedTokenProgram?: PublicKey | Pda;
+  referrer?: PublicKey | Pda;
 };
 
 // Data.
@@ -88,7 +91,7 @@ export function sell(
   // Program ID.
   const programId = context.programs.getPublicKey(
     'coinfun',
-    '6xHUH1pJ2sLCm8YwyF8DBkUyBjDvoJKWgaXiBLaDdgsp'
+    'ihC7UqkLYWxQKVuYLiWNGqGvQCZb2ih4DXMLfyM6F68'
   );
 
   // Accounts.
@@ -113,16 +116,16 @@ export function sell(
       isWritable: true as boolean,
       value: input.userAta ?? null,
     },
-    mint: { index: 4, isWritable: false as boolean, value: input.mint ?? null },
+    mint: { index: 4, isWritable: true as boolean, value: input.mint ?? null },
     global: {
       index: 5,
       isWritable: false as boolean,
       value: input.global ?? null,
     },
-    platformFeeRecipient: {
+    platformFeeVault: {
       index: 6,
       isWritable: true as boolean,
-      value: input.platformFeeRecipient ?? null,
+      value: input.platformFeeVault ?? null,
     },
     globalReserve: {
       index: 7,
@@ -134,21 +137,36 @@ export function sell(
       isWritable: true as boolean,
       value: input.reserveAta ?? null,
     },
-    systemProgram: {
+    creatorVault: {
       index: 9,
+      isWritable: true as boolean,
+      value: input.creatorVault ?? null,
+    },
+    reserveSolVault: {
+      index: 10,
+      isWritable: true as boolean,
+      value: input.reserveSolVault ?? null,
+    },
+    systemProgram: {
+      index: 11,
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
     tokenProgram: {
-      index: 10,
+      index: 12,
       isWritable: false as boolean,
       value: input.tokenProgram ?? null,
     },
     associatedTokenProgram: {
-      index: 11,
+      index: 13,
       isWritable: false as boolean,
       value: input.associatedTokenProgram ?? null,
     },
+    referrer: {
+      index: 14,
+      isWritable: true as boolean,
+      value: input.referrer ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
@@ -218,6 +236,16 @@ export function sell(
       bytes().serialize(new Uint8Array([103, 108, 111, 98, 97, 108])),
     ]);
   }
+  if (!resolvedAccounts.platformFeeVault.value) {
+    resolvedAccounts.platformFeeVault.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([
+          112, 108, 97, 116, 102, 111, 114, 109, 95, 102, 101, 101, 95, 118, 97,
+          117, 108, 116,
+        ])
+      ),
+    ]);
+  }
   if (!resolvedAccounts.globalReserve.value) {
     resolvedAccounts.globalReserve.value = context.eddsa.findPda(programId, [
       bytes().serialize(new Uint8Array([114, 101, 115, 101, 114, 118, 101])),
@@ -242,6 +270,28 @@ export function sell(
       ]
     );
   }
+  if (!resolvedAccounts.creatorVault.value) {
+    resolvedAccounts.creatorVault.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([
+          99, 114, 101, 97, 116, 111, 114, 95, 118, 97, 117, 108, 116,
+        ])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.bondingCurve.value)
+      ),
+    ]);
+  }
+  if (!resolvedAccounts.reserveSolVault.value) {
+    resolvedAccounts.reserveSolVault.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([
+          114, 101, 115, 101, 114, 118, 101, 95, 115, 111, 108, 95, 118, 97,
+          117, 108, 116,
+        ])
+      ),
+    ]);
+  }
   if (!resolvedAccounts.systemProgram.value) {
     resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
       'systemProgram',
@@ -16,6 +16,8 @@ import {
 } from '@metaplex-foundation/umi';
 import {
   Serializer,
+  array,
+  bool,
   bytes,
   mapSerializer,
   publicKey as publicKeySerializer,
@@ -27,6 +29,17 @@ import {
   ResolvedAccountsWithIndices,
   getAccountMetasAndSigners,
 } from '../shared';
+import {
+  CurveParams,
+  CurveParamsArgs,
+  LpPolicy,
+  LpPolicyArgs,
+  SurplusTokenPolicy,
+  SurplusTokenPolicyArgs,
+  getCurveParamsSerializer,
+  getLpPolicySerializer,
+  getSurplusTokenPolicySerializer,
+} from '../types';
 
 // Accounts.
 export type UpdateGlobalConfigInstructionAccounts = {
@@ -38,24 +51,40 @@ export type UpdateGlobalConfigInstructionAccounts = {
 export type UpdateGlobalConfigInstructionData = {
   discriminator: Uint8Array;
   newAuthority: PublicKey;
-  newPlatformFeeRecipient: PublicKey;
   newPlatformTradeFeeBps: bigint;
   newReserveTradeFeeBps: bigint;
   newInitialVirtualTokenReserves: bigint;
   newInitialVirtualSolReserves: bigint;
   newTokenTotalSupply: bigint;
   newGraduationThreshold: bigint;
+  newLpPolicy: LpPolicy;
+  newKeeperRewardBps: bigint;
+  newSurplusTokenPolicy: SurplusTokenPolicy;
+  newMigrationFeeLamports: bigint;
+  newCurveKinds: Array<boolean>;
+  newMinCurveParams: CurveParams;
+  newMaxCurveParams: CurveParams;
+  newCreatorTradeFeeBps: bigint;
+  newReferralFeeBps: bigint;
 };
 
 export type UpdateGlobalConfigInstructionDataArgs = {
   newAuthority: PublicKey;
-  newPlatformFeeRecipient: PublicKey;
   newPlatformTradeFeeBps: number | bigint;
   newReserveTradeFeeBps: number | bigint;
   newInitialVirtualTokenReserves: number | bigint;
   newInitialVirtualSolReserves: number | bigint;
   newTokenTotalSupply: number | bigint;
   newGraduationThreshold: number | bigint;
+  newLpPolicy: LpPolicyArgs;
+  newKeeperRewardBps: number | bigint;
+  newSurplusTokenPolicy: SurplusTokenPolicyArgs;
+  newMigrationFeeLamports: number | bigint;
+  newCurveKinds: Array<boolean>;
+  newMinCurveParams: CurveParamsArgs;
+  newMaxCurveParams: CurveParamsArgs;
+  newCreatorTradeFeeBps: number | bigint;
+  newReferralFeeBps: number | bigint;
 };
 
 export function getUpdateGlobalConfigInstructionDataSerializer(): Serializer<
@@ -71,13 +100,21 @@ export function getUpdateGlobalConfigInstructionDataSerializer(): Serializer<
       [
         ['discriminator', bytes({ size: 8 })],
         ['newAuthority', publicKeySerializer()],
-        ['newPlatformFeeRecipient', publicKeySerializer()],
         ['newPlatformTradeFeeBps', u64()],
         ['newReserveTradeFeeBps', u64()],
         ['newInitialVirtualTokenReserves', u64()],
         ['newInitialVirtualSolReserves', u64()],
         ['newTokenTotalSupply', u64()],
         ['newGraduationThreshold', u64()],
+        ['newLpPolicy', getLpPolicySerializer()],
+        ['newKeeperRewardBps', u64()],
+        ['newSurplusTokenPolicy', getSurplusTokenPolicySerializer()],
+        ['newMigrationFeeLamports', u64()],
+        ['newCurveKinds', array(bool(), { size: 3 })],
+        ['newMinCurveParams', getCurveParamsSerializer()],
+        ['newMaxCurveParams', getCurveParamsSerializer()],
+        ['newCreatorTradeFeeBps', u64()],
+        ['newReferralFeeBps', u64()],
       ],
       { description: 'UpdateGlobalConfigInstructionData' }
     ),
@@ -104,7 +141,7 @@ export function updateGlobalConfig(
   // Program ID.
   const programId = context.programs.getPublicKey(
     'coinfun',
-    '6xHUH1pJ2sLCm8YwyF8DBkUyBjDvoJKWgaXiBLaDdgsp'
+    'ihC7UqkLYWxQKVuYLiWNGqGvQCZb2ih4DXMLfyM6F68'
   );
 
   // Accounts.
@@ -29,52 +29,54 @@ import {
 } from '../shared';
 
 // Accounts.
-export type WithdrawInstructionAccounts = {
+export type UpgradeBondingCurveInstructionAccounts = {
   authority: Signer;
   global?: PublicKey | Pda;
   mint: PublicKey | Pda;
   bondingCurve?: PublicKey | Pda;
   bondingCurveAta?: PublicKey | Pda;
-  authorityAta?: PublicKey | Pda;
-  systemProgram?: PublicKey | Pda;
+  creatorVault?: PublicKey | Pda;
   tokenProgram: PublicKey | Pda;
-  associatedTokenProgram?: PublicKey | Pda;
-  rent?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
 };
 
 // Data.
-export type WithdrawInstructionData = { discriminator: Uint8Array };
+export type UpgradeBondingCurveInstructionData = { discriminator: Uint8Array };
 
-export type WithdrawInstructionDataArgs = {};
+export type UpgradeBondingCurveInstructionDataArgs = {};
 
-export function getWithdrawInstructionDataSerializer(): Serializer<
-  WithdrawInstructionDataArgs,
-  WithdrawInstructionData
+export function getUpgradeBondingCurveInstructionDataSerializer(): Serializer<
+  UpgradeBondingCurveInstructionDataArgs,
+  UpgradeBondingCurveInstructionData
 > {
   return mapSerializer<
-    WithdrawInstructionDataArgs,
+    UpgradeBondingCurveInstructionDataArgs,
     any,
-    WithdrawInstructionData
+    UpgradeBondingCurveInstructionData
   >(
-    struct<WithdrawInstructionData>([['discriminator', bytes({ size: 8 })]], {
-      description: 'WithdrawInstructionData',
-    }),
+    struct<UpgradeBondingCurveInstructionData>(
+      [['discriminator', bytes({ size: 8 })]],
+      { description: 'UpgradeBondingCurveInstructionData' }
+    ),
     (value) => ({
       ...value,
-      discriminator: new Uint8Array([183, 18, 70, 156, 148, 109, 161, 34]),
+      discriminator: new Uint8Array([12, 187, 75, 31, 125, 9, 204, 9]),
     })
-  ) as Serializer<WithdrawInstructionDataArgs, WithdrawInstructionData>;
+  ) as Serializer<
+    UpgradeBondingCurveInstructionDataArgs,
+    UpgradeBondingCurveInstructionData
+  >;
 }
 
 // Instruction.
-export function withdraw(
+export function upgradeBondingCurve(
   context: Pick<Context, 'eddsa' | 'programs'>,
-  input: WithdrawInstructionAccounts
+  input: UpgradeBondingCurveInstructionAccounts
 ): TransactionBuilder {
   // Program ID.
   const programId = context.programs.getPublicKey(
     'coinfun',
-    '6xHUH1pJ2sLCm8YwyF8DBkUyBjDvoJKWgaXiBLaDdgsp'
+    'ihC7UqkLYWxQKVuYLiWNGqGvQCZb2ih4DXMLfyM6F68'
   );
 
   // Accounts.
@@ -97,30 +99,24 @@ export function withdraw(
     },
     bondingCurveAta: {
       index: 4,
-      isWritable: true as boolean,
+      isWritable: false as boolean,
       value: input.bondingCurveAta ?? null,
     },
-    authorityAta: {
+    creatorVault: {
       index: 5,
       isWritable: true as boolean,
-      value: input.authorityAta ?? null,
-    },
-    systemProgram: {
-      index: 6,
-      isWritable: false as boolean,
-      value: input.systemProgram ?? null,
+      value: input.creatorVault ?? null,
     },
     tokenProgram: {
-      index: 7,
+      index: 6,
       isWritable: false as boolean,
       value: input.tokenProgram ?? null,
     },
-    associatedTokenProgram: {
-      index: 8,
+    systemProgram: {
+      index: 7,
       isWritable: false as boolean,
-      value: input.associatedTokenProgram ?? null,
+      value: input.systemProgram ?? null,
     },
-    rent: { index: 9, isWritable: false as boolean, value: input.rent ?? null },
   } satisfies ResolvedAccountsWithIndices;
 
   // Default values.
@@ -151,12 +147,8 @@ export function withdraw(
         publicKeySerializer().serialize(
           expectPublicKey(resolvedAccounts.bondingCurve.value)
         ),
-        bytes().serialize(
-          new Uint8Array([
-            6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
-            121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133,
-            126, 255, 0, 169,
-          ])
+        publicKeySerializer().serialize(
+          expectPublicKey(resolvedAccounts.tokenProgram.value)
         ),
         publicKeySerializer().serialize(
           expectPublicKey(resolvedAccounts.mint.value)
@@ -164,28 +156,17 @@ export function withdraw(
       ]
     );
   }
-  if (!resolvedAccounts.authorityAta.value) {
-    resolvedAccounts.authorityAta.value = context.eddsa.findPda(
-      context.programs.getPublicKey(
-        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
-        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+  if (!resolvedAccounts.creatorVault.value) {
+    resolvedAccounts.creatorVault.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([
+          99, 114, 101, 97, 116, 111, 114, 95, 118, 97, 117, 108, 116,
+        ])
       ),
-      [
-        publicKeySerializer().serialize(
-          expectPublicKey(resolvedAccounts.authority.value)
-        ),
-        bytes().serialize(
-          new Uint8Array([
-            6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235,
-            121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133,
-            126, 255, 0, 169,
-          ])
-        ),
-        publicKeySerializer().serialize(
-          expectPublicKey(resolvedAccounts.mint.value)
-        ),
-      ]
-    );
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.bondingCurve.value)
+      ),
+    ]);
   }
   if (!resolvedAccounts.systemProgram.value) {
     resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
@@ -194,21 +175,6 @@ export function withdraw(
     );
     resolvedAccounts.systemProgram.isWritable = false;
   }
-  if (!resolvedAccounts.associatedTokenProgram.value) {
-    resolvedAccounts.associatedTokenProgram.value =
-      context.programs.getPublicKey(
-        'associatedTokenProgram',
-        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
-      );
-    resolvedAccounts.associatedTokenProgram.isWritable = false;
-  }
-  if (!resolvedAccounts.rent.value) {
-    resolvedAccounts.rent.value = context.programs.getPublicKey(
-      'rent',
-      'SysvarRent111111111111111111111111111111111'
-    );
-    resolvedAccounts.rent.isWritable = false;
-  }
 
   // Accounts in order.
   const orderedAccounts: ResolvedAccount[] = Object.values(
@@ -223,7 +189,7 @@ export function withdraw(
   );
 
   // Data.
-  const data = getWithdrawInstructionDataSerializer().serialize({});
+  const data = getUpgradeBondingCurveInstructionDataSerializer().serialize({});
 
   // Bytes Created On Chain.
   const bytesCreatedOnChain = 0;
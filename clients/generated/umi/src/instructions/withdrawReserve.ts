@@ -36,6 +36,7 @@ export type WithdrawReserveInstructionAccounts = {
   globalReserve?: PublicKey | Pda;
   mint: PublicKey | Pda;
   bondingCurve?: PublicKey | Pda;
+  stakePool?: PublicKey | Pda;
   reserveAta?: PublicKey | Pda;
   authorityAta?: PublicKey | Pda;
   systemProgram?: PublicKey | Pda;
@@ -88,7 +89,7 @@ export function withdrawReserve(
   // Program ID.
   const programId = context.programs.getPublicKey(
     'coinfun',
-    '6xHUH1pJ2sLCm8YwyF8DBkUyBjDvoJKWgaXiBLaDdgsp'
+    'ihC7UqkLYWxQKVuYLiWNGqGvQCZb2ih4DXMLfyM6F68'
   );
 
   // Accounts.
@@ -114,28 +115,33 @@ export function withdrawReserve(
       isWritable: false as boolean,
       value: input.bondingCurve ?? null,
     },
-    reserveAta: {
+    stakePool: {
       index: 5,
+      isWritable: false as boolean,
+      value: input.stakePool ?? null,
+    },
+    reserveAta: {
+      index: 6,
       isWritable: true as boolean,
       value: input.reserveAta ?? null,
     },
     authorityAta: {
-      index: 6,
+      index: 7,
       isWritable: true as boolean,
       value: input.authorityAta ?? null,
     },
     systemProgram: {
-      index: 7,
+      index: 8,
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
     tokenProgram: {
-      index: 8,
+      index: 9,
       isWritable: false as boolean,
       value: input.tokenProgram ?? null,
     },
     associatedTokenProgram: {
-      index: 9,
+      index: 10,
       isWritable: false as boolean,
       value: input.associatedTokenProgram ?? null,
     },
@@ -167,6 +173,16 @@ export function withdrawReserve(
       ),
     ]);
   }
+  if (!resolvedAccounts.stakePool.value) {
+    resolvedAccounts.stakePool.value = context.eddsa.findPda(programId, [
+      bytes().serialize(
+        new Uint8Array([115, 116, 97, 107, 101, 95, 112, 111, 111, 108])
+      ),
+      publicKeySerializer().serialize(
+        expectPublicKey(resolvedAccounts.mint.value)
+      ),
+    ]);
+  }
   if (!resolvedAccounts.reserveAta.value) {
     resolvedAccounts.reserveAta.value = context.eddsa.findPda(
       context.programs.getPublicKey(
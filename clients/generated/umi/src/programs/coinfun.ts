@@ -15,7 +15,7 @@ import {
 import { getCoinfunErrorFromCode, getCoinfunErrorFromName } from '../errors';
 
 export const COINFUN_PROGRAM_ID =
-  '6xHUH1pJ2sLCm8YwyF8DBkUyBjDvoJKWgaXiBLaDdgsp' as PublicKey<'6xHUH1pJ2sLCm8YwyF8DBkUyBjDvoJKWgaXiBLaDdgsp'>;
+  'ihC7UqkLYWxQKVuYLiWNGqGvQCZb2ih4DXMLfyM6F68' as PublicKey<'ihC7UqkLYWxQKVuYLiWNGqGvQCZb2ih4DXMLfyM6F68'>;
 
 export function createCoinfunProgram(): Program {
   return {
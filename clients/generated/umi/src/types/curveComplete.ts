@@ -12,10 +12,23 @@ import {
   publicKey as publicKeySerializer,
   struct,
 } from '@metaplex-foundation/umi/serializers';
+import {
+  GraduationCause,
+  GraduationCauseArgs,
+  getGraduationCauseSerializer,
+} from '.';
 
-export type CurveComplete = { mint: PublicKey; bondingCurve: PublicKey };
+export type CurveComplete = {
+  mint: PublicKey;
+  bondingCurve: PublicKey;
+  cause: GraduationCause;
+};
 
-export type CurveCompleteArgs = CurveComplete;
+export type CurveCompleteArgs = {
+  mint: PublicKey;
+  bondingCurve: PublicKey;
+  cause: GraduationCauseArgs;
+};
 
 export function getCurveCompleteSerializer(): Serializer<
   CurveCompleteArgs,
@@ -25,6 +38,7 @@ export function getCurveCompleteSerializer(): Serializer<
     [
       ['mint', publicKeySerializer()],
       ['bondingCurve', publicKeySerializer()],
+      ['cause', getGraduationCauseSerializer()],
     ],
     { description: 'CurveComplete' }
   ) as Serializer<CurveCompleteArgs, CurveComplete>;
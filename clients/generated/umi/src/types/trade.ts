@@ -6,14 +6,22 @@
  * @see https://github.com/codama-idl/codama
  */
 
-import { PublicKey } from '@metaplex-foundation/umi';
+import { Option, OptionOrNullable, PublicKey } from '@metaplex-foundation/umi';
 import {
   Serializer,
+  option,
   publicKey as publicKeySerializer,
   struct,
   u64,
 } from '@metaplex-foundation/umi/serializers';
-import { TradeSide, TradeSideArgs, getTradeSideSerializer } from '.';
+import {
+  ReservePolicy,
+  ReservePolicyArgs,
+  TradeSide,
+  TradeSideArgs,
+  getReservePolicySerializer,
+  getTradeSideSerializer,
+} from '.';
 
 export type Trade = {
   mint: PublicKey;
@@ -21,6 +29,12 @@ export type Trade = {
   side: TradeSide;
   solAmount: bigint;
   tokenAmount: bigint;
+  solRefunded: bigint;
+  referrer: Option<PublicKey>;
+  referralFee: bigint;
+  reservePolicy: ReservePolicy;
+  reserveFee: bigint;
+  reserveTokenAmount: bigint;
 };
 
 export type TradeArgs = {
@@ -29,6 +43,12 @@ export type TradeArgs = {
   side: TradeSideArgs;
   solAmount: number | bigint;
   tokenAmount: number | bigint;
+  solRefunded: number | bigint;
+  referrer: OptionOrNullable<PublicKey>;
+  referralFee: number | bigint;
+  reservePolicy: ReservePolicyArgs;
+  reserveFee: number | bigint;
+  reserveTokenAmount: number | bigint;
 };
 
 export function getTradeSerializer(): Serializer<TradeArgs, Trade> {
@@ -39,6 +59,12 @@ export function getTradeSerializer(): Serializer<TradeArgs, Trade> {
       ['side', getTradeSideSerializer()],
       ['solAmount', u64()],
       ['tokenAmount', u64()],
+      ['solRefunded', u64()],
+      ['referrer', option(publicKeySerializer())],
+      ['referralFee', u64()],
+      ['reservePolicy', getReservePolicySerializer()],
+      ['reserveFee', u64()],
+      ['reserveTokenAmount', u64()],
     ],
     { description: 'Trade' }
   ) as Serializer<TradeArgs, Trade>;
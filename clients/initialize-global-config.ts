@@ -20,6 +20,7 @@ import { getCoinfunProgramId } from "./generated/umi/src/programs/coinfun";
 import { createCoinfunProgram } from "./generated/umi/src/programs/coinfun";
 import { initialize } from "./generated/umi/src/instructions";
 import { fetchGlobal } from "./generated/umi/src/accounts/global";
+import { SurplusTokenPolicy, lpPolicy } from "./generated/umi/src/types";
 import * as fs from "fs";
 import * as os from "os";
 import * as path from "path";
@@ -38,7 +39,7 @@ const umi = createUmi("https://api.devnet.solana.com", {
 // The program ID should match the deployed program address
 const COINFUN_PROGRAM_ID =
   process.env.COINFUN_PROGRAM_ID ||
-  "6xHUH1pJ2sLCm8YwyF8DBkUyBjDvoJKWgaXiBLaDdgsp"; // Default from generated client
+  "ihC7UqkLYWxQKVuYLiWNGqGvQCZb2ih4DXMLfyM6F68"; // Default from generated client
 console.log(`Program id: ${COINFUN_PROGRAM_ID}`);
 
 // Register the program with UMI so all instructions use this program ID
@@ -59,9 +60,6 @@ const authorityKeypair: Keypair = {
 };
 const authority = createSignerFromKeypair(umi, authorityKeypair);
 
-// Fee recipient is the same as authority
-const feeRecipient = authority;
-
 umi.use(keypairIdentity(authority));
 
 const options: TransactionBuilderSendAndConfirmOptions = {
@@ -76,6 +74,24 @@ const tokenTotalSupply = BigInt(1_000_000_000_000_000); // 1_000_000_000 * 1e6
 const platformTradeFeeBps = BigInt(100); // 1%
 const reserveTradeFeeBps = BigInt(400); // 4%
 const graduationThreshold = BigInt(2) * LAMPORTS_PER_SOL;
+const keeperRewardBps = BigInt(50); // 0.5% of a curve's SOL to whoever migrates it
+const migrationFeeLamports = LAMPORTS_PER_SOL / BigInt(10); // Platform's cut at migration
+const creatorTradeFeeBps = BigInt(0);
+const referralFeeBps = BigInt(2000); // 20% of the platform fee to a trade's referrer
+const curveKinds = [true, true, false]; // Constant product and linear launches
+// Creators may pick their own launch economics between half and twice the defaults
+const minCurveParams = {
+  initialVirtualTokenReserves: initialVirtualTokenReserves / BigInt(2),
+  initialVirtualSolReserves: initialVirtualSolReserves / BigInt(2),
+  tokenTotalSupply: tokenTotalSupply / BigInt(2),
+  graduationThreshold: graduationThreshold / BigInt(2),
+};
+const maxCurveParams = {
+  initialVirtualTokenReserves: initialVirtualTokenReserves * BigInt(2),
+  initialVirtualSolReserves: initialVirtualSolReserves * BigInt(2),
+  tokenTotalSupply: tokenTotalSupply * BigInt(2),
+  graduationThreshold: graduationThreshold * BigInt(2),
+};
 
 // Helper function to derive PDA
 function findPda(seeds: Uint8Array[]): PublicKey {
@@ -94,10 +110,7 @@ async function main() {
     try {
       await umi.rpc.airdrop(authority.publicKey, sol(20), options.confirm);
       console.log(
-        `   ✅ Airdropped 20 SOL to authority: ${authority.publicKey}`
-      );
-      console.log(
-        `   ✅ Fee recipient is authority: ${feeRecipient.publicKey}\n`
+        `   ✅ Airdropped 20 SOL to authority: ${authority.publicKey}\n`
       );
     } catch (error) {
       console.error("   ❌ Error airdropping funds:", error);
@@ -126,8 +139,16 @@ async function main() {
       tokenTotalSupply,
       platformTradeFeeBps,
       reserveTradeFeeBps,
-      platformFeeRecipient: feeRecipient.publicKey,
       graduationThreshold,
+      lpPolicy: lpPolicy("PermanentLock"),
+      keeperRewardBps,
+      surplusTokenPolicy: SurplusTokenPolicy.Burn,
+      migrationFeeLamports,
+      curveKinds,
+      minCurveParams,
+      maxCurveParams,
+      creatorTradeFeeBps,
+      referralFeeBps,
     }).sendAndConfirm(umi, options);
 
     console.log(`   ✅ Initialize successful!\n`);
@@ -149,9 +170,6 @@ async function main() {
     console.log(`   📋 Global Account Data:`);
     console.log(`      Public Key: ${globalAccount.publicKey}`);
     console.log(`      Authority: ${globalAccount.authority}`);
-    console.log(
-      `      Platform Fee Recipient: ${globalAccount.platformFeeRecipient}`
-    );
     console.log(`      Reserve: ${globalAccount.reserve}`);
     console.log(
       `      Initial Virtual Token Reserves: ${globalAccount.initialVirtualTokenReserves.toString()}`
@@ -0,0 +1,1198 @@
+// Bonding curve math, shared by the coinfun program and
+// off-chain tooling. Everything here is plain integer math with the rounding
+// the program uses on-chain, so a quote computed off-chain matches the trade
+// it describes to the lamport. `None` means the trade overflows or cannot be
+// made on this curve.
+#![no_std]
+
+// Basis points denominator (10000 = 100%)
+pub const BPS_DENOMINATOR: u64 = 10000;
+
+// Prices of the shaped curves are in lamports per token unit, scaled by this
+pub const PRICE_SCALE: u64 = 1_000_000_000_000;
+
+/// Price curve a launch trades on. The shaped kinds price by the tokens sold so
+/// far; the SOL a trade moves is the area under the price between the two
+/// points, rounded down once, so trades are path independent.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CurveKind {
+    // `virtual_sol * virtual_token` stays constant
+    #[default]
+    ConstantProduct,
+    // Price rises linearly from `start_price` to `end_price` as the supply sells
+    Linear { start_price: u64, end_price: u64 },
+    // Price doubles every `doubling_tokens` tokens sold, linearly in between
+    Exponential { start_price: u64, doubling_tokens: u64 },
+}
+
+impl CurveKind {
+    /// Whether the kind's parameters price `token_total_supply` tokens without overflowing.
+    pub fn is_valid(self, token_total_supply: u64) -> bool {
+        match self {
+            CurveKind::ConstantProduct => true,
+            CurveKind::Linear { start_price, end_price } => {
+                start_price > 0
+                    && end_price >= start_price
+                    && self.cost(token_total_supply, token_total_supply).is_some()
+            }
+            CurveKind::Exponential { start_price, doubling_tokens } => {
+                start_price > 0
+                    && doubling_tokens > 0
+                    && self.cost(token_total_supply, token_total_supply).is_some()
+                    && self.price(token_total_supply, token_total_supply).is_some()
+            }
+        }
+    }
+
+    /// Scaled price once `sold` of `token_total_supply` tokens are sold, rounded down.
+    /// `None` for the constant product, which prices from its virtual reserves.
+    fn price(self, sold: u64, token_total_supply: u64) -> Option<u64> {
+        match self {
+            CurveKind::ConstantProduct => None,
+            CurveKind::Linear { start_price, end_price } => {
+                let rise = mul_div(
+                    u128::from(end_price.checked_sub(start_price)?),
+                    u128::from(sold),
+                    u128::from(token_total_supply),
+                )?;
+                start_price.checked_add(u64::try_from(rise).ok()?)
+            }
+            CurveKind::Exponential { start_price, doubling_tokens } => {
+                let (doublings, into_step) = (sold / doubling_tokens, sold % doubling_tokens);
+                let step_price = u128::from(start_price).checked_shl(u32::try_from(doublings).ok()?)?;
+                if step_price >> doublings != u128::from(start_price) {
+                    return None;
+                }
+                let price = mul_div(
+                    step_price,
+                    u128::from(doubling_tokens) + u128::from(into_step),
+                    u128::from(doubling_tokens),
+                )?;
+                u64::try_from(price).ok()
+            }
+        }
+    }
+
+    /// SOL raised by selling the first `sold` of `token_total_supply` tokens, rounded down.
+    /// `None` for the constant product.
+    fn cost(self, sold: u64, token_total_supply: u64) -> Option<u64> {
+        u64::try_from(self.scaled_cost(sold, token_total_supply)? / u128::from(PRICE_SCALE)).ok()
+    }
+
+    // `cost` before dividing out `PRICE_SCALE`
+    fn scaled_cost(self, sold: u64, token_total_supply: u64) -> Option<u128> {
+        match self {
+            CurveKind::ConstantProduct => None,
+            // start * sold + (end - start) * sold^2 / (2 * supply)
+            CurveKind::Linear { start_price, end_price } => {
+                let rise = u128::from(end_price.checked_sub(start_price)?).checked_mul(u128::from(sold))?;
+                u128::from(start_price)
+                    .checked_mul(u128::from(sold))?
+                    .checked_add(mul_div(rise, u128::from(sold), 2 * u128::from(token_total_supply))?)
+            }
+            // Each full step of d tokens costs 1.5 * d times its start price, so with n steps
+            // and u tokens into the next: start * (3d^2 (2^n - 1) + 2^n u (2d + u)) / (2d)
+            CurveKind::Exponential { start_price, doubling_tokens } => {
+                let d = u128::from(doubling_tokens);
+                let (doublings, into_step) = (sold / doubling_tokens, u128::from(sold % doubling_tokens));
+                let growth = 1u128.checked_shl(u32::try_from(doublings).ok()?)?;
+                if growth >> doublings != 1 {
+                    return None;
+                }
+                let full_steps = d.checked_mul(d)?.checked_mul(3)?.checked_mul(growth - 1)?;
+                let partial_step = growth
+                    .checked_mul(into_step)?
+                    .checked_mul(d.checked_mul(2)?.checked_add(into_step)?)?;
+                mul_div(
+                    full_steps.checked_add(partial_step)?,
+                    u128::from(start_price),
+                    d.checked_mul(2)?,
+                )
+            }
+        }
+    }
+
+    /// Most tokens sold, up to `token_total_supply`, that cost at most `budget`.
+    /// Searched from the closed-form inverse of the cost, so only the rounding is searched.
+    fn sold_within(self, budget: u64, token_total_supply: u64) -> Option<u64> {
+        if self.cost(token_total_supply, token_total_supply)? <= budget {
+            return Some(token_total_supply);
+        }
+        // The largest scaled cost that still rounds down to `budget`
+        let target = u128::from(budget)
+            .checked_add(1)?
+            .checked_mul(u128::from(PRICE_SCALE))?
+            - 1;
+        // The square root leaves the inverse a few parts per billion off; one Newton step on
+        // the exact cost brings it to within the rounding
+        let estimate = self.sold_for_scaled_cost(target, token_total_supply).and_then(|sold| {
+            let scaled = self.scaled_cost(sold, token_total_supply)?;
+            let price = u128::from(self.price(sold, token_total_supply)?.max(1));
+            if scaled <= target {
+                sold.checked_add(u64::try_from((target - scaled) / price).ok()?)
+            } else {
+                Some(sold.saturating_sub(u64::try_from((scaled - target) / price).ok()?))
+            }
+        });
+        let first_over = estimate.unwrap_or(0).saturating_add(1);
+        let over_budget = first_holding(1, token_total_supply, first_over, |sold| {
+            Some(self.cost(sold, token_total_supply)? > budget)
+        })?;
+        Some(over_budget - 1)
+    }
+
+    /// Roughly where the scaled cost reaches `scaled`. The price is linear within a
+    /// shape (or a doubling step), so this solves `p * x + slope * x^2 / 2 = scaled` as
+    /// `x = 2 * scaled / (sqrt(p^2 + 2 * slope * scaled) + p)`.
+    fn sold_for_scaled_cost(self, scaled: u128, token_total_supply: u64) -> Option<u64> {
+        let fill = |price: u128, rise: u128, over: u128, scaled: u128| -> Option<u128> {
+            let discriminant = price
+                .checked_mul(price)?
+                .checked_add(mul_div(scaled, rise.checked_mul(2)?, over)?)?;
+            scaled.checked_mul(2)?.checked_div(sqrt_floor(discriminant).checked_add(price)?)
+        };
+        match self {
+            CurveKind::ConstantProduct => None,
+            CurveKind::Linear { start_price, end_price } => {
+                let rise = u128::from(end_price.checked_sub(start_price)?);
+                let sold = fill(u128::from(start_price), rise, u128::from(token_total_supply), scaled)?;
+                u64::try_from(sold).ok()
+            }
+            CurveKind::Exponential { start_price, doubling_tokens } => {
+                // Full steps cost 1.5 * d * start * (2^n - 1); find the step `scaled` ends in
+                let d = u128::from(doubling_tokens);
+                let step_cost = u128::from(start_price).checked_mul(d)?.checked_mul(3)? / 2;
+                let doublings = 127 - (scaled / step_cost.max(1)).checked_add(1)?.leading_zeros();
+                let step_start = u64::try_from(d.checked_mul(u128::from(doublings))?).ok()?;
+                let step_price = u128::from(self.price(step_start, token_total_supply)?);
+                let rest = scaled.saturating_sub(self.scaled_cost(step_start, token_total_supply)?);
+                let into_step = fill(step_price, step_price, d, rest)?.min(d);
+                step_start.checked_add(u64::try_from(into_step).ok()?)
+            }
+        }
+    }
+
+    /// Roughly the fewest tokens sold at which the scaled price reaches `price`.
+    fn sold_at_price(self, price: u128, token_total_supply: u64) -> Option<u64> {
+        match self {
+            CurveKind::ConstantProduct => None,
+            CurveKind::Linear { start_price, end_price } => {
+                let rise = u128::from(end_price.checked_sub(start_price)?);
+                let above_start = price.saturating_sub(u128::from(start_price));
+                if rise == 0 {
+                    // A flat price never gets above its start
+                    return Some(if above_start == 0 { 0 } else { token_total_supply });
+                }
+                // Rounded up, as the price is rounded down
+                let sold = mul_div(above_start, u128::from(token_total_supply), rise)?.checked_add(1)?;
+                u64::try_from(sold).ok()
+            }
+            CurveKind::Exponential { start_price, doubling_tokens } => {
+                let start_price = u128::from(start_price);
+                if price <= start_price {
+                    return Some(0);
+                }
+                let doublings = 127 - (price / start_price).leading_zeros();
+                let step_price = start_price.checked_shl(doublings)?;
+                if step_price >> doublings != start_price {
+                    return None;
+                }
+                let d = u128::from(doubling_tokens);
+                let sold = d
+                    .checked_mul(u128::from(doublings))?
+                    .checked_add(mul_div(price - step_price, d, step_price)?)?
+                    .checked_add(1)?;
+                u64::try_from(sold).ok()
+            }
+        }
+    }
+}
+
+/// Smallest value in `low..=high` for which `holds`, or `high` if none below it does.
+/// `holds` must switch from false to true at most once. Gallops out from `estimate`
+/// and bisects the bracket, so a close estimate costs a handful of probes.
+fn first_holding(low: u64, high: u64, estimate: u64, holds: impl Fn(u64) -> Option<bool>) -> Option<u64> {
+    let estimate = estimate.clamp(low, high);
+    let mut step = 1u64;
+    let (mut failing, mut holding) = if holds(estimate)? {
+        let mut holding = estimate;
+        loop {
+            if holding == low {
+                return Some(low);
+            }
+            let probe = holding.saturating_sub(step).max(low);
+            if !holds(probe)? {
+                break (probe, holding);
+            }
+            holding = probe;
+            step = step.saturating_mul(2);
+        }
+    } else {
+        let mut failing = estimate;
+        loop {
+            if failing == high {
+                return Some(high);
+            }
+            let probe = failing.saturating_add(step).min(high);
+            if holds(probe)? {
+                break (failing, probe);
+            }
+            failing = probe;
+            step = step.saturating_mul(2);
+        }
+    };
+    while holding - failing > 1 {
+        let mid = failing + (holding - failing) / 2;
+        if holds(mid)? {
+            holding = mid;
+        } else {
+            failing = mid;
+        }
+    }
+    Some(holding)
+}
+
+/// Integer square root, rounded down.
+fn sqrt_floor(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+    let mut x = 1u128 << (128 - value.leading_zeros()).div_ceil(2);
+    loop {
+        let y = (x + value / x) >> 1;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// What graduates a curve besides selling out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraduationTrigger {
+    // `real_sol_reserves` reaches the amount
+    SolReserves(u64),
+    // `real_token_reserves` falls to the floor
+    TokenFloor(u64),
+    // The spot price times `token_total_supply` reaches the amount, in lamports
+    MarketCap(u64),
+}
+
+/// Why a curve graduated. A curve that meets its trigger and sells out in the same
+/// buy reports the trigger.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Graduation {
+    Trigger,
+    SoldOut,
+}
+
+/// `a * b / c` rounded down, without the full-width product.
+fn mul_div(a: u128, b: u128, c: u128) -> Option<u128> {
+    a.checked_div(c)?
+        .checked_mul(b)?
+        .checked_add(a.checked_rem(c)?.checked_mul(b)? / c)
+}
+
+/// Reserves of a bonding curve and the curve they trade on, as stored on its
+/// `BondingCurve` account. Quotes return the kind and supply unchanged.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CurveState {
+    pub virtual_token_reserves: u64,
+    pub virtual_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub kind: CurveKind,
+    pub token_total_supply: u64,
+}
+
+/// Trade fee rates, as configured in `Global`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeeRates {
+    pub platform_trade_fee_bps: u64,
+    pub reserve_trade_fee_bps: u64,
+    pub creator_trade_fee_bps: u64,
+    // The reserve fee is paid out as SOL instead of buying tokens from the curve
+    pub reserve_fee_in_sol: bool,
+}
+
+impl FeeRates {
+    // Share of a trade's SOL that leaves the curve as fees
+    fn outgoing_bps(&self) -> Option<u64> {
+        let outgoing = self.platform_trade_fee_bps.checked_add(self.creator_trade_fee_bps)?;
+        if self.reserve_fee_in_sol {
+            outgoing.checked_add(self.reserve_trade_fee_bps)
+        } else {
+            Some(outgoing)
+        }
+    }
+
+    fn total_bps(&self) -> Option<u64> {
+        self.platform_trade_fee_bps
+            .checked_add(self.reserve_trade_fee_bps)?
+            .checked_add(self.creator_trade_fee_bps)
+    }
+
+    // Part of the reserve fee that buys tokens from the curve
+    fn reserve_purchase_fee(&self, fees: &FeeSplit) -> u64 {
+        if self.reserve_fee_in_sol {
+            0
+        } else {
+            fees.reserve_fee
+        }
+    }
+}
+
+/// Fees taken from one trade. The platform and creator fees leave the curve;
+/// the reserve fee buys tokens from the curve for the global reserve, or leaves
+/// it too under `FeeRates::reserve_fee_in_sol`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeeSplit {
+    pub platform_fee: u64,
+    pub reserve_fee: u64,
+    pub creator_fee: u64,
+}
+
+impl FeeSplit {
+    pub fn total(&self) -> Option<u64> {
+        self.platform_fee
+            .checked_add(self.reserve_fee)?
+            .checked_add(self.creator_fee)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BuyQuote {
+    // Part of the requested SOL that is filled, fees included
+    pub sol_in: u64,
+    // Part of the requested SOL left unfilled because the buy graduates the curve
+    pub sol_refunded: u64,
+    pub fees: FeeSplit,
+    pub reserve_tokens_out: u64,
+    pub tokens_out: u64,
+    // Reserves after the trade
+    pub curve: CurveState,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SellQuote {
+    pub token_in: u64,
+    pub sol_out_gross: u64,
+    // What the seller receives
+    pub sol_out_net: u64,
+    pub fees: FeeSplit,
+    pub reserve_tokens_out: u64,
+    // Reserves after the trade
+    pub curve: CurveState,
+}
+
+impl CurveState {
+    /// Whether a curve in this state has graduated: it met its trigger or sold out.
+    pub fn is_graduated(&self, trigger: GraduationTrigger) -> bool {
+        self.graduation(trigger).is_some()
+    }
+
+    /// Which of the trigger or a sell-out graduated the curve, if either did.
+    pub fn graduation(&self, trigger: GraduationTrigger) -> Option<Graduation> {
+        let triggered = match trigger {
+            GraduationTrigger::SolReserves(threshold) => self.real_sol_reserves >= threshold,
+            GraduationTrigger::TokenFloor(floor) => self.real_token_reserves <= floor,
+            GraduationTrigger::MarketCap(market_cap) => {
+                self.market_cap().is_some_and(|cap| cap >= market_cap)
+            }
+        };
+        if triggered {
+            Some(Graduation::Trigger)
+        } else if self.real_token_reserves == 0 {
+            Some(Graduation::SoldOut)
+        } else {
+            None
+        }
+    }
+
+    /// The spot price times `token_total_supply`, in lamports, rounded down.
+    pub fn market_cap(&self) -> Option<u64> {
+        let (price_sol, price_token) = self.spot_price()?;
+        let cap = mul_div(
+            u128::from(self.token_total_supply),
+            u128::from(price_sol),
+            u128::from(price_token),
+        )?;
+        Some(u64::try_from(cap).unwrap_or(u64::MAX))
+    }
+
+    /// The curve after buying at least `token_out` tokens with no fees.
+    fn after_tokens_out(&self, token_out: u64) -> Option<CurveState> {
+        let sol_in = self.sol_for_tokens(token_out)?;
+        let (tokens_out, virtual_sol_reserves, virtual_token_reserves) = self.swap_sol_in(sol_in)?;
+        Some(CurveState {
+            virtual_token_reserves,
+            virtual_sol_reserves,
+            real_token_reserves: self.real_token_reserves.checked_sub(tokens_out)?,
+            real_sol_reserves: self.real_sol_reserves.checked_add(sol_in)?,
+            ..*self
+        })
+    }
+
+    /// Tokens a buy has to take out for a token or market cap trigger to fire, or
+    /// `None` for a SOL trigger.
+    fn tokens_to_trigger(&self, trigger: GraduationTrigger) -> Option<Option<u64>> {
+        let tokens = match trigger {
+            GraduationTrigger::SolReserves(_) => return Some(None),
+            GraduationTrigger::TokenFloor(floor) => self.real_token_reserves.saturating_sub(floor),
+            GraduationTrigger::MarketCap(market_cap) => {
+                if self.market_cap()? >= market_cap {
+                    0
+                } else if self.kind != CurveKind::ConstantProduct {
+                    // A shaped curve's market cap follows its price at the tokens sold
+                    let sold = self.tokens_sold()?;
+                    let supply = self.token_total_supply;
+                    let target_price = u128::from(market_cap)
+                        .checked_mul(u128::from(PRICE_SCALE))?
+                        .div_ceil(u128::from(supply));
+                    let estimate = self.kind.sold_at_price(target_price, supply).unwrap_or(sold);
+                    let reached = first_holding(sold.checked_add(1)?, supply, estimate, |to| {
+                        let after = CurveState { real_token_reserves: supply - to, ..*self };
+                        Some(after.market_cap()? >= market_cap)
+                    })?;
+                    reached - sold
+                } else {
+                    // Smallest buy that lifts the market cap to the target, or the rest
+                    let (mut low, mut high) = (1, self.real_token_reserves);
+                    while low < high {
+                        let mid = low + (high - low) / 2;
+                        if self.after_tokens_out(mid)?.market_cap()? >= market_cap {
+                            high = mid;
+                        } else {
+                            low = mid + 1;
+                        }
+                    }
+                    low
+                }
+            }
+        };
+        Some(Some(tokens))
+    }
+
+    /// Current price as a SOL / token ratio, which a pool seeded from the curve opens at.
+    pub fn spot_price(&self) -> Option<(u64, u64)> {
+        match self.kind {
+            CurveKind::ConstantProduct => Some((self.virtual_sol_reserves, self.virtual_token_reserves)),
+            kind => Some((kind.price(self.tokens_sold()?, self.token_total_supply)?, PRICE_SCALE)),
+        }
+    }
+
+    // Where a shaped curve is along its price
+    fn tokens_sold(&self) -> Option<u64> {
+        self.token_total_supply.checked_sub(self.real_token_reserves)
+    }
+
+    // SOL a shaped curve moves between `from` and `to` tokens sold
+    fn cost_between(&self, from: u64, to: u64) -> Option<u64> {
+        let cost = |sold| self.kind.cost(sold, self.token_total_supply);
+        cost(to.max(from))?.checked_sub(cost(to.min(from))?)
+    }
+
+    /// Tokens `sol_in` takes out of the curve, with the virtual reserves after the swap.
+    /// The constant product rounds the tokens down; a shaped curve sells the most
+    /// tokens `sol_in` pays for and keeps the rest of it.
+    fn swap_sol_in(&self, sol_in: u64) -> Option<(u64, u64, u64)> {
+        let new_virtual_sol_reserves = self.virtual_sol_reserves.checked_add(sol_in)?;
+        if self.kind != CurveKind::ConstantProduct {
+            let sold = self.tokens_sold()?;
+            // Largest fill that costs no more than `sol_in`
+            let budget = self.kind.cost(sold, self.token_total_supply)?.saturating_add(sol_in);
+            let fill = self.kind.sold_within(budget, self.token_total_supply)?.checked_sub(sold)?;
+            let new_virtual_token_reserves = self.virtual_token_reserves.checked_sub(fill)?;
+            return Some((fill, new_virtual_sol_reserves, new_virtual_token_reserves));
+        }
+        let k = u128::from(self.virtual_sol_reserves)
+            .checked_mul(u128::from(self.virtual_token_reserves))?;
+        let new_virtual_token_reserves =
+            k.checked_div(u128::from(new_virtual_sol_reserves))? as u64;
+        let tokens_out = self
+            .virtual_token_reserves
+            .checked_sub(new_virtual_token_reserves)?;
+        Some((tokens_out, new_virtual_sol_reserves, new_virtual_token_reserves))
+    }
+
+    /// Least SOL that takes at least `token_out` tokens out, as rounded by `swap_sol_in`.
+    fn sol_for_tokens(&self, token_out: u64) -> Option<u64> {
+        if token_out == 0 {
+            return Some(0);
+        }
+        if self.kind != CurveKind::ConstantProduct {
+            let sold = self.tokens_sold()?;
+            if token_out > self.real_token_reserves {
+                return None;
+            }
+            return self.cost_between(sold, sold.checked_add(token_out)?);
+        }
+        let k = u128::from(self.virtual_sol_reserves)
+            .checked_mul(u128::from(self.virtual_token_reserves))?;
+        // The new virtual token reserves, k / new_virtual_sol rounded down, must not exceed this
+        let remaining = self.virtual_token_reserves.checked_sub(token_out)?;
+        let new_virtual_sol_reserves = k / (u128::from(remaining) + 1) + 1;
+        u64::try_from(new_virtual_sol_reserves.saturating_sub(u128::from(self.virtual_sol_reserves))).ok()
+    }
+
+    /// Least tokens that take at least `sol_out` SOL out, as rounded by `swap_token_in`.
+    fn tokens_for_sol(&self, sol_out: u64) -> Option<u64> {
+        if sol_out == 0 {
+            return Some(0);
+        }
+        if self.kind != CurveKind::ConstantProduct {
+            let sold = self.tokens_sold()?;
+            // Smallest sell that pays out at least `sol_out`: down to the most tokens sold
+            // that cost no more than what is left
+            let left = self.kind.cost(sold, self.token_total_supply)?.checked_sub(sol_out)?;
+            return sold.checked_sub(self.kind.sold_within(left, self.token_total_supply)?);
+        }
+        let k = u128::from(self.virtual_sol_reserves)
+            .checked_mul(u128::from(self.virtual_token_reserves))?;
+        // The new virtual SOL reserves, k / new_virtual_token rounded down, must not exceed this
+        let remaining = self.virtual_sol_reserves.checked_sub(sol_out)?;
+        let new_virtual_token_reserves = k / (u128::from(remaining) + 1) + 1;
+        u64::try_from(new_virtual_token_reserves.saturating_sub(u128::from(self.virtual_token_reserves)))
+            .ok()
+    }
+
+    /// SOL `token_in` takes out of the curve, rounded down, with the virtual reserves
+    /// after the swap.
+    fn swap_token_in(&self, token_in: u64) -> Option<(u64, u64, u64)> {
+        let new_virtual_token_reserves = self.virtual_token_reserves.checked_add(token_in)?;
+        if self.kind != CurveKind::ConstantProduct {
+            let sold = self.tokens_sold()?;
+            let sol_out = self.cost_between(sold.checked_sub(token_in)?, sold)?;
+            let new_virtual_sol_reserves = self.virtual_sol_reserves.checked_sub(sol_out)?;
+            return Some((sol_out, new_virtual_sol_reserves, new_virtual_token_reserves));
+        }
+        let k = u128::from(self.virtual_sol_reserves)
+            .checked_mul(u128::from(self.virtual_token_reserves))?;
+        let new_virtual_sol_reserves =
+            k.checked_div(u128::from(new_virtual_token_reserves))? as u64;
+        let sol_out = self
+            .virtual_sol_reserves
+            .checked_sub(new_virtual_sol_reserves)?;
+        Some((sol_out, new_virtual_sol_reserves, new_virtual_token_reserves))
+    }
+}
+
+/// Platform, reserve and creator fees on `amount`, each rounded down.
+pub fn split_fees(amount: u64, rates: FeeRates) -> Option<FeeSplit> {
+    Some(FeeSplit {
+        platform_fee: amount
+            .checked_mul(rates.platform_trade_fee_bps)?
+            .checked_div(BPS_DENOMINATOR)?,
+        reserve_fee: amount
+            .checked_mul(rates.reserve_trade_fee_bps)?
+            .checked_div(BPS_DENOMINATOR)?,
+        creator_fee: amount
+            .checked_mul(rates.creator_trade_fee_bps)?
+            .checked_div(BPS_DENOMINATOR)?,
+    })
+}
+
+/// Tokens `reserve_fee` buys from the curve for the global reserve, and the
+/// curve after the purchase. The fee's SOL stays in the curve.
+pub fn reserve_purchase(curve: &CurveState, reserve_fee: u64) -> Option<(u64, CurveState)> {
+    let (tokens_out, virtual_sol_reserves, virtual_token_reserves) =
+        curve.swap_sol_in(reserve_fee)?;
+    Some((
+        tokens_out,
+        CurveState {
+            virtual_token_reserves,
+            virtual_sol_reserves,
+            real_token_reserves: curve.real_token_reserves.checked_sub(tokens_out)?,
+            real_sol_reserves: curve.real_sol_reserves.checked_add(reserve_fee)?,
+            ..*curve
+        },
+    ))
+}
+
+/// Largest buy whose curve-bound SOL (everything but the outgoing fees) fires the
+/// curve's graduation trigger or buys out its remaining tokens.
+pub fn max_buy_fill(curve: &CurveState, rates: FeeRates, trigger: GraduationTrigger) -> Option<u64> {
+    let to_trigger = match curve.tokens_to_trigger(trigger)? {
+        None => match trigger {
+            GraduationTrigger::SolReserves(threshold) => threshold.saturating_sub(curve.real_sol_reserves),
+            _ => u64::MAX,
+        },
+        Some(0) => 0,
+        // The reserve's and the trader's swaps each round their tokens down, so ask for
+        // a couple more to be sure the trigger fires
+        Some(tokens) => curve.sol_for_tokens(
+            tokens
+                .saturating_add(TRIGGER_TOKEN_MARGIN)
+                .min(curve.real_token_reserves),
+        )?,
+    };
+    let to_sell_out = if curve.kind == CurveKind::ConstantProduct {
+        constant_product_sell_out(curve)?
+    } else {
+        u128::from(curve.sol_for_tokens(curve.real_token_reserves)?)
+    };
+    let curve_sol = u128::from(to_trigger).min(to_sell_out);
+
+    // Gross up by the outgoing fees, rounding up so the curve receives at least `curve_sol`
+    let kept_bps = u128::from(BPS_DENOMINATOR.checked_sub(rates.outgoing_bps()?)?);
+    let gross = curve_sol
+        .saturating_mul(u128::from(BPS_DENOMINATOR))
+        .div_ceil(kept_bps);
+    Some(u64::try_from(gross).unwrap_or(u64::MAX))
+}
+
+const TRIGGER_TOKEN_MARGIN: u64 = 2;
+
+/// Least SOL that sells out a constant-product curve, `u128::MAX` if it cannot sell out.
+fn constant_product_sell_out(curve: &CurveState) -> Option<u128> {
+    // Selling out leaves `virtual_token_reserves - real_token_reserves` on the curve;
+    // this is the least SOL that pushes the virtual token reserves below one more than that
+    match curve
+        .virtual_token_reserves
+        .checked_sub(curve.real_token_reserves)
+    {
+        Some(floor) if floor > 0 => {
+            let k = u128::from(curve.virtual_sol_reserves)
+                .checked_mul(u128::from(curve.virtual_token_reserves))?;
+            Some((k / (u128::from(floor) + 1) + 1).saturating_sub(u128::from(curve.virtual_sol_reserves)))
+        }
+        _ => Some(u128::MAX),
+    }
+}
+
+/// Quotes a buy of `sol_amount` (fees included). The reserve fee buys its tokens
+/// first, then the rest buys the trader's. The buy that graduates the curve is
+/// filled only up to `max_buy_fill`.
+pub fn quote_buy(
+    curve: &CurveState,
+    rates: FeeRates,
+    trigger: GraduationTrigger,
+    sol_amount: u64,
+) -> Option<BuyQuote> {
+    let sol_in = sol_amount.min(max_buy_fill(curve, rates, trigger)?);
+    let fees = split_fees(sol_in, rates)?;
+    let sol_after_fees = sol_in.checked_sub(fees.total()?)?;
+
+    let (reserve_tokens_out, after_reserve) = reserve_purchase(curve, rates.reserve_purchase_fee(&fees))?;
+    let (tokens_out, virtual_sol_reserves, virtual_token_reserves) =
+        after_reserve.swap_sol_in(sol_after_fees)?;
+    // Rounding can leave the final fill a few units past what is left
+    let tokens_out = tokens_out.min(after_reserve.real_token_reserves);
+
+    Some(BuyQuote {
+        sol_in,
+        sol_refunded: sol_amount - sol_in,
+        fees,
+        reserve_tokens_out,
+        tokens_out,
+        curve: CurveState {
+            virtual_token_reserves,
+            virtual_sol_reserves,
+            real_token_reserves: after_reserve.real_token_reserves.checked_sub(tokens_out)?,
+            real_sol_reserves: after_reserve.real_sol_reserves.checked_add(sol_after_fees)?,
+            ..*curve
+        },
+    })
+}
+
+/// Quotes the cheapest buy that yields at least `token_amount` tokens, fees and the
+/// reserve purchase included. `None` if the curve cannot fill it before graduating.
+pub fn quote_buy_exact_out(
+    curve: &CurveState,
+    rates: FeeRates,
+    trigger: GraduationTrigger,
+    token_amount: u64,
+) -> Option<BuyQuote> {
+    // The reserve fee moves the price the user buys at, and depends on the gross amount;
+    // iterate from no reserve fee up to the least consistent one
+    let mut reserve_fee = 0;
+    for _ in 0..MAX_EXACT_OUT_ITERATIONS {
+        let (_, after_reserve) = reserve_purchase(curve, reserve_fee)?;
+        let sol_after_fees = after_reserve.sol_for_tokens(token_amount)?;
+        let sol_in = gross_for_net(sol_after_fees, rates)?;
+        let fees = split_fees(sol_in, rates)?;
+        let purchase_fee = rates.reserve_purchase_fee(&fees);
+        if purchase_fee <= reserve_fee {
+            let quote = quote_buy(curve, rates, trigger, sol_in)?;
+            return (quote.sol_refunded == 0 && quote.tokens_out >= token_amount).then_some(quote);
+        }
+        reserve_fee = purchase_fee;
+    }
+    None
+}
+
+// Each round moves the reserve fee by at most the reserve rate (under 30%) of the previous
+// move, so 37 rounds take any u64 move to zero
+const MAX_EXACT_OUT_ITERATIONS: usize = 40;
+
+/// Least gross amount whose fees leave at least `sol_after_fees`.
+fn gross_for_net(sol_after_fees: u64, rates: FeeRates) -> Option<u64> {
+    let net_of = |gross: u64| -> Option<u64> { gross.checked_sub(split_fees(gross, rates)?.total()?) };
+    let kept_bps = BPS_DENOMINATOR.checked_sub(rates.total_bps()?)?;
+    let estimate = u128::from(sol_after_fees)
+        .checked_mul(u128::from(BPS_DENOMINATOR))?
+        .div_ceil(u128::from(kept_bps));
+    // Per-part rounding can put the estimate a unit or two off
+    let mut gross = u64::try_from(estimate).ok()?;
+    while net_of(gross)? < sol_after_fees {
+        gross = gross.checked_add(1)?;
+    }
+    while gross > 0 && net_of(gross - 1)? >= sol_after_fees {
+        gross -= 1;
+    }
+    Some(gross)
+}
+
+/// Quotes a sell of `token_amount`. Fees come out of the gross SOL, and the
+/// reserve fee buys tokens back at the price after the sell.
+pub fn quote_sell(curve: &CurveState, rates: FeeRates, token_amount: u64) -> Option<SellQuote> {
+    let (sol_out_gross, virtual_sol_reserves, virtual_token_reserves) =
+        curve.swap_token_in(token_amount)?;
+    let fees = split_fees(sol_out_gross, rates)?;
+    let sol_out_net = sol_out_gross.checked_sub(fees.total()?)?;
+
+    let after_sell = CurveState {
+        virtual_token_reserves,
+        virtual_sol_reserves,
+        real_token_reserves: curve.real_token_reserves.checked_add(token_amount)?,
+        ..*curve
+    };
+    let (reserve_tokens_out, after_reserve) =
+        reserve_purchase(&after_sell, rates.reserve_purchase_fee(&fees))?;
+
+    Some(SellQuote {
+        token_in: token_amount,
+        sol_out_gross,
+        sol_out_net,
+        fees,
+        reserve_tokens_out,
+        curve: CurveState {
+            // A purchasing reserve fee stayed in the curve, the gross output leaves it
+            real_sol_reserves: after_reserve.real_sol_reserves.checked_sub(sol_out_gross)?,
+            ..after_reserve
+        },
+    })
+}
+
+/// Quotes the smallest sell that pays out at least `sol_out_net` after fees.
+/// The reserve purchase follows the payout, so only the fee gross-up is inverted.
+/// `None` if the curve does not hold that much SOL.
+pub fn quote_sell_exact_out(curve: &CurveState, rates: FeeRates, sol_out_net: u64) -> Option<SellQuote> {
+    let sol_out_gross = gross_for_net(sol_out_net, rates)?;
+    let token_in = curve.tokens_for_sol(sol_out_gross)?;
+    let quote = quote_sell(curve, rates, token_in)?;
+    (quote.sol_out_net >= sol_out_net).then_some(quote)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOL: u64 = 1_000_000_000;
+    const RATES: FeeRates = FeeRates {
+        platform_trade_fee_bps: 100,
+        reserve_trade_fee_bps: 400,
+        creator_trade_fee_bps: 50,
+        reserve_fee_in_sol: false,
+    };
+    const THRESHOLD: u64 = 85 * SOL;
+    const GRADUATION: GraduationTrigger = GraduationTrigger::SolReserves(THRESHOLD);
+    const NEVER: GraduationTrigger = GraduationTrigger::SolReserves(u64::MAX);
+
+    fn fresh_curve() -> CurveState {
+        CurveState {
+            virtual_token_reserves: 1_073_000_191_000_000,
+            virtual_sol_reserves: 30 * SOL,
+            real_token_reserves: SUPPLY,
+            real_sol_reserves: 0,
+            kind: CurveKind::ConstantProduct,
+            token_total_supply: SUPPLY,
+        }
+    }
+
+    const SUPPLY: u64 = 1_000_000_000_000_000;
+    const SHAPES: [CurveKind; 2] = [
+        CurveKind::Linear { start_price: 28_000_000, end_price: 280_000_000 },
+        CurveKind::Exponential { start_price: 28_000_000, doubling_tokens: SUPPLY / 4 },
+    ];
+
+    // Shaped curves have no virtual reserves beyond their real ones
+    fn shaped_curve(kind: CurveKind) -> CurveState {
+        CurveState {
+            virtual_token_reserves: SUPPLY,
+            virtual_sol_reserves: 0,
+            real_token_reserves: SUPPLY,
+            real_sol_reserves: 0,
+            kind,
+            token_total_supply: SUPPLY,
+        }
+    }
+
+    #[test]
+    fn fee_split_rounds_each_part_down() {
+        let fees = split_fees(999, RATES).unwrap();
+        assert_eq!(fees, FeeSplit { platform_fee: 9, reserve_fee: 39, creator_fee: 4 });
+        assert_eq!(fees.total(), Some(52));
+    }
+
+    #[test]
+    fn buy_accounts_for_every_lamport_and_token() {
+        let curve = fresh_curve();
+        let quote = quote_buy(&curve, RATES, GRADUATION, SOL).unwrap();
+
+        assert_eq!(quote.sol_in, SOL);
+        assert_eq!(quote.sol_refunded, 0);
+        assert_eq!(
+            quote.curve.real_sol_reserves - curve.real_sol_reserves,
+            quote.sol_in - quote.fees.platform_fee - quote.fees.creator_fee
+        );
+        assert_eq!(
+            curve.real_token_reserves - quote.curve.real_token_reserves,
+            quote.tokens_out + quote.reserve_tokens_out
+        );
+        assert!(quote.reserve_tokens_out > 0);
+    }
+
+    #[test]
+    fn reserve_buys_before_the_trader() {
+        let curve = fresh_curve();
+        let quote = quote_buy(&curve, RATES, GRADUATION, SOL).unwrap();
+        let (reserve_tokens, after_reserve) = reserve_purchase(&curve, quote.fees.reserve_fee).unwrap();
+
+        assert_eq!(reserve_tokens, quote.reserve_tokens_out);
+        assert_eq!(after_reserve.real_sol_reserves, quote.fees.reserve_fee);
+    }
+
+    #[test]
+    fn graduating_buy_is_capped_at_the_threshold() {
+        let curve = fresh_curve();
+        let quote = quote_buy(&curve, RATES, GRADUATION, 100 * SOL).unwrap();
+
+        assert!(quote.sol_refunded > 0);
+        assert_eq!(quote.sol_in + quote.sol_refunded, 100 * SOL);
+        assert!(quote.curve.is_graduated(GRADUATION));
+        // Rounding the gross amount up overshoots by at most a couple of lamports
+        assert!(quote.curve.real_sol_reserves - THRESHOLD <= 2);
+    }
+
+    #[test]
+    fn token_floor_buy_stops_at_the_floor() {
+        let floor = SUPPLY / 5;
+        let trigger = GraduationTrigger::TokenFloor(floor);
+        for curve in [fresh_curve(), shaped_curve(SHAPES[0]), shaped_curve(SHAPES[1])] {
+            let quote = quote_buy(&curve, RATES, trigger, 1_000 * SOL).unwrap();
+
+            assert!(quote.sol_refunded > 0);
+            assert_eq!(quote.curve.graduation(trigger), Some(Graduation::Trigger));
+            // Rounding the gross amount up overshoots by a couple of lamports worth of tokens
+            assert!(floor - quote.curve.real_token_reserves < SUPPLY / 100_000_000);
+            // The same buy never graduates on SOL alone
+            assert!(!quote.curve.is_graduated(NEVER));
+        }
+    }
+
+    #[test]
+    fn market_cap_buy_stops_at_the_target() {
+        let target = 100 * SOL;
+        let trigger = GraduationTrigger::MarketCap(target);
+        for curve in [fresh_curve(), shaped_curve(SHAPES[0]), shaped_curve(SHAPES[1])] {
+            assert!(curve.market_cap().unwrap() < target);
+            let quote = quote_buy(&curve, RATES, trigger, 1_000 * SOL).unwrap();
+
+            assert!(quote.sol_refunded > 0);
+            assert_eq!(quote.curve.graduation(trigger), Some(Graduation::Trigger));
+            // A buy a little smaller leaves the market cap short of the target
+            let smaller = quote_buy(&curve, RATES, trigger, quote.sol_in - quote.sol_in / 1_000).unwrap();
+            assert_eq!(smaller.curve.graduation(trigger), None);
+        }
+    }
+
+    #[test]
+    fn sold_out_curves_report_it() {
+        let quote = quote_buy(&fresh_curve(), RATES, NEVER, 1_000 * SOL).unwrap();
+        assert_eq!(quote.curve.graduation(NEVER), Some(Graduation::SoldOut));
+        assert_eq!(quote.curve.graduation(GRADUATION), Some(Graduation::Trigger));
+    }
+
+    #[test]
+    fn graduating_buy_is_capped_at_the_remaining_tokens() {
+        let curve = fresh_curve();
+        let quote = quote_buy(&curve, RATES, NEVER, 1_000 * SOL).unwrap();
+
+        assert!(quote.sol_refunded > 0);
+        assert_eq!(quote.curve.real_token_reserves, 0);
+        assert!(quote.curve.is_graduated(NEVER));
+    }
+
+    #[test]
+    fn exact_out_buy_charges_the_least_sol_for_the_tokens() {
+        let curve = quote_buy(&fresh_curve(), RATES, GRADUATION, 3 * SOL).unwrap().curve;
+        for token_amount in [1, 1_000_000, 35_000_000_000, 20_000_000_000_000] {
+            let quote = quote_buy_exact_out(&curve, RATES, GRADUATION, token_amount).unwrap();
+            assert!(quote.tokens_out >= token_amount);
+            assert_eq!(quote.sol_refunded, 0);
+            // One lamport less is not enough
+            let cheaper = quote_buy(&curve, RATES, GRADUATION, quote.sol_in - 1).unwrap();
+            assert!(cheaper.tokens_out < token_amount);
+        }
+    }
+
+    #[test]
+    fn exact_out_buy_stops_at_graduation() {
+        let curve = fresh_curve();
+        let most = quote_buy(&curve, RATES, GRADUATION, u64::MAX).unwrap();
+        let quote = quote_buy_exact_out(&curve, RATES, GRADUATION, most.tokens_out).unwrap();
+        assert!(quote.sol_in <= most.sol_in);
+        // Past what the graduating fill buys, or past the curve's tokens, there is no quote
+        assert_eq!(quote_buy_exact_out(&curve, RATES, GRADUATION, most.tokens_out * 2), None);
+        assert_eq!(quote_buy_exact_out(&curve, RATES, NEVER, curve.real_token_reserves + 1), None);
+    }
+
+    #[test]
+    fn sell_accounts_for_every_lamport_and_token() {
+        let bought = quote_buy(&fresh_curve(), RATES, GRADUATION, 5 * SOL).unwrap();
+        let curve = bought.curve;
+        let quote = quote_sell(&curve, RATES, bought.tokens_out).unwrap();
+
+        assert_eq!(
+            curve.real_sol_reserves - quote.curve.real_sol_reserves,
+            quote.sol_out_net + quote.fees.platform_fee + quote.fees.creator_fee
+        );
+        assert_eq!(
+            quote.curve.real_token_reserves - curve.real_token_reserves,
+            bought.tokens_out - quote.reserve_tokens_out
+        );
+    }
+
+    #[test]
+    fn sol_reserve_fees_leave_the_curve() {
+        let rates = FeeRates { reserve_fee_in_sol: true, ..RATES };
+        let curve = fresh_curve();
+        let bought = quote_buy(&curve, rates, GRADUATION, 5 * SOL).unwrap();
+        assert_eq!(bought.reserve_tokens_out, 0);
+        assert_eq!(
+            bought.curve.real_sol_reserves - curve.real_sol_reserves,
+            bought.sol_in - bought.fees.total().unwrap()
+        );
+        assert_eq!(curve.real_token_reserves - bought.curve.real_token_reserves, bought.tokens_out);
+
+        let sold = quote_sell(&bought.curve, rates, bought.tokens_out / 2).unwrap();
+        assert_eq!(sold.reserve_tokens_out, 0);
+        assert_eq!(bought.curve.real_sol_reserves - sold.curve.real_sol_reserves, sold.sol_out_gross);
+
+        // The graduating fill grosses up by the reserve fee too, and exact-out quotes still invert
+        let graduating = quote_buy(&curve, rates, GRADUATION, 100 * SOL).unwrap();
+        // Each of the three outgoing fees rounds down by under a lamport
+        assert!(graduating.curve.real_sol_reserves - THRESHOLD <= 3);
+        let quote = quote_buy_exact_out(&curve, rates, GRADUATION, bought.tokens_out).unwrap();
+        assert!(quote.tokens_out >= bought.tokens_out && quote.sol_in <= bought.sol_in);
+        let quote = quote_sell_exact_out(&bought.curve, rates, SOL).unwrap();
+        assert!(quote.sol_out_net >= SOL);
+    }
+
+    #[test]
+    fn exact_out_sell_pulls_the_least_tokens_for_the_sol() {
+        let curve = quote_buy(&fresh_curve(), RATES, GRADUATION, 5 * SOL).unwrap().curve;
+        for sol_out_net in [1, 1_000, SOL / 7, 2 * SOL] {
+            let quote = quote_sell_exact_out(&curve, RATES, sol_out_net).unwrap();
+            assert!(quote.sol_out_net >= sol_out_net);
+            // One token less is not enough
+            let smaller = quote_sell(&curve, RATES, quote.token_in - 1).unwrap();
+            assert!(smaller.sol_out_net < sol_out_net);
+        }
+        // The curve cannot pay out more than it holds
+        assert_eq!(quote_sell_exact_out(&curve, RATES, curve.real_sol_reserves), None);
+    }
+
+    #[test]
+    fn round_trip_only_gains_rounding_dust() {
+        // Trade against a curve that already holds SOL, so the sell side can pay out
+        let curve = quote_buy(&fresh_curve(), RATES, GRADUATION, 10 * SOL).unwrap().curve;
+        for sol in [1, 1_000, SOL / 3, SOL, 7 * SOL] {
+            let bought = quote_buy(&curve, RATES, GRADUATION, sol).unwrap();
+            let sold = quote_sell(&bought.curve, RATES, bought.tokens_out).unwrap();
+            // Each swap rounds its output up by at most one unit in the trader's favour
+            assert!(sold.sol_out_net <= bought.sol_in + 1);
+        }
+    }
+
+    #[test]
+    fn shaped_prices_follow_their_parameters() {
+        let linear = SHAPES[0];
+        assert_eq!(linear.price(0, SUPPLY), Some(28_000_000));
+        assert_eq!(linear.price(SUPPLY / 2, SUPPLY), Some(154_000_000));
+        assert_eq!(linear.price(SUPPLY, SUPPLY), Some(280_000_000));
+        // Selling out raises the average price times the supply
+        assert_eq!(linear.cost(SUPPLY, SUPPLY), Some(154_000_000_000));
+
+        let exponential = SHAPES[1];
+        let step = SUPPLY / 4;
+        assert_eq!(exponential.price(0, SUPPLY), Some(28_000_000));
+        assert_eq!(exponential.price(step / 2, SUPPLY), Some(42_000_000));
+        assert_eq!(exponential.price(step, SUPPLY), Some(56_000_000));
+        assert_eq!(exponential.price(SUPPLY, SUPPLY), Some(448_000_000));
+        // A step costs one and a half times its start price per token
+        assert_eq!(exponential.cost(step, SUPPLY), Some(10_500_000_000));
+        assert_eq!(exponential.cost(2 * step, SUPPLY), Some(31_500_000_000));
+    }
+
+    #[test]
+    fn shaped_curves_stay_solvent_and_price_upwards() {
+        for kind in SHAPES {
+            let mut curve = shaped_curve(kind);
+            for sol in [1, SOL / 3, 7 * SOL, 20 * SOL] {
+                let (price, _) = curve.spot_price().unwrap();
+                let bought = quote_buy(&curve, RATES, NEVER, sol).unwrap();
+                assert!(bought.curve.spot_price().unwrap().0 >= price);
+                let sold = quote_sell(&bought.curve, RATES, bought.tokens_out / 3).unwrap();
+                curve = sold.curve;
+
+                // The curve always holds enough SOL to buy back everything it sold
+                let cost = kind.cost(curve.tokens_sold().unwrap(), SUPPLY).unwrap();
+                assert!(curve.real_sol_reserves >= cost);
+                assert_eq!(curve.virtual_token_reserves, curve.real_token_reserves);
+            }
+        }
+    }
+
+    #[test]
+    fn shaped_trades_are_path_independent() {
+        let no_fees = FeeRates::default();
+        for kind in SHAPES {
+            let curve = quote_buy(&shaped_curve(kind), no_fees, NEVER, 30 * SOL).unwrap().curve;
+            let whole = quote_sell(&curve, no_fees, 10_000_000_000_000).unwrap();
+            let first = quote_sell(&curve, no_fees, 4_000_000_000_000).unwrap();
+            let second = quote_sell(&first.curve, no_fees, 6_000_000_000_000).unwrap();
+            assert_eq!(whole.sol_out_gross, first.sol_out_gross + second.sol_out_gross);
+            assert_eq!(whole.curve, second.curve);
+
+            // Split buys can only lose the rounding dust each one leaves behind
+            let once = quote_buy(&curve, no_fees, NEVER, 3 * SOL).unwrap();
+            let first = quote_buy(&curve, no_fees, NEVER, SOL).unwrap();
+            let second = quote_buy(&first.curve, no_fees, NEVER, 2 * SOL).unwrap();
+            assert!(first.tokens_out + second.tokens_out <= once.tokens_out);
+            assert!(first.tokens_out + second.tokens_out + 1 >= once.tokens_out);
+        }
+    }
+
+    #[test]
+    fn shaped_round_trip_gains_nothing() {
+        for kind in SHAPES {
+            let curve = quote_buy(&shaped_curve(kind), RATES, GRADUATION, 10 * SOL).unwrap().curve;
+            for sol in [1, 1_000, SOL / 3, SOL, 7 * SOL] {
+                let bought = quote_buy(&curve, RATES, GRADUATION, sol).unwrap();
+                let sold = quote_sell(&bought.curve, RATES, bought.tokens_out).unwrap();
+                assert!(sold.sol_out_net <= bought.sol_in);
+            }
+        }
+    }
+
+    #[test]
+    fn shaped_graduating_buy_sells_out_exactly() {
+        for kind in SHAPES {
+            let curve = shaped_curve(kind);
+            let quote = quote_buy(&curve, RATES, NEVER, 1_000 * SOL).unwrap();
+            assert!(quote.sol_refunded > 0);
+            assert_eq!(quote.curve.real_token_reserves, 0);
+            assert!(quote.curve.real_sol_reserves >= kind.cost(SUPPLY, SUPPLY).unwrap());
+
+            let quote = quote_buy(&curve, RATES, GRADUATION, 1_000 * SOL).unwrap();
+            assert!(quote.curve.is_graduated(GRADUATION));
+            assert!(quote.curve.real_sol_reserves - THRESHOLD <= 2);
+        }
+    }
+
+    #[test]
+    fn exact_out_trades_invert_shaped_curves() {
+        for kind in SHAPES {
+            let curve = quote_buy(&shaped_curve(kind), RATES, GRADUATION, 5 * SOL).unwrap().curve;
+            for token_amount in [1, 1_000_000, 35_000_000_000, 20_000_000_000_000] {
+                let quote = quote_buy_exact_out(&curve, RATES, GRADUATION, token_amount).unwrap();
+                assert!(quote.tokens_out >= token_amount);
+                let cheaper = quote_buy(&curve, RATES, GRADUATION, quote.sol_in - 1).unwrap();
+                assert!(cheaper.tokens_out < token_amount);
+            }
+            for sol_out_net in [1, 1_000, SOL / 7, 2 * SOL] {
+                let quote = quote_sell_exact_out(&curve, RATES, sol_out_net).unwrap();
+                assert!(quote.sol_out_net >= sol_out_net);
+                let smaller = quote_sell(&curve, RATES, quote.token_in - 1).unwrap();
+                assert!(smaller.sol_out_net < sol_out_net);
+            }
+            assert_eq!(quote_sell_exact_out(&curve, RATES, curve.real_sol_reserves), None);
+        }
+    }
+
+    #[test]
+    fn shaped_inverses_match_a_plain_search() {
+        // Smallest value in `low..=high` for which `holds`, bisected over the whole range
+        let bisect = |mut low: u64, mut high: u64, holds: &dyn Fn(u64) -> bool| {
+            while low < high {
+                let mid = low + (high - low) / 2;
+                if holds(mid) {
+                    high = mid;
+                } else {
+                    low = mid + 1;
+                }
+            }
+            low
+        };
+        let kinds = [
+            SHAPES[0],
+            SHAPES[1],
+            CurveKind::Linear { start_price: 1, end_price: 280_000_000_000 },
+            CurveKind::Linear { start_price: 28_000_000, end_price: 28_000_000 },
+            CurveKind::Exponential { start_price: 1, doubling_tokens: SUPPLY / 20 },
+        ];
+        for kind in kinds {
+            let cost = |sold| kind.cost(sold, SUPPLY).unwrap();
+            for sold in [0, 1, 35_000_000_000, SUPPLY / 3, SUPPLY - 1] {
+                let curve = CurveState {
+                    virtual_token_reserves: SUPPLY - sold,
+                    real_token_reserves: SUPPLY - sold,
+                    real_sol_reserves: cost(sold),
+                    ..shaped_curve(kind)
+                };
+                for sol in [0, 1, 999, SOL / 3, 7 * SOL, 1_000 * SOL] {
+                    let over = |to| to == SUPPLY + 1 || cost(to) - cost(sold) > sol;
+                    let bought = bisect(sold, SUPPLY + 1, &over);
+                    assert_eq!(curve.swap_sol_in(sol).unwrap().0, bought - sold - 1);
+                    if sol > 0 && sol <= cost(sold) {
+                        let sold_back = bisect(1, sold, &|back| cost(sold) - cost(sold - back) >= sol);
+                        assert_eq!(curve.tokens_for_sol(sol), Some(sold_back));
+                    }
+                }
+                let market_cap_at =
+                    |to: u64| CurveState { real_token_reserves: SUPPLY - to, ..curve }.market_cap().unwrap();
+                let targets = [market_cap_at(sold) + 1, market_cap_at(SUPPLY / 2), market_cap_at(SUPPLY), u64::MAX];
+                for target in targets {
+                    if target <= market_cap_at(sold) {
+                        continue;
+                    }
+                    let reached = bisect(sold + 1, SUPPLY, &|to| market_cap_at(to) >= target);
+                    assert_eq!(
+                        curve.tokens_to_trigger(GraduationTrigger::MarketCap(target)),
+                        Some(Some(reached - sold))
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn shaped_parameters_are_validated() {
+        assert!(SHAPES.iter().all(|kind| kind.is_valid(SUPPLY)));
+        assert!(!CurveKind::Linear { start_price: 0, end_price: 1 }.is_valid(SUPPLY));
+        assert!(!CurveKind::Linear { start_price: 2, end_price: 1 }.is_valid(SUPPLY));
+        assert!(!CurveKind::Linear { start_price: 1, end_price: 2 }.is_valid(0));
+        assert!(!CurveKind::Exponential { start_price: 1, doubling_tokens: 0 }.is_valid(SUPPLY));
+        // Doubling every token would overflow long before the supply sells
+        assert!(!CurveKind::Exponential { start_price: 1, doubling_tokens: 1 }.is_valid(SUPPLY));
+    }
+
+    #[test]
+    fn overflowing_trades_quote_none() {
+        let curve = fresh_curve();
+        assert_eq!(quote_sell(&curve, RATES, u64::MAX), None);
+        assert_eq!(split_fees(u64::MAX, RATES), None);
+    }
+}
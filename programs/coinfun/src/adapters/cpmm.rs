@@ -0,0 +1,134 @@
+// Raydium CP-Swap, the CPMM target: a constant-product pool whose LP tokens,
+// minus the amount the AMM locks, land in the migration authority's LP account.
+// It opens at the deposit ratio, which `migrate` sizes to the curve's final price.
+// CP-Swap fees compound into the pool, so there is nothing for `harvest_pool_fees`,
+// and the LP is burned or time-locked but never locked for good. The pool state lives
+// at a PDA of this program, which CP-Swap accepts in place of its own pool address
+// when it signs, so nobody can create the pool before `migrate`.
+//
+// Remaining accounts for `create_pool`:
+//   0. amm_config              registered in `Global.migration_configs`
+//   1. pool_authority
+//   2. pool_state              (w) PDA ["cpmm_pool", mint] of this program
+//   3. lp_mint                 (w)
+//   4. creator_lp_token        (w) LP ATA of the migration authority
+//   5. token_0_vault           (w)
+//   6. token_1_vault           (w)
+//   7. create_pool_fee         (w)
+//   8. observation_state       (w)
+//   9. associated_token_program
+//  10. rent
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, TokenAccount};
+use super::{check_config, check_pda, invoke_dex, take_accounts, Access, CreatedPool, PoolDeposit};
+use crate::errors::ErrorCode;
+use crate::states::{LpPolicy, MigrationTarget};
+
+pub const ID: Pubkey = pubkey!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
+
+// Fronted by the migrator for the pool accounts and Raydium's pool creation fee;
+// the unused part is refunded
+pub const CREATION_LAMPORTS: u64 = 200_000_000;
+
+const INITIALIZE_DISCRIMINATOR: [u8; 8] = [175, 175, 109, 31, 13, 152, 155, 237];
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeArgs {
+    pub init_amount_0: u64,
+    pub init_amount_1: u64,
+    pub open_time: u64,
+}
+
+/// Creates the pool, ordering the launched mint and WSOL by address as CP-Swap
+/// requires, then burns the LP tokens if the curve's policy says so.
+pub fn create_pool<'info>(
+    deposit: &PoolDeposit<'info>,
+    remaining: &[AccountInfo<'info>],
+    migration_authority_seeds: &[&[u8]],
+) -> Result<CreatedPool> {
+    let [amm_config, pool_authority, pool_state, lp_mint, creator_lp_token, token_0_vault, token_1_vault, create_pool_fee, observation_state, associated_token_program, rent] =
+        take_accounts::<11>(remaining)?;
+    require!(
+        deposit.lp_policy.supports(MigrationTarget::Cpmm),
+        ErrorCode::LpPolicyNotSupported
+    );
+    check_config(amm_config, deposit)?;
+    let mint_key = deposit.token_mint.key();
+    let pool_state_bump = check_pda(pool_state, &[b"cpmm_pool", mint_key.as_ref()])?;
+    let pool_state_seeds: &[&[u8]] = &[b"cpmm_pool", mint_key.as_ref(), &[pool_state_bump]];
+
+    let token_side = (
+        &deposit.token_mint,
+        &deposit.token_account,
+        &deposit.token_program,
+        deposit.token_amount,
+    );
+    let wsol_side = (
+        &deposit.wsol_mint,
+        &deposit.wsol_account,
+        &deposit.wsol_token_program,
+        deposit.sol_amount,
+    );
+    let ((mint_0, account_0, program_0, amount_0), (mint_1, account_1, program_1, amount_1)) =
+        if deposit.token_mint.key() < deposit.wsol_mint.key() {
+            (token_side, wsol_side)
+        } else {
+            (wsol_side, token_side)
+        };
+
+    invoke_dex(
+        &deposit.dex_program,
+        INITIALIZE_DISCRIMINATOR,
+        InitializeArgs {
+            init_amount_0: amount_0,
+            init_amount_1: amount_1,
+            open_time: 0,
+        },
+        &[
+            (&deposit.migration_authority, Access::Signer),
+            (amm_config, Access::Read),
+            (pool_authority, Access::Read),
+            (pool_state, Access::Signer),
+            (mint_0, Access::Read),
+            (mint_1, Access::Read),
+            (lp_mint, Access::Write),
+            (account_0, Access::Write),
+            (account_1, Access::Write),
+            (creator_lp_token, Access::Write),
+            (token_0_vault, Access::Write),
+            (token_1_vault, Access::Write),
+            (create_pool_fee, Access::Write),
+            (observation_state, Access::Write),
+            (&deposit.wsol_token_program, Access::Read),
+            (program_0, Access::Read),
+            (program_1, Access::Read),
+            (associated_token_program, Access::Read),
+            (&deposit.system_program, Access::Read),
+            (rent, Access::Read),
+        ],
+        &[migration_authority_seeds, pool_state_seeds],
+    )?;
+
+    // Time-locked LP stays in the migration authority's account
+    if deposit.lp_policy == LpPolicy::Burn {
+        let lp_amount =
+            TokenAccount::try_deserialize(&mut &creator_lp_token.try_borrow_data()?[..])?.amount;
+        token::burn(
+            CpiContext::new(
+                deposit.wsol_token_program.clone(),
+                Burn {
+                    mint: lp_mint.clone(),
+                    from: creator_lp_token.clone(),
+                    authority: deposit.migration_authority.clone(),
+                },
+            )
+            .with_signer(&[migration_authority_seeds]),
+            lp_amount,
+        )?;
+    }
+
+    Ok(CreatedPool {
+        pool: pool_state.key(),
+        lp_mint: lp_mint.key(),
+    })
+}
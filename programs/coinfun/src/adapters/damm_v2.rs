@@ -0,0 +1,316 @@
+// Meteora DAMM v2 (cp-amm): one full-range pool position, represented by an NFT
+// held by the migration authority. A pool someone created first is seeded through a
+// new position if it trades near the curve's final price.
+//
+// Remaining accounts for `create_pool`:
+//   0. position_nft_mint     (w) PDA ["position_nft_mint", mint] of this program
+//   1. position_nft_account  (w)
+//   2. pool_config               registered in `Global.migration_configs`
+//   3. pool_authority
+//   4. pool                  (w)
+//   5. position              (w)
+//   6. token_a_vault         (w)
+//   7. token_b_vault         (w)
+//   8. token_2022_program
+//   9. event_authority
+//
+// Remaining accounts for `claim_fees`:
+//   0. pool_authority
+//   1. pool
+//   2. position              (w)
+//   3. token_a_vault         (w)
+//   4. token_b_vault         (w)
+//   5. position_nft_account
+//   6. event_authority
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn};
+use super::{
+    check_config, check_pda, check_pool_price, invoke_dex, pool_exists, take_accounts, Access,
+    CreatedPool, FeeClaim, PoolDeposit,
+};
+use crate::consts::EXISTING_POOL_PRICE_TOLERANCE_BPS;
+use crate::errors::ErrorCode;
+use crate::math::{mul_div_floor, sqrt_floor};
+use crate::states::LpPolicy;
+
+pub const ID: Pubkey = pubkey!("cpamdpZCGKUy5JxQXB4dcpGPiikHawvSWAd6mEn1sGG");
+
+// Fronted by the migrator for the pool accounts; the unused part is refunded
+pub const CREATION_LAMPORTS: u64 = 50_000_000;
+
+// Full-range Q64.64 sqrt price bounds used by cp-amm pool configs
+pub const MIN_SQRT_PRICE: u128 = 4_295_048_016;
+pub const MAX_SQRT_PRICE: u128 = 79_226_673_521_066_979_257_578_248_091;
+
+const INITIALIZE_POOL_DISCRIMINATOR: [u8; 8] = [95, 180, 10, 172, 84, 174, 232, 40];
+const PERMANENT_LOCK_POSITION_DISCRIMINATOR: [u8; 8] = [165, 176, 125, 6, 231, 171, 186, 213];
+const CLAIM_POSITION_FEE_DISCRIMINATOR: [u8; 8] = [180, 38, 154, 17, 133, 33, 162, 211];
+const CREATE_POSITION_DISCRIMINATOR: [u8; 8] = [48, 215, 197, 153, 96, 203, 180, 133];
+const ADD_LIQUIDITY_DISCRIMINATOR: [u8; 8] = [181, 157, 89, 67, 143, 182, 52, 72];
+
+// Offsets into cp-amm's `Pool` account, discriminator included
+const POOL_TOKEN_A_MINT_OFFSET: usize = 168;
+const POOL_SQRT_PRICE_OFFSET: usize = 456;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializePoolParameters {
+    pub liquidity: u128,
+    pub sqrt_price: u128,
+    pub activation_point: Option<u64>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AddLiquidityParameters {
+    pub liquidity_delta: u128,
+    pub token_a_amount_threshold: u64,
+    pub token_b_amount_threshold: u64,
+}
+
+/// Creates the pool with the launched mint as token A and WSOL as token B, or adds
+/// a position to the pool if someone created it first, then locks the position or
+/// burns its NFT as the curve's policy requires.
+pub fn create_pool<'info>(
+    deposit: &PoolDeposit<'info>,
+    remaining: &[AccountInfo<'info>],
+    migration_authority_seeds: &[&[u8]],
+) -> Result<CreatedPool> {
+    let [position_nft_mint, position_nft_account, config, pool_authority, pool, position, token_a_vault, token_b_vault, token_2022_program, event_authority] =
+        take_accounts::<10>(remaining)?;
+
+    check_config(config, deposit)?;
+    let mint_key = deposit.token_mint.key();
+    let nft_mint_bump = check_pda(position_nft_mint, &[b"position_nft_mint", mint_key.as_ref()])?;
+    let position_nft_mint_seeds: &[&[u8]] =
+        &[b"position_nft_mint", mint_key.as_ref(), &[nft_mint_bump]];
+
+    // Open at the curve's last price rather than the deposit ratio
+    let curve_sqrt_price = sqrt_price_from_amounts(deposit.price_token, deposit.price_sol)
+        .ok_or(ErrorCode::PoolPriceOutOfRange)?;
+
+    let liquidity = if pool_exists(pool, deposit)? {
+        let sqrt_price = existing_pool_sqrt_price(pool, config, deposit)?;
+        // A sqrt price within half the tolerance keeps the price within all of it
+        check_pool_price(sqrt_price, curve_sqrt_price, EXISTING_POOL_PRICE_TOLERANCE_BPS / 2)?;
+        let liquidity = liquidity_from_amounts(deposit.token_amount, deposit.sol_amount, sqrt_price)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        invoke_dex(
+            &deposit.dex_program,
+            CREATE_POSITION_DISCRIMINATOR,
+            (),
+            &[
+                (&deposit.migration_authority, Access::Read),
+                (position_nft_mint, Access::Signer),
+                (position_nft_account, Access::Write),
+                (pool, Access::Write),
+                (position, Access::Write),
+                (pool_authority, Access::Read),
+                (&deposit.migration_authority, Access::Signer),
+                (token_2022_program, Access::Read),
+                (&deposit.system_program, Access::Read),
+                (event_authority, Access::Read),
+                (&deposit.dex_program, Access::Read),
+            ],
+            &[migration_authority_seeds, position_nft_mint_seeds],
+        )?;
+        // The deposits cap what the pool may pull for the liquidity
+        invoke_dex(
+            &deposit.dex_program,
+            ADD_LIQUIDITY_DISCRIMINATOR,
+            AddLiquidityParameters {
+                liquidity_delta: liquidity,
+                token_a_amount_threshold: deposit.token_amount,
+                token_b_amount_threshold: deposit.sol_amount,
+            },
+            &[
+                (pool, Access::Write),
+                (position, Access::Write),
+                (&deposit.token_account, Access::Write),
+                (&deposit.wsol_account, Access::Write),
+                (token_a_vault, Access::Write),
+                (token_b_vault, Access::Write),
+                (&deposit.token_mint, Access::Read),
+                (&deposit.wsol_mint, Access::Read),
+                (position_nft_account, Access::Read),
+                (&deposit.migration_authority, Access::Signer),
+                (&deposit.token_program, Access::Read),
+                (&deposit.wsol_token_program, Access::Read),
+                (event_authority, Access::Read),
+                (&deposit.dex_program, Access::Read),
+            ],
+            &[migration_authority_seeds],
+        )?;
+        liquidity
+    } else {
+        let liquidity =
+            liquidity_from_amounts(deposit.token_amount, deposit.sol_amount, curve_sqrt_price)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        invoke_dex(
+            &deposit.dex_program,
+            INITIALIZE_POOL_DISCRIMINATOR,
+            InitializePoolParameters {
+                liquidity,
+                sqrt_price: curve_sqrt_price,
+                activation_point: None,
+            },
+            &[
+                (&deposit.migration_authority, Access::Read),
+                (position_nft_mint, Access::Signer),
+                (position_nft_account, Access::Write),
+                (&deposit.migration_authority, Access::Signer),
+                (config, Access::Read),
+                (pool_authority, Access::Read),
+                (pool, Access::Write),
+                (position, Access::Write),
+                (&deposit.token_mint, Access::Read),
+                (&deposit.wsol_mint, Access::Read),
+                (token_a_vault, Access::Write),
+                (token_b_vault, Access::Write),
+                (&deposit.token_account, Access::Write),
+                (&deposit.wsol_account, Access::Write),
+                (&deposit.token_program, Access::Read),
+                (&deposit.wsol_token_program, Access::Read),
+                (token_2022_program, Access::Read),
+                (&deposit.system_program, Access::Read),
+                (event_authority, Access::Read),
+                (&deposit.dex_program, Access::Read),
+            ],
+            &[migration_authority_seeds, position_nft_mint_seeds],
+        )?;
+        liquidity
+    };
+
+    match deposit.lp_policy {
+        // Locked liquidity still earns fees, harvested through `claim_fees`
+        LpPolicy::PermanentLock => invoke_dex(
+            &deposit.dex_program,
+            PERMANENT_LOCK_POSITION_DISCRIMINATOR,
+            liquidity,
+            &[
+                (pool, Access::Write),
+                (position, Access::Write),
+                (position_nft_account, Access::Read),
+                (&deposit.migration_authority, Access::Signer),
+                (event_authority, Access::Read),
+                (&deposit.dex_program, Access::Read),
+            ],
+            &[migration_authority_seeds],
+        )?,
+        // Without its NFT the position can never be withdrawn from
+        LpPolicy::Burn => token_interface::burn(
+            CpiContext::new(
+                token_2022_program.clone(),
+                Burn {
+                    mint: position_nft_mint.clone(),
+                    from: position_nft_account.clone(),
+                    authority: deposit.migration_authority.clone(),
+                },
+            )
+            .with_signer(&[migration_authority_seeds]),
+            1,
+        )?,
+        LpPolicy::TimeLock { .. } => {}
+    }
+
+    Ok(CreatedPool {
+        pool: pool.key(),
+        lp_mint: position_nft_mint.key(),
+    })
+}
+
+/// Sqrt price of a pool created before the migration. It must be the canonical pool
+/// for the registered config, with the launched mint as token A.
+fn existing_pool_sqrt_price(
+    pool: &AccountInfo,
+    config: &AccountInfo,
+    deposit: &PoolDeposit,
+) -> Result<u128> {
+    let (mint_a, mint_b) = (deposit.token_mint.key(), deposit.wsol_mint.key());
+    let (expected, _) = Pubkey::find_program_address(
+        &[b"pool", config.key.as_ref(), mint_a.max(mint_b).as_ref(), mint_a.min(mint_b).as_ref()],
+        deposit.dex_program.key,
+    );
+    require_keys_eq!(pool.key(), expected, ErrorCode::InvalidMigrationAccount);
+
+    let data = pool.try_borrow_data()?;
+    let token_a_mint = data
+        .get(POOL_TOKEN_A_MINT_OFFSET..POOL_TOKEN_A_MINT_OFFSET + 32)
+        .and_then(|bytes| Pubkey::try_from(bytes).ok())
+        .ok_or(ErrorCode::InvalidMigrationAccount)?;
+    // A pool quoting the mint in WSOL's place is priced the other way up
+    require_keys_eq!(token_a_mint, mint_a, ErrorCode::PoolPriceMismatch);
+    data.get(POOL_SQRT_PRICE_OFFSET..POOL_SQRT_PRICE_OFFSET + 16)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u128::from_le_bytes)
+        .ok_or_else(|| error!(ErrorCode::InvalidMigrationAccount))
+}
+
+/// Claims the position's accrued fees without touching its liquidity.
+pub fn claim_fees<'info>(
+    claim: &FeeClaim<'info>,
+    remaining: &[AccountInfo<'info>],
+    migration_authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let [pool_authority, pool, position, token_a_vault, token_b_vault, position_nft_account, event_authority] =
+        take_accounts::<7>(remaining)?;
+
+    invoke_dex(
+        &claim.dex_program,
+        CLAIM_POSITION_FEE_DISCRIMINATOR,
+        (),
+        &[
+            (pool_authority, Access::Read),
+            (pool, Access::Read),
+            (position, Access::Write),
+            (&claim.token_account, Access::Write),
+            (&claim.wsol_account, Access::Write),
+            (token_a_vault, Access::Write),
+            (token_b_vault, Access::Write),
+            (&claim.token_mint, Access::Read),
+            (&claim.wsol_mint, Access::Read),
+            (position_nft_account, Access::Read),
+            (&claim.migration_authority, Access::Signer),
+            (&claim.token_program, Access::Read),
+            (&claim.wsol_token_program, Access::Read),
+            (event_authority, Access::Read),
+            (&claim.dex_program, Access::Read),
+        ],
+        &[migration_authority_seeds],
+    )
+}
+
+/// Q64.64 square root of the price of token A quoted in token B.
+/// Only prices below 1 (raw units) are representable, which always holds for
+/// a launched token quoted in lamports.
+pub fn sqrt_price_from_amounts(token_a_amount: u64, token_b_amount: u64) -> Option<u128> {
+    let price_x128 = mul_div_floor(
+        u128::from(token_b_amount) << 64,
+        1u128 << 64,
+        u128::from(token_a_amount),
+    )?;
+    let sqrt_price = sqrt_floor(price_x128);
+    (MIN_SQRT_PRICE..MAX_SQRT_PRICE)
+        .contains(&sqrt_price)
+        .then_some(sqrt_price)
+}
+
+/// Largest full-range liquidity that both deposits can cover at `sqrt_price`.
+pub fn liquidity_from_amounts(
+    token_a_amount: u64,
+    token_b_amount: u64,
+    sqrt_price: u128,
+) -> Option<u128> {
+    // Δa = L * (√P_max - √P) / (√P * √P_max)
+    let liquidity_from_a = mul_div_floor(
+        u128::from(token_a_amount).checked_mul(sqrt_price)?,
+        MAX_SQRT_PRICE,
+        MAX_SQRT_PRICE.checked_sub(sqrt_price)?,
+    )?;
+    // Δb = L * (√P - √P_min) / 2^128
+    let liquidity_from_b = mul_div_floor(
+        u128::from(token_b_amount) << 64,
+        1u128 << 64,
+        sqrt_price.checked_sub(MIN_SQRT_PRICE)?,
+    )?;
+    Some(liquidity_from_a.min(liquidity_from_b))
+}
@@ -0,0 +1,425 @@
+// Meteora DLMM (lb_clmm): a fresh pair at the bin nearest the curve's final price,
+// seeded with one 69-bin spot position centred on the active bin. A pair someone
+// created first is seeded the same way around its own active bin, if that bin is
+// near the curve's final price. The position is
+// owned by the migration authority for good: DLMM positions can neither change
+// owner nor be burned, so `LpPolicy::PermanentLock` is the only policy offered.
+//
+// Remaining accounts for `create_pool`:
+//   0. lb_pair                     (w)
+//   1. bin_array_bitmap_extension  (w) or the DLMM program ID for none
+//   2. reserve_x                   (w)
+//   3. reserve_y                   (w)
+//   4. oracle                      (w)
+//   5. preset_parameter                registered in `Global.migration_configs`
+//   6. position                    (w) PDA ["dlmm_position", mint] of this program
+//   7. bin_array_lower             (w)
+//   8. bin_array_upper             (w)
+//   9. rent
+//  10. event_authority
+//
+// Remaining accounts for `claim_fees`:
+//   0. lb_pair                     (w)
+//   1. position                    (w)
+//   2. bin_array_lower             (w)
+//   3. bin_array_upper             (w)
+//   4. reserve_x                   (w)
+//   5. reserve_y                   (w)
+//   6. event_authority
+use anchor_lang::prelude::*;
+use super::{
+    check_config, check_pda, invoke_dex, pool_exists, take_accounts, Access, CreatedPool, FeeClaim,
+    PoolDeposit,
+};
+use crate::consts::EXISTING_POOL_PRICE_TOLERANCE_BPS;
+use crate::errors::ErrorCode;
+use crate::math::mul_q64;
+use crate::states::LpPolicy;
+
+pub const ID: Pubkey = pubkey!("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo");
+
+// Fronted by the migrator for the pair, bin arrays and position; the unused part is refunded
+pub const CREATION_LAMPORTS: u64 = 300_000_000;
+
+const MAX_BIN_ID: i32 = 443_636;
+const BINS_PER_ARRAY: i32 = 70;
+const POSITION_HALF_WIDTH: i32 = 34;
+// Bin ids stay below 2^19 in magnitude
+const ID_BITS: usize = 19;
+
+const INITIALIZE_LB_PAIR_DISCRIMINATOR: [u8; 8] = [45, 154, 237, 210, 221, 15, 166, 92];
+const INITIALIZE_BIN_ARRAY_DISCRIMINATOR: [u8; 8] = [35, 86, 19, 185, 78, 212, 75, 211];
+const INITIALIZE_POSITION_DISCRIMINATOR: [u8; 8] = [219, 192, 234, 71, 190, 191, 102, 80];
+const ADD_LIQUIDITY_BY_STRATEGY_DISCRIMINATOR: [u8; 8] = [7, 3, 150, 127, 148, 40, 61, 200];
+const CLAIM_FEE_DISCRIMINATOR: [u8; 8] = [169, 32, 79, 137, 136, 232, 70, 137];
+
+// Offsets into lb_clmm's `LbPair` account, discriminator included
+const PAIR_BASE_FACTOR_OFFSET: usize = 8;
+const PAIR_ACTIVE_ID_OFFSET: usize = 76;
+const PAIR_BIN_STEP_OFFSET: usize = 80;
+const PAIR_TOKEN_X_MINT_OFFSET: usize = 88;
+const PAIR_TOKEN_Y_MINT_OFFSET: usize = 120;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeLbPairArgs {
+    pub active_id: i32,
+    pub bin_step: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializePositionArgs {
+    pub lower_bin_id: i32,
+    pub width: i32,
+}
+
+// Variant order matches lb_clmm, only `SpotImBalanced` is used
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub enum StrategyType {
+    SpotOneSide,
+    CurveOneSide,
+    BidAskOneSide,
+    SpotBalanced,
+    CurveBalanced,
+    BidAskBalanced,
+    SpotImBalanced,
+    CurveImBalanced,
+    BidAskImBalanced,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct StrategyParameters {
+    pub min_bin_id: i32,
+    pub max_bin_id: i32,
+    pub strategy_type: StrategyType,
+    pub parameteres: [u8; 64],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LiquidityParameterByStrategy {
+    pub amount_x: u64,
+    pub amount_y: u64,
+    pub active_id: i32,
+    pub max_active_bin_slippage: i32,
+    pub strategy_parameters: StrategyParameters,
+}
+
+/// Creates the pair with the launched mint as token X and WSOL as token Y, unless
+/// someone created it first, then deposits both sides into a single position.
+pub fn create_pool<'info>(
+    deposit: &PoolDeposit<'info>,
+    remaining: &[AccountInfo<'info>],
+    migration_authority_seeds: &[&[u8]],
+) -> Result<CreatedPool> {
+    let [lb_pair, bitmap_extension, reserve_x, reserve_y, oracle, preset_parameter, position, bin_array_lower, bin_array_upper, rent, event_authority] =
+        take_accounts::<11>(remaining)?;
+    require!(
+        deposit.lp_policy == LpPolicy::PermanentLock,
+        ErrorCode::LpPolicyNotSupported
+    );
+
+    let mint_key = deposit.token_mint.key();
+    let position_bump = check_pda(position, &[b"dlmm_position", mint_key.as_ref()])?;
+    let position_seeds: &[&[u8]] = &[b"dlmm_position", mint_key.as_ref(), &[position_bump]];
+
+    // PresetParameter starts with its bin step and base factor, right after the discriminator
+    check_config(preset_parameter, deposit)?;
+    require_keys_eq!(
+        *preset_parameter.owner,
+        deposit.dex_program.key(),
+        ErrorCode::InvalidMigrationAccount
+    );
+    let (bin_step, base_factor) = {
+        let preset = preset_parameter.try_borrow_data()?;
+        (read_u16(&preset, 8)?, read_u16(&preset, 10)?)
+    };
+    let curve_active_id = active_id_from_amounts(deposit.price_token, deposit.price_sol, bin_step)
+        .ok_or(ErrorCode::PoolPriceOutOfRange)?;
+    let bitmap_extension_access = if bitmap_extension.key() == deposit.dex_program.key() {
+        Access::Read
+    } else {
+        Access::Write
+    };
+
+    let active_id = if pool_exists(lb_pair, deposit)? {
+        existing_pair_active_id(lb_pair, deposit, bin_step, base_factor, curve_active_id)?
+    } else {
+        invoke_dex(
+            &deposit.dex_program,
+            INITIALIZE_LB_PAIR_DISCRIMINATOR,
+            InitializeLbPairArgs { active_id: curve_active_id, bin_step },
+            &[
+                (lb_pair, Access::Write),
+                (bitmap_extension, bitmap_extension_access),
+                (&deposit.token_mint, Access::Read),
+                (&deposit.wsol_mint, Access::Read),
+                (reserve_x, Access::Write),
+                (reserve_y, Access::Write),
+                (oracle, Access::Write),
+                (preset_parameter, Access::Read),
+                (&deposit.migration_authority, Access::Signer),
+                (&deposit.token_program, Access::Read),
+                (&deposit.system_program, Access::Read),
+                (rent, Access::Read),
+                (event_authority, Access::Read),
+                (&deposit.dex_program, Access::Read),
+            ],
+            &[migration_authority_seeds],
+        )?;
+        curve_active_id
+    };
+
+    // The position may span two bin arrays; create whichever do not exist yet
+    let lower_bin_id = active_id - POSITION_HALF_WIDTH;
+    let upper_bin_id = active_id + POSITION_HALF_WIDTH;
+    let lower_index = lower_bin_id.div_euclid(BINS_PER_ARRAY);
+    let upper_index = upper_bin_id.div_euclid(BINS_PER_ARRAY);
+    for (bin_array, index) in [(bin_array_lower, lower_index), (bin_array_upper, upper_index)] {
+        if bin_array.data_is_empty() {
+            invoke_dex(
+                &deposit.dex_program,
+                INITIALIZE_BIN_ARRAY_DISCRIMINATOR,
+                i64::from(index),
+                &[
+                    (lb_pair, Access::Read),
+                    (bin_array, Access::Write),
+                    (&deposit.migration_authority, Access::Signer),
+                    (&deposit.system_program, Access::Read),
+                ],
+                &[migration_authority_seeds],
+            )?;
+        }
+        if lower_index == upper_index {
+            break;
+        }
+    }
+
+    invoke_dex(
+        &deposit.dex_program,
+        INITIALIZE_POSITION_DISCRIMINATOR,
+        InitializePositionArgs {
+            lower_bin_id,
+            width: 2 * POSITION_HALF_WIDTH + 1,
+        },
+        &[
+            (&deposit.migration_authority, Access::Signer),
+            (position, Access::Signer),
+            (lb_pair, Access::Read),
+            (&deposit.migration_authority, Access::Signer),
+            (&deposit.system_program, Access::Read),
+            (rent, Access::Read),
+            (event_authority, Access::Read),
+            (&deposit.dex_program, Access::Read),
+        ],
+        &[migration_authority_seeds, position_seeds],
+    )?;
+
+    invoke_dex(
+        &deposit.dex_program,
+        ADD_LIQUIDITY_BY_STRATEGY_DISCRIMINATOR,
+        LiquidityParameterByStrategy {
+            amount_x: deposit.token_amount,
+            amount_y: deposit.sol_amount,
+            active_id,
+            max_active_bin_slippage: 0,
+            strategy_parameters: StrategyParameters {
+                min_bin_id: lower_bin_id,
+                max_bin_id: upper_bin_id,
+                strategy_type: StrategyType::SpotImBalanced,
+                parameteres: [0; 64],
+            },
+        },
+        &[
+            (position, Access::Write),
+            (lb_pair, Access::Write),
+            (bitmap_extension, bitmap_extension_access),
+            (&deposit.token_account, Access::Write),
+            (&deposit.wsol_account, Access::Write),
+            (reserve_x, Access::Write),
+            (reserve_y, Access::Write),
+            (&deposit.token_mint, Access::Read),
+            (&deposit.wsol_mint, Access::Read),
+            (bin_array_lower, Access::Write),
+            (bin_array_upper, Access::Write),
+            (&deposit.migration_authority, Access::Signer),
+            (&deposit.token_program, Access::Read),
+            (&deposit.wsol_token_program, Access::Read),
+            (event_authority, Access::Read),
+            (&deposit.dex_program, Access::Read),
+        ],
+        &[migration_authority_seeds],
+    )?;
+
+    Ok(CreatedPool {
+        pool: lb_pair.key(),
+        lp_mint: Pubkey::default(),
+    })
+}
+
+/// Active bin of a pair created before the migration. It must pair the launched mint,
+/// as token X, with WSOL under the registered preset's fees, and sit within the price
+/// tolerance of `curve_active_id` with room for the whole position.
+fn existing_pair_active_id(
+    lb_pair: &AccountInfo,
+    deposit: &PoolDeposit,
+    bin_step: u16,
+    base_factor: u16,
+    curve_active_id: i32,
+) -> Result<i32> {
+    let data = lb_pair.try_borrow_data()?;
+    require!(
+        read_u16(&data, PAIR_BIN_STEP_OFFSET)? == bin_step
+            && read_u16(&data, PAIR_BASE_FACTOR_OFFSET)? == base_factor,
+        ErrorCode::InvalidMigrationAccount
+    );
+    // A pair quoting the mint in WSOL's place is priced the other way up
+    let mint_at = |offset: usize| {
+        data.get(offset..offset + 32)
+            .and_then(|bytes| Pubkey::try_from(bytes).ok())
+    };
+    require!(
+        mint_at(PAIR_TOKEN_X_MINT_OFFSET) == Some(deposit.token_mint.key())
+            && mint_at(PAIR_TOKEN_Y_MINT_OFFSET) == Some(deposit.wsol_mint.key()),
+        ErrorCode::PoolPriceMismatch
+    );
+    let active_id = data
+        .get(PAIR_ACTIVE_ID_OFFSET..PAIR_ACTIVE_ID_OFFSET + 4)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(i32::from_le_bytes)
+        .ok_or(ErrorCode::InvalidMigrationAccount)?;
+
+    // Each bin moves the price by `bin_step` basis points
+    let drift = u64::from(active_id.abs_diff(curve_active_id));
+    require!(
+        drift.saturating_mul(u64::from(bin_step)) <= EXISTING_POOL_PRICE_TOLERANCE_BPS,
+        ErrorCode::PoolPriceMismatch
+    );
+    let limit = MAX_BIN_ID - POSITION_HALF_WIDTH;
+    require!((-limit..=limit).contains(&active_id), ErrorCode::PoolPriceOutOfRange);
+    Ok(active_id)
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16> {
+    data.get(offset..offset + 2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+        .ok_or_else(|| error!(ErrorCode::InvalidMigrationAccount))
+}
+
+/// Claims the fees accrued by the migration authority's position.
+pub fn claim_fees<'info>(
+    claim: &FeeClaim<'info>,
+    remaining: &[AccountInfo<'info>],
+    migration_authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let [lb_pair, position, bin_array_lower, bin_array_upper, reserve_x, reserve_y, event_authority] =
+        take_accounts::<7>(remaining)?;
+
+    invoke_dex(
+        &claim.dex_program,
+        CLAIM_FEE_DISCRIMINATOR,
+        (),
+        &[
+            (lb_pair, Access::Write),
+            (position, Access::Write),
+            (bin_array_lower, Access::Write),
+            (bin_array_upper, Access::Write),
+            (&claim.migration_authority, Access::Signer),
+            (reserve_x, Access::Write),
+            (reserve_y, Access::Write),
+            (&claim.token_account, Access::Write),
+            (&claim.wsol_account, Access::Write),
+            (&claim.token_mint, Access::Read),
+            (&claim.wsol_mint, Access::Read),
+            (&claim.token_program, Access::Read),
+            (event_authority, Access::Read),
+            (&claim.dex_program, Access::Read),
+        ],
+        &[migration_authority_seeds],
+    )
+}
+
+/// Bin whose price, `(1 + bin_step / 10_000) ^ id` lamports per raw token unit,
+/// is the largest not above the curve's final price.
+pub fn active_id_from_amounts(token_amount: u64, sol_amount: u64, bin_step: u16) -> Option<i32> {
+    if token_amount == 0 || sol_amount == 0 || bin_step == 0 {
+        return None;
+    }
+    // Q64.64, like DLMM's own bin prices
+    let one = 1u128 << 64;
+    let price = (u128::from(sol_amount) << 64) / u128::from(token_amount);
+    if price == 0 {
+        return None;
+    }
+
+    // base ^ 2^i for every bit of a bin id; `None` once a power overflows
+    let mut powers = [None; ID_BITS];
+    powers[0] = Some(one + (u128::from(bin_step) << 64) / 10_000);
+    for i in 1..ID_BITS {
+        powers[i] = powers[i - 1].and_then(|power| mul_q64(power, power));
+    }
+
+    // Build the exponent bit by bit from the top, keeping the running power on the
+    // right side of the price
+    let mut exponent = 0i32;
+    let id = if price >= one {
+        // Largest id with base ^ id <= price
+        let mut value = one;
+        for i in (0..ID_BITS).rev() {
+            if let Some(next) = powers[i].and_then(|power| mul_q64(value, power)) {
+                if next <= price {
+                    value = next;
+                    exponent |= 1 << i;
+                }
+            }
+        }
+        exponent
+    } else {
+        // Largest k with price * base ^ k < 1, so the bin is -(k + 1)
+        let mut value = price;
+        for i in (0..ID_BITS).rev() {
+            if let Some(next) = powers[i].and_then(|power| mul_q64(value, power)) {
+                if next < one {
+                    value = next;
+                    exponent |= 1 << i;
+                }
+            }
+        }
+        -exponent - 1
+    };
+    // Keep the whole position inside the valid bin range
+    let limit = MAX_BIN_ID - POSITION_HALF_WIDTH;
+    (-limit..=limit).contains(&id).then_some(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn active_id_matches_bin_prices() {
+        // Equal amounts sit exactly on bin 0
+        assert_eq!(active_id_from_amounts(1_000, 1_000, 25), Some(0));
+        // 1.0025 ^ 277 ~ 1.9977 and 1.0025 ^ 278 ~ 2.0027
+        assert_eq!(active_id_from_amounts(1_000, 2_000, 25), Some(277));
+        assert_eq!(active_id_from_amounts(2_000, 1_000, 25), Some(-278));
+        // Just below a bin's price rounds down to the bin beneath
+        assert_eq!(active_id_from_amounts(10_000, 10_024, 25), Some(0));
+        assert_eq!(active_id_from_amounts(10_000, 10_025, 25), Some(1));
+        assert_eq!(active_id_from_amounts(10_000, 9_999, 25), Some(-1));
+        // A typical graduation: 85 SOL against 200M six-decimal tokens
+        assert_eq!(
+            active_id_from_amounts(200_000_000_000_000, 85_000_000_000, 100),
+            Some(-781)
+        );
+    }
+
+    #[test]
+    fn active_id_rejects_prices_outside_the_bin_range() {
+        assert_eq!(active_id_from_amounts(0, 1, 25), None);
+        assert_eq!(active_id_from_amounts(1, 1, 0), None);
+        // 2^-64 and 2^64 are past the last bin at a 0.01% step
+        assert_eq!(active_id_from_amounts(u64::MAX, 1, 1), None);
+        assert!(active_id_from_amounts(1, u64::MAX, 1).is_none());
+        // but inside it at 1%
+        assert_eq!(active_id_from_amounts(1, u64::MAX, 100), Some(4458));
+    }
+}
@@ -0,0 +1,171 @@
+// DEX adapters used by `migrate` and `harvest_pool_fees`. Each one seeds a pool from
+// a `PoolDeposit`, applies the curve's `LpPolicy` to the LP it gets back, and
+// takes its DEX-specific accounts from the instruction's remaining accounts in
+// the order documented at the top of the adapter.
+pub mod damm_v2;
+pub mod dlmm;
+pub mod cpmm;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+use crate::consts::BPS_DENOMINATOR;
+use crate::errors::ErrorCode;
+use crate::states::{LpPolicy, MigrationTarget};
+
+/// What a graduated curve brings to its pool, held by the migration authority.
+pub struct PoolDeposit<'info> {
+    pub dex_program: AccountInfo<'info>,
+    pub migration_authority: AccountInfo<'info>,
+    pub token_mint: AccountInfo<'info>,
+    pub token_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub wsol_mint: AccountInfo<'info>,
+    pub wsol_account: AccountInfo<'info>,
+    pub wsol_token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub token_amount: u64,
+    pub sol_amount: u64,
+    // The curve's final price as a SOL / token ratio, which the pool opens at
+    pub price_sol: u64,
+    pub price_token: u64,
+    pub lp_policy: LpPolicy,
+    pub pool_config: Pubkey,
+}
+
+/// The pool a migration created, and the mint of the LP the migration authority holds
+/// in it: CPMM LP tokens or the DAMM v2 position NFT. DLMM positions have no mint.
+pub struct CreatedPool {
+    pub pool: Pubkey,
+    pub lp_mint: Pubkey,
+}
+
+/// Where the fees of a position held by the migration authority are paid out.
+pub struct FeeClaim<'info> {
+    pub dex_program: AccountInfo<'info>,
+    pub migration_authority: AccountInfo<'info>,
+    pub token_mint: AccountInfo<'info>,
+    pub token_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub wsol_mint: AccountInfo<'info>,
+    pub wsol_account: AccountInfo<'info>,
+    pub wsol_token_program: AccountInfo<'info>,
+}
+
+/// Claims the migration authority's position fees on `target`.
+pub fn claim_fees<'info>(
+    target: MigrationTarget,
+    claim: &FeeClaim<'info>,
+    remaining: &[AccountInfo<'info>],
+    migration_authority_seeds: &[&[u8]],
+) -> Result<()> {
+    match target {
+        MigrationTarget::DammV2 => damm_v2::claim_fees(claim, remaining, migration_authority_seeds),
+        MigrationTarget::Dlmm => dlmm::claim_fees(claim, remaining, migration_authority_seeds),
+        // CP-Swap fees compound into the pool
+        MigrationTarget::Cpmm => err!(ErrorCode::LpFeeClaimNotSupported),
+    }
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum Access {
+    Read,
+    Write,
+    Signer,
+}
+
+/// Invokes an Anchor instruction on a DEX program with PDA signers.
+pub(crate) fn invoke_dex<'info>(
+    program: &AccountInfo<'info>,
+    discriminator: [u8; 8],
+    args: impl AnchorSerialize,
+    accounts: &[(&AccountInfo<'info>, Access)],
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = discriminator.to_vec();
+    args.serialize(&mut data)?;
+
+    let metas = accounts
+        .iter()
+        .map(|(info, access)| match access {
+            Access::Read => AccountMeta::new_readonly(*info.key, false),
+            Access::Write => AccountMeta::new(*info.key, false),
+            Access::Signer => AccountMeta::new(*info.key, true),
+        })
+        .collect();
+    let mut infos: Vec<AccountInfo<'info>> =
+        accounts.iter().map(|(info, _)| (*info).clone()).collect();
+    infos.push(program.clone());
+
+    invoke_signed(
+        &Instruction {
+            program_id: *program.key,
+            accounts: metas,
+            data,
+        },
+        &infos,
+        signer_seeds,
+    )?;
+    Ok(())
+}
+
+/// Checks that `account` is this program's PDA for `seeds` and returns its bump.
+pub(crate) fn check_pda(account: &AccountInfo, seeds: &[&[u8]]) -> Result<u8> {
+    let (expected, bump) = Pubkey::find_program_address(seeds, &crate::ID);
+    require_keys_eq!(account.key(), expected, ErrorCode::InvalidMigrationAccount);
+    Ok(bump)
+}
+
+/// Checks that the config account passed for the pool is the one registered in `Global`.
+pub(crate) fn check_config(account: &AccountInfo, deposit: &PoolDeposit) -> Result<()> {
+    require_keys_eq!(account.key(), deposit.pool_config, ErrorCode::InvalidMigrationAccount);
+    Ok(())
+}
+
+/// Whether the pool was created before the migration. Its address follows from the
+/// pinned config and the mints, so anyone can create it first, at any price; the
+/// adapter then seeds the existing pool if `check_pool_price` accepts its price.
+pub(crate) fn pool_exists(pool: &AccountInfo, deposit: &PoolDeposit) -> Result<bool> {
+    if pool.data_is_empty() {
+        return Ok(false);
+    }
+    require_keys_eq!(*pool.owner, deposit.dex_program.key(), ErrorCode::InvalidMigrationAccount);
+    Ok(true)
+}
+
+/// Checks that an existing pool's price is within `tolerance_bps` of the curve's.
+/// Anyone can trade a pool opened elsewhere back to the curve's price and then migrate.
+pub(crate) fn check_pool_price(pool_price: u128, curve_price: u128, tolerance_bps: u64) -> Result<()> {
+    let tolerance = curve_price
+        .checked_mul(u128::from(tolerance_bps))
+        .ok_or(ProgramError::ArithmeticOverflow)?
+        / u128::from(BPS_DENOMINATOR);
+    require!(pool_price.abs_diff(curve_price) <= tolerance, ErrorCode::PoolPriceMismatch);
+    Ok(())
+}
+
+/// Splits the first `N` remaining accounts off for an adapter.
+pub(crate) fn take_accounts<'a, 'info, const N: usize>(
+    remaining: &'a [AccountInfo<'info>],
+) -> Result<&'a [AccountInfo<'info>; N]> {
+    remaining
+        .get(..N)
+        .and_then(|accounts| accounts.try_into().ok())
+        .ok_or_else(|| error!(ErrorCode::MissingMigrationAccounts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pool_price_tolerance_is_inclusive_both_ways() {
+        assert!(check_pool_price(10_100, 10_000, 100).is_ok());
+        assert!(check_pool_price(9_900, 10_000, 100).is_ok());
+        assert!(check_pool_price(10_101, 10_000, 100).is_err());
+        assert!(check_pool_price(9_899, 10_000, 100).is_err());
+        assert!(check_pool_price(10_000, 10_000, 0).is_ok());
+    }
+}
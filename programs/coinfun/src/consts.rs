@@ -1,5 +1,21 @@
 // Maximum fee basis points (30%)
 pub const MAX_FEE_BPS: u64 = 3000;
 
+// Maximum fee basis points while a launch fee decays (50%), base fees included
+pub const MAX_LAUNCH_FEE_BPS: u64 = 5000;
+
+// Payees `distribute_platform_fees` can split the platform fee vault between
+pub const MAX_PLATFORM_FEE_RECIPIENTS: usize = 5;
+
 // Basis points denominator (10000 = 100%)
-pub const BPS_DENOMINATOR: u64 = 10000;
+pub const BPS_DENOMINATOR: u64 = curve_math::BPS_DENOMINATOR;
+
+// How far from the curve's final price a pool created before `migrate` may trade
+// for `migrate` to seed it anyway (1%)
+pub const EXISTING_POOL_PRICE_TOLERANCE_BPS: u64 = 100;
+
+// Fixed-point scale of `StakePool::reward_per_share`
+pub const REWARD_PER_SHARE_PRECISION: u128 = 1_000_000_000_000;
+
+// Slots a stake pool streams newly swept rewards over (about a day at 400ms slots)
+pub const REWARD_STREAM_SLOTS: u64 = 216_000;
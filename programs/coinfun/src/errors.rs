@@ -12,6 +12,72 @@ pub enum ErrorCode {
     BondingCurveNotComplete,
     #[msg("Fee basis points cannot exceed 3000 (30%)")]
     FeeTooHigh,
-    #[msg("Total supply must be greater than the initial real token reserves.")]
+    #[msg("Initial virtual token reserves must be greater than the total supply")]
     InvalidTokenReserveConfiguration,
+    #[msg("Bonding curve has nothing left to migrate")]
+    NothingToMigrate,
+    #[msg("Pool price derived from the curve is out of range")]
+    PoolPriceOutOfRange,
+    #[msg("Bonding curve cannot move to that status")]
+    InvalidCurveStatusTransition,
+    #[msg("Bonding curve is not refunding")]
+    CurveNotRefunding,
+    #[msg("Migration target is not enabled")]
+    MigrationTargetNotAllowed,
+    #[msg("Not enough remaining accounts for the migration target")]
+    MissingMigrationAccounts,
+    #[msg("Unexpected account passed for the migration target")]
+    InvalidMigrationAccount,
+    #[msg("LP policy is not supported by the migration target")]
+    LpPolicyNotSupported,
+    #[msg("Migration target has no LP fees to claim")]
+    LpFeeClaimNotSupported,
+    #[msg("LP position is locked")]
+    LpLocked,
+    #[msg("Bonding curve has not been migrated")]
+    CurveNotMigrated,
+    #[msg("The curve's DEX pool already exists at another price")]
+    PoolPriceMismatch,
+    #[msg("Bonding curve cannot fill that token amount before graduating")]
+    TokenAmountUnavailable,
+    #[msg("Bonding curve cannot pay out that SOL amount")]
+    SolAmountUnavailable,
+    #[msg("Curve kind not allowed")]
+    CurveKindNotAllowed,
+    #[msg("Invalid curve parameters")]
+    InvalidCurveParams,
+    #[msg("Curve parameters outside the allowed bounds")]
+    CurveParamsOutOfBounds,
+    #[msg("SOL/USD price feed is not configured or not passed")]
+    MissingPriceFeed,
+    #[msg("Invalid SOL/USD price feed account")]
+    InvalidPriceFeed,
+    #[msg("SOL/USD price is too old")]
+    PriceFeedStale,
+    #[msg("SOL/USD price confidence interval is too wide")]
+    PriceFeedUncertain,
+    #[msg("Invalid launch window")]
+    InvalidLaunchWindow,
+    #[msg("Buy exceeds the launch window's SOL cap")]
+    LaunchBuyTooLarge,
+    #[msg("Wallet exceeds the launch window's token cap")]
+    LaunchWalletCapExceeded,
+    #[msg("Launch window buys need the wallet's launch allocation account")]
+    MissingLaunchAllocation,
+    #[msg("Trading on this curve has not started yet")]
+    TradingNotStarted,
+    #[msg("Invalid launch fee")]
+    InvalidLaunchFee,
+    #[msg("Launch fee basis points cannot exceed 5000 (50%)")]
+    LaunchFeeTooHigh,
+    #[msg("Traders cannot refer themselves")]
+    SelfReferral,
+    #[msg("Invalid platform fee recipients")]
+    InvalidFeeRecipients,
+    #[msg("Amount exceeds what the reserve holds")]
+    InsufficientReserve,
+    #[msg("Unstake exceeds the staked amount")]
+    InsufficientStake,
+    #[msg("The mint's reserve belongs to its stakers")]
+    ReserveStaked,
 }
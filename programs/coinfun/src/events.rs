@@ -1,4 +1,7 @@
 use anchor_lang::prelude::*;
+use crate::states::{
+    CurveStatus, GraduationMode, LpPolicy, MigrationTarget, PlatformFeeRecipient, ReservePolicy,
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub enum TradeSide {
@@ -17,12 +20,205 @@ pub struct Trade {
     pub mint: Pubkey,
     pub trader: Pubkey,
     pub side: TradeSide,
+    // For buys, the filled part of the requested SOL
     pub sol_amount: u64,
     pub token_amount: u64,
+    // Requested SOL left unfilled because the buy graduated the curve
+    pub sol_refunded: u64,
+    // Wallet of the trade's referrer, and its share of the platform fee
+    pub referrer: Option<Pubkey>,
+    pub referral_fee: u64,
+    // What the reserve fee became under the curve's policy: `reserve_token_amount` tokens
+    // accumulated or burned, or `reserve_fee` lamports kept in the reserve SOL vault
+    pub reserve_policy: ReservePolicy,
+    pub reserve_fee: u64,
+    pub reserve_token_amount: u64,
+}
+
+// Which condition graduated a curve
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub enum GraduationCause {
+    SolThreshold,
+    TokenFloor,
+    MarketCap,
+    UsdMarketCap,
+    SoldOut,
+}
+
+impl From<GraduationMode> for GraduationCause {
+    fn from(mode: GraduationMode) -> Self {
+        match mode {
+            GraduationMode::SolThreshold => GraduationCause::SolThreshold,
+            GraduationMode::TokenFloor { .. } => GraduationCause::TokenFloor,
+            GraduationMode::MarketCap { .. } => GraduationCause::MarketCap,
+            GraduationMode::UsdMarketCap { .. } => GraduationCause::UsdMarketCap,
+        }
+    }
 }
 
 #[event]
 pub struct CurveComplete {
     pub mint: Pubkey,
     pub bonding_curve: Pubkey,
+    pub cause: GraduationCause,
+}
+
+#[event]
+pub struct TradingOpened {
+    pub mint: Pubkey,
+    // Maker of the curve's first trade
+    pub trader: Pubkey,
+    pub slot: u64,
+    pub unix_timestamp: i64,
+}
+
+#[event]
+pub struct CurveStatusChanged {
+    pub mint: Pubkey,
+    pub from: CurveStatus,
+    pub to: CurveStatus,
+}
+
+#[event]
+pub struct Refunded {
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+    pub token_amount: u64,
+    pub sol_amount: u64,
+}
+
+#[event]
+pub struct CurveRepriced {
+    pub mint: Pubkey,
+    pub previous_platform_trade_fee_bps: u64,
+    pub previous_reserve_trade_fee_bps: u64,
+    pub previous_creator_trade_fee_bps: u64,
+    pub previous_graduation_threshold: u64,
+    pub platform_trade_fee_bps: u64,
+    pub reserve_trade_fee_bps: u64,
+    pub creator_trade_fee_bps: u64,
+    pub graduation_threshold: u64,
+}
+
+#[event]
+pub struct CurveMigrated {
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub target: MigrationTarget,
+    pub pool: Pubkey,
+    pub lp_mint: Pubkey, // Default for DLMM, whose position has no mint
+    pub token_amount: u64,
+    pub sol_amount: u64,
+    pub lp_policy: LpPolicy,
+    pub lp_unlock_slot: u64,
+    pub keeper: Pubkey,
+    pub keeper_reward: u64,
+    // Paid into the platform fee vault before the pool was seeded with `sol_amount`
+    pub migration_fee: u64,
+    // Left out of the pool to match the curve's final price
+    pub surplus_token_amount: u64,
+    pub surplus_sol_amount: u64,
+    // Deposit the pool did not take, swept to the reserve
+    pub leftover_token_amount: u64,
+    pub leftover_sol_amount: u64,
+}
+
+#[event]
+pub struct MigrationProgramSet {
+    pub target: MigrationTarget,
+    pub program: Pubkey,
+    pub config: Pubkey,
+}
+
+#[event]
+pub struct PriceFeedSet {
+    pub price_feed: Pubkey,
+    pub max_price_age_secs: u64,
+    pub max_price_confidence_bps: u64,
+}
+
+#[event]
+pub struct PlatformFeeRecipientsSet {
+    pub recipients: Vec<PlatformFeeRecipient>,
+}
+
+#[event]
+pub struct PlatformFeesDistributed {
+    // Paid to `recipients[i]`, in `Global` order
+    pub recipients: Vec<Pubkey>,
+    pub amounts: Vec<u64>,
+}
+
+#[event]
+pub struct LpReleased {
+    pub mint: Pubkey,
+    pub lp_mint: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PoolFeesHarvested {
+    pub mint: Pubkey,
+    pub harvester: Pubkey,
+    pub token_amount: u64,
+    pub sol_amount: u64,
+}
+
+#[event]
+pub struct ReferralFeesClaimed {
+    pub referrer: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct CreatorFeesClaimed {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ReserveSolWithdrawn {
+    pub authority: Pubkey,
+    pub amount: u64,
+    // Lamports left in the vault above its rent
+    pub remaining: u64,
+}
+
+#[event]
+pub struct StakePoolCreated {
+    pub mint: Pubkey,
+    pub stake_pool: Pubkey,
+}
+
+#[event]
+pub struct Staked {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub total_staked: u64,
+}
+
+#[event]
+pub struct Unstaked {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub total_staked: u64,
+}
+
+// Reserve tokens swept into a stake pool for its stakers
+#[event]
+pub struct StakingRewardsAccrued {
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub reward_per_share: u128,
+}
+
+#[event]
+pub struct StakingRewardsClaimed {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
 }
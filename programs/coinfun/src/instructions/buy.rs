@@ -1,12 +1,14 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
+    token_interface::{self, Burn, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+use crate::states::{
+    Global, BondingCurve, CurveStatus, GraduationMode, LaunchAllocation, Referrer, ReservePolicy,
 };
-use crate::states::{Global, BondingCurve};
 use crate::errors::ErrorCode;
-use crate::events::{Trade, TradeSide, CurveComplete};
-use crate::consts::BPS_DENOMINATOR;
+use crate::events::{Trade, TradeSide, CurveComplete, GraduationCause};
+use curve_math::{quote_buy, BuyQuote, Graduation, GraduationTrigger};
 
 #[derive(Accounts)]
 pub struct Buy<'info> {
@@ -31,7 +33,9 @@ pub struct Buy<'info> {
         associated_token::authority = signer
     )]
     pub user_ata: InterfaceAccount<'info, TokenAccount>,
+    // Mutable for `ReservePolicy::Burn`
     #[account(
+        mut,
         constraint = bonding_curve.mint == mint.key()
     )]
     pub mint: InterfaceAccount<'info, Mint>,
@@ -40,8 +44,13 @@ pub struct Buy<'info> {
         bump
     )]
     pub global: Account<'info, Global>,
-    #[account(mut, constraint = global.platform_fee_recipient == platform_fee_recipient.key())]
-    pub platform_fee_recipient: SystemAccount<'info>,
+    // Accrues platform fees until `distribute_platform_fees`
+    #[account(
+        mut,
+        seeds = [b"platform_fee_vault"],
+        bump
+    )]
+    pub platform_fee_vault: SystemAccount<'info>,
     /// CHECK: Global reserve PDA (authority for all reserve ATAs)
     #[account(
         seeds = [b"reserve"], bump,
@@ -56,128 +65,196 @@ pub struct Buy<'info> {
         associated_token::token_program = token_program,
     )]
     pub reserve_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"creator_vault", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub creator_vault: SystemAccount<'info>,
+    // Receives the reserve fee of `KeepSol` curves
+    #[account(
+        mut,
+        seeds = [b"reserve_sol_vault"],
+        bump
+    )]
+    pub reserve_sol_vault: SystemAccount<'info>,
     pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+    /// CHECK: SOL/USD price account, only needed by `UsdMarketCap` curves; checked against
+    /// `Global.sol_usd_price_feed` and decoded in `Global::sol_usd_price`
+    pub price_feed: Option<UncheckedAccount<'info>>,
+    // Only needed during the curve's launch window
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + LaunchAllocation::INIT_SPACE,
+        seeds = [b"launch_allocation", bonding_curve.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub launch_allocation: Option<Account<'info, LaunchAllocation>>,
+    // Optional; a registered referrer earns `referral_fee_bps` of the platform fee
+    #[account(
+        mut,
+        seeds = [b"referrer", referrer.authority.as_ref()],
+        bump
+    )]
+    pub referrer: Option<Account<'info, Referrer>>,
+}
+
+impl Buy<'_> {
+    /// The curve's graduation trigger, priced from the SOL/USD feed if it needs it.
+    pub fn graduation_trigger(&self) -> Result<GraduationTrigger> {
+        let sol_usd_price = match self.bonding_curve.graduation_mode {
+            GraduationMode::UsdMarketCap { .. } => {
+                let price_feed = self.price_feed.as_ref().ok_or(ErrorCode::MissingPriceFeed)?;
+                Some(self.global.sol_usd_price(price_feed)?)
+            }
+            _ => None,
+        };
+        self.bonding_curve.graduation_trigger(sol_usd_price)
+    }
 }
 
 pub fn handler(ctx: Context<Buy>, sol_amount: u64, min_token_output: u64) -> Result<()> {
+    let trigger = ctx.accounts.graduation_trigger()?;
     let curve = &mut ctx.accounts.bonding_curve;
-    require!(!curve.complete, ErrorCode::BondingCurveComplete);
+    require!(curve.status == CurveStatus::Active, ErrorCode::BondingCurveComplete);
     require_gt!(sol_amount, 0);
 
-    // Calculate fees consistently for all trades
-    let total_fee_bps = ctx
-        .accounts
-        .global
-        .platform_trade_fee_bps
-        .checked_add(ctx.accounts.global.reserve_trade_fee_bps)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-    let fee = sol_amount
-        .checked_mul(total_fee_bps)
-        .and_then(|res| res.checked_div(BPS_DENOMINATOR))
-        .ok_or(ProgramError::ArithmeticOverflow)?;
+    // The reserve fee buys its tokens first, then the rest buys the user's. The graduating
+    // buy is only filled up to the curve's trigger or the remaining tokens; the unfilled
+    // part is refunded by never being taken from the buyer
+    let quote = quote_buy(
+        &curve.curve_state(),
+        curve.fee_rates(Clock::get()?.slot),
+        trigger,
+        sol_amount,
+    )
+    .ok_or(ProgramError::ArithmeticOverflow)?;
+    require_gt!(quote.sol_in, 0);
 
-    // Split fees between platform and reserve
-    let platform_fee = sol_amount
-        .checked_mul(ctx.accounts.global.platform_trade_fee_bps)
-        .and_then(|res| res.checked_div(BPS_DENOMINATOR))
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-    let reserve_fee = sol_amount
-        .checked_mul(ctx.accounts.global.reserve_trade_fee_bps)
-        .and_then(|res| res.checked_div(BPS_DENOMINATOR))
+    // Slippage is checked at the price implied by the request, scaled to the filled part
+    let min_filled_output = u128::from(min_token_output)
+        .checked_mul(u128::from(quote.sol_in))
+        .and_then(|res| res.checked_div(u128::from(sol_amount)))
         .ok_or(ProgramError::ArithmeticOverflow)?;
+    require_gte!(u128::from(quote.tokens_out), min_filled_output);
 
-    let sol_amount_after_fee = sol_amount
-        .checked_sub(fee)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
+    settle(ctx, quote, trigger)
+}
 
-    // Calculate tokens for reserve if reserve_fee > 0 (must happen BEFORE user purchase)
-    let mut reserve_tokens_out: u64 = 0;
-    if reserve_fee > 0 {
-        // Use reserve fee to buy tokens at the current rate
-        let reserve_k = u128::from(curve.virtual_sol_reserves)
-            .checked_mul(u128::from(curve.virtual_token_reserves))
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-        let reserve_new_virtual_sol_reserves = curve
-            .virtual_sol_reserves
-            .checked_add(reserve_fee)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-        let reserve_new_virtual_token_reserves = reserve_k
-            .checked_div(u128::from(reserve_new_virtual_sol_reserves))
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-        reserve_tokens_out = curve
-            .virtual_token_reserves
-            .checked_sub(reserve_new_virtual_token_reserves as u64)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-        
-        // Update virtual reserves for reserve purchase
-        curve.virtual_sol_reserves = reserve_new_virtual_sol_reserves;
-        curve.virtual_token_reserves = reserve_new_virtual_token_reserves as u64;
-        curve.real_sol_reserves = curve
-            .real_sol_reserves
-            .checked_add(reserve_fee)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-        curve.real_token_reserves = curve
-            .real_token_reserves
-            .checked_sub(reserve_tokens_out)
+/// Applies a buy quote: updates the curve, moves the SOL and tokens, emits the
+/// trade and graduates the curve if the buy met `trigger` or sold it out.
+pub(crate) fn settle(ctx: Context<Buy>, quote: BuyQuote, trigger: GraduationTrigger) -> Result<()> {
+    let curve = &mut ctx.accounts.bonding_curve;
+    let clock = Clock::get()?;
+    curve.open_for_trade(&clock, ctx.accounts.signer.key())?;
+    // Anti-sniper caps: per buy, and per wallet over the window
+    if curve.in_launch_window(clock.slot) {
+        let window = curve.launch_window;
+        require_gte!(window.max_sol_per_buy, quote.sol_in, ErrorCode::LaunchBuyTooLarge);
+        let allocation = ctx
+            .accounts
+            .launch_allocation
+            .as_mut()
+            .ok_or(ErrorCode::MissingLaunchAllocation)?;
+        allocation.tokens_bought = allocation
+            .tokens_bought
+            .checked_add(quote.tokens_out)
             .ok_or(ProgramError::ArithmeticOverflow)?;
+        require_gte!(
+            window.max_tokens_per_wallet,
+            allocation.tokens_bought,
+            ErrorCode::LaunchWalletCapExceeded
+        );
     }
-
-    // Calculate token output for user using the constant product formula
-    let k = u128::from(curve.virtual_sol_reserves)
-        .checked_mul(u128::from(curve.virtual_token_reserves))
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-
-    let new_virtual_sol_reserves = curve
-        .virtual_sol_reserves
-        .checked_add(sol_amount_after_fee)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-
-    let new_virtual_token_reserves = k
-        .checked_div(u128::from(new_virtual_sol_reserves))
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-
-    let tokens_out = curve
-        .virtual_token_reserves
-        .checked_sub(new_virtual_token_reserves as u64)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-
-    require_gte!(tokens_out, min_token_output);
-    require_gte!(curve.real_token_reserves, tokens_out);
-
-    // State Updates for user purchase
-    curve.virtual_sol_reserves = new_virtual_sol_reserves;
-    curve.virtual_token_reserves = new_virtual_token_reserves as u64;
-    curve.real_sol_reserves = curve
-        .real_sol_reserves
-        .checked_add(sol_amount_after_fee)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-    curve.real_token_reserves = curve
-        .real_token_reserves
-        .checked_sub(tokens_out)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let platform_fee = quote.fees.platform_fee;
+    let creator_fee = quote.fees.creator_fee;
+    let reserve_fee = quote.fees.reserve_fee;
+    let reserve_tokens_out = quote.reserve_tokens_out;
+    let tokens_out = quote.tokens_out;
+    let reserve_policy = curve.reserve_policy;
+    curve.set_curve_state(quote.curve);
+    // The referrer's share comes out of the platform fee
+    let referral_fee = match &ctx.accounts.referrer {
+        Some(referrer) => {
+            require_keys_neq!(referrer.authority, ctx.accounts.signer.key(), ErrorCode::SelfReferral);
+            ctx.accounts
+                .global
+                .referral_fee(platform_fee)
+                .ok_or(ProgramError::ArithmeticOverflow)?
+        }
+        None => 0,
+    };
 
     // SOL Transfers (CPIs)
-    // Platform fee always goes to platform_fee_recipient
-    if platform_fee > 0 {
+    // Platform fee, less any referral fee, accrues in the platform fee vault, which
+    // `initialize` funded to rent exemption
+    let platform_fee_to_vault = platform_fee
+        .checked_sub(referral_fee)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if platform_fee_to_vault > 0 {
         let platform_fee_transfer_cpi_context = CpiContext::new(
             ctx.accounts.system_program.to_account_info(),
             anchor_lang::system_program::Transfer {
                 from: ctx.accounts.signer.to_account_info(),
-                to: ctx.accounts.platform_fee_recipient.to_account_info(),
+                to: ctx.accounts.platform_fee_vault.to_account_info(),
             },
         );
+        anchor_lang::system_program::transfer(platform_fee_transfer_cpi_context, platform_fee_to_vault)?;
+    }
+
+    if let Some(referrer) = ctx.accounts.referrer.as_mut().filter(|_| referral_fee > 0) {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.signer.to_account_info(),
+                    to: referrer.to_account_info(),
+                },
+            ),
+            referral_fee,
+        )?;
+        referrer.accrue(referral_fee)?;
+    }
+
+    // Creator fee accrues in the curve's creator vault until claimed
+    if creator_fee > 0 {
         anchor_lang::system_program::transfer(
-            platform_fee_transfer_cpi_context,
-            platform_fee,
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.signer.to_account_info(),
+                    to: ctx.accounts.creator_vault.to_account_info(),
+                },
+            ),
+            creator_fee,
+        )?;
+    }
+
+    // `KeepSol` curves pay the reserve fee into the reserve SOL vault instead
+    let reserve_sol = if reserve_policy == ReservePolicy::KeepSol { reserve_fee } else { 0 };
+    if reserve_sol > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.signer.to_account_info(),
+                    to: ctx.accounts.reserve_sol_vault.to_account_info(),
+                },
+            ),
+            reserve_sol,
         )?;
     }
 
     // All remaining SOL (user's portion + reserve fee) goes to bonding curve
     // Reserve fee is already accounted for in state updates above
-    let total_sol_to_curve = sol_amount_after_fee
-        .checked_add(reserve_fee)
+    let total_sol_to_curve = quote
+        .sol_in
+        .checked_sub(platform_fee)
+        .and_then(|sol| sol.checked_sub(creator_fee))
+        .and_then(|sol| sol.checked_sub(reserve_sol))
         .ok_or(ProgramError::ArithmeticOverflow)?;
     let sol_transfer_cpi_context = CpiContext::new(
         ctx.accounts.system_program.to_account_info(),
@@ -188,24 +265,35 @@ pub fn handler(ctx: Context<Buy>, sol_amount: u64, min_token_output: u64) -> Res
     );
     anchor_lang::system_program::transfer(sol_transfer_cpi_context, total_sol_to_curve)?;
 
-    // Transfer reserve tokens to reserve ATA
-    if reserve_fee > 0 {
+    // Transfer reserve tokens to reserve ATA, or burn them
+    if reserve_tokens_out > 0 {
         let bonding_curve_seeds: &[&[&[u8]]] = &[&[
             b"bonding_curve",
             ctx.accounts.mint.to_account_info().key.as_ref(),
             &[ctx.bumps.bonding_curve],
         ]];
-        let decimals = ctx.accounts.mint.decimals;
-        let reserve_cpi_accounts = TransferChecked {
-            mint: ctx.accounts.mint.to_account_info(),
-            from: ctx.accounts.bonding_curve_ata.to_account_info(),
-            to: ctx.accounts.reserve_ata.to_account_info(),
-            authority: curve.to_account_info(),
-        };
         let reserve_cpi_program = ctx.accounts.token_program.to_account_info();
-        let reserve_cpi_context = CpiContext::new(reserve_cpi_program, reserve_cpi_accounts)
-            .with_signer(bonding_curve_seeds);
-        token_interface::transfer_checked(reserve_cpi_context, reserve_tokens_out, decimals)?;
+        if reserve_policy == ReservePolicy::Burn {
+            let burn_cpi_accounts = Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.bonding_curve_ata.to_account_info(),
+                authority: curve.to_account_info(),
+            };
+            let burn_cpi_context = CpiContext::new(reserve_cpi_program, burn_cpi_accounts)
+                .with_signer(bonding_curve_seeds);
+            token_interface::burn(burn_cpi_context, reserve_tokens_out)?;
+        } else {
+            let decimals = ctx.accounts.mint.decimals;
+            let reserve_cpi_accounts = TransferChecked {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.bonding_curve_ata.to_account_info(),
+                to: ctx.accounts.reserve_ata.to_account_info(),
+                authority: curve.to_account_info(),
+            };
+            let reserve_cpi_context = CpiContext::new(reserve_cpi_program, reserve_cpi_accounts)
+                .with_signer(bonding_curve_seeds);
+            token_interface::transfer_checked(reserve_cpi_context, reserve_tokens_out, decimals)?;
+        }
     }
 
     // Token Transfer (CPI) for user
@@ -230,21 +318,33 @@ pub fn handler(ctx: Context<Buy>, sol_amount: u64, min_token_output: u64) -> Res
         mint: ctx.accounts.mint.key(),
         trader: ctx.accounts.signer.key(),
         side: TradeSide::Buy,
-        sol_amount,
+        sol_amount: quote.sol_in,
         token_amount: tokens_out,
+        sol_refunded: quote.sol_refunded,
+        referrer: ctx.accounts.referrer.as_ref().map(|referrer| referrer.authority),
+        referral_fee,
+        reserve_policy,
+        reserve_fee,
+        reserve_token_amount: reserve_tokens_out,
     });
 
     // Check for graduation
-    if curve.real_sol_reserves >= ctx.accounts.global.graduation_threshold {
-        curve.complete = true;
+    if let Some(graduation) = curve.curve_state().graduation(trigger) {
+        let cause = match graduation {
+            Graduation::SoldOut => GraduationCause::SoldOut,
+            Graduation::Trigger => curve.graduation_mode.into(),
+        };
+        curve.set_status(CurveStatus::Complete)?;
         msg!("Bonding curve has graduated!");
         
         // Emit curve complete event
         emit!(CurveComplete {
             mint: ctx.accounts.mint.key(),
             bonding_curve: ctx.accounts.bonding_curve.key(),
+            cause,
         });
     }
 
     Ok(())
 }
+
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::states::CurveStatus;
+use crate::errors::ErrorCode;
+use crate::instructions::buy::{settle, Buy};
+use curve_math::quote_buy_exact_out;
+
+// Takes the same accounts as `buy`
+pub fn handler(ctx: Context<Buy>, token_amount: u64, max_sol_cost: u64) -> Result<()> {
+    let trigger = ctx.accounts.graduation_trigger()?;
+    let curve = &ctx.accounts.bonding_curve;
+    require!(curve.status == CurveStatus::Active, ErrorCode::BondingCurveComplete);
+    require_gt!(token_amount, 0);
+
+    // Inverts the curve, fee gross-up and reserve purchase included, for the least SOL
+    // that buys `token_amount`
+    let quote = quote_buy_exact_out(
+        &curve.curve_state(),
+        curve.fee_rates(Clock::get()?.slot),
+        trigger,
+        token_amount,
+    )
+    .ok_or(ErrorCode::TokenAmountUnavailable)?;
+    require_gte!(max_sol_cost, quote.sol_in);
+
+    settle(ctx, quote, trigger)
+}
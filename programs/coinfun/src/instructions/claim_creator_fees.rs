@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::states::BondingCurve;
+use crate::errors::ErrorCode;
+use crate::events::CreatorFeesClaimed;
+
+#[derive(Accounts)]
+pub struct ClaimCreatorFees<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        seeds = [b"bonding_curve", bonding_curve.mint.as_ref()],
+        bump,
+        constraint = bonding_curve.creator == creator.key() @ ErrorCode::Unauthorized
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    #[account(
+        mut,
+        seeds = [b"creator_vault", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub creator_vault: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Pays out everything the curve's trades have accrued to its creator
+pub fn handler(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+    // The vault keeps its rent-exempt minimum
+    let amount = ctx
+        .accounts
+        .creator_vault
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+    require!(amount > 0, ErrorCode::NothingToWithdraw);
+
+    let bonding_curve_key = ctx.accounts.bonding_curve.key();
+    let creator_vault_seeds: &[&[&[u8]]] = &[&[
+        b"creator_vault",
+        bonding_curve_key.as_ref(),
+        &[ctx.bumps.creator_vault],
+    ]];
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.creator_vault.to_account_info(),
+                to: ctx.accounts.creator.to_account_info(),
+            },
+        )
+        .with_signer(creator_vault_seeds),
+        amount,
+    )?;
+
+    emit!(CreatorFeesClaimed {
+        mint: ctx.accounts.bonding_curve.mint,
+        creator: ctx.accounts.creator.key(),
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::states::Referrer;
+use crate::errors::ErrorCode;
+use crate::events::ReferralFeesClaimed;
+
+#[derive(Accounts)]
+pub struct ClaimReferralFees<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"referrer", authority.key().as_ref()],
+        bump,
+        constraint = referrer.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub referrer: Account<'info, Referrer>,
+}
+
+pub fn handler(ctx: Context<ClaimReferralFees>) -> Result<()> {
+    let referrer = &mut ctx.accounts.referrer;
+    let amount = referrer.unclaimed_fees;
+    require!(amount > 0, ErrorCode::NothingToWithdraw);
+    referrer.unclaimed_fees = 0;
+
+    // The account is program-owned, so its lamports move directly
+    referrer.sub_lamports(amount)?;
+    ctx.accounts.authority.add_lamports(amount)?;
+
+    emit!(ReferralFeesClaimed {
+        referrer: ctx.accounts.authority.key(),
+        amount,
+    });
+
+    Ok(())
+}
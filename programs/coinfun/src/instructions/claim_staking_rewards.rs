@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+use crate::states::{Global, StakePool, StakePosition};
+use crate::errors::ErrorCode;
+use crate::events::StakingRewardsClaimed;
+use super::stake::sweep_reserve;
+
+#[derive(Accounts)]
+pub struct ClaimStakingRewards<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"stake_pool", mint.key().as_ref()],
+        bump
+    )]
+    pub stake_pool: Account<'info, StakePool>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = stake_pool,
+        associated_token::token_program = token_program,
+    )]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"stake_position", stake_pool.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = owner
+    )]
+    pub owner_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        seeds = [b"global"],
+        bump
+    )]
+    pub global: Account<'info, Global>,
+    /// CHECK: Global reserve PDA (authority for all reserve ATAs)
+    #[account(
+        seeds = [b"reserve"], bump,
+        constraint = global.reserve == global_reserve.key()
+    )]
+    pub global_reserve: UncheckedAccount<'info>,
+    // The reserve's tokens for the mint, swept into the pool for its stakers
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = global_reserve,
+        associated_token::token_program = token_program,
+    )]
+    pub reserve_ata: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+// Pays out the position's rewards, including what the reserve holds for the pool now
+pub fn handler(ctx: Context<ClaimStakingRewards>) -> Result<()> {
+    sweep_reserve(
+        &mut ctx.accounts.stake_pool,
+        &ctx.accounts.reserve_ata,
+        &ctx.accounts.stake_vault,
+        &ctx.accounts.mint,
+        &ctx.accounts.global_reserve,
+        ctx.bumps.global_reserve,
+        &ctx.accounts.token_program,
+    )?;
+
+    let position = &mut ctx.accounts.stake_position;
+    position.settle(&ctx.accounts.stake_pool)?;
+    let amount = position.unclaimed_rewards;
+    require!(amount > 0, ErrorCode::NothingToWithdraw);
+    position.unclaimed_rewards = 0;
+
+    let mint_key = ctx.accounts.mint.key();
+    let stake_pool_seeds: &[&[&[u8]]] = &[&[
+        b"stake_pool",
+        mint_key.as_ref(),
+        &[ctx.bumps.stake_pool],
+    ]];
+    let decimals = ctx.accounts.mint.decimals;
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.stake_vault.to_account_info(),
+        to: ctx.accounts.owner_ata.to_account_info(),
+        authority: ctx.accounts.stake_pool.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(stake_pool_seeds);
+    token_interface::transfer_checked(cpi_context, amount, decimals)?;
+
+    emit!(StakingRewardsClaimed {
+        mint: ctx.accounts.mint.key(),
+        owner: ctx.accounts.owner.key(),
+        amount,
+    });
+
+    Ok(())
+}
@@ -7,8 +7,13 @@ use anchor_spl::{
     },
     token_interface::{self, Mint, MintTo, TokenAccount, TokenInterface},
 };
-use crate::states::{Global, BondingCurve};
+use crate::states::{
+    Global, BondingCurve, CurveKind, CurveParams, CurveStatus, GraduationMode, LaunchFee,
+    LaunchWindow, MigrationTarget, ReservePolicy, TradingStart,
+};
+use crate::errors::ErrorCode;
 use crate::events::TokenCreated;
+use crate::consts::MAX_LAUNCH_FEE_BPS;
 
 #[derive(Accounts)]
 pub struct Create<'info> {
@@ -44,6 +49,13 @@ pub struct Create<'info> {
         associated_token::token_program = token_program,
     )]
     pub bonding_curve_ata: InterfaceAccount<'info, TokenAccount>,
+    // Collects the curve's creator fees, funded to rent exemption below
+    #[account(
+        mut,
+        seeds = [b"creator_vault", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub creator_vault: SystemAccount<'info>,
     /// CHECK: Validate address by deriving pda
     #[account(
         mut,
@@ -59,12 +71,83 @@ pub struct Create<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<Create>,
     token_name: String,
     token_symbol: String,
     token_uri: String,
+    migration_target: MigrationTarget,
+    curve_kind: CurveKind,
+    curve_params: Option<CurveParams>,
+    graduation_mode: GraduationMode,
+    launch_window: Option<LaunchWindow>,
+    trading_starts_at: TradingStart,
+    launch_fee: Option<LaunchFee>,
+    reserve_policy: ReservePolicy,
 ) -> Result<()> {
+    require!(
+        ctx.accounts.global.migration_program(migration_target).is_some(),
+        ErrorCode::MigrationTargetNotAllowed
+    );
+    let lp_policy = ctx.accounts.global.lp_policy;
+    require!(lp_policy.supports(migration_target), ErrorCode::LpPolicyNotSupported);
+    let launch_window = match launch_window {
+        Some(window) => {
+            require!(window.is_valid(), ErrorCode::InvalidLaunchWindow);
+            window
+        }
+        None => LaunchWindow::default(),
+    };
+    let global = &ctx.accounts.global;
+    // The launch fee starts on top of the trade fees the curve snapshots below
+    let launch_fee = match launch_fee {
+        Some(fee) => {
+            require!(fee.is_valid(), ErrorCode::InvalidLaunchFee);
+            require!(
+                global
+                    .platform_trade_fee_bps
+                    .saturating_add(global.reserve_trade_fee_bps)
+                    .saturating_add(global.creator_trade_fee_bps)
+                    .saturating_add(fee.extra_bps)
+                    <= MAX_LAUNCH_FEE_BPS,
+                ErrorCode::LaunchFeeTooHigh
+            );
+            fee
+        }
+        None => LaunchFee::default(),
+    };
+    require!(global.allows_curve_kind(curve_kind), ErrorCode::CurveKindNotAllowed);
+    // Creators may set their own economics within the authority's bounds
+    let params = match curve_params {
+        Some(params) => {
+            require!(
+                params.is_within(&global.min_curve_params, &global.max_curve_params),
+                ErrorCode::CurveParamsOutOfBounds
+            );
+            params
+        }
+        None => global.default_curve_params(),
+    };
+    // A constant product curve sells out only while its virtual token reserves stay above zero
+    require!(
+        curve_kind != CurveKind::ConstantProduct
+            || params.initial_virtual_token_reserves > params.token_total_supply,
+        ErrorCode::InvalidTokenReserveConfiguration
+    );
+    require!(
+        curve_math::CurveKind::from(curve_kind).is_valid(params.token_total_supply),
+        ErrorCode::InvalidCurveParams
+    );
+    // Shaped curves price from the tokens sold and have no virtual reserves of their own
+    let (virtual_token_reserves, virtual_sol_reserves) = match curve_kind {
+        CurveKind::ConstantProduct => (
+            params.initial_virtual_token_reserves,
+            params.initial_virtual_sol_reserves,
+        ),
+        _ => (params.token_total_supply, 0),
+    };
+
     msg!("Creating metadata account...");
     msg!(
         "Metadata account address: {}",
@@ -112,13 +195,45 @@ pub fn handler(
     ctx.accounts.bonding_curve.set_inner(BondingCurve {
         mint: ctx.accounts.mint.key(),
         creator: ctx.accounts.creator.key(),
-        virtual_token_reserves: ctx.accounts.global.initial_virtual_token_reserves,
-        virtual_sol_reserves: ctx.accounts.global.initial_virtual_sol_reserves,
-        real_token_reserves: ctx.accounts.global.token_total_supply,
+        virtual_token_reserves,
+        virtual_sol_reserves,
+        real_token_reserves: params.token_total_supply,
         real_sol_reserves: 0,
-        token_total_supply: ctx.accounts.global.token_total_supply,
-        complete: false,
+        token_total_supply: params.token_total_supply,
+        status: CurveStatus::Active,
+        migration_target,
+        lp_policy,
+        lp_unlock_slot: 0,
+        lp_mint: Pubkey::default(),
+        curve_kind,
+        graduation_threshold: params.graduation_threshold,
+        platform_trade_fee_bps: global.platform_trade_fee_bps,
+        reserve_trade_fee_bps: global.reserve_trade_fee_bps,
+        graduation_mode,
+        created_slot: Clock::get()?.slot,
+        launch_window,
+        trading_starts_at,
+        trading_opened_slot: 0,
+        launch_fee,
+        creator_trade_fee_bps: global.creator_trade_fee_bps,
+        reserve_policy,
     });
+    // A curve that starts graduated could never trade. USD targets move with the price,
+    // so they can only be checked against it in `buy`
+    let curve = &ctx.accounts.bonding_curve;
+    match graduation_mode {
+        GraduationMode::UsdMarketCap { market_cap_usd } => {
+            require!(
+                ctx.accounts.global.sol_usd_price_feed != Pubkey::default(),
+                ErrorCode::MissingPriceFeed
+            );
+            require_gt!(market_cap_usd, 0, ErrorCode::InvalidCurveParams);
+        }
+        _ => require!(
+            !curve.curve_state().is_graduated(curve.graduation_trigger(None)?),
+            ErrorCode::InvalidCurveParams
+        ),
+    }
 
     let cpi_accounts = MintTo {
         mint: ctx.accounts.mint.to_account_info(),
@@ -127,7 +242,24 @@ pub fn handler(
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer_seeds);
-    token_interface::mint_to(cpi_context, ctx.accounts.global.token_total_supply)?;
+    token_interface::mint_to(cpi_context, params.token_total_supply)?;
+
+    // Fees below the rent-exempt minimum could not land in an empty vault
+    let vault_rent = Rent::get()?
+        .minimum_balance(0)
+        .saturating_sub(ctx.accounts.creator_vault.lamports());
+    if vault_rent > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.signer.to_account_info(),
+                    to: ctx.accounts.creator_vault.to_account_info(),
+                },
+            ),
+            vault_rent,
+        )?;
+    }
 
     // Emit event
     emit!(TokenCreated {
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use crate::states::{BondingCurve, CurveStatus, StakePool};
+use crate::errors::ErrorCode;
+use crate::events::StakePoolCreated;
+
+#[derive(Accounts)]
+pub struct CreateStakePool<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        constraint = bonding_curve.mint == mint.key()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    // Only graduated tokens can be staked
+    #[account(
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump,
+        constraint = matches!(
+            bonding_curve.status,
+            CurveStatus::Complete | CurveStatus::Migrating | CurveStatus::Migrated
+        ) @ ErrorCode::BondingCurveNotComplete
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + StakePool::INIT_SPACE,
+        seeds = [b"stake_pool", mint.key().as_ref()],
+        bump
+    )]
+    pub stake_pool: Account<'info, StakePool>,
+    // Holds the staked tokens and the swept rewards not yet claimed
+    #[account(
+        init,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = stake_pool,
+        associated_token::token_program = token_program,
+    )]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+// Permissionless; anyone can open staking for a graduated token, which hands the
+// reserve's tokens for the mint, past and future, to its stakers
+pub fn handler(ctx: Context<CreateStakePool>) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    ctx.accounts.stake_pool.set_inner(StakePool {
+        mint: ctx.accounts.mint.key(),
+        total_staked: 0,
+        reward_per_share: 0,
+        total_rewards: 0,
+        pending_rewards: 0,
+        reward_rate: 0,
+        last_update_slot: slot,
+        stream_end_slot: slot,
+    });
+
+    emit!(StakePoolCreated {
+        mint: ctx.accounts.mint.key(),
+        stake_pool: ctx.accounts.stake_pool.key(),
+    });
+
+    Ok(())
+}
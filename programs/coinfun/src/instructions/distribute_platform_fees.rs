@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::states::Global;
+use crate::errors::ErrorCode;
+use crate::events::PlatformFeesDistributed;
+
+#[derive(Accounts)]
+pub struct DistributePlatformFees<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump
+    )]
+    pub global: Account<'info, Global>,
+    /// Data-less PDA that accumulates the platform's trade fees
+    #[account(
+        mut,
+        seeds = [b"platform_fee_vault"],
+        bump
+    )]
+    pub platform_fee_vault: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Permissionless: the vault only ever pays the recipients configured in `Global`,
+// passed as writable remaining accounts in the same order
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, DistributePlatformFees<'info>>) -> Result<()> {
+    let global = &ctx.accounts.global;
+    let recipients: Vec<Pubkey> = global
+        .active_platform_fee_recipients()
+        .map(|payee| payee.recipient)
+        .collect();
+    require!(!recipients.is_empty(), ErrorCode::InvalidFeeRecipients);
+    require!(
+        ctx.remaining_accounts.len() == recipients.len()
+            && ctx
+                .remaining_accounts
+                .iter()
+                .zip(&recipients)
+                .all(|(account, recipient)| account.key() == *recipient),
+        ErrorCode::InvalidFeeRecipients
+    );
+
+    // The vault keeps its rent-exempt minimum, and the rounding dust for next time
+    let amount = ctx
+        .accounts
+        .platform_fee_vault
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+    require!(amount > 0, ErrorCode::NothingToWithdraw);
+    let mut amounts = global
+        .platform_fee_shares(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let platform_fee_vault_seeds: &[&[&[u8]]] = &[&[
+        b"platform_fee_vault",
+        &[ctx.bumps.platform_fee_vault],
+    ]];
+    let rent = Rent::get()?.minimum_balance(0);
+    for (recipient, share) in ctx.remaining_accounts.iter().zip(amounts.iter_mut()) {
+        // A share too small to open an empty recipient's account stays in the vault
+        // for the next payout
+        if recipient.lamports() == 0 && *share < rent {
+            *share = 0;
+        }
+        if *share == 0 {
+            continue;
+        }
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.platform_fee_vault.to_account_info(),
+                    to: recipient.clone(),
+                },
+            )
+            .with_signer(platform_fee_vault_seeds),
+            *share,
+        )?;
+    }
+
+    emit!(PlatformFeesDistributed { recipients, amounts });
+
+    Ok(())
+}
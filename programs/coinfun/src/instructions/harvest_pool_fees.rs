@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, spl_token::native_mint, CloseAccount, Token},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use crate::adapters::{self, FeeClaim};
+use crate::states::{Global, BondingCurve, CurveStatus};
+use crate::errors::ErrorCode;
+use crate::events::PoolFeesHarvested;
+
+#[derive(Accounts)]
+pub struct HarvestPoolFees<'info> {
+    /// Anyone can harvest; pays for any account that has to be created
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"global"],
+        bump
+    )]
+    pub global: Box<Account<'info, Global>>,
+    /// CHECK: Global reserve PDA (authority for all reserve ATAs)
+    #[account(
+        seeds = [b"reserve"], bump,
+        constraint = global.reserve == global_reserve.key()
+    )]
+    pub global_reserve: UncheckedAccount<'info>,
+    /// Data-less PDA that accumulates the SOL side of harvested fees
+    #[account(
+        mut,
+        seeds = [b"reserve_sol_vault"],
+        bump
+    )]
+    pub reserve_sol_vault: SystemAccount<'info>,
+    #[account(
+        constraint = bonding_curve.mint == mint.key()
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    /// Owner of the locked LP position
+    #[account(
+        mut,
+        seeds = [b"migration_authority", mint.key().as_ref()],
+        bump
+    )]
+    pub migration_authority: SystemAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = global_reserve,
+        associated_token::token_program = token_program,
+    )]
+    pub reserve_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// Receives the WSOL fees, then is closed to unwrap them
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = wsol_mint,
+        associated_token::authority = migration_authority,
+        associated_token::token_program = wsol_token_program,
+    )]
+    pub migration_wsol_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(address = native_mint::ID)]
+    pub wsol_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: Must be the registered program for the curve's migration target
+    #[account(
+        constraint = global.migration_program(bonding_curve.migration_target) == Some(dex_program.key())
+            @ ErrorCode::MigrationTargetNotAllowed
+    )]
+    pub dex_program: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub wsol_token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+// DEX-specific accounts are passed as remaining accounts, see the adapter for the target
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, HarvestPoolFees<'info>>) -> Result<()> {
+    require!(
+        ctx.accounts.bonding_curve.status == CurveStatus::Migrated,
+        ErrorCode::CurveNotMigrated
+    );
+
+    let mint_key = ctx.accounts.mint.key();
+    let migration_authority_seeds: &[&[u8]] = &[
+        b"migration_authority",
+        mint_key.as_ref(),
+        &[ctx.bumps.migration_authority],
+    ];
+    let token_before = ctx.accounts.reserve_ata.amount;
+
+    // Token fees go straight into the reserve ATA
+    let claim = FeeClaim {
+        dex_program: ctx.accounts.dex_program.to_account_info(),
+        migration_authority: ctx.accounts.migration_authority.to_account_info(),
+        token_mint: ctx.accounts.mint.to_account_info(),
+        token_account: ctx.accounts.reserve_ata.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        wsol_mint: ctx.accounts.wsol_mint.to_account_info(),
+        wsol_account: ctx.accounts.migration_wsol_account.to_account_info(),
+        wsol_token_program: ctx.accounts.wsol_token_program.to_account_info(),
+    };
+    adapters::claim_fees(
+        ctx.accounts.bonding_curve.migration_target,
+        &claim,
+        ctx.remaining_accounts,
+        migration_authority_seeds,
+    )?;
+
+    ctx.accounts.reserve_ata.reload()?;
+    ctx.accounts.migration_wsol_account.reload()?;
+    let token_amount = ctx.accounts.reserve_ata.amount.saturating_sub(token_before);
+    let sol_amount = ctx.accounts.migration_wsol_account.amount;
+
+    // Unwrap the WSOL side into the migration authority; its account rent goes back to the payer
+    token::close_account(
+        CpiContext::new(
+            ctx.accounts.wsol_token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.migration_wsol_account.to_account_info(),
+                destination: ctx.accounts.migration_authority.to_account_info(),
+                authority: ctx.accounts.migration_authority.to_account_info(),
+            },
+        )
+        .with_signer(&[migration_authority_seeds]),
+    )?;
+
+    if sol_amount > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.migration_authority.to_account_info(),
+                    to: ctx.accounts.reserve_sol_vault.to_account_info(),
+                },
+            )
+            .with_signer(&[migration_authority_seeds]),
+            sol_amount,
+        )?;
+    }
+
+    let account_rent = ctx.accounts.migration_authority.lamports();
+    if account_rent > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.migration_authority.to_account_info(),
+                    to: ctx.accounts.payer.to_account_info(),
+                },
+            )
+            .with_signer(&[migration_authority_seeds]),
+            account_rent,
+        )?;
+    }
+
+    emit!(PoolFeesHarvested {
+        mint: mint_key,
+        harvester: ctx.accounts.payer.key(),
+        token_amount,
+        sol_amount,
+    });
+
+    Ok(())
+}
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::states::Global;
+use crate::states::{CurveParams, Global, LpPolicy, SurplusTokenPolicy};
 use crate::errors::ErrorCode;
-use crate::consts::MAX_FEE_BPS;
+use crate::consts::{BPS_DENOMINATOR, MAX_FEE_BPS};
+use crate::adapters::{cpmm, damm_v2, dlmm};
 
 #[derive(Accounts)]
 pub struct Initialize<'info> {
@@ -22,9 +23,22 @@ pub struct Initialize<'info> {
         seeds = [b"reserve"], bump
     )]
     pub global_reserve: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"platform_fee_vault"],
+        bump
+    )]
+    pub platform_fee_vault: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"reserve_sol_vault"],
+        bump
+    )]
+    pub reserve_sol_vault: SystemAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<Initialize>,
     initial_virtual_token_reserves: u64,
@@ -32,20 +46,40 @@ pub fn handler(
     token_total_supply: u64,
     platform_trade_fee_bps: u64,
     reserve_trade_fee_bps: u64,
-    platform_fee_recipient: Pubkey,
     graduation_threshold: u64,
+    lp_policy: LpPolicy,
+    keeper_reward_bps: u64,
+    surplus_token_policy: SurplusTokenPolicy,
+    migration_fee_lamports: u64,
+    curve_kinds: [bool; 3],
+    min_curve_params: CurveParams,
+    max_curve_params: CurveParams,
+    creator_trade_fee_bps: u64,
+    referral_fee_bps: u64,
 ) -> Result<()> {
     // Enforce 30% maximum fee cap
     require!(
         platform_trade_fee_bps
-            .checked_add(reserve_trade_fee_bps)
-            .unwrap_or(u64::MAX)
+            .saturating_add(reserve_trade_fee_bps)
+            .saturating_add(creator_trade_fee_bps)
             <= MAX_FEE_BPS,
         ErrorCode::FeeTooHigh
     );
+    require!(keeper_reward_bps <= MAX_FEE_BPS, ErrorCode::FeeTooHigh);
+    require!(referral_fee_bps <= BPS_DENOMINATOR, ErrorCode::FeeTooHigh);
+    // The defaults must themselves be a valid launch
+    let default_curve_params = CurveParams {
+        initial_virtual_token_reserves,
+        initial_virtual_sol_reserves,
+        token_total_supply,
+        graduation_threshold,
+    };
+    require!(
+        default_curve_params.is_within(&min_curve_params, &max_curve_params),
+        ErrorCode::CurveParamsOutOfBounds
+    );
     ctx.accounts.global.set_inner(Global {
         authority: ctx.accounts.authority.key(),
-        platform_fee_recipient,
         reserve: ctx.accounts.global_reserve.key(), // Global reserve PDA address
         initial_virtual_token_reserves,
         initial_virtual_sol_reserves,
@@ -53,7 +87,44 @@ pub fn handler(
         platform_trade_fee_bps,
         reserve_trade_fee_bps,
         graduation_threshold,
+        // Canonical program per target, see `set_migration_program`
+        migration_programs: [damm_v2::ID, dlmm::ID, cpmm::ID],
+        lp_policy,
+        keeper_reward_bps,
+        // Set per target with `set_migration_program` before migrations can run
+        migration_configs: [Pubkey::default(); 3],
+        surplus_token_policy,
+        migration_fee_lamports,
+        curve_kinds,
+        min_curve_params,
+        max_curve_params,
+        // Set with `set_price_feed` before `UsdMarketCap` curves can launch
+        sol_usd_price_feed: Pubkey::default(),
+        max_price_age_secs: 0,
+        max_price_confidence_bps: 0,
+        creator_trade_fee_bps,
+        referral_fee_bps,
+        // Set with `set_platform_fee_recipients` before the platform fee vault can pay out
+        platform_fee_recipients: Default::default(),
     });
-    
+
+    // Fund the SOL vaults to rent exemption once, so trades can pay any fee into them
+    let rent = Rent::get()?.minimum_balance(0);
+    for vault in [&ctx.accounts.platform_fee_vault, &ctx.accounts.reserve_sol_vault] {
+        let vault_rent = rent.saturating_sub(vault.lamports());
+        if vault_rent > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: vault.to_account_info(),
+                    },
+                ),
+                vault_rent,
+            )?;
+        }
+    }
+
     Ok(())
 }
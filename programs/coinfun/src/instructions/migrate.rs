@@ -0,0 +1,378 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, spl_token::native_mint, CloseAccount, SyncNative, Token},
+    token_interface::{self, Burn, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+use crate::adapters::{cpmm, damm_v2, dlmm, PoolDeposit};
+use crate::math::deposits_at_price;
+use crate::states::{
+    Global, BondingCurve, CurveStatus, LpPolicy, MigrationTarget, SurplusTokenPolicy,
+};
+use crate::errors::ErrorCode;
+use crate::consts::BPS_DENOMINATOR;
+use crate::events::CurveMigrated;
+
+#[derive(Accounts)]
+pub struct Migrate<'info> {
+    /// Anyone can migrate a graduated curve; fronts the pool rent and earns the keeper reward
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+    #[account(
+        seeds = [b"global"],
+        bump
+    )]
+    pub global: Box<Account<'info, Global>>,
+    /// Receives the migration fee, paid out with the trade fees by `distribute_platform_fees`
+    #[account(
+        mut,
+        seeds = [b"platform_fee_vault"],
+        bump
+    )]
+    pub platform_fee_vault: SystemAccount<'info>,
+    /// CHECK: Global reserve PDA (authority for all reserve ATAs)
+    #[account(
+        seeds = [b"reserve"], bump,
+        constraint = global.reserve == global_reserve.key()
+    )]
+    pub global_reserve: UncheckedAccount<'info>,
+    /// Receives surplus tokens under `SurplusTokenPolicy::Reserve`, and deposit tokens the pool left
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        associated_token::mint = mint,
+        associated_token::authority = global_reserve,
+        associated_token::token_program = token_program,
+    )]
+    pub reserve_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// Receives SOL the curve's final price leaves out of the pool, and deposit SOL the pool left
+    #[account(
+        mut,
+        seeds = [b"reserve_sol_vault"],
+        bump
+    )]
+    pub reserve_sol_vault: SystemAccount<'info>,
+    /// Writable so surplus tokens can be burned
+    #[account(
+        mut,
+        constraint = bonding_curve.mint == mint.key()
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bonding_curve,
+        associated_token::token_program = token_program,
+    )]
+    pub bonding_curve_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// Data-less PDA that holds the pool deposits, pays for the pool accounts and keeps the LP
+    #[account(
+        mut,
+        seeds = [b"migration_authority", mint.key().as_ref()],
+        bump
+    )]
+    pub migration_authority: SystemAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        associated_token::mint = mint,
+        associated_token::authority = migration_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub migration_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        associated_token::mint = wsol_mint,
+        associated_token::authority = migration_authority,
+        associated_token::token_program = wsol_token_program,
+    )]
+    pub migration_wsol_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(address = native_mint::ID)]
+    pub wsol_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: Must be the registered program for the curve's migration target
+    #[account(
+        constraint = global.migration_program(bonding_curve.migration_target) == Some(dex_program.key())
+            @ ErrorCode::MigrationTargetNotAllowed
+    )]
+    pub dex_program: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub wsol_token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+// DEX-specific accounts are passed as remaining accounts, see the adapter for the target
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, Migrate<'info>>) -> Result<()> {
+    require!(
+        ctx.accounts.bonding_curve.status == CurveStatus::Complete,
+        ErrorCode::BondingCurveNotComplete
+    );
+    ctx.accounts.bonding_curve.set_status(CurveStatus::Migrating)?;
+
+    // The pool gets the curve's tokens and its SOL above rent, minus the keeper reward
+    // and the migration fee, sized to open at the curve's final price
+    let curve_tokens = ctx.accounts.bonding_curve_ata.amount;
+    let rent = Rent::get()?;
+    let curve_info = ctx.accounts.bonding_curve.to_account_info();
+    let curve_sol = curve_info
+        .lamports()
+        .saturating_sub(rent.minimum_balance(curve_info.data_len()));
+    let keeper_reward = curve_sol
+        .checked_mul(ctx.accounts.global.keeper_reward_bps)
+        .ok_or(ProgramError::ArithmeticOverflow)?
+        .checked_div(BPS_DENOMINATOR)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let migration_fee = ctx.accounts.global.migration_fee_lamports;
+    let available_sol = curve_sol
+        .checked_sub(keeper_reward)
+        .and_then(|res| res.checked_sub(migration_fee))
+        .ok_or(ErrorCode::NothingToMigrate)?;
+    let (price_sol, price_token) = ctx
+        .accounts
+        .bonding_curve
+        .curve_state()
+        .spot_price()
+        .ok_or(ErrorCode::PoolPriceOutOfRange)?;
+    let (token_amount, sol_amount) = deposits_at_price(
+        curve_tokens,
+        available_sol,
+        price_sol,
+        price_token,
+    )
+    .ok_or(ErrorCode::PoolPriceOutOfRange)?;
+    require!(token_amount > 0 && sol_amount > 0, ErrorCode::NothingToMigrate);
+    let surplus_token_amount = curve_tokens - token_amount;
+    let surplus_sol_amount = available_sol - sol_amount;
+
+    let mint_key = ctx.accounts.mint.key();
+    let bonding_curve_seeds: &[&[&[u8]]] = &[&[
+        b"bonding_curve",
+        mint_key.as_ref(),
+        &[ctx.bumps.bonding_curve],
+    ]];
+    let migration_authority_seeds: &[&[u8]] = &[
+        b"migration_authority",
+        mint_key.as_ref(),
+        &[ctx.bumps.migration_authority],
+    ];
+
+    // Move the curve's tokens to the migration authority
+    let decimals = ctx.accounts.mint.decimals;
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.bonding_curve_ata.to_account_info(),
+        to: ctx.accounts.migration_token_account.to_account_info(),
+        authority: ctx.accounts.bonding_curve.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(bonding_curve_seeds);
+    token_interface::transfer_checked(cpi_context, token_amount, decimals)?;
+
+    if surplus_token_amount > 0 {
+        match ctx.accounts.global.surplus_token_policy {
+            SurplusTokenPolicy::Burn => token_interface::burn(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Burn {
+                        mint: ctx.accounts.mint.to_account_info(),
+                        from: ctx.accounts.bonding_curve_ata.to_account_info(),
+                        authority: ctx.accounts.bonding_curve.to_account_info(),
+                    },
+                )
+                .with_signer(bonding_curve_seeds),
+                surplus_token_amount,
+            )?,
+            SurplusTokenPolicy::Reserve => token_interface::transfer_checked(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        mint: ctx.accounts.mint.to_account_info(),
+                        from: ctx.accounts.bonding_curve_ata.to_account_info(),
+                        to: ctx.accounts.reserve_ata.to_account_info(),
+                        authority: ctx.accounts.bonding_curve.to_account_info(),
+                    },
+                )
+                .with_signer(bonding_curve_seeds),
+                surplus_token_amount,
+                decimals,
+            )?,
+        }
+    }
+
+    if surplus_sol_amount > 0 {
+        ctx.accounts.bonding_curve.sub_lamports(surplus_sol_amount)?;
+        ctx.accounts.reserve_sol_vault.add_lamports(surplus_sol_amount)?;
+    }
+
+    ctx.accounts.bonding_curve.sub_lamports(keeper_reward)?;
+    ctx.accounts.keeper.add_lamports(keeper_reward)?;
+
+    if migration_fee > 0 {
+        ctx.accounts.bonding_curve.sub_lamports(migration_fee)?;
+        ctx.accounts.platform_fee_vault.add_lamports(migration_fee)?;
+    }
+
+    // Wrap the curve's SOL directly into the migration authority's WSOL account
+    ctx.accounts.bonding_curve.sub_lamports(sol_amount)?;
+    ctx.accounts.migration_wsol_account.add_lamports(sol_amount)?;
+    token::sync_native(CpiContext::new(
+        ctx.accounts.wsol_token_program.to_account_info(),
+        SyncNative {
+            account: ctx.accounts.migration_wsol_account.to_account_info(),
+        },
+    ))?;
+
+    // The migration authority pays for the pool accounts, fronted by the caller
+    let target = ctx.accounts.bonding_curve.migration_target;
+    let creation_lamports = match target {
+        MigrationTarget::DammV2 => damm_v2::CREATION_LAMPORTS,
+        MigrationTarget::Dlmm => dlmm::CREATION_LAMPORTS,
+        MigrationTarget::Cpmm => cpmm::CREATION_LAMPORTS,
+    };
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.keeper.to_account_info(),
+                to: ctx.accounts.migration_authority.to_account_info(),
+            },
+        ),
+        creation_lamports,
+    )?;
+
+    let deposit = PoolDeposit {
+        dex_program: ctx.accounts.dex_program.to_account_info(),
+        migration_authority: ctx.accounts.migration_authority.to_account_info(),
+        token_mint: ctx.accounts.mint.to_account_info(),
+        token_account: ctx.accounts.migration_token_account.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        wsol_mint: ctx.accounts.wsol_mint.to_account_info(),
+        wsol_account: ctx.accounts.migration_wsol_account.to_account_info(),
+        wsol_token_program: ctx.accounts.wsol_token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        token_amount,
+        sol_amount,
+        price_sol,
+        price_token,
+        lp_policy: ctx.accounts.bonding_curve.lp_policy,
+        pool_config: ctx.accounts.global.migration_config(target),
+    };
+    let remaining = ctx.remaining_accounts;
+    let created = match target {
+        MigrationTarget::DammV2 => damm_v2::create_pool(&deposit, remaining, migration_authority_seeds)?,
+        MigrationTarget::Dlmm => dlmm::create_pool(&deposit, remaining, migration_authority_seeds)?,
+        MigrationTarget::Cpmm => cpmm::create_pool(&deposit, remaining, migration_authority_seeds)?,
+    };
+
+    // Sweep whatever the pool left of the deposits to the reserve, closing the token
+    // account and unwrapping the WSOL into the migration authority
+    ctx.accounts.migration_token_account.reload()?;
+    ctx.accounts.migration_wsol_account.reload()?;
+    let leftover_token_amount = ctx.accounts.migration_token_account.amount;
+    let leftover_sol_amount = ctx.accounts.migration_wsol_account.amount;
+    if leftover_token_amount > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.migration_token_account.to_account_info(),
+                    to: ctx.accounts.reserve_ata.to_account_info(),
+                    authority: ctx.accounts.migration_authority.to_account_info(),
+                },
+            )
+            .with_signer(&[migration_authority_seeds]),
+            leftover_token_amount,
+            decimals,
+        )?;
+    }
+    // The keeper paid for the token account, so its rent goes back to the keeper
+    token_interface::close_account(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::CloseAccount {
+                account: ctx.accounts.migration_token_account.to_account_info(),
+                destination: ctx.accounts.keeper.to_account_info(),
+                authority: ctx.accounts.migration_authority.to_account_info(),
+            },
+        )
+        .with_signer(&[migration_authority_seeds]),
+    )?;
+    token::close_account(
+        CpiContext::new(
+            ctx.accounts.wsol_token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.migration_wsol_account.to_account_info(),
+                destination: ctx.accounts.migration_authority.to_account_info(),
+                authority: ctx.accounts.migration_authority.to_account_info(),
+            },
+        )
+        .with_signer(&[migration_authority_seeds]),
+    )?;
+    if leftover_sol_amount > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.migration_authority.to_account_info(),
+                    to: ctx.accounts.reserve_sol_vault.to_account_info(),
+                },
+            )
+            .with_signer(&[migration_authority_seeds]),
+            leftover_sol_amount,
+        )?;
+    }
+
+    // Refund whatever the pool accounts did not consume, and the WSOL account's rent
+    let unused_rent = ctx.accounts.migration_authority.lamports();
+    if unused_rent > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.migration_authority.to_account_info(),
+                    to: ctx.accounts.keeper.to_account_info(),
+                },
+            )
+            .with_signer(&[migration_authority_seeds]),
+            unused_rent,
+        )?;
+    }
+
+    let curve = &mut ctx.accounts.bonding_curve;
+    curve.real_token_reserves = 0;
+    curve.real_sol_reserves = 0;
+    curve.lp_mint = created.lp_mint;
+    if let LpPolicy::TimeLock { lp_lock_slots } = curve.lp_policy {
+        curve.lp_unlock_slot = Clock::get()?.slot.saturating_add(lp_lock_slots);
+    }
+    curve.set_status(CurveStatus::Migrated)?;
+
+    emit!(CurveMigrated {
+        mint: mint_key,
+        bonding_curve: curve.key(),
+        target,
+        pool: created.pool,
+        lp_mint: created.lp_mint,
+        token_amount: token_amount - leftover_token_amount,
+        sol_amount: sol_amount - leftover_sol_amount,
+        lp_policy: curve.lp_policy,
+        lp_unlock_slot: curve.lp_unlock_slot,
+        keeper: ctx.accounts.keeper.key(),
+        keeper_reward,
+        migration_fee,
+        surplus_token_amount,
+        surplus_sol_amount,
+        leftover_token_amount,
+        leftover_sol_amount,
+    });
+
+    Ok(())
+}
@@ -2,16 +2,56 @@ pub mod initialize;
 pub mod create;
 pub mod buy;
 pub mod sell;
-pub mod withdraw;
 pub mod withdraw_reserve;
 pub mod deposit_to_reserve;
 pub mod update_global_config;
+pub mod migrate;
+pub mod set_curve_status;
+pub mod refund;
+pub mod upgrade_bonding_curve;
+pub mod upgrade_global;
+pub mod set_migration_program;
+pub mod release_lp;
+pub mod harvest_pool_fees;
+pub mod withdraw_reserve_sol;
+pub mod buy_exact_out;
+pub mod sell_for_sol;
+pub mod reprice_curve;
+pub mod set_price_feed;
+pub mod claim_creator_fees;
+pub mod register_referrer;
+pub mod claim_referral_fees;
+pub mod set_platform_fee_recipients;
+pub mod distribute_platform_fees;
+pub mod create_stake_pool;
+pub mod stake;
+pub mod unstake;
+pub mod claim_staking_rewards;
 
 pub use initialize::*;
 pub use create::*;
 pub use buy::*;
 pub use sell::*;
-pub use withdraw::*;
 pub use withdraw_reserve::*;
 pub use deposit_to_reserve::*;
 pub use update_global_config::*;
+pub use migrate::*;
+pub use set_curve_status::*;
+pub use refund::*;
+pub use upgrade_bonding_curve::*;
+pub use upgrade_global::*;
+pub use set_migration_program::*;
+pub use release_lp::*;
+pub use harvest_pool_fees::*;
+pub use withdraw_reserve_sol::*;
+pub use reprice_curve::*;
+pub use set_price_feed::*;
+pub use claim_creator_fees::*;
+pub use register_referrer::*;
+pub use claim_referral_fees::*;
+pub use set_platform_fee_recipients::*;
+pub use distribute_platform_fees::*;
+pub use create_stake_pool::*;
+pub use stake::*;
+pub use unstake::*;
+pub use claim_staking_rewards::*;
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount, TokenInterface};
+use crate::states::{BondingCurve, CurveStatus};
+use crate::errors::ErrorCode;
+use crate::events::Refunded;
+
+#[derive(Accounts)]
+pub struct Refund<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = bonding_curve
+    )]
+    pub bonding_curve_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = signer
+    )]
+    pub user_ata: InterfaceAccount<'info, TokenAccount>,
+    // Mutable for the burn of the refunded tokens
+    #[account(
+        mut,
+        constraint = bonding_curve.mint == mint.key()
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<Refund>, token_amount: u64, min_sol_output: u64) -> Result<()> {
+    require!(
+        ctx.accounts.bonding_curve.status == CurveStatus::Refunding,
+        ErrorCode::CurveNotRefunding
+    );
+    require_gt!(token_amount, 0);
+
+    // Every token outside the curve has the same claim on its SOL
+    let circulating = ctx
+        .accounts
+        .mint
+        .supply
+        .checked_sub(ctx.accounts.bonding_curve_ata.amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let sol_out = ctx
+        .accounts
+        .bonding_curve
+        .refund_sol(token_amount, circulating)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    require_gte!(sol_out, min_sol_output);
+
+    // Burning the returned tokens keeps the share of those still out unchanged
+    token_interface::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.user_ata.to_account_info(),
+                authority: ctx.accounts.signer.to_account_info(),
+            },
+        ),
+        token_amount,
+    )?;
+
+    let curve = &mut ctx.accounts.bonding_curve;
+    curve.real_sol_reserves -= sol_out;
+    curve.sub_lamports(sol_out)?;
+    ctx.accounts.signer.add_lamports(sol_out)?;
+
+    emit!(Refunded {
+        mint: ctx.accounts.mint.key(),
+        holder: ctx.accounts.signer.key(),
+        token_amount,
+        sol_amount: sol_out,
+    });
+
+    Ok(())
+}
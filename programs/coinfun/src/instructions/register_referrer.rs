@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use crate::states::Referrer;
+
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Referrer::INIT_SPACE,
+        seeds = [b"referrer", authority.key().as_ref()],
+        bump
+    )]
+    pub referrer: Account<'info, Referrer>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterReferrer>) -> Result<()> {
+    ctx.accounts.referrer.set_inner(Referrer {
+        authority: ctx.accounts.authority.key(),
+        unclaimed_fees: 0,
+        total_fees_earned: 0,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+use crate::states::{Global, BondingCurve, CurveStatus, LpPolicy};
+use crate::errors::ErrorCode;
+use crate::events::LpReleased;
+
+#[derive(Accounts)]
+pub struct ReleaseLp<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"global"],
+        bump,
+        constraint = global.authority == authority.key()
+    )]
+    pub global: Box<Account<'info, Global>>,
+    #[account(
+        seeds = [b"bonding_curve", bonding_curve.mint.as_ref()],
+        bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(
+        seeds = [b"migration_authority", bonding_curve.mint.as_ref()],
+        bump
+    )]
+    pub migration_authority: SystemAccount<'info>,
+    /// CPMM LP mint or DAMM v2 position NFT mint, as recorded by `migrate`
+    #[account(
+        constraint = lp_mint.key() == bonding_curve.lp_mint @ ErrorCode::InvalidMigrationAccount
+    )]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = lp_mint,
+        token::authority = migration_authority,
+        token::token_program = lp_token_program,
+    )]
+    pub lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Receives the LP, chosen by the authority
+    pub recipient: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = lp_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = lp_token_program,
+    )]
+    pub recipient_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub lp_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ReleaseLp>) -> Result<()> {
+    let curve = &ctx.accounts.bonding_curve;
+    require!(curve.status == CurveStatus::Migrated, ErrorCode::CurveNotMigrated);
+    // Only time-locked LP ever leaves the migration authority
+    require!(
+        matches!(curve.lp_policy, LpPolicy::TimeLock { .. })
+            && Clock::get()?.slot >= curve.lp_unlock_slot,
+        ErrorCode::LpLocked
+    );
+
+    let amount = ctx.accounts.lp_account.amount;
+    require!(amount > 0, ErrorCode::NothingToWithdraw);
+
+    let migration_authority_seeds: &[&[&[u8]]] = &[&[
+        b"migration_authority",
+        curve.mint.as_ref(),
+        &[ctx.bumps.migration_authority],
+    ]];
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.lp_mint.to_account_info(),
+        from: ctx.accounts.lp_account.to_account_info(),
+        to: ctx.accounts.recipient_lp_account.to_account_info(),
+        authority: ctx.accounts.migration_authority.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.lp_token_program.to_account_info();
+    let cpi_context =
+        CpiContext::new(cpi_program, cpi_accounts).with_signer(migration_authority_seeds);
+    token_interface::transfer_checked(cpi_context, amount, ctx.accounts.lp_mint.decimals)?;
+
+    emit!(LpReleased {
+        mint: curve.mint,
+        lp_mint: ctx.accounts.lp_mint.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+    });
+
+    Ok(())
+}
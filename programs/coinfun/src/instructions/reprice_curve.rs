@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::states::{Global, BondingCurve, CurveStatus, GraduationMode};
+use crate::errors::ErrorCode;
+use crate::events::CurveRepriced;
+use crate::consts::MAX_FEE_BPS;
+use curve_math::GraduationTrigger;
+
+#[derive(Accounts)]
+pub struct RepriceCurve<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"global"],
+        bump,
+        constraint = global.authority == authority.key()
+    )]
+    pub global: Account<'info, Global>,
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.mint.as_ref()],
+        bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    #[account(
+        mut,
+        seeds = [b"creator_vault", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub creator_vault: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Curves trade on the fees and threshold snapshotted at `create`; this is the
+// only way to change them afterwards
+pub fn handler(
+    ctx: Context<RepriceCurve>,
+    platform_trade_fee_bps: u64,
+    reserve_trade_fee_bps: u64,
+    creator_trade_fee_bps: u64,
+    graduation_threshold: u64,
+) -> Result<()> {
+    require!(
+        platform_trade_fee_bps
+            .saturating_add(reserve_trade_fee_bps)
+            .saturating_add(creator_trade_fee_bps)
+            <= MAX_FEE_BPS,
+        ErrorCode::FeeTooHigh
+    );
+    let curve = &mut ctx.accounts.bonding_curve;
+    // Only curves that still trade; a new threshold must not graduate the curve outside a buy
+    require!(curve.status == CurveStatus::Active, ErrorCode::BondingCurveComplete);
+    require!(
+        curve.graduation_mode != GraduationMode::SolThreshold
+            || !curve
+                .curve_state()
+                .is_graduated(GraduationTrigger::SolReserves(graduation_threshold)),
+        ErrorCode::InvalidCurveParams
+    );
+
+    emit!(CurveRepriced {
+        mint: curve.mint,
+        previous_platform_trade_fee_bps: curve.platform_trade_fee_bps,
+        previous_reserve_trade_fee_bps: curve.reserve_trade_fee_bps,
+        previous_creator_trade_fee_bps: curve.creator_trade_fee_bps,
+        previous_graduation_threshold: curve.graduation_threshold,
+        platform_trade_fee_bps,
+        reserve_trade_fee_bps,
+        creator_trade_fee_bps,
+        graduation_threshold,
+    });
+    curve.platform_trade_fee_bps = platform_trade_fee_bps;
+    curve.reserve_trade_fee_bps = reserve_trade_fee_bps;
+    curve.creator_trade_fee_bps = creator_trade_fee_bps;
+    curve.graduation_threshold = graduation_threshold;
+
+    // A creator fee turned on for an upgraded curve needs its vault funded before trades pay into it
+    if creator_trade_fee_bps > 0 {
+        let vault_rent = Rent::get()?
+            .minimum_balance(0)
+            .saturating_sub(ctx.accounts.creator_vault.lamports());
+        if vault_rent > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: ctx.accounts.creator_vault.to_account_info(),
+                    },
+                ),
+                vault_rent,
+            )?;
+        }
+    }
+
+    Ok(())
+}
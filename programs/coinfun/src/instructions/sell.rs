@@ -1,12 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
+    token_interface::{self, Burn, Mint, TokenAccount, TokenInterface, TransferChecked},
 };
-use crate::states::{Global, BondingCurve};
+use crate::states::{Global, BondingCurve, CurveStatus, Referrer, ReservePolicy};
 use crate::errors::ErrorCode;
 use crate::events::{Trade, TradeSide};
-use crate::consts::BPS_DENOMINATOR;
+use curve_math::{quote_sell, SellQuote};
 
 #[derive(Accounts)]
 pub struct Sell<'info> {
@@ -30,7 +30,9 @@ pub struct Sell<'info> {
         associated_token::authority = signer
     )]
     pub user_ata: InterfaceAccount<'info, TokenAccount>,
+    // Mutable for `ReservePolicy::Burn`
     #[account(
+        mut,
         constraint = bonding_curve.mint == mint.key()
     )]
     pub mint: InterfaceAccount<'info, Mint>,
@@ -39,8 +41,13 @@ pub struct Sell<'info> {
         bump
     )]
     pub global: Account<'info, Global>,
-    #[account(mut, constraint = global.platform_fee_recipient == platform_fee_recipient.key())]
-    pub platform_fee_recipient: SystemAccount<'info>,
+    // Accrues platform fees until `distribute_platform_fees`
+    #[account(
+        mut,
+        seeds = [b"platform_fee_vault"],
+        bump
+    )]
+    pub platform_fee_vault: SystemAccount<'info>,
     /// CHECK: Global reserve PDA (authority for all reserve ATAs)
     #[account(
         seeds = [b"reserve"], bump,
@@ -55,113 +62,71 @@ pub struct Sell<'info> {
         associated_token::token_program = token_program,
     )]
     pub reserve_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"creator_vault", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub creator_vault: SystemAccount<'info>,
+    // Receives the reserve fee of `KeepSol` curves
+    #[account(
+        mut,
+        seeds = [b"reserve_sol_vault"],
+        bump
+    )]
+    pub reserve_sol_vault: SystemAccount<'info>,
     pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+    // Optional; a registered referrer earns `referral_fee_bps` of the platform fee
+    #[account(
+        mut,
+        seeds = [b"referrer", referrer.authority.as_ref()],
+        bump
+    )]
+    pub referrer: Option<Account<'info, Referrer>>,
 }
 
 pub fn handler(ctx: Context<Sell>, token_amount: u64, min_sol_output: u64) -> Result<()> {
     let curve = &mut ctx.accounts.bonding_curve;
-    require!(!curve.complete, ErrorCode::BondingCurveComplete);
+    require!(curve.status == CurveStatus::Active, ErrorCode::BondingCurveComplete);
     require_gt!(token_amount, 0);
 
-    // Calculate SOL output
-    let k = u128::from(curve.virtual_sol_reserves)
-        .checked_mul(u128::from(curve.virtual_token_reserves))
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-    let new_virtual_token_reserves = curve
-        .virtual_token_reserves
-        .checked_add(token_amount)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-    let new_virtual_sol_reserves = k
-        .checked_div(u128::from(new_virtual_token_reserves))
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-    let sol_out_gross = curve
-        .virtual_sol_reserves
-        .checked_sub(new_virtual_sol_reserves as u64)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-
-    // Calculate fees (split between platform and reserve)
-    let total_fee_bps = ctx
-        .accounts
-        .global
-        .platform_trade_fee_bps
-        .checked_add(ctx.accounts.global.reserve_trade_fee_bps)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-    let fee = sol_out_gross
-        .checked_mul(total_fee_bps)
-        .and_then(|res| res.checked_div(BPS_DENOMINATOR))
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-
-    let platform_fee = sol_out_gross
-        .checked_mul(ctx.accounts.global.platform_trade_fee_bps)
-        .and_then(|res| res.checked_div(BPS_DENOMINATOR))
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-    let reserve_fee = sol_out_gross
-        .checked_mul(ctx.accounts.global.reserve_trade_fee_bps)
-        .and_then(|res| res.checked_div(BPS_DENOMINATOR))
+    // Fees come out of the gross SOL; the reserve fee stays in the curve and buys
+    // tokens back at the price after the user's sell
+    let quote = quote_sell(&curve.curve_state(), curve.fee_rates(Clock::get()?.slot), token_amount)
         .ok_or(ProgramError::ArithmeticOverflow)?;
 
-    let sol_out_net = sol_out_gross
-        .checked_sub(fee)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
+    // Validation
+    require_gte!(quote.sol_out_net, min_sol_output);
 
-    // Calculate tokens for reserve if reserve_fee > 0
-    // Reserve fee is used to buy tokens back from the curve at the rate after user's sell
-    let mut reserve_tokens_out: u64 = 0;
-    if reserve_fee > 0 {
-        // Use reserve fee to buy tokens at the current rate (after user's sell)
-        // new_virtual_sol_reserves already accounts for the user's sell
-        // Reserve fee buys tokens, so we add it to virtual SOL reserves
-        let reserve_k = u128::from(new_virtual_sol_reserves as u64)
-            .checked_mul(u128::from(new_virtual_token_reserves))
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-        let reserve_new_virtual_sol_reserves = (new_virtual_sol_reserves as u64)
-            .checked_add(reserve_fee)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-        let reserve_new_virtual_token_reserves = reserve_k
-            .checked_div(u128::from(reserve_new_virtual_sol_reserves))
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-        reserve_tokens_out = new_virtual_token_reserves
-            .checked_sub(reserve_new_virtual_token_reserves as u64)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-        
-        // Update virtual reserves for reserve purchase
-        curve.virtual_sol_reserves = reserve_new_virtual_sol_reserves;
-        curve.virtual_token_reserves = reserve_new_virtual_token_reserves as u64;
-    } else {
-        // No reserve fee, just update for user's sell
-        curve.virtual_sol_reserves = new_virtual_sol_reserves as u64;
-        curve.virtual_token_reserves = new_virtual_token_reserves;
-    }
+    settle(ctx, quote)
+}
 
-    // Validation
-    require_gte!(sol_out_net, min_sol_output);
-    
-    // State Updates for user's sell
-    // User receives net SOL (gross - all fees)
-    // Platform fee goes out, reserve fee stays in curve (used to buy tokens)
-    // So real SOL reserves decrease by: sol_out_gross - reserve_fee
-    let sol_removed_from_curve = sol_out_gross
-        .checked_sub(reserve_fee)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-    curve.real_sol_reserves = curve
-        .real_sol_reserves
-        .checked_sub(sol_removed_from_curve)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-    // User's tokens are added back
-    curve.real_token_reserves = curve
-        .real_token_reserves
-        .checked_add(token_amount)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-    
-    // Reserve tokens are deducted (if reserve_fee > 0)
-    if reserve_fee > 0 {
-        curve.real_token_reserves = curve
-            .real_token_reserves
-            .checked_sub(reserve_tokens_out)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-    }
+/// Applies a sell quote: updates the curve, moves the tokens and SOL and emits the trade.
+pub(crate) fn settle(ctx: Context<Sell>, quote: SellQuote) -> Result<()> {
+    let token_amount = quote.token_in;
+    let platform_fee = quote.fees.platform_fee;
+    let creator_fee = quote.fees.creator_fee;
+    let reserve_fee = quote.fees.reserve_fee;
+    let reserve_tokens_out = quote.reserve_tokens_out;
+    let sol_out_net = quote.sol_out_net;
+    let reserve_policy = ctx.accounts.bonding_curve.reserve_policy;
+    ctx.accounts
+        .bonding_curve
+        .open_for_trade(&Clock::get()?, ctx.accounts.signer.key())?;
+    ctx.accounts.bonding_curve.set_curve_state(quote.curve);
+    // The referrer's share comes out of the platform fee
+    let referral_fee = match &ctx.accounts.referrer {
+        Some(referrer) => {
+            require_keys_neq!(referrer.authority, ctx.accounts.signer.key(), ErrorCode::SelfReferral);
+            ctx.accounts
+                .global
+                .referral_fee(platform_fee)
+                .ok_or(ProgramError::ArithmeticOverflow)?
+        }
+        None => 0,
+    };
 
     // Token Transfer (CPI) - User sends tokens to bonding curve
     let decimals = ctx.accounts.mint.decimals;
@@ -176,32 +141,69 @@ pub fn handler(ctx: Context<Sell>, token_amount: u64, min_sol_output: u64) -> Re
     token_interface::transfer_checked(cpi_context, token_amount, decimals)?;
 
     // Reserve fee is used to buy tokens back from the curve
-    // Transfer reserve tokens to reserve ATA
-    if reserve_fee > 0 {
+    // Transfer reserve tokens to reserve ATA, or burn them
+    if reserve_tokens_out > 0 {
         let mint_key = ctx.accounts.mint.key();
         let bonding_curve_seeds: &[&[&[u8]]] = &[&[
             b"bonding_curve",
             mint_key.as_ref(),
             &[ctx.bumps.bonding_curve],
         ]];
-        let reserve_cpi_accounts = TransferChecked {
-            mint: ctx.accounts.mint.to_account_info(),
-            from: ctx.accounts.bonding_curve_ata.to_account_info(),
-            to: ctx.accounts.reserve_ata.to_account_info(),
-            authority: ctx.accounts.bonding_curve.to_account_info(),
-        };
         let reserve_cpi_program = ctx.accounts.token_program.to_account_info();
-        let reserve_cpi_context = CpiContext::new(reserve_cpi_program, reserve_cpi_accounts)
-            .with_signer(bonding_curve_seeds);
-        token_interface::transfer_checked(reserve_cpi_context, reserve_tokens_out, decimals)?;
+        if reserve_policy == ReservePolicy::Burn {
+            let burn_cpi_accounts = Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.bonding_curve_ata.to_account_info(),
+                authority: ctx.accounts.bonding_curve.to_account_info(),
+            };
+            let burn_cpi_context = CpiContext::new(reserve_cpi_program, burn_cpi_accounts)
+                .with_signer(bonding_curve_seeds);
+            token_interface::burn(burn_cpi_context, reserve_tokens_out)?;
+        } else {
+            let reserve_cpi_accounts = TransferChecked {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.bonding_curve_ata.to_account_info(),
+                to: ctx.accounts.reserve_ata.to_account_info(),
+                authority: ctx.accounts.bonding_curve.to_account_info(),
+            };
+            let reserve_cpi_context = CpiContext::new(reserve_cpi_program, reserve_cpi_accounts)
+                .with_signer(bonding_curve_seeds);
+            token_interface::transfer_checked(reserve_cpi_context, reserve_tokens_out, decimals)?;
+        }
     }
 
+    // The platform fee vault was funded to rent exemption by `initialize`
+    let platform_fee_to_vault = platform_fee
+        .checked_sub(referral_fee)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // `KeepSol` curves pay the reserve fee out to the reserve SOL vault instead
+    let reserve_sol = if reserve_policy == ReservePolicy::KeepSol { reserve_fee } else { 0 };
+
     // SOL Transfers using direct lamport manipulation (PDA cannot use CPI to send SOL)
-    // Total lamports must balance: curve loses (platform_fee + sol_out_net) = sol_removed_from_curve
-    ctx.accounts.bonding_curve.sub_lamports(platform_fee.checked_add(sol_out_net).unwrap())?;
+    // Total lamports must balance: curve loses (platform_fee + creator_fee + reserve_sol + sol_out_net), the drop in real_sol_reserves
+    let sol_out_gross = platform_fee
+        .checked_add(creator_fee)
+        .and_then(|fees| fees.checked_add(reserve_sol))
+        .and_then(|fees| fees.checked_add(sol_out_net))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    ctx.accounts.bonding_curve.sub_lamports(sol_out_gross)?;
     
-    if platform_fee > 0 {
-        ctx.accounts.platform_fee_recipient.add_lamports(platform_fee)?;
+    if platform_fee_to_vault > 0 {
+        ctx.accounts.platform_fee_vault.add_lamports(platform_fee_to_vault)?;
+    }
+
+    if let Some(referrer) = ctx.accounts.referrer.as_mut().filter(|_| referral_fee > 0) {
+        referrer.add_lamports(referral_fee)?;
+        referrer.accrue(referral_fee)?;
+    }
+
+    if creator_fee > 0 {
+        ctx.accounts.creator_vault.add_lamports(creator_fee)?;
+    }
+
+    if reserve_sol > 0 {
+        ctx.accounts.reserve_sol_vault.add_lamports(reserve_sol)?;
     }
     
     ctx.accounts.signer.add_lamports(sol_out_net)?;
@@ -213,6 +215,12 @@ pub fn handler(ctx: Context<Sell>, token_amount: u64, min_sol_output: u64) -> Re
         side: TradeSide::Sell,
         sol_amount: sol_out_net,
         token_amount,
+        sol_refunded: 0,
+        referrer: ctx.accounts.referrer.as_ref().map(|referrer| referrer.authority),
+        referral_fee,
+        reserve_policy,
+        reserve_fee,
+        reserve_token_amount: reserve_tokens_out,
     });
 
     Ok(())
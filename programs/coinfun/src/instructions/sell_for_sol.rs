@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+use crate::states::CurveStatus;
+use crate::errors::ErrorCode;
+use crate::instructions::sell::{settle, Sell};
+use curve_math::quote_sell_exact_out;
+
+// Takes the same accounts as `sell`
+pub fn handler(ctx: Context<Sell>, sol_out_net: u64, max_tokens_in: u64) -> Result<()> {
+    let curve = &ctx.accounts.bonding_curve;
+    require!(curve.status == CurveStatus::Active, ErrorCode::BondingCurveComplete);
+    require_gt!(sol_out_net, 0);
+
+    // Inverts the fee gross-up and the curve for the fewest tokens that pay out `sol_out_net`
+    let quote = quote_sell_exact_out(&curve.curve_state(), curve.fee_rates(Clock::get()?.slot), sol_out_net)
+        .ok_or(ErrorCode::SolAmountUnavailable)?;
+    require_gte!(max_tokens_in, quote.token_in);
+
+    settle(ctx, quote)
+}
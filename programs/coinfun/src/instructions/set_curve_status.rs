@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::states::{Global, BondingCurve, CurveStatus};
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetCurveStatus<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"global"],
+        bump,
+        constraint = global.authority == authority.key()
+    )]
+    pub global: Account<'info, Global>,
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.mint.as_ref()],
+        bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+}
+
+pub fn handler(ctx: Context<SetCurveStatus>, status: CurveStatus) -> Result<()> {
+    // Graduation and on-chain migration are driven by `buy` and `migrate`; the authority
+    // only confirms a manual migration of a curve drained before `migrate` existed, or sends
+    // a stuck graduated curve to refunds.
+    require!(
+        matches!(status, CurveStatus::Migrated | CurveStatus::Refunding),
+        ErrorCode::InvalidCurveStatusTransition
+    );
+    ctx.accounts.bonding_curve.set_status(status)
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::states::{Global, MigrationTarget};
+use crate::events::MigrationProgramSet;
+
+#[derive(Accounts)]
+pub struct SetMigrationProgram<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"global"],
+        bump,
+        constraint = global.authority == authority.key()
+    )]
+    pub global: Account<'info, Global>,
+}
+
+pub fn handler(
+    ctx: Context<SetMigrationProgram>,
+    target: MigrationTarget,
+    program_id: Pubkey,
+    config: Pubkey,
+) -> Result<()> {
+    // `Pubkey::default()` disables the target for new launches and pending migrations
+    ctx.accounts.global.migration_programs[target as usize] = program_id;
+    ctx.accounts.global.migration_configs[target as usize] = config;
+
+    emit!(MigrationProgramSet {
+        target,
+        program: program_id,
+        config,
+    });
+
+    Ok(())
+}
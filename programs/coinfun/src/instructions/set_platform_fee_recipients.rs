@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::states::{Global, PlatformFeeRecipient};
+use crate::errors::ErrorCode;
+use crate::events::PlatformFeeRecipientsSet;
+use crate::consts::MAX_PLATFORM_FEE_RECIPIENTS;
+
+#[derive(Accounts)]
+pub struct SetPlatformFeeRecipients<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"global"],
+        bump,
+        constraint = global.authority == authority.key()
+    )]
+    pub global: Account<'info, Global>,
+    #[account(
+        mut,
+        seeds = [b"platform_fee_vault"],
+        bump
+    )]
+    pub platform_fee_vault: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Replaces the whole list; the vault's balance is split by the new weights from the
+// next `distribute_platform_fees`
+pub fn handler(ctx: Context<SetPlatformFeeRecipients>, recipients: Vec<PlatformFeeRecipient>) -> Result<()> {
+    require!(
+        (1..=MAX_PLATFORM_FEE_RECIPIENTS).contains(&recipients.len()),
+        ErrorCode::InvalidFeeRecipients
+    );
+    for (i, payee) in recipients.iter().enumerate() {
+        require!(
+            payee.weight > 0
+                && payee.recipient != Pubkey::default()
+                && recipients[..i].iter().all(|other| other.recipient != payee.recipient),
+            ErrorCode::InvalidFeeRecipients
+        );
+    }
+
+    let mut platform_fee_recipients = [PlatformFeeRecipient::default(); MAX_PLATFORM_FEE_RECIPIENTS];
+    platform_fee_recipients[..recipients.len()].copy_from_slice(&recipients);
+    ctx.accounts.global.platform_fee_recipients = platform_fee_recipients;
+
+    // `initialize` funds the vault to rent exemption; deployments from before it are
+    // funded here
+    let vault_rent = Rent::get()?
+        .minimum_balance(0)
+        .saturating_sub(ctx.accounts.platform_fee_vault.lamports());
+    if vault_rent > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.platform_fee_vault.to_account_info(),
+                },
+            ),
+            vault_rent,
+        )?;
+    }
+
+    emit!(PlatformFeeRecipientsSet { recipients });
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::states::Global;
+use crate::errors::ErrorCode;
+use crate::events::PriceFeedSet;
+use crate::consts::BPS_DENOMINATOR;
+
+#[derive(Accounts)]
+pub struct SetPriceFeed<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"global"],
+        bump,
+        constraint = global.authority == authority.key()
+    )]
+    pub global: Account<'info, Global>,
+}
+
+pub fn handler(
+    ctx: Context<SetPriceFeed>,
+    price_feed: Pubkey,
+    max_price_age_secs: u64,
+    max_price_confidence_bps: u64,
+) -> Result<()> {
+    require!(max_price_confidence_bps <= BPS_DENOMINATOR, ErrorCode::InvalidPriceFeed);
+
+    // `Pubkey::default()` disables new `UsdMarketCap` launches, and buys on existing ones
+    // until a feed is set again
+    let global = &mut ctx.accounts.global;
+    global.sol_usd_price_feed = price_feed;
+    global.max_price_age_secs = max_price_age_secs;
+    global.max_price_confidence_bps = max_price_confidence_bps;
+
+    emit!(PriceFeedSet {
+        price_feed,
+        max_price_age_secs,
+        max_price_confidence_bps,
+    });
+
+    Ok(())
+}
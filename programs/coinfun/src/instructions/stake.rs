@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+use crate::states::{Global, StakePool, StakePosition};
+use crate::events::{Staked, StakingRewardsAccrued};
+
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"stake_pool", mint.key().as_ref()],
+        bump
+    )]
+    pub stake_pool: Account<'info, StakePool>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = stake_pool,
+        associated_token::token_program = token_program,
+    )]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + StakePosition::INIT_SPACE,
+        seeds = [b"stake_position", stake_pool.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = owner
+    )]
+    pub owner_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        seeds = [b"global"],
+        bump
+    )]
+    pub global: Account<'info, Global>,
+    /// CHECK: Global reserve PDA (authority for all reserve ATAs)
+    #[account(
+        seeds = [b"reserve"], bump,
+        constraint = global.reserve == global_reserve.key()
+    )]
+    pub global_reserve: UncheckedAccount<'info>,
+    // The reserve's tokens for the mint, swept into the pool for its stakers
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = global_reserve,
+        associated_token::token_program = token_program,
+    )]
+    pub reserve_ata: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler(ctx: Context<Stake>, amount: u64) -> Result<()> {
+    require_gt!(amount, 0);
+
+    // Rewards released so far belong to the stakes from before this one
+    sweep_reserve(
+        &mut ctx.accounts.stake_pool,
+        &ctx.accounts.reserve_ata,
+        &ctx.accounts.stake_vault,
+        &ctx.accounts.mint,
+        &ctx.accounts.global_reserve,
+        ctx.bumps.global_reserve,
+        &ctx.accounts.token_program,
+    )?;
+
+    let pool = &mut ctx.accounts.stake_pool;
+    let position = &mut ctx.accounts.stake_position;
+    position.settle(pool)?;
+    position.owner = ctx.accounts.owner.key();
+    position.amount = position
+        .amount
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    pool.total_staked = pool
+        .total_staked
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let decimals = ctx.accounts.mint.decimals;
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.owner_ata.to_account_info(),
+        to: ctx.accounts.stake_vault.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+    token_interface::transfer_checked(cpi_context, amount, decimals)?;
+
+    emit!(Staked {
+        mint: ctx.accounts.mint.key(),
+        owner: ctx.accounts.owner.key(),
+        amount,
+        total_staked: ctx.accounts.stake_pool.total_staked,
+    });
+
+    Ok(())
+}
+
+/// Moves the reserve's whole balance of the mint into the stake vault, and releases
+/// what has streamed since the last update to the current stakers. New tokens restart
+/// the stream; see `StakePool::add_rewards`.
+pub(crate) fn sweep_reserve<'info>(
+    stake_pool: &mut Account<'info, StakePool>,
+    reserve_ata: &InterfaceAccount<'info, TokenAccount>,
+    stake_vault: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    global_reserve: &UncheckedAccount<'info>,
+    global_reserve_bump: u8,
+    token_program: &Interface<'info, TokenInterface>,
+) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    let released = stake_pool.update(slot)?;
+    if released > 0 {
+        emit!(StakingRewardsAccrued {
+            mint: mint.key(),
+            amount: released,
+            reward_per_share: stake_pool.reward_per_share,
+        });
+    }
+
+    let amount = reserve_ata.amount;
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let global_reserve_seeds: &[&[&[u8]]] = &[&[b"reserve", &[global_reserve_bump]]];
+    let cpi_accounts = TransferChecked {
+        mint: mint.to_account_info(),
+        from: reserve_ata.to_account_info(),
+        to: stake_vault.to_account_info(),
+        authority: global_reserve.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(token_program.to_account_info(), cpi_accounts)
+        .with_signer(global_reserve_seeds);
+    token_interface::transfer_checked(cpi_context, amount, mint.decimals)?;
+    stake_pool.add_rewards(amount, slot)
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::states::Global;
+use crate::states::{CurveParams, Global, LpPolicy, SurplusTokenPolicy};
 use crate::errors::ErrorCode;
-use crate::consts::MAX_FEE_BPS;
+use crate::consts::{BPS_DENOMINATOR, MAX_FEE_BPS};
 
 #[derive(Accounts)]
 pub struct UpdateGlobalConfig<'info> {
@@ -20,26 +20,46 @@ pub struct UpdateGlobalConfig<'info> {
 pub fn handler(
     ctx: Context<UpdateGlobalConfig>,
     new_authority: Pubkey,
-    new_platform_fee_recipient: Pubkey,
     new_platform_trade_fee_bps: u64,
     new_reserve_trade_fee_bps: u64,
     new_initial_virtual_token_reserves: u64,
     new_initial_virtual_sol_reserves: u64,
     new_token_total_supply: u64,
     new_graduation_threshold: u64,
+    new_lp_policy: LpPolicy,
+    new_keeper_reward_bps: u64,
+    new_surplus_token_policy: SurplusTokenPolicy,
+    new_migration_fee_lamports: u64,
+    new_curve_kinds: [bool; 3],
+    new_min_curve_params: CurveParams,
+    new_max_curve_params: CurveParams,
+    new_creator_trade_fee_bps: u64,
+    new_referral_fee_bps: u64,
 ) -> Result<()> {
     // Enforce 30% maximum fee cap
     require!(
         new_platform_trade_fee_bps
-            .checked_add(new_reserve_trade_fee_bps)
-            .unwrap_or(u64::MAX)
+            .saturating_add(new_reserve_trade_fee_bps)
+            .saturating_add(new_creator_trade_fee_bps)
             <= MAX_FEE_BPS,
         ErrorCode::FeeTooHigh
     );
+    require!(new_keeper_reward_bps <= MAX_FEE_BPS, ErrorCode::FeeTooHigh);
+    require!(new_referral_fee_bps <= BPS_DENOMINATOR, ErrorCode::FeeTooHigh);
+    // The defaults must themselves be a valid launch
+    let default_curve_params = CurveParams {
+        initial_virtual_token_reserves: new_initial_virtual_token_reserves,
+        initial_virtual_sol_reserves: new_initial_virtual_sol_reserves,
+        token_total_supply: new_token_total_supply,
+        graduation_threshold: new_graduation_threshold,
+    };
+    require!(
+        default_curve_params.is_within(&new_min_curve_params, &new_max_curve_params),
+        ErrorCode::CurveParamsOutOfBounds
+    );
 
     ctx.accounts.global.set_inner(Global {
         authority: new_authority,
-        platform_fee_recipient: new_platform_fee_recipient,
         reserve: ctx.accounts.global.reserve, // Keep existing reserve PDA
         initial_virtual_token_reserves: new_initial_virtual_token_reserves,
         initial_virtual_sol_reserves: new_initial_virtual_sol_reserves,
@@ -47,6 +67,21 @@ pub fn handler(
         platform_trade_fee_bps: new_platform_trade_fee_bps,
         reserve_trade_fee_bps: new_reserve_trade_fee_bps,
         graduation_threshold: new_graduation_threshold,
+        migration_programs: ctx.accounts.global.migration_programs,
+        lp_policy: new_lp_policy,
+        keeper_reward_bps: new_keeper_reward_bps,
+        migration_configs: ctx.accounts.global.migration_configs,
+        surplus_token_policy: new_surplus_token_policy,
+        migration_fee_lamports: new_migration_fee_lamports,
+        curve_kinds: new_curve_kinds,
+        min_curve_params: new_min_curve_params,
+        max_curve_params: new_max_curve_params,
+        sol_usd_price_feed: ctx.accounts.global.sol_usd_price_feed,
+        max_price_age_secs: ctx.accounts.global.max_price_age_secs,
+        max_price_confidence_bps: ctx.accounts.global.max_price_confidence_bps,
+        creator_trade_fee_bps: new_creator_trade_fee_bps,
+        referral_fee_bps: new_referral_fee_bps,
+        platform_fee_recipients: ctx.accounts.global.platform_fee_recipients,
     });
 
     Ok(())
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::states::{Global, BondingCurve, CurveStatus};
+
+#[derive(Accounts)]
+pub struct UpgradeBondingCurve<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"global"],
+        bump,
+        constraint = global.authority == authority.key()
+    )]
+    pub global: Account<'info, Global>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: May still be in a legacy layout; decoded and re-encoded in the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump
+    )]
+    pub bonding_curve: UncheckedAccount<'info>,
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = bonding_curve,
+        associated_token::token_program = token_program,
+    )]
+    pub bonding_curve_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"creator_vault", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub creator_vault: SystemAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<UpgradeBondingCurve>) -> Result<()> {
+    let curve_info = ctx.accounts.bonding_curve.to_account_info();
+
+    // Grow the account to the current layout; appended fields start zeroed
+    let space = 8 + BondingCurve::INIT_SPACE;
+    if curve_info.data_len() < space {
+        let rent_due = Rent::get()?
+            .minimum_balance(space)
+            .saturating_sub(curve_info.lamports());
+        if rent_due > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: curve_info.clone(),
+                    },
+                ),
+                rent_due,
+            )?;
+        }
+        curve_info.resize(space)?;
+    }
+
+    let mut curve = BondingCurve::try_deserialize(&mut &curve_info.try_borrow_data()?[..])?;
+
+    // Before statuses existed, the authority's `withdraw` drained graduated curves but left
+    // them `complete`
+    if curve.status == CurveStatus::Complete && ctx.accounts.bonding_curve_ata.amount == 0 {
+        curve.set_status(CurveStatus::Migrating)?;
+    }
+
+    // Curves from before per-curve economics keep trading on the global ones
+    if curve.graduation_threshold == 0 {
+        let global = &ctx.accounts.global;
+        curve.graduation_threshold = global.graduation_threshold;
+        curve.platform_trade_fee_bps = global.platform_trade_fee_bps;
+        curve.reserve_trade_fee_bps = global.reserve_trade_fee_bps;
+    }
+
+    curve.try_serialize(&mut &mut curve_info.try_borrow_mut_data()?[..])?;
+
+    // Curves from before creator fees have no funded vault; trades pay any creator fee into it
+    let vault_rent = Rent::get()?
+        .minimum_balance(0)
+        .saturating_sub(ctx.accounts.creator_vault.lamports());
+    if vault_rent > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.creator_vault.to_account_info(),
+                },
+            ),
+            vault_rent,
+        )?;
+    }
+
+    Ok(())
+}
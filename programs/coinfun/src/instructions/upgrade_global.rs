@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use crate::states::Global;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct UpgradeGlobal<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: May still be in a legacy layout; decoded and re-encoded in the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [b"global"],
+        bump
+    )]
+    pub global: UncheckedAccount<'info>,
+    /// Funded here for deployments from before `initialize` funded it
+    #[account(
+        mut,
+        seeds = [b"reserve_sol_vault"],
+        bump
+    )]
+    pub reserve_sol_vault: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<UpgradeGlobal>) -> Result<()> {
+    let global_info = ctx.accounts.global.to_account_info();
+
+    // `authority` is the first field in every layout
+    {
+        let data = global_info.try_borrow_data()?;
+        let authority = data
+            .get(8..40)
+            .map(Pubkey::try_from)
+            .and_then(|key| key.ok())
+            .ok_or(ErrorCode::Unauthorized)?;
+        require_keys_eq!(authority, ctx.accounts.authority.key(), ErrorCode::Unauthorized);
+    }
+
+    let space = 8 + Global::INIT_SPACE;
+    if global_info.data_len() != space {
+        // Every earlier layout, from the original 152 bytes to the last one 32 bytes longer
+        // than this, holds a `platform_fee_recipient` right after `authority`, from before
+        // the migration fee went to the platform fee vault. Drop it and shift the rest down
+        let mut data = global_info.try_borrow_mut_data()?;
+        let len = data.len();
+        require_gte!(len, 72, anchor_lang::error::ErrorCode::AccountDidNotDeserialize);
+        data.copy_within(72.., 40);
+        data[len - 32..].fill(0);
+    }
+
+    // Grow the account to the current layout; appended fields start zeroed
+    if global_info.data_len() != space {
+        let rent_due = Rent::get()?
+            .minimum_balance(space)
+            .saturating_sub(global_info.lamports());
+        if rent_due > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: global_info.clone(),
+                    },
+                ),
+                rent_due,
+            )?;
+        }
+        global_info.resize(space)?;
+    }
+
+    let mut global = Global::try_deserialize(&mut &global_info.try_borrow_data()?[..])?;
+
+    // Configs from before curve kinds only launched constant product curves
+    if global.curve_kinds.iter().all(|allowed| !allowed) {
+        global.curve_kinds[0] = true;
+    }
+
+    // Configs from before creator-chosen params only allow the defaults
+    if global.max_curve_params.token_total_supply == 0 {
+        global.min_curve_params = global.default_curve_params();
+        global.max_curve_params = global.default_curve_params();
+    }
+
+    global.try_serialize(&mut &mut global_info.try_borrow_mut_data()?[..])?;
+
+    // Trades and migrations pay straight into the reserve SOL vault, so it has to be rent
+    // exempt already
+    let vault_rent = Rent::get()?
+        .minimum_balance(0)
+        .saturating_sub(ctx.accounts.reserve_sol_vault.lamports());
+    if vault_rent > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.reserve_sol_vault.to_account_info(),
+                },
+            ),
+            vault_rent,
+        )?;
+    }
+
+    Ok(())
+}
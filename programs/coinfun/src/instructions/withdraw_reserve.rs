@@ -3,7 +3,7 @@ use anchor_spl::{
     associated_token::AssociatedToken,
     token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
 };
-use crate::states::{Global, BondingCurve};
+use crate::states::{Global, BondingCurve, StakePool};
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
@@ -32,6 +32,12 @@ pub struct WithdrawReserve<'info> {
         constraint = bonding_curve.mint == mint.key()
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
+    // The mint's stake pool, if one was opened; its stakers own the reserve's tokens
+    #[account(
+        seeds = [b"stake_pool", mint.key().as_ref()],
+        bump
+    )]
+    pub stake_pool: Option<Account<'info, StakePool>>,
     #[account(
         mut,
         associated_token::mint = mint,
@@ -56,6 +62,8 @@ pub fn handler(ctx: Context<WithdrawReserve>, amount: u64) -> Result<()> {
     require!(amount > 0, ErrorCode::NothingToWithdraw);
     require!(amount <= token_balance, ErrorCode::NothingToWithdraw);
 
+    require!(ctx.accounts.stake_pool.is_none(), ErrorCode::ReserveStaked);
+
     let decimals = ctx.accounts.mint.decimals;
     // Use global reserve PDA as authority (seeded with ["reserve"])
     let global_reserve_seeds: &[&[&[u8]]] = &[&[
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::states::Global;
+use crate::errors::ErrorCode;
+use crate::events::ReserveSolWithdrawn;
+
+#[derive(Accounts)]
+pub struct WithdrawReserveSol<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"global"],
+        bump,
+        constraint = global.authority == authority.key()
+    )]
+    pub global: Account<'info, Global>,
+    #[account(
+        mut,
+        seeds = [b"reserve_sol_vault"],
+        bump
+    )]
+    pub reserve_sol_vault: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<WithdrawReserveSol>, amount: u64) -> Result<()> {
+    // The vault keeps its rent-exempt minimum
+    let available = ctx
+        .accounts
+        .reserve_sol_vault
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+    require!(amount > 0, ErrorCode::NothingToWithdraw);
+    require!(amount <= available, ErrorCode::InsufficientReserve);
+
+    let reserve_sol_vault_seeds: &[&[&[u8]]] = &[&[
+        b"reserve_sol_vault",
+        &[ctx.bumps.reserve_sol_vault],
+    ]];
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.reserve_sol_vault.to_account_info(),
+                to: ctx.accounts.authority.to_account_info(),
+            },
+        )
+        .with_signer(reserve_sol_vault_seeds),
+        amount,
+    )?;
+
+    emit!(ReserveSolWithdrawn {
+        authority: ctx.accounts.authority.key(),
+        amount,
+        remaining: available - amount,
+    });
+
+    Ok(())
+}
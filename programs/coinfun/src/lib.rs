@@ -1,15 +1,24 @@
 // fixes unexpected `cfg` errors
 // check https://solana.stackexchange.com/questions/17777/unexpected-cfg-condition-value-solana
 #![allow(unexpected_cfgs)]
+// `#[program]` expands to a call to the deprecated `AccountInfo::realloc` in anchor 0.31
+#![allow(deprecated)]
 use anchor_lang::prelude::*;
 
 mod states;
 mod errors;
 mod events;
 mod consts;
+mod math;
+mod oracle;
+mod adapters;
 mod instructions;
 
 use instructions::*;
+use states::{
+    CurveKind, CurveParams, CurveStatus, GraduationMode, LaunchFee, LaunchWindow, LpPolicy,
+    MigrationTarget, PlatformFeeRecipient, ReservePolicy, SurplusTokenPolicy, TradingStart,
+};
 
 declare_id!("ihC7UqkLYWxQKVuYLiWNGqGvQCZb2ih4DXMLfyM6F68");
 
@@ -17,6 +26,7 @@ declare_id!("ihC7UqkLYWxQKVuYLiWNGqGvQCZb2ih4DXMLfyM6F68");
 pub mod coinfun {
     use super::*;
 
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize(
         ctx: Context<Initialize>,
         initial_virtual_token_reserves: u64,
@@ -24,8 +34,16 @@ pub mod coinfun {
         token_total_supply: u64,
         platform_trade_fee_bps: u64,
         reserve_trade_fee_bps: u64,
-        platform_fee_recipient: Pubkey,
         graduation_threshold: u64,
+        lp_policy: LpPolicy,
+        keeper_reward_bps: u64,
+        surplus_token_policy: SurplusTokenPolicy,
+        migration_fee_lamports: u64,
+        curve_kinds: [bool; 3],
+        min_curve_params: CurveParams,
+        max_curve_params: CurveParams,
+        creator_trade_fee_bps: u64,
+        referral_fee_bps: u64,
     ) -> Result<()> {
         instructions::initialize::handler(
             ctx,
@@ -34,30 +52,64 @@ pub mod coinfun {
             token_total_supply,
             platform_trade_fee_bps,
             reserve_trade_fee_bps,
-            platform_fee_recipient,
             graduation_threshold,
+            lp_policy,
+            keeper_reward_bps,
+            surplus_token_policy,
+            migration_fee_lamports,
+            curve_kinds,
+            min_curve_params,
+            max_curve_params,
+            creator_trade_fee_bps,
+            referral_fee_bps,
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         ctx: Context<Create>,
         token_name: String,
         token_symbol: String,
         token_uri: String,
+        migration_target: MigrationTarget,
+        curve_kind: CurveKind,
+        curve_params: Option<CurveParams>,
+        graduation_mode: GraduationMode,
+        launch_window: Option<LaunchWindow>,
+        trading_starts_at: TradingStart,
+        launch_fee: Option<LaunchFee>,
+        reserve_policy: ReservePolicy,
     ) -> Result<()> {
-        instructions::create::handler(ctx, token_name, token_symbol, token_uri)
+        instructions::create::handler(
+            ctx,
+            token_name,
+            token_symbol,
+            token_uri,
+            migration_target,
+            curve_kind,
+            curve_params,
+            graduation_mode,
+            launch_window,
+            trading_starts_at,
+            launch_fee,
+            reserve_policy,
+        )
     }
 
     pub fn buy(ctx: Context<Buy>, sol_amount: u64, min_token_output: u64) -> Result<()> {
         instructions::buy::handler(ctx, sol_amount, min_token_output)
     }
 
+    pub fn buy_exact_out(ctx: Context<Buy>, token_amount: u64, max_sol_cost: u64) -> Result<()> {
+        instructions::buy_exact_out::handler(ctx, token_amount, max_sol_cost)
+    }
+
     pub fn sell(ctx: Context<Sell>, token_amount: u64, min_sol_output: u64) -> Result<()> {
         instructions::sell::handler(ctx, token_amount, min_sol_output)
     }
 
-    pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
-        instructions::withdraw::handler(ctx)
+    pub fn sell_for_sol(ctx: Context<Sell>, sol_out_net: u64, max_tokens_in: u64) -> Result<()> {
+        instructions::sell_for_sol::handler(ctx, sol_out_net, max_tokens_in)
     }
 
     pub fn withdraw_reserve(ctx: Context<WithdrawReserve>, amount: u64) -> Result<()> {
@@ -68,28 +120,153 @@ pub mod coinfun {
         instructions::deposit_to_reserve::handler(ctx, amount)
     }
 
+    pub fn migrate<'info>(ctx: Context<'_, '_, 'info, 'info, Migrate<'info>>) -> Result<()> {
+        instructions::migrate::handler(ctx)
+    }
+
+    pub fn set_curve_status(ctx: Context<SetCurveStatus>, status: CurveStatus) -> Result<()> {
+        instructions::set_curve_status::handler(ctx, status)
+    }
+
+    pub fn refund(ctx: Context<Refund>, token_amount: u64, min_sol_output: u64) -> Result<()> {
+        instructions::refund::handler(ctx, token_amount, min_sol_output)
+    }
+
+    pub fn reprice_curve(
+        ctx: Context<RepriceCurve>,
+        platform_trade_fee_bps: u64,
+        reserve_trade_fee_bps: u64,
+        creator_trade_fee_bps: u64,
+        graduation_threshold: u64,
+    ) -> Result<()> {
+        instructions::reprice_curve::handler(
+            ctx,
+            platform_trade_fee_bps,
+            reserve_trade_fee_bps,
+            creator_trade_fee_bps,
+            graduation_threshold,
+        )
+    }
+
+    pub fn upgrade_bonding_curve(ctx: Context<UpgradeBondingCurve>) -> Result<()> {
+        instructions::upgrade_bonding_curve::handler(ctx)
+    }
+
+    pub fn upgrade_global(ctx: Context<UpgradeGlobal>) -> Result<()> {
+        instructions::upgrade_global::handler(ctx)
+    }
+
+    pub fn set_migration_program(
+        ctx: Context<SetMigrationProgram>,
+        target: MigrationTarget,
+        program_id: Pubkey,
+        config: Pubkey,
+    ) -> Result<()> {
+        instructions::set_migration_program::handler(ctx, target, program_id, config)
+    }
+
+    pub fn set_price_feed(
+        ctx: Context<SetPriceFeed>,
+        price_feed: Pubkey,
+        max_price_age_secs: u64,
+        max_price_confidence_bps: u64,
+    ) -> Result<()> {
+        instructions::set_price_feed::handler(ctx, price_feed, max_price_age_secs, max_price_confidence_bps)
+    }
+
+    pub fn release_lp(ctx: Context<ReleaseLp>) -> Result<()> {
+        instructions::release_lp::handler(ctx)
+    }
+
+    pub fn harvest_pool_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, HarvestPoolFees<'info>>,
+    ) -> Result<()> {
+        instructions::harvest_pool_fees::handler(ctx)
+    }
+
+    pub fn withdraw_reserve_sol(ctx: Context<WithdrawReserveSol>, amount: u64) -> Result<()> {
+        instructions::withdraw_reserve_sol::handler(ctx, amount)
+    }
+
+    pub fn claim_creator_fees(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+        instructions::claim_creator_fees::handler(ctx)
+    }
+
+    pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+        instructions::register_referrer::handler(ctx)
+    }
+
+    pub fn claim_referral_fees(ctx: Context<ClaimReferralFees>) -> Result<()> {
+        instructions::claim_referral_fees::handler(ctx)
+    }
+
+    pub fn set_platform_fee_recipients(
+        ctx: Context<SetPlatformFeeRecipients>,
+        recipients: Vec<PlatformFeeRecipient>,
+    ) -> Result<()> {
+        instructions::set_platform_fee_recipients::handler(ctx, recipients)
+    }
+
+    pub fn distribute_platform_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributePlatformFees<'info>>,
+    ) -> Result<()> {
+        instructions::distribute_platform_fees::handler(ctx)
+    }
+
+    pub fn create_stake_pool(ctx: Context<CreateStakePool>) -> Result<()> {
+        instructions::create_stake_pool::handler(ctx)
+    }
+
+    pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
+        instructions::stake::handler(ctx, amount)
+    }
+
+    pub fn unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+        instructions::unstake::handler(ctx, amount)
+    }
+
+    pub fn claim_staking_rewards(ctx: Context<ClaimStakingRewards>) -> Result<()> {
+        instructions::claim_staking_rewards::handler(ctx)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn update_global_config(
         ctx: Context<UpdateGlobalConfig>,
         new_authority: Pubkey,
-        new_platform_fee_recipient: Pubkey,
         new_platform_trade_fee_bps: u64,
         new_reserve_trade_fee_bps: u64,
         new_initial_virtual_token_reserves: u64,
         new_initial_virtual_sol_reserves: u64,
         new_token_total_supply: u64,
         new_graduation_threshold: u64,
+        new_lp_policy: LpPolicy,
+        new_keeper_reward_bps: u64,
+        new_surplus_token_policy: SurplusTokenPolicy,
+        new_migration_fee_lamports: u64,
+        new_curve_kinds: [bool; 3],
+        new_min_curve_params: CurveParams,
+        new_max_curve_params: CurveParams,
+        new_creator_trade_fee_bps: u64,
+        new_referral_fee_bps: u64,
     ) -> Result<()> {
         instructions::update_global_config::handler(
             ctx,
             new_authority,
-            new_platform_fee_recipient,
             new_platform_trade_fee_bps,
             new_reserve_trade_fee_bps,
             new_initial_virtual_token_reserves,
             new_initial_virtual_sol_reserves,
             new_token_total_supply,
             new_graduation_threshold,
+            new_lp_policy,
+            new_keeper_reward_bps,
+            new_surplus_token_policy,
+            new_migration_fee_lamports,
+            new_curve_kinds,
+            new_min_curve_params,
+            new_max_curve_params,
+            new_creator_trade_fee_bps,
+            new_referral_fee_bps,
         )
     }
 }
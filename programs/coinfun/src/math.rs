@@ -0,0 +1,179 @@
+// Fixed-width helpers for the Q64.64 math used when seeding DEX pools.
+// Everything here rounds down; callers rely on that so the amounts a pool
+// pulls for a given liquidity never exceed what was deposited.
+
+/// Computes `a * b / denominator` with a 256-bit intermediate product.
+/// Returns `None` on division by zero or if the result does not fit in a u128.
+pub fn mul_div_floor(a: u128, b: u128, denominator: u128) -> Option<u128> {
+    if denominator == 0 {
+        return None;
+    }
+    let (hi, lo) = full_mul(a, b);
+    if hi >= denominator {
+        return None;
+    }
+
+    // Shift-subtract long division of (hi, lo) by denominator.
+    let mut remainder = hi;
+    let mut quotient: u128 = 0;
+    for i in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((lo >> i) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= denominator {
+            remainder = remainder.wrapping_sub(denominator);
+            quotient |= 1;
+        }
+    }
+    Some(quotient)
+}
+
+/// Multiplies two Q64.64 numbers. Returns `None` if the result does not fit in a u128.
+pub fn mul_q64(a: u128, b: u128) -> Option<u128> {
+    let (hi, lo) = full_mul(a, b);
+    if hi >> 64 != 0 {
+        return None;
+    }
+    Some((hi << 64) | (lo >> 64))
+}
+
+/// Integer square root, rounded down.
+pub fn sqrt_floor(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+    let mut x = 1u128 << ((128 - value.leading_zeros()).div_ceil(2));
+    loop {
+        let y = (x + value / x) >> 1;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// Sizes pool deposits to the curve's final price, `price_sol / price_token`,
+/// using all of whichever side runs out first. Returns `(token_deposit, sol_deposit)`.
+pub fn deposits_at_price(
+    token_amount: u64,
+    sol_amount: u64,
+    price_sol: u64,
+    price_token: u64,
+) -> Option<(u64, u64)> {
+    let tokens_for_sol = mul_div_floor(
+        u128::from(sol_amount),
+        u128::from(price_token),
+        u128::from(price_sol),
+    )?;
+    if tokens_for_sol <= u128::from(token_amount) {
+        return Some((tokens_for_sol as u64, sol_amount));
+    }
+    let sol_for_tokens = mul_div_floor(
+        u128::from(token_amount),
+        u128::from(price_sol),
+        u128::from(price_token),
+    )?;
+    Some((token_amount, u64::try_from(sol_for_tokens).ok()?))
+}
+
+fn full_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+
+    let cross = (lo_lo >> 64) + (hi_lo & MASK) + (lo_hi & MASK);
+    let lo = (cross << 64) | (lo_lo & MASK);
+    let hi = hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (cross >> 64);
+    (hi, lo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const Q64: u128 = 1 << 64;
+
+    #[test]
+    fn mul_div_floor_rounds_down_through_a_wide_product() {
+        assert_eq!(mul_div_floor(7, 3, 2), Some(10));
+        assert_eq!(mul_div_floor(1, 1, 3), Some(0));
+        assert_eq!(mul_div_floor(u128::MAX, 1, 1), Some(u128::MAX));
+        // The product overflows a u128 but the quotient does not
+        assert_eq!(mul_div_floor(u128::MAX, u128::MAX, u128::MAX), Some(u128::MAX));
+        assert_eq!(mul_div_floor(u128::MAX, 3, 4), Some(u128::MAX / 4 * 3 + 2));
+        assert_eq!(mul_div_floor(Q64, Q64, Q64), Some(Q64));
+        assert_eq!(mul_div_floor(Q64 - 1, Q64 + 1, Q64), Some(Q64 - 1));
+    }
+
+    #[test]
+    fn mul_div_floor_rejects_zero_denominators_and_wide_results() {
+        assert_eq!(mul_div_floor(1, 1, 0), None);
+        assert_eq!(mul_div_floor(u128::MAX, 2, 1), None);
+        // 2^128 exactly is one past the largest result
+        assert_eq!(mul_div_floor(Q64, Q64, 1), None);
+        assert_eq!(mul_div_floor(Q64, Q64, 2), Some(1 << 127));
+    }
+
+    #[test]
+    fn mul_q64_keeps_q64_64_scaling() {
+        assert_eq!(mul_q64(Q64, Q64), Some(Q64));
+        assert_eq!(mul_q64(Q64 / 2, 3 * Q64), Some(3 * Q64 / 2));
+        // The fractional part of the product is dropped
+        assert_eq!(mul_q64(1, 1), Some(0));
+        assert_eq!(mul_q64(Q64 + 1, Q64 - 1), Some(Q64 - 1));
+        assert_eq!(mul_q64(u128::MAX, Q64), Some(u128::MAX));
+        assert_eq!(mul_q64(u128::MAX, Q64 + 1), None);
+        assert_eq!(mul_q64(1 << 96, 1 << 96), None);
+    }
+
+    #[test]
+    fn sqrt_floor_rounds_down() {
+        assert_eq!(sqrt_floor(0), 0);
+        assert_eq!(sqrt_floor(1), 1);
+        assert_eq!(sqrt_floor(3), 1);
+        assert_eq!(sqrt_floor(4), 2);
+        assert_eq!(sqrt_floor(99), 9);
+        assert_eq!(sqrt_floor(Q64), 1 << 32);
+        assert_eq!(sqrt_floor(Q64 - 1), (1 << 32) - 1);
+        assert_eq!(sqrt_floor(u128::MAX), u64::MAX as u128);
+        for root in [u64::MAX as u128, (1 << 64) - 2, 12_345_678_901] {
+            assert_eq!(sqrt_floor(root * root), root);
+            assert_eq!(sqrt_floor(root * root - 1), root - 1);
+        }
+    }
+
+    #[test]
+    fn deposits_at_price_uses_all_of_the_scarcer_side() {
+        // 1 lamport per 10 tokens: the SOL runs out first
+        assert_eq!(deposits_at_price(1_000, 50, 1, 10), Some((500, 50)));
+        // The tokens run out first
+        assert_eq!(deposits_at_price(1_000, 500, 1, 10), Some((1_000, 100)));
+        // Exactly at the price, both sides go in whole
+        assert_eq!(deposits_at_price(1_000, 100, 1, 10), Some((1_000, 100)));
+        // The other side is rounded down, so neither deposit exceeds the price
+        assert_eq!(deposits_at_price(1_000, 7, 3, 10), Some((23, 7)));
+        assert_eq!(deposits_at_price(10, 1_000, 10, 3), Some((10, 33)));
+    }
+
+    #[test]
+    fn deposits_at_price_handles_extreme_prices() {
+        assert_eq!(deposits_at_price(1, 1, 0, 1), None);
+        assert_eq!(deposits_at_price(u64::MAX, u64::MAX, 1, 1), Some((u64::MAX, u64::MAX)));
+        // Tokens for all the SOL overflow u64 but the token side is the scarcer one
+        assert_eq!(
+            deposits_at_price(u64::MAX, u64::MAX, 1, u64::MAX),
+            Some((u64::MAX, 1))
+        );
+        assert_eq!(
+            deposits_at_price(u64::MAX, u64::MAX, u64::MAX, 1),
+            Some((1, u64::MAX))
+        );
+        // A price so low that no whole lamport matches the tokens
+        assert_eq!(deposits_at_price(5, u64::MAX, 1, 10), Some((5, 0)));
+    }
+}
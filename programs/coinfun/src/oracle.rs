@@ -0,0 +1,203 @@
+// SOL/USD prices from Pyth pull-oracle `PriceUpdateV2` accounts. The layout is
+// decoded here rather than through the Pyth SDK; only fully verified updates
+// are accepted, and every read is checked for staleness and confidence.
+use anchor_lang::prelude::*;
+use crate::consts::BPS_DENOMINATOR;
+use crate::errors::ErrorCode;
+
+// Pyth Solana receiver, owner of every `PriceUpdateV2` account
+pub const PYTH_RECEIVER_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+
+const PRICE_UPDATE_V2_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+// USD amounts, like `GraduationMode::UsdMarketCap`, are in micro-USD
+pub const USD_DECIMALS: i32 = 6;
+const SOL_DECIMALS: i32 = 9;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+enum VerificationLevel {
+    Partial { num_signatures: u8 },
+    Full,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct PriceFeedMessage {
+    feed_id: [u8; 32],
+    price: i64,
+    conf: u64,
+    exponent: i32,
+    publish_time: i64,
+    prev_publish_time: i64,
+    ema_price: i64,
+    ema_conf: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct PriceUpdateV2 {
+    write_authority: Pubkey,
+    verification_level: VerificationLevel,
+    price_message: PriceFeedMessage,
+    posted_slot: u64,
+}
+
+/// A SOL/USD price, `price * 10^exponent` USD per SOL, that passed the checks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SolUsdPrice {
+    pub price: u64,
+    pub exponent: i32,
+}
+
+impl SolUsdPrice {
+    /// Reads the price from a `PriceUpdateV2` account published at most `max_age_secs`
+    /// before `now`, whose confidence interval is at most `max_confidence_bps` of the price.
+    pub fn read(
+        price_feed: &AccountInfo,
+        max_age_secs: u64,
+        max_confidence_bps: u64,
+        now: i64,
+    ) -> Result<Self> {
+        require_keys_eq!(*price_feed.owner, PYTH_RECEIVER_ID, ErrorCode::InvalidPriceFeed);
+        Self::decode(&price_feed.try_borrow_data()?, max_age_secs, max_confidence_bps, now)
+    }
+
+    fn decode(data: &[u8], max_age_secs: u64, max_confidence_bps: u64, now: i64) -> Result<Self> {
+        require!(
+            data.get(..8) == Some(&PRICE_UPDATE_V2_DISCRIMINATOR[..]),
+            ErrorCode::InvalidPriceFeed
+        );
+        let update = PriceUpdateV2::deserialize(&mut &data[8..])
+            .map_err(|_| error!(ErrorCode::InvalidPriceFeed))?;
+        require!(
+            matches!(update.verification_level, VerificationLevel::Full),
+            ErrorCode::InvalidPriceFeed
+        );
+        let message = update.price_message;
+        let price = u64::try_from(message.price)
+            .ok()
+            .filter(|price| *price > 0)
+            .ok_or(ErrorCode::InvalidPriceFeed)?;
+
+        // Updates published after `now` are as fresh as they get
+        let age = u64::try_from(now.saturating_sub(message.publish_time)).unwrap_or(0);
+        require!(age <= max_age_secs, ErrorCode::PriceFeedStale);
+        require!(
+            u128::from(message.conf) * u128::from(BPS_DENOMINATOR)
+                <= u128::from(price) * u128::from(max_confidence_bps),
+            ErrorCode::PriceFeedUncertain
+        );
+
+        Ok(SolUsdPrice {
+            price,
+            exponent: message.exponent,
+        })
+    }
+
+    /// Lamports worth `usd` micro-USD at this price, rounded down. Saturates at `u64::MAX`.
+    pub fn lamports_for_usd(&self, usd: u64) -> Option<u64> {
+        // lamports = usd * 10^(SOL_DECIMALS - USD_DECIMALS) / (price * 10^exponent)
+        let scale = (SOL_DECIMALS - USD_DECIMALS).checked_sub(self.exponent)?;
+        let power = 10u128.checked_pow(scale.unsigned_abs())?;
+        let lamports = if scale >= 0 {
+            u128::from(usd).checked_mul(power)? / u128::from(self.price)
+        } else {
+            u128::from(usd) / u128::from(self.price).checked_mul(power)?
+        };
+        Some(u64::try_from(lamports).unwrap_or(u64::MAX))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+
+    // $150.00 per SOL with a $0.075 confidence interval, as in tests/fixtures/sol_usd_price.json
+    fn price_update(verification_level: VerificationLevel, price: i64, publish_time: i64) -> Vec<u8> {
+        let update = PriceUpdateV2 {
+            write_authority: Pubkey::default(),
+            verification_level,
+            price_message: PriceFeedMessage {
+                feed_id: [0; 32],
+                price,
+                conf: 7_500_000,
+                exponent: -8,
+                publish_time,
+                prev_publish_time: publish_time - 1,
+                ema_price: price,
+                ema_conf: 7_500_000,
+            },
+            posted_slot: 1,
+        };
+        let mut data = PRICE_UPDATE_V2_DISCRIMINATOR.to_vec();
+        update.serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn reads_fresh_and_tight_prices_only() {
+        let data = price_update(VerificationLevel::Full, 15_000_000_000, NOW - 30);
+        let price = SolUsdPrice::decode(&data, 60, 10, NOW).unwrap();
+        assert_eq!(price, SolUsdPrice { price: 15_000_000_000, exponent: -8 });
+        // $300 of market cap is two SOL
+        assert_eq!(price.lamports_for_usd(300_000_000), Some(2_000_000_000));
+
+        let error = |result: Result<SolUsdPrice>| result.unwrap_err();
+        assert_eq!(
+            error(SolUsdPrice::decode(&data, 29, 10, NOW)),
+            ErrorCode::PriceFeedStale.into()
+        );
+        assert_eq!(
+            error(SolUsdPrice::decode(&data, 60, 4, NOW)),
+            ErrorCode::PriceFeedUncertain.into()
+        );
+        let partial = price_update(VerificationLevel::Partial { num_signatures: 3 }, 15_000_000_000, NOW);
+        assert_eq!(
+            error(SolUsdPrice::decode(&partial, 60, 10, NOW)),
+            ErrorCode::InvalidPriceFeed.into()
+        );
+        let negative = price_update(VerificationLevel::Full, -1, NOW);
+        assert_eq!(
+            error(SolUsdPrice::decode(&negative, 60, 10, NOW)),
+            ErrorCode::InvalidPriceFeed.into()
+        );
+        assert_eq!(
+            error(SolUsdPrice::decode(&data[..40], 60, 10, NOW)),
+            ErrorCode::InvalidPriceFeed.into()
+        );
+    }
+
+    #[test]
+    fn rejects_prices_older_than_the_max_age() {
+        let data = price_update(VerificationLevel::Full, 15_000_000_000, NOW);
+        assert!(SolUsdPrice::decode(&data, 60, 10, NOW + 60).is_ok());
+        assert_eq!(
+            SolUsdPrice::decode(&data, 60, 10, NOW + 61).unwrap_err(),
+            ErrorCode::PriceFeedStale.into()
+        );
+        // A day after publishing, the fixture is stale for any bound short of a day
+        assert_eq!(
+            SolUsdPrice::decode(&data, 86_399, 10, NOW + 86_400).unwrap_err(),
+            ErrorCode::PriceFeedStale.into()
+        );
+        assert!(SolUsdPrice::decode(&data, u64::MAX, 10, i64::MAX).is_ok());
+    }
+
+    #[test]
+    fn rejects_confidence_wider_than_the_max() {
+        // $0.075 on $150.00 is exactly 5 bps
+        let data = price_update(VerificationLevel::Full, 15_000_000_000, NOW);
+        assert!(SolUsdPrice::decode(&data, 60, 5, NOW).is_ok());
+        assert_eq!(
+            SolUsdPrice::decode(&data, 60, 4, NOW).unwrap_err(),
+            ErrorCode::PriceFeedUncertain.into()
+        );
+        // The same interval on $75.00 is 10 bps
+        let cheap = price_update(VerificationLevel::Full, 7_500_000_000, NOW);
+        assert_eq!(
+            SolUsdPrice::decode(&cheap, 60, 9, NOW).unwrap_err(),
+            ErrorCode::PriceFeedUncertain.into()
+        );
+        assert!(SolUsdPrice::decode(&cheap, 60, 10, NOW).is_ok());
+    }
+}
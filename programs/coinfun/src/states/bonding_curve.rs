@@ -1,5 +1,160 @@
 use anchor_lang::prelude::*;
+use curve_math::{CurveState, FeeRates, GraduationTrigger};
+use crate::consts::MAX_LAUNCH_FEE_BPS;
+use crate::errors::ErrorCode;
+use crate::events::{CurveStatusChanged, TradingOpened};
+use crate::oracle::SolUsdPrice;
+use crate::states::{LpPolicy, MigrationTarget};
 
+// `Active` and `Complete` keep the byte values of the legacy `complete: bool`,
+// so curves created before the status field decode unchanged.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum CurveStatus {
+    Active,
+    Complete,
+    Migrating,
+    Migrated,
+    // A graduated curve that will not migrate. Trading stops and holders `refund`
+    // their tokens for an equal share of the curve's SOL
+    Refunding,
+}
+
+impl CurveStatus {
+    pub fn can_transition_to(self, to: CurveStatus) -> bool {
+        matches!(
+            (self, to),
+            (CurveStatus::Active, CurveStatus::Complete)
+                | (CurveStatus::Complete, CurveStatus::Migrating)
+                | (CurveStatus::Complete, CurveStatus::Refunding)
+                | (CurveStatus::Migrating, CurveStatus::Migrated)
+        )
+    }
+}
+
+// Price curve a launch trades on, chosen at `create` from the kinds `Global`
+// allows. Prices are scaled by `curve_math::PRICE_SCALE`. `ConstantProduct`
+// comes first so curves from before the field decode to it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum CurveKind {
+    // Virtual reserves from `Global`, `virtual_sol * virtual_token` constant
+    ConstantProduct,
+    // Price rises linearly from `start_price` to `end_price` as the supply sells
+    Linear { start_price: u64, end_price: u64 },
+    // Price doubles every `doubling_tokens` tokens sold, linearly in between
+    Exponential { start_price: u64, doubling_tokens: u64 },
+}
+
+impl From<CurveKind> for curve_math::CurveKind {
+    fn from(kind: CurveKind) -> Self {
+        match kind {
+            CurveKind::ConstantProduct => Self::ConstantProduct,
+            CurveKind::Linear { start_price, end_price } => Self::Linear { start_price, end_price },
+            CurveKind::Exponential { start_price, doubling_tokens } => {
+                Self::Exponential { start_price, doubling_tokens }
+            }
+        }
+    }
+}
+
+// What graduates a curve besides selling out, chosen at `create`. `SolThreshold`
+// comes first so curves from before the field decode to it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum GraduationMode {
+    // `real_sol_reserves` reaches the curve's `graduation_threshold`
+    SolThreshold,
+    // `real_token_reserves` falls to `token_floor`
+    TokenFloor { token_floor: u64 },
+    // `virtual_sol / virtual_token * token_total_supply` reaches `market_cap`, in lamports
+    MarketCap { market_cap: u64 },
+    // The same market cap reaches `market_cap_usd`, in micro-USD at the `Global` SOL/USD feed's price
+    UsdMarketCap { market_cap_usd: u64 },
+}
+
+// Caps `buy` enforces for `slots` slots after `create`, against bots buying up the
+// launch. All zero, as on curves from before the field, means no window.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug, Default)]
+pub struct LaunchWindow {
+    pub slots: u64,
+    // SOL a single buy may fill, fees included
+    pub max_sol_per_buy: u64,
+    // Tokens one wallet may buy from the curve over the window, see `LaunchAllocation`
+    pub max_tokens_per_wallet: u64,
+}
+
+impl LaunchWindow {
+    pub fn is_valid(&self) -> bool {
+        self.slots > 0 && self.max_sol_per_buy > 0 && self.max_tokens_per_wallet > 0
+    }
+}
+
+// How a launch fee falls from `extra_bps` to nothing over `decay_slots`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug, Default)]
+pub enum FeeDecay {
+    #[default]
+    Linear,
+    // Halves every `half_life_slots`, linearly in between, and ends at `decay_slots`
+    Exponential { half_life_slots: u64 },
+}
+
+// Extra fee on top of the curve's trade fees from its launch, paid to the reserve.
+// All zero, as on curves from before the field, means no launch fee.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug, Default)]
+pub struct LaunchFee {
+    pub extra_bps: u64,
+    pub decay_slots: u64,
+    pub decay: FeeDecay,
+}
+
+impl LaunchFee {
+    pub fn is_valid(&self) -> bool {
+        self.extra_bps > 0
+            && self.decay_slots > 0
+            && !matches!(self.decay, FeeDecay::Exponential { half_life_slots: 0 })
+    }
+
+    /// Extra fee `elapsed` slots after launch.
+    pub fn bps_at(&self, elapsed: u64) -> u64 {
+        if elapsed >= self.decay_slots {
+            return 0;
+        }
+        match self.decay {
+            FeeDecay::Linear => {
+                let remaining = u128::from(self.decay_slots - elapsed);
+                (u128::from(self.extra_bps) * remaining / u128::from(self.decay_slots)) as u64
+            }
+            FeeDecay::Exponential { half_life_slots } => {
+                let (halvings, into_half_life) = (elapsed / half_life_slots, elapsed % half_life_slots);
+                let from = self.extra_bps.checked_shr(u32::try_from(halvings).unwrap_or(u32::MAX)).unwrap_or(0);
+                let drop = u128::from(from - from / 2) * u128::from(into_half_life) / u128::from(half_life_slots);
+                from - drop as u64
+            }
+        }
+    }
+}
+
+// What the reserve share of a curve's trade fees becomes, chosen at `create`.
+// `Accumulate` comes first so curves from before the field decode to it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum ReservePolicy {
+    // Buys tokens from the curve into the global reserve's ATA
+    Accumulate,
+    // Buys tokens from the curve and burns them
+    Burn,
+    // Kept as SOL in the reserve SOL vault, without touching the curve
+    KeepSol,
+}
+
+// When a curve starts accepting trades, chosen at `create`. `Immediately` comes
+// first so curves from before the field decode to it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum TradingStart {
+    Immediately,
+    UnixTimestamp { unix_timestamp: i64 },
+    Slot { slot: u64 },
+}
+
+// New fields must be appended and decode to their default from zeroed bytes,
+// see `upgrade_bonding_curve`.
 #[account]
 #[derive(InitSpace)]
 pub struct BondingCurve {
@@ -10,5 +165,337 @@ pub struct BondingCurve {
     pub real_token_reserves: u64,
     pub real_sol_reserves: u64,
     pub token_total_supply: u64,
-    pub complete: bool,
+    pub status: CurveStatus,
+    pub migration_target: MigrationTarget,
+    pub lp_policy: LpPolicy,
+    pub lp_unlock_slot: u64, // Set at migration for `LpPolicy::TimeLock`
+    pub lp_mint: Pubkey, // LP the migration authority holds, set by `migrate`; default before then
+    pub curve_kind: CurveKind,
+    pub graduation_threshold: u64, // Set at create; zero until `upgrade_bonding_curve` on older curves
+    // Fee schedule snapshotted from `Global` at create, changed only by `reprice_curve`
+    pub platform_trade_fee_bps: u64,
+    pub reserve_trade_fee_bps: u64,
+    pub graduation_mode: GraduationMode,
+    pub created_slot: u64, // Zero on curves from before the field
+    pub launch_window: LaunchWindow,
+    pub trading_starts_at: TradingStart,
+    pub trading_opened_slot: u64, // Slot of the first trade, zero until then
+    pub launch_fee: LaunchFee,
+    pub creator_trade_fee_bps: u64, // Snapshotted at create, zero on curves from before the field
+    pub reserve_policy: ReservePolicy,
+}
+
+impl BondingCurve {
+    /// Reserves and curve kind as taken and returned by the `curve_math` quotes.
+    pub fn curve_state(&self) -> CurveState {
+        CurveState {
+            virtual_token_reserves: self.virtual_token_reserves,
+            virtual_sol_reserves: self.virtual_sol_reserves,
+            real_token_reserves: self.real_token_reserves,
+            real_sol_reserves: self.real_sol_reserves,
+            kind: self.curve_kind.into(),
+            token_total_supply: self.token_total_supply,
+        }
+    }
+
+    /// Trade fees at `slot`. A decaying launch fee adds to the reserve's share, and the
+    /// total never exceeds `MAX_LAUNCH_FEE_BPS`.
+    pub fn fee_rates(&self, slot: u64) -> FeeRates {
+        let elapsed = slot.saturating_sub(self.launch_slot(slot));
+        let reserve_trade_fee_bps = self
+            .reserve_trade_fee_bps
+            .saturating_add(self.launch_fee.bps_at(elapsed))
+            .min(
+                MAX_LAUNCH_FEE_BPS
+                    .saturating_sub(self.platform_trade_fee_bps)
+                    .saturating_sub(self.creator_trade_fee_bps),
+            );
+        FeeRates {
+            platform_trade_fee_bps: self.platform_trade_fee_bps,
+            reserve_trade_fee_bps,
+            creator_trade_fee_bps: self.creator_trade_fee_bps,
+            reserve_fee_in_sol: self.reserve_policy == ReservePolicy::KeepSol,
+        }
+    }
+
+    /// Slot the curve's launch window and launch fee run from: its creation or, if it opens
+    /// later, its scheduled slot or first trade, which a trade at `slot` would be.
+    pub fn launch_slot(&self, slot: u64) -> u64 {
+        match self.trading_starts_at {
+            TradingStart::Immediately => self.created_slot,
+            TradingStart::UnixTimestamp { .. } if self.trading_opened_slot == 0 => slot,
+            TradingStart::UnixTimestamp { .. } => self.trading_opened_slot,
+            TradingStart::Slot { slot } => slot,
+        }
+    }
+
+    /// The curve's trigger in `curve_math` terms. `UsdMarketCap` curves need the SOL/USD
+    /// price to turn their target into lamports.
+    pub fn graduation_trigger(&self, sol_usd_price: Option<SolUsdPrice>) -> Result<GraduationTrigger> {
+        Ok(match self.graduation_mode {
+            GraduationMode::SolThreshold => GraduationTrigger::SolReserves(self.graduation_threshold),
+            GraduationMode::TokenFloor { token_floor } => GraduationTrigger::TokenFloor(token_floor),
+            GraduationMode::MarketCap { market_cap } => GraduationTrigger::MarketCap(market_cap),
+            GraduationMode::UsdMarketCap { market_cap_usd } => {
+                let price = sol_usd_price.ok_or(ErrorCode::MissingPriceFeed)?;
+                let market_cap = price
+                    .lamports_for_usd(market_cap_usd)
+                    .ok_or(ProgramError::ArithmeticOverflow)?;
+                GraduationTrigger::MarketCap(market_cap)
+            }
+        })
+    }
+
+    /// Whether `slot` falls in the curve's launch window.
+    pub fn in_launch_window(&self, slot: u64) -> bool {
+        slot < self.launch_slot(slot).saturating_add(self.launch_window.slots)
+    }
+
+    /// Fails before the curve's scheduled start. The curve's first trade records its slot
+    /// and emits `TradingOpened`.
+    pub fn open_for_trade(&mut self, clock: &Clock, trader: Pubkey) -> Result<()> {
+        let started = match self.trading_starts_at {
+            TradingStart::Immediately => true,
+            TradingStart::UnixTimestamp { unix_timestamp } => clock.unix_timestamp >= unix_timestamp,
+            TradingStart::Slot { slot } => clock.slot >= slot,
+        };
+        require!(started, ErrorCode::TradingNotStarted);
+        if self.trading_opened_slot == 0 {
+            self.trading_opened_slot = clock.slot;
+            emit!(TradingOpened {
+                mint: self.mint,
+                trader,
+                slot: clock.slot,
+                unix_timestamp: clock.unix_timestamp,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn set_curve_state(&mut self, state: CurveState) {
+        self.virtual_token_reserves = state.virtual_token_reserves;
+        self.virtual_sol_reserves = state.virtual_sol_reserves;
+        self.real_token_reserves = state.real_token_reserves;
+        self.real_sol_reserves = state.real_sol_reserves;
+    }
+
+    /// SOL a `Refunding` curve pays for `token_amount` of the `circulating` tokens,
+    /// rounded down: their share of `real_sol_reserves`.
+    pub fn refund_sol(&self, token_amount: u64, circulating: u64) -> Option<u64> {
+        if token_amount > circulating {
+            return None;
+        }
+        let sol = u128::from(self.real_sol_reserves).checked_mul(u128::from(token_amount))?
+            / u128::from(circulating);
+        u64::try_from(sol).ok()
+    }
+
+    pub fn set_status(&mut self, to: CurveStatus) -> Result<()> {
+        require!(
+            self.status.can_transition_to(to),
+            ErrorCode::InvalidCurveStatusTransition
+        );
+        emit!(CurveStatusChanged {
+            mint: self.mint,
+            from: self.status,
+            to,
+        });
+        self.status = to;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::{CurveParams, Global, SurplusTokenPolicy};
+    use curve_math::{quote_buy, quote_sell};
+
+    fn global() -> Global {
+        Global {
+            authority: Pubkey::default(),
+            reserve: Pubkey::default(),
+            initial_virtual_token_reserves: 1_073_000_191_000_000,
+            initial_virtual_sol_reserves: 30_000_000_000,
+            token_total_supply: 1_000_000_000_000_000,
+            platform_trade_fee_bps: 100,
+            reserve_trade_fee_bps: 400,
+            graduation_threshold: 2_000_000_000,
+            migration_programs: [Pubkey::default(); 3],
+            lp_policy: LpPolicy::PermanentLock,
+            keeper_reward_bps: 50,
+            migration_configs: [Pubkey::default(); 3],
+            surplus_token_policy: SurplusTokenPolicy::Burn,
+            migration_fee_lamports: 0,
+            curve_kinds: [true; 3],
+            min_curve_params: CurveParams {
+                initial_virtual_token_reserves: 0,
+                initial_virtual_sol_reserves: 0,
+                token_total_supply: 0,
+                graduation_threshold: 0,
+            },
+            max_curve_params: CurveParams {
+                initial_virtual_token_reserves: u64::MAX,
+                initial_virtual_sol_reserves: u64::MAX,
+                token_total_supply: u64::MAX,
+                graduation_threshold: u64::MAX,
+            },
+            sol_usd_price_feed: Pubkey::default(),
+            max_price_age_secs: 0,
+            max_price_confidence_bps: 0,
+            creator_trade_fee_bps: 0,
+            referral_fee_bps: 0,
+            platform_fee_recipients: Default::default(),
+        }
+    }
+
+    fn curve(global: &Global) -> BondingCurve {
+        BondingCurve {
+            mint: Pubkey::default(),
+            creator: Pubkey::default(),
+            virtual_token_reserves: global.initial_virtual_token_reserves,
+            virtual_sol_reserves: global.initial_virtual_sol_reserves,
+            real_token_reserves: global.token_total_supply,
+            real_sol_reserves: 0,
+            token_total_supply: global.token_total_supply,
+            status: CurveStatus::Active,
+            migration_target: MigrationTarget::DammV2,
+            lp_policy: LpPolicy::PermanentLock,
+            lp_unlock_slot: 0,
+            lp_mint: Pubkey::default(),
+            curve_kind: CurveKind::ConstantProduct,
+            graduation_threshold: global.graduation_threshold,
+            platform_trade_fee_bps: global.platform_trade_fee_bps,
+            reserve_trade_fee_bps: global.reserve_trade_fee_bps,
+            graduation_mode: GraduationMode::SolThreshold,
+            created_slot: 0,
+            launch_window: LaunchWindow::default(),
+            trading_starts_at: TradingStart::Immediately,
+            trading_opened_slot: 0,
+            launch_fee: LaunchFee::default(),
+            creator_trade_fee_bps: global.creator_trade_fee_bps,
+            reserve_policy: ReservePolicy::Accumulate,
+        }
+    }
+
+    // Inline handler math from before `curve_math`, kept as the reference the quotes must match
+    fn reference_buy(curve: &mut BondingCurve, global: &Global, sol_in: u64) -> (u64, u64) {
+        let platform_fee = sol_in * global.platform_trade_fee_bps / 10000;
+        let reserve_fee = sol_in * global.reserve_trade_fee_bps / 10000;
+        let sol_after_fee = sol_in - platform_fee - reserve_fee;
+
+        let k = u128::from(curve.virtual_sol_reserves) * u128::from(curve.virtual_token_reserves);
+        let vs = curve.virtual_sol_reserves + reserve_fee;
+        let vt = (k / u128::from(vs)) as u64;
+        let reserve_tokens_out = curve.virtual_token_reserves - vt;
+        curve.virtual_sol_reserves = vs;
+        curve.virtual_token_reserves = vt;
+        curve.real_sol_reserves += reserve_fee;
+        curve.real_token_reserves -= reserve_tokens_out;
+
+        let k = u128::from(curve.virtual_sol_reserves) * u128::from(curve.virtual_token_reserves);
+        let vs = curve.virtual_sol_reserves + sol_after_fee;
+        let vt = (k / u128::from(vs)) as u64;
+        let tokens_out = (curve.virtual_token_reserves - vt).min(curve.real_token_reserves);
+        curve.virtual_sol_reserves = vs;
+        curve.virtual_token_reserves = vt;
+        curve.real_sol_reserves += sol_after_fee;
+        curve.real_token_reserves -= tokens_out;
+        (tokens_out, reserve_tokens_out)
+    }
+
+    fn reference_sell(curve: &mut BondingCurve, global: &Global, token_in: u64) -> (u64, u64) {
+        let k = u128::from(curve.virtual_sol_reserves) * u128::from(curve.virtual_token_reserves);
+        let vt = curve.virtual_token_reserves + token_in;
+        let vs = (k / u128::from(vt)) as u64;
+        let sol_out_gross = curve.virtual_sol_reserves - vs;
+        let platform_fee = sol_out_gross * global.platform_trade_fee_bps / 10000;
+        let reserve_fee = sol_out_gross * global.reserve_trade_fee_bps / 10000;
+        let sol_out_net = sol_out_gross - platform_fee - reserve_fee;
+
+        let k = u128::from(vs) * u128::from(vt);
+        let reserve_vs = vs + reserve_fee;
+        let reserve_vt = (k / u128::from(reserve_vs)) as u64;
+        let reserve_tokens_out = vt - reserve_vt;
+        curve.virtual_sol_reserves = reserve_vs;
+        curve.virtual_token_reserves = reserve_vt;
+        curve.real_sol_reserves -= sol_out_gross - reserve_fee;
+        curve.real_token_reserves = curve.real_token_reserves + token_in - reserve_tokens_out;
+        (sol_out_net, reserve_tokens_out)
+    }
+
+    #[test]
+    fn handlers_agree_with_quotes() {
+        let global = global();
+        let mut on_chain = curve(&global);
+        let mut reference = curve(&global);
+
+        for sol_in in [1, 10_000, 123_456_789, 300_000_000] {
+            let buy = quote_buy(
+                &on_chain.curve_state(),
+                on_chain.fee_rates(0),
+                on_chain.graduation_trigger(None).unwrap(),
+                sol_in,
+            )
+            .unwrap();
+            on_chain.set_curve_state(buy.curve);
+            assert_eq!(buy.sol_in, sol_in);
+            assert_eq!(
+                (buy.tokens_out, buy.reserve_tokens_out),
+                reference_buy(&mut reference, &global, sol_in)
+            );
+            assert_eq!(on_chain.curve_state(), reference.curve_state());
+
+            let token_in = buy.tokens_out / 2;
+            let sell = quote_sell(&on_chain.curve_state(), on_chain.fee_rates(0), token_in).unwrap();
+            on_chain.set_curve_state(sell.curve);
+            assert_eq!(
+                (sell.sol_out_net, sell.reserve_tokens_out),
+                reference_sell(&mut reference, &global, token_in)
+            );
+            assert_eq!(on_chain.curve_state(), reference.curve_state());
+        }
+    }
+
+    #[test]
+    fn launch_fee_decays_to_nothing() {
+        let linear = LaunchFee { extra_bps: 2000, decay_slots: 100, decay: FeeDecay::Linear };
+        assert_eq!(
+            [0, 25, 50, 99, 100, 1_000].map(|elapsed| linear.bps_at(elapsed)),
+            [2000, 1500, 1000, 20, 0, 0]
+        );
+        let exponential = LaunchFee {
+            extra_bps: 2000,
+            decay_slots: 100,
+            decay: FeeDecay::Exponential { half_life_slots: 10 },
+        };
+        assert_eq!(
+            [0, 5, 10, 20, 95, 100].map(|elapsed| exponential.bps_at(elapsed)),
+            [2000, 1500, 1000, 500, 2, 0]
+        );
+
+        // The extra fee goes to the reserve, and the total stays under the ceiling
+        let mut on_chain = curve(&global());
+        on_chain.created_slot = 1_000;
+        on_chain.launch_fee = LaunchFee { extra_bps: 9000, ..linear };
+        assert_eq!(on_chain.fee_rates(1_000).reserve_trade_fee_bps, MAX_LAUNCH_FEE_BPS - 100);
+        assert_eq!(on_chain.fee_rates(1_090).reserve_trade_fee_bps, 400 + 900);
+        assert_eq!(on_chain.fee_rates(1_100).reserve_trade_fee_bps, 400);
+        // A creator fee leaves less room under the ceiling
+        on_chain.creator_trade_fee_bps = 50;
+        assert_eq!(on_chain.fee_rates(1_000).reserve_trade_fee_bps, MAX_LAUNCH_FEE_BPS - 150);
+    }
+
+    #[test]
+    fn refunds_are_pro_rata() {
+        let mut on_chain = curve(&global());
+        on_chain.real_sol_reserves = 85_000_000_000;
+        let circulating = 800_000_000_000_000;
+        assert_eq!(on_chain.refund_sol(circulating / 4, circulating), Some(21_250_000_000));
+        assert_eq!(on_chain.refund_sol(circulating, circulating), Some(85_000_000_000));
+        // Rounds down, and never pays for more than is out
+        assert_eq!(on_chain.refund_sol(1, circulating), Some(0));
+        assert_eq!(on_chain.refund_sol(circulating + 1, circulating), None);
+        assert_eq!(on_chain.refund_sol(1, 0), None);
+    }
 }
@@ -1,10 +1,90 @@
 use anchor_lang::prelude::*;
+use crate::consts::{BPS_DENOMINATOR, MAX_PLATFORM_FEE_RECIPIENTS};
+use crate::errors::ErrorCode;
+use crate::oracle::SolUsdPrice;
+use crate::states::CurveKind;
+
+// Where a graduated curve's liquidity goes. The discriminant indexes
+// `Global.migration_programs`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum MigrationTarget {
+    DammV2,
+    Dlmm,
+    Cpmm,
+}
+
+// What happens to the LP position a migration produces. Snapshotted onto each
+// curve at `create`, so a launch's policy cannot change under its holders.
+// `PermanentLock` comes first so curves from before the field decode to it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum LpPolicy {
+    // Held by the migration authority forever; fees stay claimable
+    PermanentLock,
+    // LP tokens or position NFT burned at migration
+    Burn,
+    // Held by the migration authority until `lp_lock_slots` after migration
+    TimeLock { lp_lock_slots: u64 },
+}
+
+impl LpPolicy {
+    // DLMM positions can be neither burned nor handed over, and locked CP-Swap LP
+    // could never collect the fees that compound into it
+    pub fn supports(self, target: MigrationTarget) -> bool {
+        match target {
+            MigrationTarget::DammV2 => true,
+            MigrationTarget::Dlmm => self == LpPolicy::PermanentLock,
+            MigrationTarget::Cpmm => self != LpPolicy::PermanentLock,
+        }
+    }
+}
+
+// What `migrate` does with the curve tokens left over once the pool deposits
+// are sized to the curve's final price.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum SurplusTokenPolicy {
+    Burn,
+    // Sent to the global reserve's ATA for the mint
+    Reserve,
+}
+
+// One payee of `distribute_platform_fees`, paid `weight` parts of each payout.
+// Unused slots are left zeroed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug, Default)]
+pub struct PlatformFeeRecipient {
+    pub recipient: Pubkey,
+    pub weight: u64,
+}
+
+// Economics of a launch. `create` takes them from the creator within the
+// bounds in `Global`, or falls back to the defaults in `Global`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub struct CurveParams {
+    pub initial_virtual_token_reserves: u64,
+    pub initial_virtual_sol_reserves: u64,
+    pub token_total_supply: u64,
+    pub graduation_threshold: u64,
+}
+
+impl CurveParams {
+    pub fn is_within(&self, min: &CurveParams, max: &CurveParams) -> bool {
+        let within = |value: u64, min: u64, max: u64| (min..=max).contains(&value);
+        within(
+            self.initial_virtual_token_reserves,
+            min.initial_virtual_token_reserves,
+            max.initial_virtual_token_reserves,
+        ) && within(
+            self.initial_virtual_sol_reserves,
+            min.initial_virtual_sol_reserves,
+            max.initial_virtual_sol_reserves,
+        ) && within(self.token_total_supply, min.token_total_supply, max.token_total_supply)
+            && within(self.graduation_threshold, min.graduation_threshold, max.graduation_threshold)
+    }
+}
 
 #[account]
 #[derive(InitSpace)]
 pub struct Global {
     pub authority: Pubkey,
-    pub platform_fee_recipient: Pubkey,
     pub reserve: Pubkey, // Global reserve PDA (authority for all reserve ATAs)
     pub initial_virtual_token_reserves: u64,
     pub initial_virtual_sol_reserves: u64,
@@ -12,4 +92,92 @@ pub struct Global {
     pub platform_trade_fee_bps: u64,
     pub reserve_trade_fee_bps: u64,
     pub graduation_threshold: u64,
+    pub migration_programs: [Pubkey; 3], // DEX program per MigrationTarget, default = disabled
+    pub lp_policy: LpPolicy, // Applied to curves created from now on
+    pub keeper_reward_bps: u64, // Share of a curve's SOL paid to whoever migrates it
+    pub migration_configs: [Pubkey; 3], // Pool config / preset / AMM config per MigrationTarget
+    pub surplus_token_policy: SurplusTokenPolicy, // Applied at migration
+    pub migration_fee_lamports: u64, // Taken from a curve's SOL at migration for the platform
+    pub curve_kinds: [bool; 3], // Whether `create` accepts each CurveKind, by discriminant
+    pub min_curve_params: CurveParams, // Bounds on the params creators pass to `create`
+    pub max_curve_params: CurveParams,
+    pub sol_usd_price_feed: Pubkey, // Pyth `PriceUpdateV2` account for `UsdMarketCap` curves, default = disabled
+    pub max_price_age_secs: u64,
+    pub max_price_confidence_bps: u64, // Widest confidence interval accepted, relative to the price
+    pub creator_trade_fee_bps: u64, // Paid to each curve's creator vault, applied to curves created from now on
+    pub referral_fee_bps: u64, // Share of the platform fee paid to a trade's referrer
+    // Payees of the platform fee vault, see `set_platform_fee_recipients`
+    pub platform_fee_recipients: [PlatformFeeRecipient; MAX_PLATFORM_FEE_RECIPIENTS],
+}
+
+impl Global {
+    /// Part of `platform_fee` paid to a trade's referrer, rounded down.
+    pub fn referral_fee(&self, platform_fee: u64) -> Option<u64> {
+        let fee = u128::from(platform_fee)
+            .checked_mul(u128::from(self.referral_fee_bps))?
+            / u128::from(BPS_DENOMINATOR);
+        u64::try_from(fee).ok()
+    }
+
+    /// The configured platform fee recipients, in order.
+    pub fn active_platform_fee_recipients(&self) -> impl Iterator<Item = &PlatformFeeRecipient> {
+        self.platform_fee_recipients.iter().filter(|payee| payee.weight > 0)
+    }
+
+    /// `amount` split between the platform fee recipients by weight, each share rounded
+    /// down, in the order of `active_platform_fee_recipients`.
+    pub fn platform_fee_shares(&self, amount: u64) -> Option<Vec<u64>> {
+        let total_weight = self
+            .active_platform_fee_recipients()
+            .try_fold(0u128, |total, payee| total.checked_add(u128::from(payee.weight)))?;
+        self.active_platform_fee_recipients()
+            .map(|payee| {
+                let share = u128::from(amount).checked_mul(u128::from(payee.weight))? / total_weight;
+                u64::try_from(share).ok()
+            })
+            .collect()
+    }
+
+    /// DEX program for `target`, if the target is enabled.
+    pub fn migration_program(&self, target: MigrationTarget) -> Option<Pubkey> {
+        let program = self.migration_programs[target as usize];
+        (program != Pubkey::default()).then_some(program)
+    }
+
+    /// Params for launches that do not set their own.
+    pub fn default_curve_params(&self) -> CurveParams {
+        CurveParams {
+            initial_virtual_token_reserves: self.initial_virtual_token_reserves,
+            initial_virtual_sol_reserves: self.initial_virtual_sol_reserves,
+            token_total_supply: self.token_total_supply,
+            graduation_threshold: self.graduation_threshold,
+        }
+    }
+
+    pub fn allows_curve_kind(&self, kind: CurveKind) -> bool {
+        let index = match kind {
+            CurveKind::ConstantProduct => 0,
+            CurveKind::Linear { .. } => 1,
+            CurveKind::Exponential { .. } => 2,
+        };
+        self.curve_kinds[index]
+    }
+
+    /// Current SOL/USD price from `price_feed`, which must be the configured feed.
+    pub fn sol_usd_price(&self, price_feed: &AccountInfo) -> Result<SolUsdPrice> {
+        require!(self.sol_usd_price_feed != Pubkey::default(), ErrorCode::MissingPriceFeed);
+        require_keys_eq!(price_feed.key(), self.sol_usd_price_feed, ErrorCode::InvalidPriceFeed);
+        SolUsdPrice::read(
+            price_feed,
+            self.max_price_age_secs,
+            self.max_price_confidence_bps,
+            Clock::get()?.unix_timestamp,
+        )
+    }
+
+    /// Config account pools on `target` must be created with. Pinned here because
+    /// anyone can migrate, and the config sets the pool's fees.
+    pub fn migration_config(&self, target: MigrationTarget) -> Pubkey {
+        self.migration_configs[target as usize]
+    }
 }
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+// Tokens one wallet bought from a curve during its launch window, seeded with
+// `["launch_allocation", bonding_curve, wallet]`. Created by the wallet's first
+// buy in the window and never read once the window closes.
+#[account]
+#[derive(InitSpace)]
+pub struct LaunchAllocation {
+    pub tokens_bought: u64,
+}
@@ -1,5 +1,11 @@
 pub mod global;
 pub mod bonding_curve;
+pub mod launch_allocation;
+pub mod referrer;
+pub mod stake_pool;
 
 pub use global::*;
 pub use bonding_curve::*;
+pub use launch_allocation::*;
+pub use referrer::*;
+pub use stake_pool::*;
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+// A frontend partner's referral account, seeded with `["referrer", authority]`.
+// Trades that pass it pay it a share of the platform fee, held as lamports on
+// top of its rent until `claim_referral_fees`.
+#[account]
+#[derive(InitSpace)]
+pub struct Referrer {
+    pub authority: Pubkey, // Wallet that registered the account and claims from it
+    pub unclaimed_fees: u64,
+    pub total_fees_earned: u64,
+}
+
+impl Referrer {
+    /// Records `amount` lamports of referral fees the caller has paid into the account.
+    pub fn accrue(&mut self, amount: u64) -> Result<()> {
+        self.unclaimed_fees = self
+            .unclaimed_fees
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.total_fees_earned = self
+            .total_fees_earned
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
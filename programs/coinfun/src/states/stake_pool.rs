@@ -0,0 +1,229 @@
+use anchor_lang::prelude::*;
+use crate::consts::{REWARD_PER_SHARE_PRECISION, REWARD_STREAM_SLOTS};
+
+// Stakes in one graduated token, seeded with `["stake_pool", mint]`. The reserve's
+// tokens for the mint are swept into the pool's vault whenever a staker acts, streamed out over `REWARD_STREAM_SLOTS` and shared pro-rata
+// through `reward_per_share`, scaled by `REWARD_PER_SHARE_PRECISION`.
+#[account]
+#[derive(InitSpace)]
+pub struct StakePool {
+    pub mint: Pubkey,
+    pub total_staked: u64,
+    pub reward_per_share: u128, // Reward tokens per staked token since the pool was created
+    pub total_rewards: u64,     // Reserve tokens released to stakers so far
+    pub pending_rewards: u64,   // Swept into the vault but not released yet
+    pub reward_rate: u128,      // Pending tokens released per slot, scaled like `reward_per_share`
+    pub last_update_slot: u64,
+    pub stream_end_slot: u64,   // Slot by which every pending token is released
+}
+
+impl StakePool {
+    /// Releases the pending rewards streamed since `last_update_slot` to the tokens
+    /// staked now and returns how many. The stream pauses while nothing is staked.
+    pub fn update(&mut self, slot: u64) -> Result<u64> {
+        let elapsed = slot.saturating_sub(self.last_update_slot);
+        if elapsed == 0 || self.pending_rewards == 0 {
+            self.last_update_slot = self.last_update_slot.max(slot);
+            return Ok(0);
+        }
+        if self.total_staked == 0 {
+            self.stream_end_slot = self.stream_end_slot.saturating_add(elapsed);
+            self.last_update_slot = slot;
+            return Ok(0);
+        }
+
+        let released = if slot >= self.stream_end_slot {
+            self.pending_rewards
+        } else {
+            let streamed = self
+                .reward_rate
+                .checked_mul(elapsed as u128)
+                .ok_or(ProgramError::ArithmeticOverflow)?
+                / REWARD_PER_SHARE_PRECISION;
+            u64::try_from(streamed).unwrap_or(u64::MAX).min(self.pending_rewards)
+        };
+        self.last_update_slot = slot;
+        if released > 0 {
+            self.pending_rewards -= released;
+            self.distribute(released)?;
+        }
+        Ok(released)
+    }
+
+    /// Queues `amount` newly swept tokens and restarts the stream, so everything
+    /// pending is released evenly over the next `REWARD_STREAM_SLOTS`. Call `update`
+    /// first so the stream so far is paid out at the old rate.
+    pub fn add_rewards(&mut self, amount: u64, slot: u64) -> Result<()> {
+        self.pending_rewards = self
+            .pending_rewards
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.reward_rate = (self.pending_rewards as u128)
+            .checked_mul(REWARD_PER_SHARE_PRECISION)
+            .ok_or(ProgramError::ArithmeticOverflow)?
+            / REWARD_STREAM_SLOTS as u128;
+        self.last_update_slot = slot;
+        self.stream_end_slot = slot
+            .checked_add(REWARD_STREAM_SLOTS)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Shares `amount` reward tokens between the tokens staked now.
+    fn distribute(&mut self, amount: u64) -> Result<()> {
+        require_gt!(self.total_staked, 0);
+        let increase = (amount as u128)
+            .checked_mul(REWARD_PER_SHARE_PRECISION)
+            .ok_or(ProgramError::ArithmeticOverflow)?
+            / self.total_staked as u128;
+        self.reward_per_share = self
+            .reward_per_share
+            .checked_add(increase)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.total_rewards = self
+            .total_rewards
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+// One wallet's stake in a pool, seeded with `["stake_position", stake_pool, owner]`.
+// Created by the wallet's first stake and kept after a full unstake.
+#[account]
+#[derive(InitSpace)]
+pub struct StakePosition {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub reward_per_share_paid: u128, // The pool's `reward_per_share` when last settled
+    pub unclaimed_rewards: u64,
+}
+
+impl StakePosition {
+    /// Credits the rewards accrued since the last settlement to `unclaimed_rewards`.
+    /// Must run before `amount` changes. The position's share of every release since
+    /// is at most the release itself, so the product cannot outgrow the rewards paid in.
+    pub fn settle(&mut self, pool: &StakePool) -> Result<()> {
+        let accrued = pool
+            .reward_per_share
+            .checked_sub(self.reward_per_share_paid)
+            .and_then(|delta| delta.checked_mul(self.amount as u128))
+            .map(|scaled| scaled / REWARD_PER_SHARE_PRECISION)
+            .and_then(|accrued| u64::try_from(accrued).ok())
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.unclaimed_rewards = self
+            .unclaimed_rewards
+            .checked_add(accrued)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.reward_per_share_paid = pool.reward_per_share;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(total_staked: u64) -> StakePool {
+        StakePool {
+            mint: Pubkey::default(),
+            total_staked,
+            reward_per_share: 0,
+            total_rewards: 0,
+            pending_rewards: 0,
+            reward_rate: 0,
+            last_update_slot: 0,
+            stream_end_slot: 0,
+        }
+    }
+
+    fn position(amount: u64, pool: &StakePool) -> StakePosition {
+        StakePosition {
+            owner: Pubkey::default(),
+            amount,
+            reward_per_share_paid: pool.reward_per_share,
+            unclaimed_rewards: 0,
+        }
+    }
+
+    #[test]
+    fn rewards_stream_out_over_time() {
+        let mut pool = pool(100);
+        pool.add_rewards(REWARD_STREAM_SLOTS * 10, 0).unwrap();
+        assert_eq!(pool.update(0).unwrap(), 0);
+
+        // A tenth of the stream releases a tenth of the rewards
+        assert_eq!(pool.update(REWARD_STREAM_SLOTS / 10).unwrap(), REWARD_STREAM_SLOTS);
+        assert_eq!(pool.pending_rewards, REWARD_STREAM_SLOTS * 9);
+
+        // Whatever is left releases once the stream ends, rounding included
+        assert_eq!(pool.update(REWARD_STREAM_SLOTS * 2).unwrap(), REWARD_STREAM_SLOTS * 9);
+        assert_eq!(pool.pending_rewards, 0);
+        assert_eq!(pool.total_rewards, REWARD_STREAM_SLOTS * 10);
+        assert_eq!(pool.update(REWARD_STREAM_SLOTS * 3).unwrap(), 0);
+    }
+
+    #[test]
+    fn a_late_staker_shares_what_streams_after_it_joined() {
+        let mut pool = pool(1);
+        let mut first = position(1, &pool);
+        pool.add_rewards(REWARD_STREAM_SLOTS * 1_000, 0).unwrap();
+
+        // A lone staker cannot take a sweep in one go
+        assert_eq!(pool.update(1).unwrap(), 1_000);
+
+        // Nor keep what streams after others join
+        pool.update(REWARD_STREAM_SLOTS / 2).unwrap();
+        pool.total_staked += 999;
+        let mut late = position(999, &pool);
+        pool.update(REWARD_STREAM_SLOTS).unwrap();
+
+        first.settle(&pool).unwrap();
+        late.settle(&pool).unwrap();
+        assert_eq!(first.unclaimed_rewards, REWARD_STREAM_SLOTS * 500 + REWARD_STREAM_SLOTS / 2);
+        assert_eq!(late.unclaimed_rewards, REWARD_STREAM_SLOTS / 2 * 999);
+    }
+
+    #[test]
+    fn the_stream_pauses_while_nothing_is_staked() {
+        let mut pool = pool(0);
+        pool.add_rewards(REWARD_STREAM_SLOTS * 2, 0).unwrap();
+        assert_eq!(pool.update(REWARD_STREAM_SLOTS * 5).unwrap(), 0);
+        assert_eq!(pool.pending_rewards, REWARD_STREAM_SLOTS * 2);
+
+        pool.total_staked = 10;
+        let mut staker = position(10, &pool);
+        let halfway = REWARD_STREAM_SLOTS * 5 + REWARD_STREAM_SLOTS / 2;
+        assert_eq!(pool.update(halfway).unwrap(), REWARD_STREAM_SLOTS);
+        assert_eq!(pool.update(REWARD_STREAM_SLOTS * 6).unwrap(), REWARD_STREAM_SLOTS);
+        staker.settle(&pool).unwrap();
+        assert_eq!(staker.unclaimed_rewards, REWARD_STREAM_SLOTS * 2);
+    }
+
+    #[test]
+    fn new_rewards_restart_the_stream_with_what_is_pending() {
+        let mut pool = pool(1);
+        pool.add_rewards(REWARD_STREAM_SLOTS * 2, 0).unwrap();
+        assert_eq!(pool.update(REWARD_STREAM_SLOTS / 2).unwrap(), REWARD_STREAM_SLOTS);
+
+        pool.add_rewards(REWARD_STREAM_SLOTS, REWARD_STREAM_SLOTS / 2).unwrap();
+        assert_eq!(pool.stream_end_slot, REWARD_STREAM_SLOTS / 2 + REWARD_STREAM_SLOTS);
+        assert_eq!(pool.update(REWARD_STREAM_SLOTS).unwrap(), REWARD_STREAM_SLOTS);
+        assert_eq!(pool.update(REWARD_STREAM_SLOTS * 2).unwrap(), REWARD_STREAM_SLOTS);
+        assert_eq!(pool.total_rewards, REWARD_STREAM_SLOTS * 3);
+    }
+
+    #[test]
+    fn rounding_never_pays_out_more_than_was_released() {
+        let mut pool = pool(3);
+        let mut stakers = [position(1, &pool), position(1, &pool), position(1, &pool)];
+        pool.add_rewards(10, 0).unwrap();
+        pool.update(REWARD_STREAM_SLOTS).unwrap();
+        for staker in stakers.iter_mut() {
+            staker.settle(&pool).unwrap();
+        }
+        let paid: u64 = stakers.iter().map(|staker| staker.unclaimed_rewards).sum();
+        assert!(paid <= pool.total_rewards);
+        assert_eq!(paid, 9);
+    }
+}
@@ -11,10 +11,28 @@ import { expect } from "chai";
 import {
   getAssociatedTokenAddressSync,
   getAccount,
+  getMint,
   TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  NATIVE_MINT,
   createAssociatedTokenAccountInstruction,
+  createAssociatedTokenAccountIdempotentInstruction,
+  createSyncNativeInstruction,
 } from "@solana/spl-token";
 
+// Meteora DAMM v2; a stand-in is loaded at this address for local tests
+const DAMM_V2_PROGRAM_ID = new PublicKey(
+  "cpamdpZCGKUy5JxQXB4dcpGPiikHawvSWAd6mEn1sGG"
+);
+// Meteora DLMM and Raydium CP-Swap, likewise
+const DLMM_PROGRAM_ID = new PublicKey(
+  "LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo"
+);
+const CPMM_PROGRAM_ID = new PublicKey(
+  "CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C"
+);
+
 async function getTokenBalance(
   provider: anchor.Provider,
   ata: PublicKey
@@ -27,6 +45,83 @@ async function getTokenBalance(
   }
 }
 
+function deriveDammV2Accounts(
+  programId: PublicKey,
+  mint: PublicKey,
+  config: PublicKey
+) {
+  const [positionNftMint] = PublicKey.findProgramAddressSync(
+    [Buffer.from("position_nft_mint"), mint.toBuffer()],
+    programId
+  );
+  const [maxKey, minKey] =
+    Buffer.compare(mint.toBuffer(), NATIVE_MINT.toBuffer()) > 0
+      ? [mint, NATIVE_MINT]
+      : [NATIVE_MINT, mint];
+  const [pool] = PublicKey.findProgramAddressSync(
+    [Buffer.from("pool"), config.toBuffer(), maxKey.toBuffer(), minKey.toBuffer()],
+    DAMM_V2_PROGRAM_ID
+  );
+  const [position] = PublicKey.findProgramAddressSync(
+    [Buffer.from("position"), positionNftMint.toBuffer()],
+    DAMM_V2_PROGRAM_ID
+  );
+  const [positionNftAccount] = PublicKey.findProgramAddressSync(
+    [Buffer.from("position_nft_account"), positionNftMint.toBuffer()],
+    DAMM_V2_PROGRAM_ID
+  );
+  const [poolAuthority] = PublicKey.findProgramAddressSync(
+    [Buffer.from("pool_authority")],
+    DAMM_V2_PROGRAM_ID
+  );
+  const [tokenAVault] = PublicKey.findProgramAddressSync(
+    [Buffer.from("token_vault"), mint.toBuffer(), pool.toBuffer()],
+    DAMM_V2_PROGRAM_ID
+  );
+  const [tokenBVault] = PublicKey.findProgramAddressSync(
+    [Buffer.from("token_vault"), NATIVE_MINT.toBuffer(), pool.toBuffer()],
+    DAMM_V2_PROGRAM_ID
+  );
+  const [dammEventAuthority] = PublicKey.findProgramAddressSync(
+    [Buffer.from("__event_authority")],
+    DAMM_V2_PROGRAM_ID
+  );
+  return {
+    positionNftMint,
+    positionNftAccount,
+    poolConfig: config,
+    poolAuthority,
+    pool,
+    position,
+    tokenAVault,
+    tokenBVault,
+    dammEventAuthority,
+  };
+}
+
+// Remaining accounts for `migrate` into DAMM v2, in the adapter's order
+function dammV2RemainingAccounts(
+  accounts: ReturnType<typeof deriveDammV2Accounts>
+) {
+  const meta = (pubkey: PublicKey, isWritable: boolean) => ({
+    pubkey,
+    isWritable,
+    isSigner: false,
+  });
+  return [
+    meta(accounts.positionNftMint, true),
+    meta(accounts.positionNftAccount, true),
+    meta(accounts.poolConfig, false),
+    meta(accounts.poolAuthority, false),
+    meta(accounts.pool, true),
+    meta(accounts.position, true),
+    meta(accounts.tokenAVault, true),
+    meta(accounts.tokenBVault, true),
+    meta(TOKEN_2022_PROGRAM_ID, false),
+    meta(accounts.dammEventAuthority, false),
+  ];
+}
+
 async function getSolBalance(
   provider: anchor.Provider,
   pubkey: PublicKey
@@ -42,7 +137,6 @@ describe("coinfun", () => {
   const authority = (provider.wallet as anchor.Wallet).payer;
 
   // Define all keypairs and constants
-  const platformFeeRecipient = Keypair.generate();
   const creator = Keypair.generate();
   const buyer = Keypair.generate();
   const secondBuyer = Keypair.generate();
@@ -54,6 +148,26 @@ describe("coinfun", () => {
   const graduationThreshold = new anchor.BN(2 * LAMPORTS_PER_SOL); // Lower for testing
   const platformTradeFeeBps = new anchor.BN(100); // 1% to platform
   const reserveTradeFeeBps = new anchor.BN(400); // 4% to reserve (total 5%)
+  const lpPolicy = { permanentLock: {} };
+  const keeperRewardBps = new anchor.BN(50); // 0.5% of a curve's SOL to whoever migrates it
+  const surplusTokenPolicy = { burn: {} }; // Curve tokens left out of the pool are burned
+  const migrationFeeLamports = new anchor.BN(0.1 * LAMPORTS_PER_SOL); // Platform's cut at migration
+  const creatorTradeFeeBps = new anchor.BN(0); // Off by default; turned on in the creator fee test
+  const referralFeeBps = new anchor.BN(2000); // 20% of the platform fee to a trade's referrer
+  const curveKinds = [true, true, false]; // Constant product and linear launches, no exponential
+  // Creators may pick their own launch economics between half and twice the defaults
+  const minCurveParams = {
+    initialVirtualTokenReserves: initialVirtualTokenReserves.divn(2),
+    initialVirtualSolReserves: initialVirtualSolReserves.divn(2),
+    tokenTotalSupply: tokenTotalSupply.divn(2),
+    graduationThreshold: graduationThreshold.divn(2),
+  };
+  const maxCurveParams = {
+    initialVirtualTokenReserves: initialVirtualTokenReserves.muln(2),
+    initialVirtualSolReserves: initialVirtualSolReserves.muln(2),
+    tokenTotalSupply: tokenTotalSupply.muln(2),
+    graduationThreshold: graduationThreshold.muln(2),
+  };
 
   // PDAs and Keypairs
   let global: PublicKey;
@@ -62,6 +176,7 @@ describe("coinfun", () => {
   let bondingCurve: PublicKey;
   let bondingCurveAta: PublicKey;
   let reserveAta: PublicKey;
+  let platformFeeVault: PublicKey;
 
   before(async () => {
     // Fund all wallets
@@ -98,6 +213,10 @@ describe("coinfun", () => {
       [Buffer.from("reserve")],
       program.programId
     );
+    [platformFeeVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("platform_fee_vault")],
+      program.programId
+    );
     [bondingCurve] = PublicKey.findProgramAddressSync(
       [Buffer.from("bonding_curve"), mint.publicKey.toBuffer()],
       program.programId
@@ -122,8 +241,16 @@ describe("coinfun", () => {
         tokenTotalSupply,
         platformTradeFeeBps,
         reserveTradeFeeBps,
-        platformFeeRecipient.publicKey,
-        graduationThreshold
+        graduationThreshold,
+        lpPolicy,
+        keeperRewardBps,
+        surplusTokenPolicy,
+        migrationFeeLamports,
+        curveKinds,
+        minCurveParams,
+        maxCurveParams,
+        creatorTradeFeeBps,
+        referralFeeBps
       )
       .rpc();
 
@@ -131,9 +258,6 @@ describe("coinfun", () => {
     expect(globalData.authority.toBase58()).to.eq(
       authority.publicKey.toBase58()
     );
-    expect(globalData.platformFeeRecipient.toBase58()).to.eq(
-      platformFeeRecipient.publicKey.toBase58()
-    );
     expect(globalData.reserve.toBase58()).to.eq(globalReserve.toBase58());
     expect(globalData.platformTradeFeeBps.toString()).to.eq(
       platformTradeFeeBps.toString()
@@ -147,6 +271,9 @@ describe("coinfun", () => {
     expect(globalData.graduationThreshold.toString()).to.eq(
       graduationThreshold.toString()
     );
+    expect(globalData.migrationPrograms[0].toBase58()).to.eq(
+      DAMM_V2_PROGRAM_ID.toBase58()
+    );
   });
 
   it("Creates a new token and checks state and TokenCreated event", async () => {
@@ -161,7 +288,7 @@ describe("coinfun", () => {
     });
 
     await program.methods
-      .create("Test Token", "TEST", "https://test.com/token.json")
+      .create("Test Token", "TEST", "https://test.com/token.json", { dammV2: {} }, { constantProduct: {} }, null, { solThreshold: {} }, null, { immediately: {} }, null, { accumulate: {} })
       .accounts({
         signer: authority.publicKey,
         creator: creator.publicKey,
@@ -193,7 +320,8 @@ describe("coinfun", () => {
     expect(bondingCurveData.realTokenReserves.toString()).to.eq(
       tokenTotalSupply.toString()
     );
-    expect(bondingCurveData.complete).to.be.false;
+    expect(bondingCurveData.status).to.deep.eq({ active: {} });
+    expect(bondingCurveData.migrationTarget).to.deep.eq({ dammV2: {} });
 
     // Check token balances
     const bondingCurveAtaBalance = await getTokenBalance(
@@ -211,7 +339,7 @@ describe("coinfun", () => {
     );
     
     const beforeBuyerSol = await getSolBalance(provider, buyer.publicKey);
-    const beforePlatformSol = await getSolBalance(provider, platformFeeRecipient.publicKey);
+    const beforePlatformSol = await getSolBalance(provider, platformFeeVault);
     const beforeCurveSol = await getSolBalance(provider, bondingCurve);
     const beforeCurveData = await program.account.bondingCurve.fetch(bondingCurve);
     const beforeReserveTokens = await getTokenBalance(provider, reserveAta);
@@ -236,7 +364,6 @@ describe("coinfun", () => {
       })
       .accountsPartial({
         reserveAta: reserveAta,
-        platformFeeRecipient: platformFeeRecipient.publicKey,
       })
       .signers([buyer])
       .rpc();
@@ -246,7 +373,7 @@ describe("coinfun", () => {
     expect(tradeEventReceived).to.be.true;
 
     const afterBuyerSol = await getSolBalance(provider, buyer.publicKey);
-    const afterPlatformSol = await getSolBalance(provider, platformFeeRecipient.publicKey);
+    const afterPlatformSol = await getSolBalance(provider, platformFeeVault);
     const afterCurveSol = await getSolBalance(provider, bondingCurve);
     const afterCurveData = await program.account.bondingCurve.fetch(bondingCurve);
     const afterReserveTokens = await getTokenBalance(provider, reserveAta);
@@ -287,7 +414,7 @@ describe("coinfun", () => {
     );
 
     const beforeSecondBuyerSol = await getSolBalance(provider, secondBuyer.publicKey);
-    const beforePlatformSol = await getSolBalance(provider, platformFeeRecipient.publicKey);
+    const beforePlatformSol = await getSolBalance(provider, platformFeeVault);
     const beforeCurveSol = await getSolBalance(provider, bondingCurve);
     const beforeCurveData = await program.account.bondingCurve.fetch(bondingCurve);
     const beforeReserveTokens = await getTokenBalance(provider, reserveAta);
@@ -301,13 +428,12 @@ describe("coinfun", () => {
       })
       .accountsPartial({
         reserveAta: reserveAta,
-        platformFeeRecipient: platformFeeRecipient.publicKey,
       })
       .signers([secondBuyer])
       .rpc();
 
     const afterSecondBuyerSol = await getSolBalance(provider, secondBuyer.publicKey);
-    const afterPlatformSol = await getSolBalance(provider, platformFeeRecipient.publicKey);
+    const afterPlatformSol = await getSolBalance(provider, platformFeeVault);
     const afterCurveSol = await getSolBalance(provider, bondingCurve);
     const afterCurveData = await program.account.bondingCurve.fetch(bondingCurve);
     const afterReserveTokens = await getTokenBalance(provider, reserveAta);
@@ -326,6 +452,56 @@ describe("coinfun", () => {
     expect(afterCurveSol - beforeCurveSol).to.be.gt(0);
   });
 
+  it("Buys an exact token amount for no more than a SOL cap", async () => {
+    const tokenAmount = new anchor.BN(1_000_000 * 1e6);
+    const secondBuyerAta = getAssociatedTokenAddressSync(
+      mint.publicKey,
+      secondBuyer.publicKey
+    );
+    const buyExactOut = (maxSolCost: anchor.BN) =>
+      program.methods
+        .buyExactOut(tokenAmount, maxSolCost)
+        .accounts({
+          signer: secondBuyer.publicKey,
+          mint: mint.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .accountsPartial({
+          reserveAta: reserveAta,
+        })
+        .signers([secondBuyer])
+        .rpc();
+
+    // The SOL needed is well above one lamport
+    try {
+      await buyExactOut(new anchor.BN(1));
+      expect.fail("Should have failed above the SOL cap");
+    } catch (e) {
+      expect(e.toString()).to.include("RequireGteViolated");
+    }
+
+    let tradeEvent = null;
+    const listener = program.addEventListener("trade", (event) => {
+      tradeEvent = event;
+    });
+    const beforeTokens = await getTokenBalance(provider, secondBuyerAta);
+    const beforeSol = await getSolBalance(provider, secondBuyer.publicKey);
+
+    await buyExactOut(new anchor.BN(1 * LAMPORTS_PER_SOL));
+
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+    const received = (await getTokenBalance(provider, secondBuyerAta)) - beforeTokens;
+    const spent = beforeSol - (await getSolBalance(provider, secondBuyer.publicKey));
+
+    // Gets the tokens asked for and pays exactly the SOL the trade reports
+    expect(received).to.be.gte(tokenAmount.toNumber());
+    expect(tradeEvent.tokenAmount.toNumber()).to.eq(received);
+    expect(tradeEvent.solRefunded.toNumber()).to.eq(0);
+    expect(spent).to.eq(tradeEvent.solAmount.toNumber());
+    expect(spent).to.be.lte(LAMPORTS_PER_SOL);
+  });
+
   it("Allows sell with reserve token purchase and comprehensive validation", async () => {
     const buyerAta = getAssociatedTokenAddressSync(
       mint.publicKey,
@@ -335,7 +511,7 @@ describe("coinfun", () => {
     const tokenAmountToSell = new anchor.BN(Math.floor(tokenBalance / 2));
 
     const beforeBuyerSol = await getSolBalance(provider, buyer.publicKey);
-    const beforePlatformSol = await getSolBalance(provider, platformFeeRecipient.publicKey);
+    const beforePlatformSol = await getSolBalance(provider, platformFeeVault);
     const beforeCurveSol = await getSolBalance(provider, bondingCurve);
     const beforeCurveData = await program.account.bondingCurve.fetch(bondingCurve);
     const beforeReserveTokens = await getTokenBalance(provider, reserveAta);
@@ -360,7 +536,6 @@ describe("coinfun", () => {
       })
       .accountsPartial({
         reserveAta: reserveAta,
-        platformFeeRecipient: platformFeeRecipient.publicKey,
       })
       .signers([buyer])
       .rpc();
@@ -370,7 +545,7 @@ describe("coinfun", () => {
     expect(sellEventReceived).to.be.true;
 
     const afterBuyerSol = await getSolBalance(provider, buyer.publicKey);
-    const afterPlatformSol = await getSolBalance(provider, platformFeeRecipient.publicKey);
+    const afterPlatformSol = await getSolBalance(provider, platformFeeVault);
     const afterCurveSol = await getSolBalance(provider, bondingCurve);
     const afterCurveData = await program.account.bondingCurve.fetch(bondingCurve);
     const afterReserveTokens = await getTokenBalance(provider, reserveAta);
@@ -395,19 +570,76 @@ describe("coinfun", () => {
     expect(afterCurveData.realSolReserves.toNumber()).to.be.lt(beforeCurveData.realSolReserves.toNumber());
   });
 
+  it("Sells for an exact net SOL amount under a token cap", async () => {
+    const solOutNet = new anchor.BN(0.01 * LAMPORTS_PER_SOL);
+    const buyerAta = getAssociatedTokenAddressSync(
+      mint.publicKey,
+      buyer.publicKey
+    );
+    const sellForSol = (maxTokensIn: anchor.BN) =>
+      program.methods
+        .sellForSol(solOutNet, maxTokensIn)
+        .accounts({
+          signer: buyer.publicKey,
+          mint: mint.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .accountsPartial({
+          reserveAta: reserveAta,
+        })
+        .signers([buyer])
+        .rpc();
+
+    // The tokens needed are well above one unit
+    try {
+      await sellForSol(new anchor.BN(1));
+      expect.fail("Should have failed above the token cap");
+    } catch (e) {
+      expect(e.toString()).to.include("RequireGteViolated");
+    }
+
+    let tradeEvent = null;
+    const listener = program.addEventListener("trade", (event) => {
+      tradeEvent = event;
+    });
+    const beforeTokens = await getTokenBalance(provider, buyerAta);
+    const beforeSol = await getSolBalance(provider, buyer.publicKey);
+
+    await sellForSol(new anchor.BN(beforeTokens));
+
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+    const sold = beforeTokens - (await getTokenBalance(provider, buyerAta));
+    const received = (await getSolBalance(provider, buyer.publicKey)) - beforeSol;
+
+    // Receives at least the SOL asked for and gives up exactly the tokens the trade reports
+    expect(JSON.stringify(tradeEvent.side)).to.include("sell");
+    expect(tradeEvent.tokenAmount.toNumber()).to.eq(sold);
+    expect(received).to.be.gte(solOutNet.toNumber());
+    expect(received).to.eq(tradeEvent.solAmount.toNumber());
+  });
+
   it("Tests 30% fee cap validation", async () => {
     // Try to update with fees > 30%
     try {
       await program.methods
         .updateGlobalConfig(
           authority.publicKey,
-          platformFeeRecipient.publicKey,
           new anchor.BN(2000), // 20%
           new anchor.BN(1100), // 11% (total 31% > 30%)
           initialVirtualTokenReserves,
           initialVirtualSolReserves,
           tokenTotalSupply,
-          graduationThreshold
+          graduationThreshold,
+          lpPolicy,
+          keeperRewardBps,
+          surplusTokenPolicy,
+          migrationFeeLamports,
+          curveKinds,
+          minCurveParams,
+          maxCurveParams,
+          creatorTradeFeeBps,
+          referralFeeBps
         )
         .accounts({ authority: authority.publicKey })
         .rpc();
@@ -423,31 +655,51 @@ describe("coinfun", () => {
     await program.methods
       .updateGlobalConfig(
         authority.publicKey,
-        platformFeeRecipient.publicKey,
         new anchor.BN(1500), // 15%
         new anchor.BN(1500), // 15% (total 30%)
         initialVirtualTokenReserves,
         initialVirtualSolReserves,
         tokenTotalSupply,
-        graduationThreshold
+        graduationThreshold,
+        lpPolicy,
+        keeperRewardBps,
+        surplusTokenPolicy,
+        migrationFeeLamports,
+        curveKinds,
+        minCurveParams,
+        maxCurveParams,
+        creatorTradeFeeBps,
+        referralFeeBps
       )
       .accounts({ authority: authority.publicKey })
       .rpc();
 
     const globalData = await program.account.global.fetch(global);
     expect(globalData.platformTradeFeeBps.toNumber() + globalData.reserveTradeFeeBps.toNumber()).to.eq(3000);
+    // Live curves keep the fees snapshotted at create
+    const curveData = await program.account.bondingCurve.fetch(bondingCurve);
+    expect(curveData.platformTradeFeeBps.toString()).to.eq(platformTradeFeeBps.toString());
+    expect(curveData.reserveTradeFeeBps.toString()).to.eq(reserveTradeFeeBps.toString());
 
     // Reset to original fees
     await program.methods
       .updateGlobalConfig(
         authority.publicKey,
-        platformFeeRecipient.publicKey,
         platformTradeFeeBps,
         reserveTradeFeeBps,
         initialVirtualTokenReserves,
         initialVirtualSolReserves,
         tokenTotalSupply,
-        graduationThreshold
+        graduationThreshold,
+        lpPolicy,
+        keeperRewardBps,
+        surplusTokenPolicy,
+        migrationFeeLamports,
+        curveKinds,
+        minCurveParams,
+        maxCurveParams,
+        creatorTradeFeeBps,
+        referralFeeBps
       )
       .accounts({ authority: authority.publicKey })
       .rpc();
@@ -464,8 +716,15 @@ describe("coinfun", () => {
     const listener = program.addEventListener("curveComplete", (event, slot) => {
       expect(event.mint.toBase58()).to.eq(mint.publicKey.toBase58());
       expect(event.bondingCurve.toBase58()).to.eq(bondingCurve.toBase58());
+      expect(event.cause).to.deep.eq({ solThreshold: {} });
       curveCompleteReceived = true;
     });
+    // The buy overshoots the threshold, so only part of it is filled
+    let tradeEvent = null;
+    const tradeListener = program.addEventListener("trade", (event, slot) => {
+      tradeEvent = event;
+    });
+    const buyerBalanceBefore = await provider.connection.getBalance(buyer.publicKey);
 
     await program.methods
       .buy(solNeeded, new anchor.BN(0))
@@ -476,20 +735,35 @@ describe("coinfun", () => {
       })
       .accountsPartial({
         reserveAta: reserveAta,
-        platformFeeRecipient: platformFeeRecipient.publicKey,
       })
       .signers([buyer])
       .rpc();
 
     await new Promise((resolve) => setTimeout(resolve, 1000));
     await program.removeEventListener(listener);
+    await program.removeEventListener(tradeListener);
     expect(curveCompleteReceived).to.be.true;
 
+    expect(tradeEvent).to.not.be.null;
+    expect(tradeEvent.solRefunded.toNumber()).to.be.gt(0);
+    expect(tradeEvent.solAmount.add(tradeEvent.solRefunded).toString()).to.eq(
+      solNeeded.toString()
+    );
+    // Only the filled part (plus the buyer ATA rent, at most) left the buyer
+    const buyerBalanceAfter = await provider.connection.getBalance(buyer.publicKey);
+    expect(buyerBalanceBefore - buyerBalanceAfter).to.be.lt(
+      tradeEvent.solAmount.toNumber() + 0.01 * LAMPORTS_PER_SOL
+    );
+
     const curveData = await program.account.bondingCurve.fetch(bondingCurve);
-    expect(curveData.complete).to.be.true;
+    expect(curveData.status).to.deep.eq({ complete: {} });
     expect(curveData.realSolReserves.toNumber()).to.be.gte(
       graduationThreshold.toNumber()
     );
+    // The fill stops at the threshold instead of overshooting it
+    expect(curveData.realSolReserves.toNumber()).to.be.lt(
+      graduationThreshold.toNumber() + 0.01 * LAMPORTS_PER_SOL
+    );
 
     // Try to buy again, should fail
     try {
@@ -502,7 +776,6 @@ describe("coinfun", () => {
         })
         .accountsPartial({
           reserveAta: reserveAta,
-          platformFeeRecipient: platformFeeRecipient.publicKey,
         })
         .signers([buyer])
         .rpc();
@@ -512,49 +785,140 @@ describe("coinfun", () => {
     }
   });
 
-  it("Allows authority to withdraw from graduated curve (no cooldown)", async () => {
-    const authorityAta = getAssociatedTokenAddressSync(
-      mint.publicKey,
-      authority.publicKey
+  it("Refunds holders of a curve that will not migrate pro-rata", async () => {
+    const refundMint = Keypair.generate();
+    const [refundBondingCurve] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bonding_curve"), refundMint.publicKey.toBuffer()],
+      program.programId
     );
-    const beforeAuthorityToken = await getTokenBalance(provider, authorityAta);
-    const beforeAuthoritySol = await getSolBalance(provider, authority.publicKey);
-    const beforeCurveToken = await getTokenBalance(provider, bondingCurveAta);
+    const refundBondingCurveAta = getAssociatedTokenAddressSync(
+      refundMint.publicKey,
+      refundBondingCurve,
+      true
+    );
+    await program.methods
+      .create("Refund Token", "REF", "https://test.com/ref.json", { dammV2: {} }, { constantProduct: {} }, null, { solThreshold: {} }, null, { immediately: {} }, null, { accumulate: {} })
+      .accounts({
+        signer: authority.publicKey,
+        creator: creator.publicKey,
+        mint: refundMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([refundMint])
+      .rpc();
 
-    // Create the ATA if it doesn't exist
+    // Two holders buy until the curve graduates
+    const holder = Keypair.generate();
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: authority.publicKey,
+          toPubkey: holder.publicKey,
+          lamports: 2 * LAMPORTS_PER_SOL,
+        })
+      )
+    );
+    const buy = (signer: Keypair, lamports: number) =>
+      program.methods
+        .buy(new anchor.BN(lamports), new anchor.BN(0))
+        .accounts({ signer: signer.publicKey, mint: refundMint.publicKey, tokenProgram: TOKEN_PROGRAM_ID })
+        .signers([signer])
+        .rpc();
+    await buy(holder, 1 * LAMPORTS_PER_SOL);
+    await buy(authority, 2 * LAMPORTS_PER_SOL);
+    const curveData = await program.account.bondingCurve.fetch(refundBondingCurve);
+    expect(curveData.status).to.deep.eq({ complete: {} });
+
+    const refund = (signer: Keypair, tokens: anchor.BN) =>
+      program.methods
+        .refund(tokens, new anchor.BN(0))
+        .accounts({ signer: signer.publicKey, mint: refundMint.publicKey, tokenProgram: TOKEN_PROGRAM_ID })
+        .signers([signer])
+        .rpc();
+    const holderAta = getAssociatedTokenAddressSync(refundMint.publicKey, holder.publicKey);
+    const holderTokens = await getTokenBalance(provider, holderAta);
     try {
-      const createAtaIx = createAssociatedTokenAccountInstruction(
-        authority.publicKey,
-        authorityAta,
-        authority.publicKey,
-        mint.publicKey
-      );
-      const setupTx = new anchor.web3.Transaction().add(createAtaIx);
-      await provider.sendAndConfirm(setupTx, [], { commitment: "confirmed" });
+      await refund(holder, new anchor.BN(holderTokens));
+      expect.fail("Should have failed to refund a graduated curve");
+    } catch (e) {
+      expect(e.toString()).to.include("CurveNotRefunding");
+    }
+
+    // Only off-chain transitions can be set by the authority
+    try {
+      await program.methods
+        .setCurveStatus({ active: {} })
+        .accountsPartial({ authority: authority.publicKey, bondingCurve: refundBondingCurve })
+        .rpc();
+      expect.fail("Should have rejected moving the curve back to active");
     } catch (e) {
-      // ATA might already exist
+      expect(e.toString()).to.include("InvalidCurveStatusTransition");
     }
 
+    let statusEventReceived = false;
+    const listener = program.addEventListener("curveStatusChanged", (event) => {
+      expect(event.mint.toBase58()).to.eq(refundMint.publicKey.toBase58());
+      expect(event.from).to.deep.eq({ complete: {} });
+      expect(event.to).to.deep.eq({ refunding: {} });
+      statusEventReceived = true;
+    });
     await program.methods
-      .withdraw()
-      .accounts({
-        authority: authority.publicKey,
-        mint: mint.publicKey,
-        tokenProgram: TOKEN_PROGRAM_ID,
-      })
-      .signers([authority])
+      .setCurveStatus({ refunding: {} })
+      .accountsPartial({ authority: authority.publicKey, bondingCurve: refundBondingCurve })
       .rpc();
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+    expect(statusEventReceived).to.be.true;
 
-    const afterAuthorityToken = await getTokenBalance(provider, authorityAta);
-    const afterAuthoritySol = await getSolBalance(provider, authority.publicKey);
-    const finalCurveTokenBalance = await getTokenBalance(provider, bondingCurveAta);
+    // Trading stops, so nobody exits ahead of the others along the curve
+    try {
+      await program.methods
+        .sell(new anchor.BN(holderTokens), new anchor.BN(0))
+        .accounts({ signer: holder.publicKey, mint: refundMint.publicKey, tokenProgram: TOKEN_PROGRAM_ID })
+        .signers([holder])
+        .rpc();
+      expect.fail("Should have failed to sell on a refunding curve");
+    } catch (e) {
+      expect(e.toString()).to.include("BondingCurveComplete");
+    }
 
-    // Verify all tokens withdrawn
-    expect(finalCurveTokenBalance).to.eq(0);
-    expect(afterAuthorityToken).to.eq(beforeAuthorityToken + beforeCurveToken);
-    
-    // Verify SOL withdrawn (minus rent)
-    expect(afterAuthoritySol).to.be.gt(beforeAuthoritySol);
+    // Each returned token is paid the same share of the curve's SOL, and burned
+    const refunds = async (signer: Keypair, tokens: number) => {
+      const curve = await program.account.bondingCurve.fetch(refundBondingCurve);
+      const supply = Number((await getMint(provider.connection, refundMint.publicKey)).supply);
+      const circulating = supply - (await getTokenBalance(provider, refundBondingCurveAta));
+      const expected =
+        (BigInt(curve.realSolReserves.toString()) * BigInt(tokens)) / BigInt(circulating);
+      const solBefore = await getSolBalance(provider, signer.publicKey);
+      let refundedEvent = null;
+      const refundListener = program.addEventListener("refunded", (event) => {
+        refundedEvent = event;
+      });
+      await refund(signer, new anchor.BN(tokens));
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(refundListener);
+      expect(refundedEvent).to.not.be.null;
+      expect(refundedEvent.holder.toBase58()).to.eq(signer.publicKey.toBase58());
+      expect(refundedEvent.tokenAmount.toNumber()).to.eq(tokens);
+      expect(refundedEvent.solAmount.toString()).to.eq(expected.toString());
+      expect(Number((await getMint(provider.connection, refundMint.publicKey)).supply)).to.eq(
+        supply - tokens
+      );
+      return { expected, solBefore };
+    };
+
+    const half = Math.floor(holderTokens / 2);
+    const { expected, solBefore } = await refunds(holder, half);
+    expect(await getSolBalance(provider, holder.publicKey)).to.eq(
+      solBefore + Number(expected)
+    );
+    expect(await getTokenBalance(provider, holderAta)).to.eq(holderTokens - half);
+
+    // Later refunds are paid at the same rate per token
+    const authorityAta = getAssociatedTokenAddressSync(refundMint.publicKey, authority.publicKey);
+    await refunds(authority, await getTokenBalance(provider, authorityAta));
+    await refunds(holder, await getTokenBalance(provider, holderAta));
+    expect(await getTokenBalance(provider, holderAta)).to.eq(0);
   });
 
   it("Allows authority to withdraw specific amount from reserve (no cooldown)", async () => {
@@ -574,6 +938,7 @@ describe("coinfun", () => {
       .accounts({
         authority: authority.publicKey,
         mint: mint.publicKey,
+        stakePool: null,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([authority])
@@ -593,6 +958,7 @@ describe("coinfun", () => {
       .accounts({
         authority: authority.publicKey,
         mint: mint.publicKey,
+        stakePool: null,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([authority])
@@ -623,6 +989,7 @@ describe("coinfun", () => {
       .accounts({
         authority: authority.publicKey,
         mint: mint.publicKey,
+        stakePool: null,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([authority])
@@ -678,7 +1045,6 @@ describe("coinfun", () => {
   });
 
   it("Tests error conditions", async () => {
-    // Test withdrawal before graduation
     const newMint = Keypair.generate();
     const [newBondingCurve] = PublicKey.findProgramAddressSync(
       [Buffer.from("bonding_curve"), newMint.publicKey.toBuffer()],
@@ -690,7 +1056,7 @@ describe("coinfun", () => {
       true
     );
     await program.methods
-      .create("Test Token 2", "TEST2", "https://test.com/token2.json")
+      .create("Test Token 2", "TEST2", "https://test.com/token2.json", { dammV2: {} }, { constantProduct: {} }, null, { solThreshold: {} }, null, { immediately: {} }, null, { accumulate: {} })
       .accounts({
         signer: authority.publicKey,
         creator: creator.publicKey,
@@ -703,35 +1069,15 @@ describe("coinfun", () => {
       .signers([newMint])
       .rpc();
 
-    const newAuthorityAta = getAssociatedTokenAddressSync(
-      newMint.publicKey,
-      authority.publicKey
-    );
-
-    try {
-      const createNewAtaIx = createAssociatedTokenAccountInstruction(
-        authority.publicKey,
-        newAuthorityAta,
-        authority.publicKey,
-        newMint.publicKey
-      );
-      const setupNewTx = new anchor.web3.Transaction().add(createNewAtaIx);
-      await provider.sendAndConfirm(setupNewTx, [], { commitment: "confirmed" });
-    } catch (e) {}
-
+    // Only graduated curves can be sent to refunds
     try {
       await program.methods
-        .withdraw()
-        .accounts({
-          authority: authority.publicKey,
-          mint: newMint.publicKey,
-          tokenProgram: TOKEN_PROGRAM_ID,
-        })
-        .signers([authority])
+        .setCurveStatus({ refunding: {} })
+        .accountsPartial({ authority: authority.publicKey, bondingCurve: newBondingCurve })
         .rpc();
-      expect.fail("Should have failed to withdraw before graduation");
+      expect.fail("Should have failed to refund an active curve");
     } catch (e) {
-      expect(e.toString()).to.include("BondingCurveNotComplete");
+      expect(e.toString()).to.include("InvalidCurveStatusTransition");
     }
   });
 
@@ -754,7 +1100,7 @@ describe("coinfun", () => {
     );
 
     await program.methods
-      .create("Reserve Test Token", "RTT", "https://test.com/rtt.json")
+      .create("Reserve Test Token", "RTT", "https://test.com/rtt.json", { dammV2: {} }, { constantProduct: {} }, null, { solThreshold: {} }, null, { immediately: {} }, null, { accumulate: {} })
       .accounts({
         signer: authority.publicKey,
         creator: creator.publicKey,
@@ -780,7 +1126,6 @@ describe("coinfun", () => {
       })
       .accountsPartial({
         reserveAta: testReserveAta,
-        platformFeeRecipient: platformFeeRecipient.publicKey,
       })
       .signers([buyer])
       .rpc();
@@ -798,7 +1143,6 @@ describe("coinfun", () => {
       })
       .accountsPartial({
         reserveAta: testReserveAta,
-        platformFeeRecipient: platformFeeRecipient.publicKey,
       })
       .signers([secondBuyer])
       .rpc();
@@ -823,7 +1167,6 @@ describe("coinfun", () => {
       })
       .accountsPartial({
         reserveAta: testReserveAta,
-        platformFeeRecipient: platformFeeRecipient.publicKey,
       })
       .signers([buyer])
       .rpc();
@@ -834,4 +1177,1998 @@ describe("coinfun", () => {
     // Verify reserve accumulated tokens from all trades
     expect(afterSellReserve).to.be.gt(initialReserveBalance);
   });
+
+  it("Migrates a graduated curve into a DAMM v2 pool", async () => {
+    const migrateMint = Keypair.generate();
+    const [migrateBondingCurve] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bonding_curve"), migrateMint.publicKey.toBuffer()],
+      program.programId
+    );
+    const migrateBondingCurveAta = getAssociatedTokenAddressSync(
+      migrateMint.publicKey,
+      migrateBondingCurve,
+      true
+    );
+    const migrateReserveAta = getAssociatedTokenAddressSync(
+      migrateMint.publicKey,
+      globalReserve,
+      true
+    );
+
+    await program.methods
+      .create("Migrate Token", "MIG", "https://test.com/mig.json", { dammV2: {} }, { constantProduct: {} }, null, { solThreshold: {} }, null, { immediately: {} }, null, { accumulate: {} })
+      .accounts({
+        signer: authority.publicKey,
+        creator: creator.publicKey,
+        mint: migrateMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .accountsPartial({
+        bondingCurveAta: migrateBondingCurveAta,
+      })
+      .signers([migrateMint])
+      .rpc();
+
+    const [migrationAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("migration_authority"), migrateMint.publicKey.toBuffer()],
+      program.programId
+    );
+
+    // Pools are created with the config registered for the target
+    const dammConfig = Keypair.generate().publicKey;
+    await program.methods
+      .setMigrationProgram({ dammV2: {} }, DAMM_V2_PROGRAM_ID, dammConfig)
+      .accounts({ authority: authority.publicKey })
+      .rpc();
+
+    // Migrating before graduation must fail; anyone can migrate, here `buyer` is the keeper
+    const dammAccounts = deriveDammV2Accounts(
+      program.programId,
+      migrateMint.publicKey,
+      dammConfig
+    );
+    const migrate = () =>
+      program.methods
+        .migrate()
+        .accounts({
+          keeper: buyer.publicKey,
+          mint: migrateMint.publicKey,
+          dexProgram: DAMM_V2_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(dammV2RemainingAccounts(dammAccounts))
+        .signers([buyer])
+        .rpc();
+    try {
+      await migrate();
+      expect.fail("Should have failed to migrate before graduation");
+    } catch (e) {
+      expect(e.toString()).to.include("BondingCurveNotComplete");
+    }
+
+    await program.methods
+      .buy(graduationThreshold.add(new anchor.BN(0.5 * LAMPORTS_PER_SOL)), new anchor.BN(0))
+      .accounts({
+        signer: authority.publicKey,
+        mint: migrateMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .accountsPartial({
+        reserveAta: migrateReserveAta,
+      })
+      .rpc();
+
+    const beforeCurveTokens = await getTokenBalance(provider, migrateBondingCurveAta);
+    const beforeCurveSol = await getSolBalance(provider, migrateBondingCurve);
+    expect(beforeCurveTokens).to.be.gt(0);
+    const curveRent = await provider.connection.getMinimumBalanceForRentExemption(
+      (await provider.connection.getAccountInfo(migrateBondingCurve)).data.length
+    );
+    const curveSol = beforeCurveSol - curveRent;
+    const expectedKeeperReward = Math.floor(
+      (curveSol * keeperRewardBps.toNumber()) / 10000
+    );
+    const beforeMintSupply = Number(
+      (await getMint(provider.connection, migrateMint.publicKey)).supply
+    );
+    const { virtualSolReserves, virtualTokenReserves } =
+      await program.account.bondingCurve.fetch(migrateBondingCurve);
+
+    let migratedEventReceived = false;
+    let surplusTokens = 0;
+    const listener = program.addEventListener("curveMigrated", (event) => {
+      expect(event.mint.toBase58()).to.eq(migrateMint.publicKey.toBase58());
+      expect(event.target).to.deep.eq({ dammV2: {} });
+      expect(event.pool.toBase58()).to.eq(dammAccounts.pool.toBase58());
+      // Deposits are sized to the curve's final price; the rest is surplus, and
+      // whatever the pool did not take is swept to the reserve
+      expect(
+        event.tokenAmount
+          .add(event.surplusTokenAmount)
+          .add(event.leftoverTokenAmount)
+          .toNumber()
+      ).to.eq(beforeCurveTokens);
+      expect(event.migrationFee.toString()).to.eq(migrationFeeLamports.toString());
+      expect(
+        event.solAmount.add(event.surplusSolAmount).add(event.leftoverSolAmount).toNumber()
+      ).to.eq(
+        curveSol - expectedKeeperReward - migrationFeeLamports.toNumber()
+      );
+      expect(event.surplusTokenAmount.isZero() || event.surplusSolAmount.isZero()).to.be.true;
+      const poolPrice = event.solAmount.toNumber() / event.tokenAmount.toNumber();
+      const curvePrice = virtualSolReserves.toNumber() / virtualTokenReserves.toNumber();
+      expect(Math.abs(poolPrice - curvePrice) / curvePrice).to.be.lt(1e-6);
+      surplusTokens = event.surplusTokenAmount.toNumber();
+      expect(event.lpPolicy).to.deep.eq(lpPolicy);
+      expect(event.keeper.toBase58()).to.eq(buyer.publicKey.toBase58());
+      expect(event.keeperReward.toNumber()).to.eq(expectedKeeperReward);
+      migratedEventReceived = true;
+    });
+
+    const beforeVaultSol = await getSolBalance(provider, platformFeeVault);
+    await migrate();
+
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+    expect(migratedEventReceived).to.be.true;
+    expect(
+      (await getSolBalance(provider, platformFeeVault)) - beforeVaultSol
+    ).to.eq(migrationFeeLamports.toNumber());
+
+    // Liquidity went from the curve into the pool vaults, not through a wallet
+    const afterCurveSol = await getSolBalance(provider, migrateBondingCurve);
+    expect(await getTokenBalance(provider, migrateBondingCurveAta)).to.eq(0);
+    // Surplus tokens were burned under the `burn` policy
+    expect(
+      Number((await getMint(provider.connection, migrateMint.publicKey)).supply)
+    ).to.eq(beforeMintSupply - surplusTokens);
+    expect(await getTokenBalance(provider, dammAccounts.tokenAVault)).to.be.gt(0);
+    const wsolInPool = await getTokenBalance(provider, dammAccounts.tokenBVault);
+    expect(wsolInPool).to.be.gt(0);
+    expect(wsolInPool).to.be.lte(beforeCurveSol - afterCurveSol);
+
+    const positionNft = await getAccount(
+      provider.connection,
+      dammAccounts.positionNftAccount,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    // The position stays with the migration authority, permanently locked
+    expect(positionNft.owner.toBase58()).to.eq(migrationAuthority.toBase58());
+    expect(Number(positionNft.amount)).to.eq(1);
+
+    const curveData = await program.account.bondingCurve.fetch(migrateBondingCurve);
+    expect(curveData.status).to.deep.eq({ migrated: {} });
+    // Both deposit accounts are closed once their leftovers are swept
+    expect(
+      await provider.connection.getAccountInfo(
+        getAssociatedTokenAddressSync(migrateMint.publicKey, migrationAuthority, true)
+      )
+    ).to.be.null;
+
+    // A migrated curve cannot be migrated again
+    try {
+      await migrate();
+      expect.fail("Should have failed to migrate twice");
+    } catch (e) {
+      expect(e.toString()).to.include("BondingCurveNotComplete");
+    }
+
+    // The locked position's fees are harvested into the reserve, and the position stays where it is
+    const meta = (pubkey: PublicKey, isWritable: boolean) => ({
+      pubkey,
+      isWritable,
+      isSigner: false,
+    });
+    const dammFeeAccounts = [
+      meta(dammAccounts.poolAuthority, false),
+      meta(dammAccounts.pool, false),
+      meta(dammAccounts.position, true),
+      meta(dammAccounts.tokenAVault, true),
+      meta(dammAccounts.tokenBVault, true),
+      meta(dammAccounts.positionNftAccount, false),
+      meta(dammAccounts.dammEventAuthority, false),
+    ];
+    expect(
+      Number(
+        (
+          await getAccount(
+            provider.connection,
+            dammAccounts.positionNftAccount,
+            undefined,
+            TOKEN_2022_PROGRAM_ID
+          )
+        ).amount
+      )
+    ).to.eq(1);
+
+    // Anyone can harvest the position's fees into the reserve
+    let harvestedEventReceived = false;
+    const harvestListener = program.addEventListener("poolFeesHarvested", (event) => {
+      expect(event.mint.toBase58()).to.eq(migrateMint.publicKey.toBase58());
+      expect(event.harvester.toBase58()).to.eq(buyer.publicKey.toBase58());
+      harvestedEventReceived = true;
+    });
+    await program.methods
+      .harvestPoolFees()
+      .accounts({
+        payer: buyer.publicKey,
+        mint: migrateMint.publicKey,
+        dexProgram: DAMM_V2_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts(dammFeeAccounts)
+      .signers([buyer])
+      .rpc();
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(harvestListener);
+    expect(harvestedEventReceived).to.be.true;
+    // The WSOL side is unwrapped, leaving no WSOL account behind
+    expect(
+      await provider.connection.getAccountInfo(
+        getAssociatedTokenAddressSync(NATIVE_MINT, migrationAuthority, true)
+      )
+    ).to.be.null;
+
+    // `release_lp` only accepts the LP mint recorded at migration
+    expect(
+      (await program.account.bondingCurve.fetch(migrateBondingCurve)).lpMint.toBase58()
+    ).to.eq(dammAccounts.positionNftMint.toBase58());
+
+    // A permanently locked position can never be released
+    try {
+      await program.methods
+        .releaseLp()
+        .accountsPartial({
+          authority: authority.publicKey,
+          bondingCurve: migrateBondingCurve,
+          migrationAuthority,
+          lpMint: dammAccounts.positionNftMint,
+          lpAccount: dammAccounts.positionNftAccount,
+          recipient: authority.publicKey,
+          lpTokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+      expect.fail("Should have failed to release a locked position");
+    } catch (e) {
+      expect(e.toString()).to.include("LpLocked");
+    }
+  });
+
+  it("Migrates a graduated curve into a DLMM pair with the stand-in's account layout", async () => {
+    // A 1% preset, registered like a pool config
+    const binStep = 100;
+    const baseFactor = 10000;
+    const u16 = (value: number) => {
+      const bytes = Buffer.alloc(2);
+      bytes.writeUInt16LE(value);
+      return bytes;
+    };
+    const [presetParameter] = PublicKey.findProgramAddressSync(
+      [Buffer.from("preset_parameter"), u16(binStep), u16(baseFactor)],
+      DLMM_PROGRAM_ID
+    );
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        new anchor.web3.TransactionInstruction({
+          programId: DLMM_PROGRAM_ID,
+          keys: [
+            { pubkey: presetParameter, isWritable: true, isSigner: false },
+            { pubkey: authority.publicKey, isWritable: true, isSigner: true },
+            { pubkey: SystemProgram.programId, isWritable: false, isSigner: false },
+          ],
+          // `initialize_preset_parameter`
+          data: Buffer.concat([
+            Buffer.from([66, 188, 71, 211, 98, 109, 14, 186]),
+            u16(binStep),
+            u16(baseFactor),
+          ]),
+        })
+      )
+    );
+    await program.methods
+      .setMigrationProgram({ dlmm: {} }, DLMM_PROGRAM_ID, presetParameter)
+      .accounts({ authority: authority.publicKey })
+      .rpc();
+
+    const dlmmMint = Keypair.generate();
+    await program.methods
+      .create("DLMM Migrate", "DLMG", "https://test.com/dlmg.json", { dlmm: {} }, { constantProduct: {} }, null, { solThreshold: {} }, null, { immediately: {} }, null, { accumulate: {} })
+      .accounts({
+        signer: authority.publicKey,
+        creator: creator.publicKey,
+        mint: dlmmMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([dlmmMint])
+      .rpc();
+    await program.methods
+      .buy(graduationThreshold.add(new anchor.BN(0.5 * LAMPORTS_PER_SOL)), new anchor.BN(0))
+      .accounts({ signer: authority.publicKey, mint: dlmmMint.publicKey, tokenProgram: TOKEN_PROGRAM_ID })
+      .rpc();
+    const [dlmmBondingCurve] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bonding_curve"), dlmmMint.publicKey.toBuffer()],
+      program.programId
+    );
+    const [migrationAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("migration_authority"), dlmmMint.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const { virtualSolReserves, virtualTokenReserves } =
+      await program.account.bondingCurve.fetch(dlmmBondingCurve);
+
+    // The pair opens at the bin at or below the curve's final price, and the
+    // position spans 34 bins on either side of it
+    const activeId = Math.floor(
+      Math.log(virtualSolReserves.toNumber() / virtualTokenReserves.toNumber()) /
+        Math.log(1 + binStep / 10000)
+    );
+    const [maxKey, minKey] =
+      Buffer.compare(dlmmMint.publicKey.toBuffer(), NATIVE_MINT.toBuffer()) > 0
+        ? [dlmmMint.publicKey, NATIVE_MINT]
+        : [NATIVE_MINT, dlmmMint.publicKey];
+    const [lbPair] = PublicKey.findProgramAddressSync(
+      [minKey.toBuffer(), maxKey.toBuffer(), u16(binStep), u16(baseFactor)],
+      DLMM_PROGRAM_ID
+    );
+    const [reserveX] = PublicKey.findProgramAddressSync(
+      [lbPair.toBuffer(), dlmmMint.publicKey.toBuffer()],
+      DLMM_PROGRAM_ID
+    );
+    const [reserveY] = PublicKey.findProgramAddressSync(
+      [lbPair.toBuffer(), NATIVE_MINT.toBuffer()],
+      DLMM_PROGRAM_ID
+    );
+    const [oracle] = PublicKey.findProgramAddressSync(
+      [Buffer.from("oracle"), lbPair.toBuffer()],
+      DLMM_PROGRAM_ID
+    );
+    const [position] = PublicKey.findProgramAddressSync(
+      [Buffer.from("dlmm_position"), dlmmMint.publicKey.toBuffer()],
+      program.programId
+    );
+    const binArray = (binId: number) => {
+      const index = Buffer.alloc(8);
+      index.writeBigInt64LE(BigInt(Math.floor(binId / 70)));
+      return PublicKey.findProgramAddressSync(
+        [Buffer.from("bin_array"), lbPair.toBuffer(), index],
+        DLMM_PROGRAM_ID
+      )[0];
+    };
+    const [binArrayLower, binArrayUpper] = [binArray(activeId - 34), binArray(activeId + 34)];
+    const [dlmmEventAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("__event_authority")],
+      DLMM_PROGRAM_ID
+    );
+    const meta = (pubkey: PublicKey, isWritable: boolean) => ({
+      pubkey,
+      isWritable,
+      isSigner: false,
+    });
+
+    let migratedEvent = null;
+    const listener = program.addEventListener("curveMigrated", (event) => {
+      migratedEvent = event;
+    });
+    await program.methods
+      .migrate()
+      .accounts({
+        keeper: buyer.publicKey,
+        mint: dlmmMint.publicKey,
+        dexProgram: DLMM_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts([
+        meta(lbPair, true),
+        // No bitmap extension
+        meta(DLMM_PROGRAM_ID, false),
+        meta(reserveX, true),
+        meta(reserveY, true),
+        meta(oracle, true),
+        meta(presetParameter, false),
+        meta(position, true),
+        meta(binArrayLower, true),
+        meta(binArrayUpper, true),
+        meta(anchor.web3.SYSVAR_RENT_PUBKEY, false),
+        meta(dlmmEventAuthority, false),
+      ])
+      .signers([buyer])
+      .rpc();
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+    expect(migratedEvent).to.not.be.null;
+    expect(migratedEvent.target).to.deep.eq({ dlmm: {} });
+    expect(migratedEvent.pool.toBase58()).to.eq(lbPair.toBase58());
+    expect(
+      (await program.account.bondingCurve.fetch(dlmmBondingCurve)).status
+    ).to.deep.eq({ migrated: {} });
+
+    // The pair records the curve's bin and the preset, with the mint as token X
+    const pairData = (await provider.connection.getAccountInfo(lbPair)).data;
+    expect(pairData.readUInt16LE(8)).to.eq(baseFactor);
+    expect(pairData.readInt32LE(76)).to.eq(activeId);
+    expect(pairData.readUInt16LE(80)).to.eq(binStep);
+    expect(new PublicKey(pairData.subarray(88, 120)).toBase58()).to.eq(
+      dlmmMint.publicKey.toBase58()
+    );
+    expect(new PublicKey(pairData.subarray(120, 152)).toBase58()).to.eq(
+      NATIVE_MINT.toBase58()
+    );
+    // The position belongs to the migration authority and took both deposits whole
+    const positionData = (await provider.connection.getAccountInfo(position)).data;
+    expect(new PublicKey(positionData.subarray(8, 40)).toBase58()).to.eq(lbPair.toBase58());
+    expect(new PublicKey(positionData.subarray(40, 72)).toBase58()).to.eq(
+      migrationAuthority.toBase58()
+    );
+    expect(positionData.readInt32LE(72)).to.eq(activeId - 34);
+    expect(positionData.readInt32LE(76)).to.eq(activeId + 34);
+    expect(positionData.readBigUInt64LE(80).toString()).to.eq(
+      migratedEvent.tokenAmount.toString()
+    );
+    expect(positionData.readBigUInt64LE(88).toString()).to.eq(
+      migratedEvent.solAmount.toString()
+    );
+    expect(await getTokenBalance(provider, reserveX)).to.eq(migratedEvent.tokenAmount.toNumber());
+    expect(await getTokenBalance(provider, reserveY)).to.eq(migratedEvent.solAmount.toNumber());
+
+    // Fees are claimed through the position's bin arrays
+    await program.methods
+      .harvestPoolFees()
+      .accounts({
+        payer: buyer.publicKey,
+        mint: dlmmMint.publicKey,
+        dexProgram: DLMM_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts([
+        meta(lbPair, true),
+        meta(position, true),
+        meta(binArrayLower, true),
+        meta(binArrayUpper, true),
+        meta(reserveX, true),
+        meta(reserveY, true),
+        meta(dlmmEventAuthority, false),
+      ])
+      .signers([buyer])
+      .rpc();
+  });
+
+  it("Seeds a DAMM v2 pool someone created first if it trades near the curve's price", async () => {
+    const seedMint = Keypair.generate();
+    const [seedBondingCurve] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bonding_curve"), seedMint.publicKey.toBuffer()],
+      program.programId
+    );
+    const [migrationAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("migration_authority"), seedMint.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .create("Seed Token", "SEED", "https://test.com/seed.json", { dammV2: {} }, { constantProduct: {} }, null, { solThreshold: {} }, null, { immediately: {} }, null, { accumulate: {} })
+      .accounts({
+        signer: authority.publicKey,
+        creator: creator.publicKey,
+        mint: seedMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([seedMint])
+      .rpc();
+
+    // `buyer` holds tokens and WSOL to open pools of its own, then the curve graduates
+    const buy = (signer: Keypair, lamports: number) =>
+      program.methods
+        .buy(new anchor.BN(lamports), new anchor.BN(0))
+        .accounts({ signer: signer.publicKey, mint: seedMint.publicKey, tokenProgram: TOKEN_PROGRAM_ID })
+        .signers([signer])
+        .rpc();
+    await buy(buyer, 0.1 * LAMPORTS_PER_SOL);
+    await buy(authority, 3 * LAMPORTS_PER_SOL);
+    const { status, virtualSolReserves, virtualTokenReserves } =
+      await program.account.bondingCurve.fetch(seedBondingCurve);
+    expect(status).to.deep.eq({ complete: {} });
+
+    const buyerTokenAta = getAssociatedTokenAddressSync(seedMint.publicKey, buyer.publicKey);
+    const buyerWsolAta = getAssociatedTokenAddressSync(NATIVE_MINT, buyer.publicKey);
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        createAssociatedTokenAccountIdempotentInstruction(
+          authority.publicKey,
+          buyerWsolAta,
+          buyer.publicKey,
+          NATIVE_MINT
+        ),
+        SystemProgram.transfer({
+          fromPubkey: authority.publicKey,
+          toPubkey: buyerWsolAta,
+          lamports: 0.2 * LAMPORTS_PER_SOL,
+        }),
+        createSyncNativeInstruction(buyerWsolAta)
+      )
+    );
+
+    // The curve's final price as a Q64 sqrt price, and the most a small deposit buys there
+    const isqrt = (value: bigint) => {
+      let x = value;
+      let y = (x + 1n) / 2n;
+      while (y < x) {
+        x = y;
+        y = (x + value / x) / 2n;
+      }
+      return x;
+    };
+    const MIN_SQRT_PRICE = 4_295_048_016n;
+    const MAX_SQRT_PRICE = 79_226_673_521_066_979_257_578_248_091n;
+    const curveSqrtPrice = isqrt(
+      (BigInt(virtualSolReserves.toString()) << 128n) / BigInt(virtualTokenReserves.toString())
+    );
+    const liquidityFor = (tokens: bigint, lamports: bigint, sqrtPrice: bigint) => {
+      const fromA = (tokens * sqrtPrice * MAX_SQRT_PRICE) / (MAX_SQRT_PRICE - sqrtPrice);
+      const fromB = (lamports << 128n) / (sqrtPrice - MIN_SQRT_PRICE);
+      return fromA < fromB ? fromA : fromB;
+    };
+    const u128 = (value: bigint) => {
+      const bytes = Buffer.alloc(16);
+      bytes.writeBigUInt64LE(value & ((1n << 64n) - 1n), 0);
+      bytes.writeBigUInt64LE(value >> 64n, 8);
+      return bytes;
+    };
+
+    // Opens the canonical pool for `config` through the stand-in's `initialize_pool`
+    const createPoolFirst = async (config: PublicKey, sqrtPrice: bigint) => {
+      const accounts = deriveDammV2Accounts(program.programId, seedMint.publicKey, config);
+      const nftMint = Keypair.generate();
+      const [nftAccount] = PublicKey.findProgramAddressSync(
+        [Buffer.from("position_nft_account"), nftMint.publicKey.toBuffer()],
+        DAMM_V2_PROGRAM_ID
+      );
+      const [position] = PublicKey.findProgramAddressSync(
+        [Buffer.from("position"), nftMint.publicKey.toBuffer()],
+        DAMM_V2_PROGRAM_ID
+      );
+      const meta = (pubkey: PublicKey, isWritable: boolean, isSigner = false) => ({
+        pubkey,
+        isWritable,
+        isSigner,
+      });
+      const liquidity = liquidityFor(1_000_000_000n, 10_000_000n, sqrtPrice);
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          new anchor.web3.TransactionInstruction({
+            programId: DAMM_V2_PROGRAM_ID,
+            keys: [
+              meta(buyer.publicKey, false),
+              meta(nftMint.publicKey, true, true),
+              meta(nftAccount, true),
+              meta(buyer.publicKey, true, true),
+              meta(config, false),
+              meta(accounts.poolAuthority, false),
+              meta(accounts.pool, true),
+              meta(position, true),
+              meta(seedMint.publicKey, false),
+              meta(NATIVE_MINT, false),
+              meta(accounts.tokenAVault, true),
+              meta(accounts.tokenBVault, true),
+              meta(buyerTokenAta, true),
+              meta(buyerWsolAta, true),
+              meta(TOKEN_PROGRAM_ID, false),
+              meta(TOKEN_PROGRAM_ID, false),
+              meta(TOKEN_2022_PROGRAM_ID, false),
+              meta(SystemProgram.programId, false),
+              meta(accounts.dammEventAuthority, false),
+              meta(DAMM_V2_PROGRAM_ID, false),
+            ],
+            // `initialize_pool` with no activation point
+            data: Buffer.concat([
+              Buffer.from([95, 180, 10, 172, 84, 174, 232, 40]),
+              u128(liquidity),
+              u128(sqrtPrice),
+              Buffer.from([0]),
+            ]),
+          })
+        ),
+        [buyer, nftMint]
+      );
+      return { accounts, liquidity };
+    };
+    const migrateInto = async (config: PublicKey) => {
+      await program.methods
+        .setMigrationProgram({ dammV2: {} }, DAMM_V2_PROGRAM_ID, config)
+        .accounts({ authority: authority.publicKey })
+        .rpc();
+      await program.methods
+        .migrate()
+        .accounts({
+          keeper: buyer.publicKey,
+          mint: seedMint.publicKey,
+          dexProgram: DAMM_V2_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(
+          dammV2RemainingAccounts(
+            deriveDammV2Accounts(program.programId, seedMint.publicKey, config)
+          )
+        )
+        .signers([buyer])
+        .rpc();
+    };
+
+    // A pool opened at four times the curve's price cannot be seeded
+    const farConfig = Keypair.generate().publicKey;
+    await createPoolFirst(farConfig, curveSqrtPrice * 2n);
+    try {
+      await migrateInto(farConfig);
+      expect.fail("Should have refused to seed a pool far from the curve's price");
+    } catch (e) {
+      expect(e.toString()).to.include("PoolPriceMismatch");
+    }
+    expect(
+      (await program.account.bondingCurve.fetch(seedBondingCurve)).status
+    ).to.deep.eq({ complete: {} });
+
+    // A pool at the curve's price gets the curve's liquidity through a new position
+    const nearConfig = Keypair.generate().publicKey;
+    const { accounts: poolAccounts } = await createPoolFirst(nearConfig, curveSqrtPrice);
+    const beforeVaultTokens = await getTokenBalance(provider, poolAccounts.tokenAVault);
+    const beforeVaultSol = await getTokenBalance(provider, poolAccounts.tokenBVault);
+
+    let migratedEventReceived = false;
+    const listener = program.addEventListener("curveMigrated", (event) => {
+      expect(event.pool.toBase58()).to.eq(poolAccounts.pool.toBase58());
+      expect(event.lpMint.toBase58()).to.eq(poolAccounts.positionNftMint.toBase58());
+      migratedEventReceived = true;
+    });
+    await migrateInto(nearConfig);
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+    expect(migratedEventReceived).to.be.true;
+
+    expect(
+      (await program.account.bondingCurve.fetch(seedBondingCurve)).status
+    ).to.deep.eq({ migrated: {} });
+    expect(await getTokenBalance(provider, poolAccounts.tokenAVault)).to.be.gt(beforeVaultTokens);
+    expect(await getTokenBalance(provider, poolAccounts.tokenBVault)).to.be.gt(beforeVaultSol);
+    const positionNft = await getAccount(
+      provider.connection,
+      poolAccounts.positionNftAccount,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    expect(positionNft.owner.toBase58()).to.eq(migrationAuthority.toBase58());
+    expect(Number(positionNft.amount)).to.eq(1);
+    // The migration authority's token account is closed once its leftovers are swept
+    expect(
+      await provider.connection.getAccountInfo(
+        getAssociatedTokenAddressSync(seedMint.publicKey, migrationAuthority, true)
+      )
+    ).to.be.null;
+  });
+
+  it("Migrates a graduated curve into a CP-Swap pool with the stand-in's account layout", async () => {
+    // CP-Swap LP cannot be locked for good, so this curve launches under `Burn`
+    const setLpPolicy = (policy: object) =>
+      program.methods
+        .updateGlobalConfig(
+          authority.publicKey,
+          platformTradeFeeBps,
+          reserveTradeFeeBps,
+          initialVirtualTokenReserves,
+          initialVirtualSolReserves,
+          tokenTotalSupply,
+          graduationThreshold,
+          policy,
+          keeperRewardBps,
+          surplusTokenPolicy,
+          migrationFeeLamports,
+          curveKinds,
+          minCurveParams,
+          maxCurveParams,
+          creatorTradeFeeBps,
+          referralFeeBps
+        )
+        .accounts({ authority: authority.publicKey })
+        .rpc();
+    const ammConfig = Keypair.generate().publicKey;
+    await program.methods
+      .setMigrationProgram({ cpmm: {} }, CPMM_PROGRAM_ID, ammConfig)
+      .accounts({ authority: authority.publicKey })
+      .rpc();
+
+    const cpmmMint = Keypair.generate();
+    await setLpPolicy({ burn: {} });
+    await program.methods
+      .create("CPMM Migrate", "CPMG", "https://test.com/cpmg.json", { cpmm: {} }, { constantProduct: {} }, null, { solThreshold: {} }, null, { immediately: {} }, null, { accumulate: {} })
+      .accounts({
+        signer: authority.publicKey,
+        creator: creator.publicKey,
+        mint: cpmmMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([cpmmMint])
+      .rpc();
+    await setLpPolicy(lpPolicy);
+    await program.methods
+      .buy(graduationThreshold.add(new anchor.BN(0.5 * LAMPORTS_PER_SOL)), new anchor.BN(0))
+      .accounts({ signer: authority.publicKey, mint: cpmmMint.publicKey, tokenProgram: TOKEN_PROGRAM_ID })
+      .rpc();
+    const [cpmmBondingCurve] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bonding_curve"), cpmmMint.publicKey.toBuffer()],
+      program.programId
+    );
+    const [migrationAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("migration_authority"), cpmmMint.publicKey.toBuffer()],
+      program.programId
+    );
+
+    // The pool state is a coinfun PDA, so nobody can open the pool before `migrate`
+    const [poolState] = PublicKey.findProgramAddressSync(
+      [Buffer.from("cpmm_pool"), cpmmMint.publicKey.toBuffer()],
+      program.programId
+    );
+    const [poolAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault_and_lp_mint_auth_seed")],
+      CPMM_PROGRAM_ID
+    );
+    const [lpMint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pool_lp_mint"), poolState.toBuffer()],
+      CPMM_PROGRAM_ID
+    );
+    const creatorLpToken = getAssociatedTokenAddressSync(lpMint, migrationAuthority, true);
+    const vault = (mintKey: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("pool_vault"), poolState.toBuffer(), mintKey.toBuffer()],
+        CPMM_PROGRAM_ID
+      )[0];
+    const [mint0, mint1] =
+      Buffer.compare(cpmmMint.publicKey.toBuffer(), NATIVE_MINT.toBuffer()) < 0
+        ? [cpmmMint.publicKey, NATIVE_MINT]
+        : [NATIVE_MINT, cpmmMint.publicKey];
+    const [observationState] = PublicKey.findProgramAddressSync(
+      [Buffer.from("observation"), poolState.toBuffer()],
+      CPMM_PROGRAM_ID
+    );
+    const createPoolFee = Keypair.generate().publicKey;
+    const meta = (pubkey: PublicKey, isWritable: boolean) => ({
+      pubkey,
+      isWritable,
+      isSigner: false,
+    });
+
+    let migratedEvent = null;
+    const listener = program.addEventListener("curveMigrated", (event) => {
+      migratedEvent = event;
+    });
+    await program.methods
+      .migrate()
+      .accounts({
+        keeper: buyer.publicKey,
+        mint: cpmmMint.publicKey,
+        dexProgram: CPMM_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts([
+        meta(ammConfig, false),
+        meta(poolAuthority, false),
+        meta(poolState, true),
+        meta(lpMint, true),
+        meta(creatorLpToken, true),
+        meta(vault(mint0), true),
+        meta(vault(mint1), true),
+        meta(createPoolFee, true),
+        meta(observationState, true),
+        meta(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+        meta(anchor.web3.SYSVAR_RENT_PUBKEY, false),
+      ])
+      .signers([buyer])
+      .rpc();
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+    expect(migratedEvent).to.not.be.null;
+    expect(migratedEvent.target).to.deep.eq({ cpmm: {} });
+    expect(migratedEvent.pool.toBase58()).to.eq(poolState.toBase58());
+    expect(migratedEvent.lpMint.toBase58()).to.eq(lpMint.toBase58());
+    expect(migratedEvent.lpPolicy).to.deep.eq({ burn: {} });
+    expect(
+      (await program.account.bondingCurve.fetch(cpmmBondingCurve)).status
+    ).to.deep.eq({ migrated: {} });
+
+    // The pool took both deposits in mint order under the registered config
+    const poolData = (await provider.connection.getAccountInfo(poolState)).data;
+    expect(new PublicKey(poolData.subarray(8, 40)).toBase58()).to.eq(ammConfig.toBase58());
+    expect(new PublicKey(poolData.subarray(40, 72)).toBase58()).to.eq(
+      migrationAuthority.toBase58()
+    );
+    expect(await getTokenBalance(provider, vault(cpmmMint.publicKey))).to.eq(
+      migratedEvent.tokenAmount.toNumber()
+    );
+    expect(await getTokenBalance(provider, vault(NATIVE_MINT))).to.eq(
+      migratedEvent.solAmount.toNumber()
+    );
+    // All of the migration authority's LP was burned; the pool's locked share is never minted
+    expect(await getTokenBalance(provider, creatorLpToken)).to.eq(0);
+    expect(Number((await getMint(provider.connection, lpMint)).supply)).to.eq(0);
+  });
+
+
+  it("Upgrades the global config in place for its authority only", async () => {
+    const before = await program.account.global.fetch(global);
+    try {
+      await program.methods
+        .upgradeGlobal()
+        .accounts({ authority: buyer.publicKey })
+        .signers([buyer])
+        .rpc();
+      expect.fail("Should have failed for a non-authority");
+    } catch (e) {
+      expect(e.toString()).to.include("Unauthorized");
+    }
+
+    // A current config is left as it is
+    await program.methods.upgradeGlobal().accounts({ authority: authority.publicKey }).rpc();
+    const after = await program.account.global.fetch(global);
+    expect(JSON.stringify(after)).to.eq(JSON.stringify(before));
+  });
+
+  it("Only launches into enabled migration targets", async () => {
+    const dlmmMint = Keypair.generate();
+    const createDlmm = () =>
+      program.methods
+        .create("DLMM Token", "DLMM", "https://test.com/dlmm.json", { dlmm: {} }, { constantProduct: {} }, null, { solThreshold: {} }, null, { immediately: {} }, null, { accumulate: {} })
+        .accounts({
+          signer: authority.publicKey,
+          creator: creator.publicKey,
+          mint: dlmmMint.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([dlmmMint])
+        .rpc();
+
+    // Only the authority can change the registry
+    try {
+      await program.methods
+        .setMigrationProgram({ dlmm: {} }, PublicKey.default, PublicKey.default)
+        .accounts({ authority: buyer.publicKey })
+        .signers([buyer])
+        .rpc();
+      expect.fail("Should have failed for a non-authority");
+    } catch (e) {
+      expect(e.toString()).to.include("ConstraintRaw");
+    }
+
+    const globalData = await program.account.global.fetch(global);
+    const dlmmProgram = globalData.migrationPrograms[1];
+    const dlmmConfig = globalData.migrationConfigs[1];
+    await program.methods
+      .setMigrationProgram({ dlmm: {} }, PublicKey.default, PublicKey.default)
+      .accounts({ authority: authority.publicKey })
+      .rpc();
+    try {
+      await createDlmm();
+      expect.fail("Should have failed for a disabled target");
+    } catch (e) {
+      expect(e.toString()).to.include("MigrationTargetNotAllowed");
+    }
+
+    await program.methods
+      .setMigrationProgram({ dlmm: {} }, dlmmProgram, dlmmConfig)
+      .accounts({ authority: authority.publicKey })
+      .rpc();
+    await createDlmm();
+    const curveData = await program.account.bondingCurve.fetch(
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("bonding_curve"), dlmmMint.publicKey.toBuffer()],
+        program.programId
+      )[0]
+    );
+    expect(curveData.migrationTarget).to.deep.eq({ dlmm: {} });
+  });
+
+  it("Launches with creator-chosen params within the global bounds", async () => {
+    const customMint = Keypair.generate();
+    const createCustom = (curveParams: object) =>
+      program.methods
+        .create("Custom Token", "CUS", "https://test.com/cus.json", { dammV2: {} }, { constantProduct: {} }, curveParams, { solThreshold: {} }, null, { immediately: {} }, null, { accumulate: {} })
+        .accounts({
+          signer: authority.publicKey,
+          creator: creator.publicKey,
+          mint: customMint.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([customMint])
+        .rpc();
+    const params = {
+      initialVirtualTokenReserves: initialVirtualTokenReserves.muln(3).divn(2),
+      initialVirtualSolReserves: initialVirtualSolReserves.divn(2),
+      tokenTotalSupply: tokenTotalSupply.muln(3).divn(2),
+      graduationThreshold: graduationThreshold.muln(2),
+    };
+
+    try {
+      await createCustom({ ...params, graduationThreshold: graduationThreshold.muln(3) });
+      expect.fail("Should have failed above the threshold bound");
+    } catch (e) {
+      expect(e.toString()).to.include("CurveParamsOutOfBounds");
+    }
+    // Both within bounds, but the curve would run out of virtual tokens before selling out
+    try {
+      await createCustom({ ...params, tokenTotalSupply: params.initialVirtualTokenReserves });
+      expect.fail("Should have failed for a supply the virtual reserves do not cover");
+    } catch (e) {
+      expect(e.toString()).to.include("InvalidTokenReserveConfiguration");
+    }
+
+    await createCustom(params);
+    const customCurve = PublicKey.findProgramAddressSync(
+      [Buffer.from("bonding_curve"), customMint.publicKey.toBuffer()],
+      program.programId
+    )[0];
+    const curveData = await program.account.bondingCurve.fetch(customCurve);
+    expect(curveData.virtualTokenReserves.toString()).to.eq(params.initialVirtualTokenReserves.toString());
+    expect(curveData.virtualSolReserves.toString()).to.eq(params.initialVirtualSolReserves.toString());
+    expect(curveData.realTokenReserves.toString()).to.eq(params.tokenTotalSupply.toString());
+    expect(curveData.tokenTotalSupply.toString()).to.eq(params.tokenTotalSupply.toString());
+    expect(curveData.graduationThreshold.toString()).to.eq(params.graduationThreshold.toString());
+    const mintInfo = await getMint(provider.connection, customMint.publicKey);
+    expect(mintInfo.supply.toString()).to.eq(params.tokenTotalSupply.toString());
+  });
+
+  it("Reprices a curve only through the authority instruction", async () => {
+    const repricedMint = Keypair.generate();
+    await program.methods
+      .create("Repriced Token", "REP", "https://test.com/rep.json", { dammV2: {} }, { constantProduct: {} }, null, { solThreshold: {} }, null, { immediately: {} }, null, { accumulate: {} })
+      .accounts({
+        signer: authority.publicKey,
+        creator: creator.publicKey,
+        mint: repricedMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([repricedMint])
+      .rpc();
+    const repricedCurve = PublicKey.findProgramAddressSync(
+      [Buffer.from("bonding_curve"), repricedMint.publicKey.toBuffer()],
+      program.programId
+    )[0];
+    const reprice = (signer: Keypair, platformBps: number, reserveBps: number) =>
+      program.methods
+        .repriceCurve(new anchor.BN(platformBps), new anchor.BN(reserveBps), new anchor.BN(0), graduationThreshold.muln(2))
+        .accounts({ authority: signer.publicKey, bondingCurve: repricedCurve })
+        .signers([signer])
+        .rpc();
+
+    try {
+      await reprice(buyer, 0, 0);
+      expect.fail("Should have failed for a non-authority");
+    } catch (e) {
+      expect(e.toString()).to.include("ConstraintRaw");
+    }
+    try {
+      await reprice(authority, 2000, 1100);
+      expect.fail("Should have failed above the fee cap");
+    } catch (e) {
+      expect(e.toString()).to.include("FeeTooHigh");
+    }
+
+    let repricedEvent = null;
+    const listener = program.addEventListener("curveRepriced", (event) => {
+      repricedEvent = event;
+    });
+    await reprice(authority, 50, 150);
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+
+    expect(repricedEvent.mint.toBase58()).to.eq(repricedMint.publicKey.toBase58());
+    expect(repricedEvent.previousPlatformTradeFeeBps.toString()).to.eq(platformTradeFeeBps.toString());
+    expect(repricedEvent.previousReserveTradeFeeBps.toString()).to.eq(reserveTradeFeeBps.toString());
+    expect(repricedEvent.previousGraduationThreshold.toString()).to.eq(graduationThreshold.toString());
+    const curveData = await program.account.bondingCurve.fetch(repricedCurve);
+    expect(curveData.platformTradeFeeBps.toNumber()).to.eq(50);
+    expect(curveData.reserveTradeFeeBps.toNumber()).to.eq(150);
+    expect(curveData.graduationThreshold.toString()).to.eq(graduationThreshold.muln(2).toString());
+  });
+
+  it("Launches on allowed curve kinds and prices linear curves by tokens sold", async () => {
+    const priceScale = 1e12; // curve_math::PRICE_SCALE
+    const linear = {
+      linear: { startPrice: new anchor.BN(28_000_000), endPrice: new anchor.BN(280_000_000) },
+    };
+    const shapedMint = Keypair.generate();
+    const createShaped = (curveKind: object) =>
+      program.methods
+        .create("Linear Token", "LIN", "https://test.com/lin.json", { dammV2: {} }, curveKind, null, { solThreshold: {} }, null, { immediately: {} }, null, { accumulate: {} })
+        .accounts({
+          signer: authority.publicKey,
+          creator: creator.publicKey,
+          mint: shapedMint.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([shapedMint])
+        .rpc();
+
+    try {
+      await createShaped({
+        exponential: { startPrice: new anchor.BN(28_000_000), doublingTokens: tokenTotalSupply.divn(4) },
+      });
+      expect.fail("Should have failed for a disallowed curve kind");
+    } catch (e) {
+      expect(e.toString()).to.include("CurveKindNotAllowed");
+    }
+    try {
+      await createShaped({
+        linear: { startPrice: new anchor.BN(280_000_000), endPrice: new anchor.BN(28_000_000) },
+      });
+      expect.fail("Should have failed for a falling price");
+    } catch (e) {
+      expect(e.toString()).to.include("InvalidCurveParams");
+    }
+
+    await createShaped(linear);
+    const shapedCurve = PublicKey.findProgramAddressSync(
+      [Buffer.from("bonding_curve"), shapedMint.publicKey.toBuffer()],
+      program.programId
+    )[0];
+    const beforeCurveData = await program.account.bondingCurve.fetch(shapedCurve);
+    expect(beforeCurveData.curveKind).to.deep.eq(linear);
+    // Shaped curves have no virtual reserves beyond their real ones
+    expect(beforeCurveData.virtualTokenReserves.toString()).to.eq(tokenTotalSupply.toString());
+    expect(beforeCurveData.virtualSolReserves.toNumber()).to.eq(0);
+
+    const solAmount = new anchor.BN(0.5 * LAMPORTS_PER_SOL);
+    await program.methods
+      .buy(solAmount, new anchor.BN(0))
+      .accounts({
+        signer: buyer.publicKey,
+        mint: shapedMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .accountsPartial({
+        reserveAta: getAssociatedTokenAddressSync(shapedMint.publicKey, globalReserve, true),
+      })
+      .signers([buyer])
+      .rpc();
+
+    // Everything the curve sold was paid for at or above the starting price
+    const afterCurveData = await program.account.bondingCurve.fetch(shapedCurve);
+    const sold = tokenTotalSupply.sub(afterCurveData.realTokenReserves).toNumber();
+    const curveSol = afterCurveData.realSolReserves.toNumber();
+    expect(sold).to.be.gt(0);
+    expect(curveSol / sold).to.be.gte(28_000_000 / priceScale);
+    expect(curveSol / sold).to.be.lte(280_000_000 / priceScale);
+  });
+
+  it("Keeps shaped-curve buys within the default compute budget", async () => {
+    // Default per-instruction limit, with nothing requested from the compute budget program
+    const defaultComputeUnits = 200_000;
+    const linear = {
+      linear: { startPrice: new anchor.BN(28_000_000), endPrice: new anchor.BN(280_000_000) },
+    };
+    const createShaped = (shapedMint: Keypair, graduationMode: object) =>
+      program.methods
+        .create("Linear Token", "LIN", "https://test.com/lin.json", { dammV2: {} }, linear, null, graduationMode, null, { immediately: {} }, null, { accumulate: {} })
+        .accounts({
+          signer: authority.publicKey,
+          creator: creator.publicKey,
+          mint: shapedMint.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([shapedMint])
+        .rpc();
+    const unitsConsumed = async (tx: anchor.web3.Transaction) => {
+      tx.feePayer = buyer.publicKey;
+      const simulation = await provider.connection.simulateTransaction(tx, [buyer]);
+      expect(simulation.value.err).to.be.null;
+      return simulation.value.unitsConsumed;
+    };
+    const buyTx = (shapedMint: Keypair, solAmount: anchor.BN) =>
+      program.methods
+        .buy(solAmount, new anchor.BN(0))
+        .accounts({
+          signer: buyer.publicKey,
+          mint: shapedMint.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .accountsPartial({
+          reserveAta: getAssociatedTokenAddressSync(shapedMint.publicKey, globalReserve, true),
+        })
+        .transaction();
+
+    // A SOL-threshold curve: a small buy, one capped where it graduates the curve, and an
+    // exact-out buy, which re-prices the reserve purchase until it settles
+    const thresholdMint = Keypair.generate();
+    await createShaped(thresholdMint, { solThreshold: {} });
+    for (const solAmount of [new anchor.BN(1_000), new anchor.BN(5 * LAMPORTS_PER_SOL)]) {
+      expect(await unitsConsumed(await buyTx(thresholdMint, solAmount))).to.be.lt(defaultComputeUnits);
+    }
+    const exactOutTx = await program.methods
+      .buyExactOut(new anchor.BN(35_000_000 * 1e6), new anchor.BN(100 * LAMPORTS_PER_SOL))
+      .accounts({
+        signer: buyer.publicKey,
+        mint: thresholdMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .accountsPartial({
+        reserveAta: getAssociatedTokenAddressSync(thresholdMint.publicKey, globalReserve, true),
+      })
+      .transaction();
+    expect(await unitsConsumed(exactOutTx)).to.be.lt(defaultComputeUnits);
+
+    // A market cap curve searches for the tokens that reach its target on every buy. It
+    // opens at 28 SOL; 40 SOL is a short way up, so the larger buy graduates it
+    const marketCapMint = Keypair.generate();
+    await createShaped(marketCapMint, { marketCap: { marketCap: new anchor.BN(40 * LAMPORTS_PER_SOL) } });
+    for (const solAmount of [new anchor.BN(1_000), new anchor.BN(5 * LAMPORTS_PER_SOL)]) {
+      expect(await unitsConsumed(await buyTx(marketCapMint, solAmount))).to.be.lt(defaultComputeUnits);
+    }
+    await program.methods
+      .buy(new anchor.BN(5 * LAMPORTS_PER_SOL), new anchor.BN(0))
+      .accounts({
+        signer: buyer.publicKey,
+        mint: marketCapMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .accountsPartial({
+        reserveAta: getAssociatedTokenAddressSync(marketCapMint.publicKey, globalReserve, true),
+      })
+      .signers([buyer])
+      .rpc();
+    const marketCapCurve = PublicKey.findProgramAddressSync(
+      [Buffer.from("bonding_curve"), marketCapMint.publicKey.toBuffer()],
+      program.programId
+    )[0];
+    const curveData = await program.account.bondingCurve.fetch(marketCapCurve);
+    expect(curveData.status).to.deep.eq({ complete: {} });
+  });
+
+  it("Graduates a token-floor curve when its tokens fall to the floor", async () => {
+    const floorMint = Keypair.generate();
+    const createWithFloor = (tokenFloor: anchor.BN) =>
+      program.methods
+        .create("Floor Token", "FLR", "https://test.com/flr.json", { dammV2: {} }, { constantProduct: {} }, null, {
+          tokenFloor: { tokenFloor },
+        }, null, { immediately: {} }, null, { accumulate: {} })
+        .accounts({
+          signer: authority.publicKey,
+          creator: creator.publicKey,
+          mint: floorMint.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([floorMint])
+        .rpc();
+
+    try {
+      await createWithFloor(tokenTotalSupply);
+      expect.fail("Should have failed for a curve that starts graduated");
+    } catch (e) {
+      expect(e.toString()).to.include("InvalidCurveParams");
+    }
+    // About a million tokens above the floor, far less than the SOL threshold buys
+    const tokenFloor = tokenTotalSupply.sub(new anchor.BN(1_000_000_000_000));
+    await createWithFloor(tokenFloor);
+    const floorCurve = PublicKey.findProgramAddressSync(
+      [Buffer.from("bonding_curve"), floorMint.publicKey.toBuffer()],
+      program.programId
+    )[0];
+
+    let completeEvent = null;
+    const listener = program.addEventListener("curveComplete", (event) => {
+      completeEvent = event;
+    });
+    await program.methods
+      .buy(new anchor.BN(LAMPORTS_PER_SOL), new anchor.BN(0))
+      .accounts({
+        signer: buyer.publicKey,
+        mint: floorMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .accountsPartial({
+        reserveAta: getAssociatedTokenAddressSync(floorMint.publicKey, globalReserve, true),
+      })
+      .signers([buyer])
+      .rpc();
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+
+    expect(completeEvent.mint.toBase58()).to.eq(floorMint.publicKey.toBase58());
+    expect(completeEvent.cause).to.deep.eq({ tokenFloor: {} });
+    const curveData = await program.account.bondingCurve.fetch(floorCurve);
+    expect(curveData.status).to.deep.eq({ complete: {} });
+    expect(curveData.realTokenReserves.lte(tokenFloor)).to.be.true;
+    expect(curveData.realSolReserves.lt(graduationThreshold)).to.be.true;
+  });
+
+  it("Graduates a USD market cap curve at the fixture SOL/USD price", async () => {
+    // tests/fixtures/sol_usd_price.json, loaded by Anchor.toml: $150.00 per SOL
+    const solUsdPriceFeed = new PublicKey("7UVimffxr9ow1uXYxsr4LHAcV58mLzhmwaeKvJ1pjLiE");
+    const setPriceFeed = (maxAgeSecs: anchor.BN, maxConfidenceBps = new anchor.BN(10)) =>
+      program.methods
+        .setPriceFeed(solUsdPriceFeed, maxAgeSecs, maxConfidenceBps)
+        .accounts({ authority: authority.publicKey })
+        .rpc();
+    // The fixture was published long ago, so only an unbounded age accepts it
+    await setPriceFeed(new anchor.BN("18446744073709551615"));
+
+    // The fresh curve is worth about 28 SOL, or $4,200; $4,500 is a short way up
+    const usdMint = Keypair.generate();
+    await program.methods
+      .create("USD Token", "USD", "https://test.com/usd.json", { dammV2: {} }, { constantProduct: {} }, null, {
+        usdMarketCap: { marketCapUsd: new anchor.BN(4_500_000_000) },
+      }, null, { immediately: {} }, null, { accumulate: {} })
+      .accounts({
+        signer: authority.publicKey,
+        creator: creator.publicKey,
+        mint: usdMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([usdMint])
+      .rpc();
+    const usdCurve = PublicKey.findProgramAddressSync(
+      [Buffer.from("bonding_curve"), usdMint.publicKey.toBuffer()],
+      program.programId
+    )[0];
+    const buy = (priceFeed: PublicKey | null) =>
+      program.methods
+        .buy(new anchor.BN(5 * LAMPORTS_PER_SOL), new anchor.BN(0))
+        .accounts({
+          signer: buyer.publicKey,
+          mint: usdMint.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .accountsPartial({
+          reserveAta: getAssociatedTokenAddressSync(usdMint.publicKey, globalReserve, true),
+          priceFeed,
+        })
+        .signers([buyer])
+        .rpc();
+
+    try {
+      await buy(null);
+      expect.fail("Should have failed without the price feed");
+    } catch (e) {
+      expect(e.toString()).to.include("MissingPriceFeed");
+    }
+    try {
+      await buy(Keypair.generate().publicKey);
+      expect.fail("Should have failed for the wrong price feed");
+    } catch (e) {
+      expect(e.toString()).to.include("InvalidPriceFeed");
+    }
+    await setPriceFeed(new anchor.BN(60));
+    try {
+      await buy(solUsdPriceFeed);
+      expect.fail("Should have failed for a stale price");
+    } catch (e) {
+      expect(e.toString()).to.include("PriceFeedStale");
+    }
+    // The fixture's $0.075 confidence interval is 5 bps of the price
+    await setPriceFeed(new anchor.BN("18446744073709551615"), new anchor.BN(4));
+    try {
+      await buy(solUsdPriceFeed);
+      expect.fail("Should have failed for a too-wide confidence interval");
+    } catch (e) {
+      expect(e.toString()).to.include("PriceFeedUncertain");
+    }
+    await setPriceFeed(new anchor.BN("18446744073709551615"));
+
+    let completeEvent = null;
+    const listener = program.addEventListener("curveComplete", (event) => {
+      completeEvent = event;
+    });
+    await buy(solUsdPriceFeed);
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+
+    expect(completeEvent.cause).to.deep.eq({ usdMarketCap: {} });
+    const curveData = await program.account.bondingCurve.fetch(usdCurve);
+    expect(curveData.status).to.deep.eq({ complete: {} });
+    // Market cap in lamports at the spot price, then in micro-USD at $150
+    const marketCapLamports = curveData.virtualSolReserves
+      .mul(curveData.tokenTotalSupply)
+      .div(curveData.virtualTokenReserves);
+    expect(marketCapLamports.muln(150).divn(1000).gte(new anchor.BN(4_500_000_000))).to.be.true;
+  });
+
+  it("Caps buys per transaction and per wallet during the launch window", async () => {
+    const launchWindow = {
+      slots: new anchor.BN(1_000),
+      maxSolPerBuy: new anchor.BN(LAMPORTS_PER_SOL),
+      maxTokensPerWallet: new anchor.BN(40_000_000_000_000),
+    };
+    const windowMint = Keypair.generate();
+    const createWithWindow = (window: object) =>
+      program.methods
+        .create("Window Token", "WIN", "https://test.com/win.json", { dammV2: {} }, { constantProduct: {} }, null, { solThreshold: {} }, window, { immediately: {} }, null, { accumulate: {} })
+        .accounts({
+          signer: authority.publicKey,
+          creator: creator.publicKey,
+          mint: windowMint.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([windowMint])
+        .rpc();
+
+    try {
+      await createWithWindow({ ...launchWindow, maxSolPerBuy: new anchor.BN(0) });
+      expect.fail("Should have failed for an empty cap");
+    } catch (e) {
+      expect(e.toString()).to.include("InvalidLaunchWindow");
+    }
+    await createWithWindow(launchWindow);
+    const windowCurve = PublicKey.findProgramAddressSync(
+      [Buffer.from("bonding_curve"), windowMint.publicKey.toBuffer()],
+      program.programId
+    )[0];
+    const [launchAllocation] = PublicKey.findProgramAddressSync(
+      [Buffer.from("launch_allocation"), windowCurve.toBuffer(), buyer.publicKey.toBuffer()],
+      program.programId
+    );
+    const buy = (solAmount: anchor.BN, allocation: PublicKey | null) =>
+      program.methods
+        .buy(solAmount, new anchor.BN(0))
+        .accounts({
+          signer: buyer.publicKey,
+          mint: windowMint.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .accountsPartial({
+          reserveAta: getAssociatedTokenAddressSync(windowMint.publicKey, globalReserve, true),
+          launchAllocation: allocation,
+        })
+        .signers([buyer])
+        .rpc();
+
+    try {
+      await buy(new anchor.BN(2 * LAMPORTS_PER_SOL), launchAllocation);
+      expect.fail("Should have failed above the per-buy cap");
+    } catch (e) {
+      expect(e.toString()).to.include("LaunchBuyTooLarge");
+    }
+    try {
+      await buy(new anchor.BN(LAMPORTS_PER_SOL / 2), null);
+      expect.fail("Should have failed without the allocation account");
+    } catch (e) {
+      expect(e.toString()).to.include("MissingLaunchAllocation");
+    }
+
+    // The first SOL buys about 33M tokens and fits; another half SOL crosses 40M
+    await buy(new anchor.BN(LAMPORTS_PER_SOL), launchAllocation);
+    const allocationData = await program.account.launchAllocation.fetch(launchAllocation);
+    expect(allocationData.tokensBought.toNumber()).to.be.gt(0);
+    try {
+      await buy(new anchor.BN(LAMPORTS_PER_SOL / 2), launchAllocation);
+      expect.fail("Should have failed above the per-wallet cap");
+    } catch (e) {
+      expect(e.toString()).to.include("LaunchWalletCapExceeded");
+    }
+  });
+
+  it("Rejects trades before a curve's scheduled start and reports the first trade", async () => {
+    const scheduledMint = Keypair.generate();
+    const startsAt = Math.floor(Date.now() / 1000) + 5;
+    await program.methods
+      .create("Scheduled Token", "SCH", "https://test.com/sch.json", { dammV2: {} }, { constantProduct: {} }, null, { solThreshold: {} }, null, {
+        unixTimestamp: { unixTimestamp: new anchor.BN(startsAt) },
+      }, null, { accumulate: {} })
+      .accounts({
+        signer: authority.publicKey,
+        creator: creator.publicKey,
+        mint: scheduledMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([scheduledMint])
+      .rpc();
+    const scheduledCurve = PublicKey.findProgramAddressSync(
+      [Buffer.from("bonding_curve"), scheduledMint.publicKey.toBuffer()],
+      program.programId
+    )[0];
+    const buy = () =>
+      program.methods
+        .buy(new anchor.BN(LAMPORTS_PER_SOL / 10), new anchor.BN(0))
+        .accounts({
+          signer: buyer.publicKey,
+          mint: scheduledMint.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .accountsPartial({
+          reserveAta: getAssociatedTokenAddressSync(scheduledMint.publicKey, globalReserve, true),
+        })
+        .signers([buyer])
+        .rpc();
+
+    try {
+      await buy();
+      expect.fail("Should have failed before the start");
+    } catch (e) {
+      expect(e.toString()).to.include("TradingNotStarted");
+    }
+
+    // Wait out the schedule on the validator's clock
+    while ((await provider.connection.getBlockTime(await provider.connection.getSlot())) < startsAt) {
+      await new Promise((resolve) => setTimeout(resolve, 500));
+    }
+    let openedEvent = null;
+    let openedEvents = 0;
+    const listener = program.addEventListener("tradingOpened", (event) => {
+      openedEvent = event;
+      openedEvents += 1;
+    });
+    await buy();
+    await buy();
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+
+    expect(openedEvents).to.eq(1);
+    expect(openedEvent.mint.toBase58()).to.eq(scheduledMint.publicKey.toBase58());
+    expect(openedEvent.trader.toBase58()).to.eq(buyer.publicKey.toBase58());
+    expect(openedEvent.unixTimestamp.toNumber()).to.be.gte(startsAt);
+    const curveData = await program.account.bondingCurve.fetch(scheduledCurve);
+    expect(curveData.tradingOpenedSlot.toString()).to.eq(openedEvent.slot.toString());
+  });
+
+  it("Charges a decaying launch fee to the reserve on top of the trade fees", async () => {
+    const createWithLaunchFee = (launchMint: Keypair, launchFee: object | null) =>
+      program.methods
+        .create("Launch Fee Token", "LFT", "https://test.com/lft.json", { dammV2: {} }, { constantProduct: {} }, null, { solThreshold: {} }, null, { immediately: {} }, launchFee, { accumulate: {} })
+        .accounts({
+          signer: authority.publicKey,
+          creator: creator.publicKey,
+          mint: launchMint.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([launchMint])
+        .rpc();
+    const launchFee = {
+      extraBps: new anchor.BN(2000),
+      decaySlots: new anchor.BN(100_000),
+      decay: { linear: {} },
+    };
+
+    const feeMint = Keypair.generate();
+    try {
+      await createWithLaunchFee(feeMint, { ...launchFee, extraBps: new anchor.BN(5000) });
+      expect.fail("Should have failed above the launch fee ceiling");
+    } catch (e) {
+      expect(e.toString()).to.include("LaunchFeeTooHigh");
+    }
+    const plainMint = Keypair.generate();
+    await createWithLaunchFee(feeMint, launchFee);
+    await createWithLaunchFee(plainMint, null);
+
+    // The same buy on both curves: the launch fee buys the reserve more and the buyer less
+    const buyOn = async (launchMint: Keypair) => {
+      const reserveAta = getAssociatedTokenAddressSync(launchMint.publicKey, globalReserve, true);
+      await program.methods
+        .buy(new anchor.BN(LAMPORTS_PER_SOL), new anchor.BN(0))
+        .accounts({
+          signer: buyer.publicKey,
+          mint: launchMint.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .accountsPartial({
+          reserveAta,
+        })
+        .signers([buyer])
+        .rpc();
+      const buyerAta = getAssociatedTokenAddressSync(launchMint.publicKey, buyer.publicKey);
+      return {
+        buyerTokens: await getTokenBalance(provider, buyerAta),
+        reserveTokens: await getTokenBalance(provider, reserveAta),
+      };
+    };
+    const withFee = await buyOn(feeMint);
+    const withoutFee = await buyOn(plainMint);
+
+    expect(withFee.buyerTokens).to.be.lt(withoutFee.buyerTokens);
+    expect(withFee.reserveTokens).to.be.gt(withoutFee.reserveTokens);
+  });
+
+  it("Accrues creator fees to the curve's vault and pays them to the creator", async () => {
+    const setCreatorFee = (bps: number) =>
+      program.methods
+        .updateGlobalConfig(
+          authority.publicKey,
+          platformTradeFeeBps,
+          reserveTradeFeeBps,
+          initialVirtualTokenReserves,
+          initialVirtualSolReserves,
+          tokenTotalSupply,
+          graduationThreshold,
+          lpPolicy,
+          keeperRewardBps,
+          surplusTokenPolicy,
+          migrationFeeLamports,
+          curveKinds,
+          minCurveParams,
+          maxCurveParams,
+          new anchor.BN(bps),
+          referralFeeBps
+        )
+        .accounts({ authority: authority.publicKey })
+        .rpc();
+
+    // The creator share counts towards the 30% cap with the other trade fees
+    try {
+      await setCreatorFee(2600);
+      expect.fail("Should have failed above the fee cap");
+    } catch (e) {
+      expect(e.toString()).to.include("FeeTooHigh");
+    }
+    await setCreatorFee(100);
+
+    const creatorMint = Keypair.generate();
+    await program.methods
+      .create("Creator Fee Token", "CFT", "https://test.com/cft.json", { dammV2: {} }, { constantProduct: {} }, null, { solThreshold: {} }, null, { immediately: {} }, null, { accumulate: {} })
+      .accounts({
+        signer: authority.publicKey,
+        creator: creator.publicKey,
+        mint: creatorMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([creatorMint])
+      .rpc();
+    // Curves keep the creator fee they launched with
+    await setCreatorFee(0);
+    const creatorCurve = PublicKey.findProgramAddressSync(
+      [Buffer.from("bonding_curve"), creatorMint.publicKey.toBuffer()],
+      program.programId
+    )[0];
+    const [creatorVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("creator_vault"), creatorCurve.toBuffer()],
+      program.programId
+    );
+    const curveData = await program.account.bondingCurve.fetch(creatorCurve);
+    expect(curveData.creatorTradeFeeBps.toNumber()).to.eq(100);
+    const vaultRent = await getSolBalance(provider, creatorVault);
+
+    const solIn = new anchor.BN(LAMPORTS_PER_SOL);
+    await program.methods
+      .buy(solIn, new anchor.BN(0))
+      .accounts({
+        signer: buyer.publicKey,
+        mint: creatorMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .accountsPartial({
+        reserveAta: getAssociatedTokenAddressSync(creatorMint.publicKey, globalReserve, true),
+      })
+      .signers([buyer])
+      .rpc();
+    const accrued = (await getSolBalance(provider, creatorVault)) - vaultRent;
+    expect(accrued).to.eq(solIn.toNumber() / 100);
+
+    const claim = (signer: Keypair) =>
+      program.methods
+        .claimCreatorFees()
+        .accounts({ creator: signer.publicKey, bondingCurve: creatorCurve })
+        .signers([signer])
+        .rpc();
+    try {
+      await claim(buyer);
+      expect.fail("Should have failed for someone other than the creator");
+    } catch (e) {
+      expect(e.toString()).to.include("Unauthorized");
+    }
+
+    let claimedEvent = null;
+    const listener = program.addEventListener("creatorFeesClaimed", (event) => {
+      claimedEvent = event;
+    });
+    const creatorBefore = await getSolBalance(provider, creator.publicKey);
+    await claim(creator);
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+
+    expect(claimedEvent.amount.toNumber()).to.eq(accrued);
+    expect(await getSolBalance(provider, creator.publicKey)).to.eq(creatorBefore + accrued);
+    expect(await getSolBalance(provider, creatorVault)).to.eq(vaultRent);
+    try {
+      await claim(creator);
+      expect.fail("Should have failed with nothing accrued");
+    } catch (e) {
+      expect(e.toString()).to.include("NothingToWithdraw");
+    }
+  });
+
+  it("Pays referrers a share of the platform fee and lets them claim it", async () => {
+    const partner = Keypair.generate();
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: authority.publicKey,
+          toPubkey: partner.publicKey,
+          lamports: 2 * LAMPORTS_PER_SOL,
+        })
+      )
+    );
+    await program.methods
+      .registerReferrer()
+      .accounts({ authority: partner.publicKey })
+      .signers([partner])
+      .rpc();
+    const [referrer] = PublicKey.findProgramAddressSync(
+      [Buffer.from("referrer"), partner.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const referredMint = Keypair.generate();
+    await program.methods
+      .create("Referred Token", "REF", "https://test.com/ref.json", { dammV2: {} }, { constantProduct: {} }, null, { solThreshold: {} }, null, { immediately: {} }, null, { accumulate: {} })
+      .accounts({
+        signer: authority.publicKey,
+        creator: creator.publicKey,
+        mint: referredMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([referredMint])
+      .rpc();
+    const referredReserveAta = getAssociatedTokenAddressSync(referredMint.publicKey, globalReserve, true);
+    const buy = (trader: Keypair) =>
+      program.methods
+        .buy(new anchor.BN(LAMPORTS_PER_SOL), new anchor.BN(0))
+        .accounts({
+          signer: trader.publicKey,
+          mint: referredMint.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .accountsPartial({
+          reserveAta: referredReserveAta,
+          referrer,
+        })
+        .signers([trader])
+        .rpc();
+
+    try {
+      await buy(partner);
+      expect.fail("Should have failed for a self-referral");
+    } catch (e) {
+      expect(e.toString()).to.include("SelfReferral");
+    }
+
+    let tradeEvent = null;
+    const listener = program.addEventListener("trade", (event) => {
+      tradeEvent = event;
+    });
+    const platformBefore = await getSolBalance(provider, platformFeeVault);
+    await buy(buyer);
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+
+    const platformFee = LAMPORTS_PER_SOL * platformTradeFeeBps.toNumber() / 10000;
+    const referralFee = platformFee * referralFeeBps.toNumber() / 10000;
+    expect(tradeEvent.referrer.toBase58()).to.eq(partner.publicKey.toBase58());
+    expect(tradeEvent.referralFee.toNumber()).to.eq(referralFee);
+    expect(await getSolBalance(provider, platformFeeVault)).to.eq(
+      platformBefore + platformFee - referralFee
+    );
+
+    // Sells pay the referrer out of the curve's SOL
+    const buyerAta = getAssociatedTokenAddressSync(referredMint.publicKey, buyer.publicKey);
+    await program.methods
+      .sell(new anchor.BN(await getTokenBalance(provider, buyerAta)).divn(2), new anchor.BN(0))
+      .accounts({
+        signer: buyer.publicKey,
+        mint: referredMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .accountsPartial({
+        reserveAta: referredReserveAta,
+        referrer,
+      })
+      .signers([buyer])
+      .rpc();
+    const referrerData = await program.account.referrer.fetch(referrer);
+    expect(referrerData.unclaimedFees.toNumber()).to.be.gt(referralFee);
+    expect(referrerData.totalFeesEarned.toString()).to.eq(referrerData.unclaimedFees.toString());
+
+    const partnerBefore = await getSolBalance(provider, partner.publicKey);
+    await program.methods
+      .claimReferralFees()
+      .accounts({ authority: partner.publicKey })
+      .signers([partner])
+      .rpc();
+    expect(await getSolBalance(provider, partner.publicKey)).to.eq(
+      partnerBefore + referrerData.unclaimedFees.toNumber()
+    );
+    try {
+      await program.methods
+        .claimReferralFees()
+        .accounts({ authority: partner.publicKey })
+        .signers([partner])
+        .rpc();
+      expect.fail("Should have failed with nothing accrued");
+    } catch (e) {
+      expect(e.toString()).to.include("NothingToWithdraw");
+    }
+  });
+
+  it("Splits the platform fee vault between weighted recipients", async () => {
+    const treasury = Keypair.generate();
+    const partnerShare = Keypair.generate();
+    const setRecipients = (signer: Keypair, recipients: { recipient: PublicKey; weight: anchor.BN }[]) =>
+      program.methods
+        .setPlatformFeeRecipients(recipients)
+        .accounts({ authority: signer.publicKey })
+        .signers([signer])
+        .rpc();
+    const distribute = (recipients: PublicKey[]) =>
+      program.methods
+        .distributePlatformFees()
+        .remainingAccounts(recipients.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false })))
+        .rpc();
+
+    // Nothing is paid out before recipients are configured
+    try {
+      await distribute([]);
+      expect.fail("Should have failed without recipients");
+    } catch (e) {
+      expect(e.toString()).to.include("InvalidFeeRecipients");
+    }
+    const recipients = [
+      { recipient: treasury.publicKey, weight: new anchor.BN(3) },
+      { recipient: partnerShare.publicKey, weight: new anchor.BN(1) },
+    ];
+    try {
+      await setRecipients(buyer, recipients);
+      expect.fail("Should have failed for a non-authority");
+    } catch (e) {
+      expect(e.toString()).to.include("ConstraintRaw");
+    }
+    try {
+      await setRecipients(authority, [{ ...recipients[0], weight: new anchor.BN(0) }]);
+      expect.fail("Should have failed for a zero weight");
+    } catch (e) {
+      expect(e.toString()).to.include("InvalidFeeRecipients");
+    }
+    await setRecipients(authority, recipients);
+
+    // Recipients must be passed in order
+    try {
+      await distribute([partnerShare.publicKey, treasury.publicKey]);
+      expect.fail("Should have failed for recipients out of order");
+    } catch (e) {
+      expect(e.toString()).to.include("InvalidFeeRecipients");
+    }
+
+    const vaultRent = await provider.connection.getMinimumBalanceForRentExemption(0);
+    const accrued = (await getSolBalance(provider, platformFeeVault)) - vaultRent;
+    expect(accrued).to.be.gt(0);
+    await distribute([treasury.publicKey, partnerShare.publicKey]);
+
+    expect(await getSolBalance(provider, treasury.publicKey)).to.eq(Math.floor((accrued * 3) / 4));
+    expect(await getSolBalance(provider, partnerShare.publicKey)).to.eq(Math.floor(accrued / 4));
+    // Rounding dust stays in the vault for the next distribution
+    expect(await getSolBalance(provider, platformFeeVault)).to.be.lt(vaultRent + 2);
+  });
+
+  it("Burns or keeps the reserve fee as SOL according to the curve's reserve policy", async () => {
+    const [reserveSolVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("reserve_sol_vault")],
+      program.programId
+    );
+    const launch = async (policy: any) => {
+      const policyMint = Keypair.generate();
+      await program.methods
+        .create("Reserve Policy Token", "RPT", "https://test.com/rpt.json", { dammV2: {} }, { constantProduct: {} }, null, { solThreshold: {} }, null, { immediately: {} }, null, policy)
+        .accounts({
+          signer: authority.publicKey,
+          creator: creator.publicKey,
+          mint: policyMint.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([policyMint])
+        .rpc();
+      return policyMint.publicKey;
+    };
+    const buyWithEvent = async (policyMint: PublicKey) => {
+      let tradeEvent = null;
+      const listener = program.addEventListener("trade", (event) => {
+        tradeEvent = event;
+      });
+      await program.methods
+        .buy(new anchor.BN(LAMPORTS_PER_SOL), new anchor.BN(0))
+        .accounts({
+          signer: buyer.publicKey,
+          mint: policyMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .accountsPartial({
+          reserveAta: getAssociatedTokenAddressSync(policyMint, globalReserve, true),
+        })
+        .signers([buyer])
+        .rpc();
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+      return tradeEvent;
+    };
+
+    // Burn: the reserve's tokens leave the supply instead of reaching the reserve ATA
+    const burnMint = await launch({ burn: {} });
+    const supplyBefore = (await getMint(provider.connection, burnMint)).supply;
+    const burnEvent = await buyWithEvent(burnMint);
+    expect(burnEvent.reservePolicy).to.have.property("burn");
+    expect(burnEvent.reserveTokenAmount.toNumber()).to.be.gt(0);
+    expect((await getMint(provider.connection, burnMint)).supply.toString()).to.eq(
+      (supplyBefore - BigInt(burnEvent.reserveTokenAmount.toString())).toString()
+    );
+    expect(
+      await getTokenBalance(provider, getAssociatedTokenAddressSync(burnMint, globalReserve, true))
+    ).to.eq(0);
+
+    // KeepSol: the reserve fee is paid to the reserve SOL vault and buys no tokens
+    const keepSolMint = await launch({ keepSol: {} });
+    const vaultRent = await provider.connection.getMinimumBalanceForRentExemption(0);
+    // `initialize` funded the vault, so the buyer pays exactly the fee into it
+    const vaultBefore = await getSolBalance(provider, reserveSolVault);
+    expect(vaultBefore).to.be.gte(vaultRent);
+    const keepSolEvent = await buyWithEvent(keepSolMint);
+    expect(keepSolEvent.reservePolicy).to.have.property("keepSol");
+    expect(keepSolEvent.reserveTokenAmount.toNumber()).to.eq(0);
+    expect(keepSolEvent.reserveFee.toNumber()).to.be.gt(0);
+    expect(await getSolBalance(provider, reserveSolVault)).to.eq(
+      vaultBefore + keepSolEvent.reserveFee.toNumber()
+    );
+
+    // The authority withdraws the kept SOL, never the vault's rent
+    const withdrawReserveSol = (amount: anchor.BN) =>
+      program.methods
+        .withdrawReserveSol(amount)
+        .accounts({ authority: authority.publicKey })
+        .rpc();
+    const available = (await getSolBalance(provider, reserveSolVault)) - vaultRent;
+    try {
+      await withdrawReserveSol(new anchor.BN(available + 1));
+      expect.fail("Should have failed for more than the vault holds");
+    } catch (e) {
+      expect(e.toString()).to.include("InsufficientReserve");
+    }
+    let withdrawnEvent = null;
+    const listener = program.addEventListener("reserveSolWithdrawn", (event) => {
+      withdrawnEvent = event;
+    });
+    await withdrawReserveSol(keepSolEvent.reserveFee);
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+    expect(withdrawnEvent.authority.toBase58()).to.eq(authority.publicKey.toBase58());
+    expect(withdrawnEvent.amount.toString()).to.eq(keepSolEvent.reserveFee.toString());
+    expect(withdrawnEvent.remaining.toNumber()).to.eq(available - keepSolEvent.reserveFee.toNumber());
+  });
+
+  it("Streams the reserve's tokens to stakers of a graduated token over time, pro-rata", async () => {
+    // Staking opens only once a curve has graduated
+    const activeMint = Keypair.generate();
+    await program.methods
+      .create("Unstakable Token", "UST", "https://test.com/ust.json", { dammV2: {} }, { constantProduct: {} }, null, { solThreshold: {} }, null, { immediately: {} }, null, { accumulate: {} })
+      .accounts({
+        signer: authority.publicKey,
+        creator: creator.publicKey,
+        mint: activeMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([activeMint])
+      .rpc();
+    const createPool = (poolMint: PublicKey) =>
+      program.methods
+        .createStakePool()
+        .accounts({ signer: authority.publicKey, mint: poolMint, tokenProgram: TOKEN_PROGRAM_ID })
+        .rpc();
+    try {
+      await createPool(activeMint.publicKey);
+      expect.fail("Should have failed for an active curve");
+    } catch (e) {
+      expect(e.toString()).to.include("BondingCurveNotComplete");
+    }
+    const [stakePool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("stake_pool"), mint.publicKey.toBuffer()],
+      program.programId
+    );
+    const stakeVault = getAssociatedTokenAddressSync(mint.publicKey, stakePool, true);
+    const withdrawReserve = (amount: anchor.BN, pool: PublicKey | null) =>
+      program.methods
+        .withdrawReserve(amount)
+        .accounts({
+          authority: authority.publicKey,
+          mint: mint.publicKey,
+          stakePool: pool,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([authority])
+        .rpc();
+
+    // Set aside tokens to deposit once a staker is in, while the reserve is still the authority's
+    const depositAmount = new anchor.BN(1_000_000);
+    await withdrawReserve(depositAmount, null);
+    await createPool(mint.publicKey);
+
+    // From now on the reserve's whole balance belongs to the stakers
+    const reserveBalance = await getTokenBalance(provider, reserveAta);
+    expect(reserveBalance).to.be.gt(0);
+    try {
+      await withdrawReserve(new anchor.BN(1), stakePool);
+      expect.fail("Should have failed for a staked reserve");
+    } catch (e) {
+      expect(e.toString()).to.include("ReserveStaked");
+    }
+
+    const staking = (method: "stake" | "unstake", signer: Keypair, amount: anchor.BN) =>
+      program.methods[method](amount)
+        .accounts({ owner: signer.publicKey, mint: mint.publicKey, tokenProgram: TOKEN_PROGRAM_ID })
+        .accountsPartial({ reserveAta })
+        .signers([signer])
+        .rpc();
+    const claim = (signer: Keypair) =>
+      program.methods
+        .claimStakingRewards()
+        .accounts({ owner: signer.publicKey, mint: mint.publicKey, tokenProgram: TOKEN_PROGRAM_ID })
+        .accountsPartial({ reserveAta })
+        .signers([signer])
+        .rpc();
+
+    // The first stake sweeps what the reserve already held into the vault, to be streamed out
+    await staking("stake", buyer, new anchor.BN(1));
+    expect(await getTokenBalance(provider, reserveAta)).to.eq(0);
+    expect(await getTokenBalance(provider, stakeVault)).to.eq(1 + reserveBalance);
+    let poolData = await program.account.stakePool.fetch(stakePool);
+    expect(poolData.pendingRewards.toNumber()).to.eq(reserveBalance);
+
+    // Deposits reach the stakers the same way
+    await program.methods
+      .depositToReserve(depositAmount)
+      .accounts({
+        authority: authority.publicKey,
+        mint: mint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([authority])
+      .rpc();
+    const streamed = reserveBalance + depositAmount.toNumber();
+
+    // The deposit is swept into the vault and restarts the stream, not paid at once
+    await staking("stake", secondBuyer, new anchor.BN(1_000_000));
+    expect(await getTokenBalance(provider, reserveAta)).to.eq(0);
+    expect(await getTokenBalance(provider, stakeVault)).to.eq(1_000_001 + streamed);
+    poolData = await program.account.stakePool.fetch(stakePool);
+    expect(poolData.totalRewards.add(poolData.pendingRewards).toNumber()).to.eq(streamed);
+    expect(poolData.streamEndSlot.sub(poolData.lastUpdateSlot).toNumber()).to.eq(216_000);
+
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+
+    try {
+      await staking("unstake", secondBuyer, new anchor.BN(2_000_000));
+      expect.fail("Should have failed above the staked amount");
+    } catch (e) {
+      expect(e.toString()).to.include("InsufficientStake");
+    }
+    const secondBuyerAta = getAssociatedTokenAddressSync(mint.publicKey, secondBuyer.publicKey);
+    const secondBefore = await getTokenBalance(provider, secondBuyerAta);
+    await staking("unstake", secondBuyer, new anchor.BN(1_000_000));
+    expect(await getTokenBalance(provider, secondBuyerAta)).to.eq(secondBefore + 1_000_000);
+    // Only a few slots' worth of the reserve has been released
+    poolData = await program.account.stakePool.fetch(stakePool);
+    expect(poolData.totalRewards.toNumber()).to.be.gt(0);
+    expect(poolData.totalRewards.toNumber()).to.be.lt(streamed / 100);
+    expect(poolData.totalRewards.add(poolData.pendingRewards).toNumber()).to.eq(streamed);
+
+    // Rewards earned before unstaking stay claimable
+    const [secondPosition] = PublicKey.findProgramAddressSync(
+      [Buffer.from("stake_position"), stakePool.toBuffer(), secondBuyer.publicKey.toBuffer()],
+      program.programId
+    );
+    const unclaimed = (await program.account.stakePosition.fetch(secondPosition)).unclaimedRewards;
+    expect(unclaimed.toNumber()).to.be.gt(0);
+    let claimedEvent = null;
+    const listener = program.addEventListener("stakingRewardsClaimed", (event) => {
+      claimedEvent = event;
+    });
+    await claim(secondBuyer);
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+    expect(claimedEvent.amount.toString()).to.eq(unclaimed.toString());
+    expect(await getTokenBalance(provider, secondBuyerAta)).to.eq(
+      secondBefore + 1_000_000 + unclaimed.toNumber()
+    );
+    try {
+      await claim(secondBuyer);
+      expect.fail("Should have failed with nothing accrued");
+    } catch (e) {
+      expect(e.toString()).to.include("NothingToWithdraw");
+    }
+  });
 });
@@ -0,0 +1,217 @@
+// fixes unexpected `cfg` errors
+// check https://solana.stackexchange.com/questions/17777/unexpected-cfg-condition-value-solana
+#![allow(unexpected_cfgs)]
+// `#[program]` expands to a call to the deprecated `AccountInfo::realloc` in anchor 0.31
+#![allow(deprecated)]
+//! Local stand-in for Raydium CP-Swap, loaded at the real program address by
+//! `anchor test`. It keeps the account order, signer and writable flags, PDA
+//! seeds and arguments of `initialize`, and its LP sizing, and nothing else: no
+//! swaps, fees or observations, and no pool creation fee is charged.
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::Token,
+    token_interface::{self, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked},
+};
+
+declare_id!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
+
+// LP cp-swap keeps in the pool for good on creation
+const LOCKED_LP_AMOUNT: u64 = 100;
+
+#[program]
+pub mod cpmm_stub {
+    use super::*;
+
+    pub fn initialize(
+        ctx: Context<InitializeCtx>,
+        init_amount_0: u64,
+        init_amount_1: u64,
+        _open_time: u64,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.token_0_mint.key() < ctx.accounts.token_1_mint.key(),
+            StubError::InvalidInput
+        );
+        require!(init_amount_0 > 0 && init_amount_1 > 0, StubError::InvalidInput);
+
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_0_program.to_account_info(),
+                TransferChecked {
+                    mint: ctx.accounts.token_0_mint.to_account_info(),
+                    from: ctx.accounts.creator_token_0.to_account_info(),
+                    to: ctx.accounts.token_0_vault.to_account_info(),
+                    authority: ctx.accounts.creator.to_account_info(),
+                },
+            ),
+            init_amount_0,
+            ctx.accounts.token_0_mint.decimals,
+        )?;
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_1_program.to_account_info(),
+                TransferChecked {
+                    mint: ctx.accounts.token_1_mint.to_account_info(),
+                    from: ctx.accounts.creator_token_1.to_account_info(),
+                    to: ctx.accounts.token_1_vault.to_account_info(),
+                    authority: ctx.accounts.creator.to_account_info(),
+                },
+            ),
+            init_amount_1,
+            ctx.accounts.token_1_mint.decimals,
+        )?;
+
+        // LP supply is the geometric mean of the deposits, as in cp-swap
+        let liquidity = u64::try_from(isqrt(u128::from(init_amount_0) * u128::from(init_amount_1)))
+            .map_err(|_| StubError::InvalidInput)?;
+        let creator_lp = liquidity
+            .checked_sub(LOCKED_LP_AMOUNT)
+            .ok_or(StubError::InitLpAmountTooLess)?;
+        token_interface::mint_to(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.lp_mint.to_account_info(),
+                    to: ctx.accounts.creator_lp_token.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                },
+            )
+            .with_signer(&[&[b"vault_and_lp_mint_auth_seed", &[ctx.bumps.authority]]]),
+            creator_lp,
+        )?;
+
+        ctx.accounts.pool_state.set_inner(PoolState {
+            amm_config: ctx.accounts.amm_config.key(),
+            pool_creator: ctx.accounts.creator.key(),
+            token_0_vault: ctx.accounts.token_0_vault.key(),
+            token_1_vault: ctx.accounts.token_1_vault.key(),
+            lp_mint: ctx.accounts.lp_mint.key(),
+            token_0_mint: ctx.accounts.token_0_mint.key(),
+            token_1_mint: ctx.accounts.token_1_mint.key(),
+            observation_key: ctx.accounts.observation_state.key(),
+            lp_supply: liquidity,
+        });
+        ctx.accounts.observation_state.pool_id = ctx.accounts.pool_state.key();
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PoolState {
+    pub amm_config: Pubkey,
+    pub pool_creator: Pubkey,
+    pub token_0_vault: Pubkey,
+    pub token_1_vault: Pubkey,
+    pub lp_mint: Pubkey,
+    pub token_0_mint: Pubkey,
+    pub token_1_mint: Pubkey,
+    pub observation_key: Pubkey,
+    pub lp_supply: u64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ObservationState {
+    pub pool_id: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct InitializeCtx<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    /// CHECK: Fee tier, not read by the stub
+    pub amm_config: UncheckedAccount<'info>,
+    /// CHECK: Vault and LP mint authority PDA
+    #[account(seeds = [b"vault_and_lp_mint_auth_seed"], bump)]
+    pub authority: UncheckedAccount<'info>,
+    // cp-swap takes a signing keypair in place of its own pool PDA
+    #[account(init, payer = creator, space = 8 + PoolState::INIT_SPACE)]
+    pub pool_state: Box<Account<'info, PoolState>>,
+    #[account(mint::token_program = token_0_program)]
+    pub token_0_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mint::token_program = token_1_program)]
+    pub token_1_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init,
+        payer = creator,
+        seeds = [b"pool_lp_mint", pool_state.key().as_ref()],
+        bump,
+        mint::decimals = 9,
+        mint::authority = authority,
+        mint::token_program = token_program,
+    )]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut, token::mint = token_0_mint, token::authority = creator)]
+    pub creator_token_0: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = token_1_mint, token::authority = creator)]
+    pub creator_token_1: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = creator,
+        associated_token::mint = lp_mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
+    )]
+    pub creator_lp_token: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = creator,
+        seeds = [b"pool_vault", pool_state.key().as_ref(), token_0_mint.key().as_ref()],
+        bump,
+        token::mint = token_0_mint,
+        token::authority = authority,
+        token::token_program = token_0_program,
+    )]
+    pub token_0_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = creator,
+        seeds = [b"pool_vault", pool_state.key().as_ref(), token_1_mint.key().as_ref()],
+        bump,
+        token::mint = token_1_mint,
+        token::authority = authority,
+        token::token_program = token_1_program,
+    )]
+    pub token_1_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Receives cp-swap's creation fee, which the stub does not charge
+    #[account(mut)]
+    pub create_pool_fee: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + ObservationState::INIT_SPACE,
+        seeds = [b"observation", pool_state.key().as_ref()],
+        bump
+    )]
+    pub observation_state: Box<Account<'info, ObservationState>>,
+    pub token_program: Program<'info, Token>,
+    pub token_0_program: Interface<'info, TokenInterface>,
+    pub token_1_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[error_code]
+pub enum StubError {
+    #[msg("Invalid input")]
+    InvalidInput,
+    #[msg("Init lp amount is too less(Because 100 amount lp will be locked)")]
+    InitLpAmountTooLess,
+}
+
+fn isqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+    let mut x = value;
+    let mut y = (x + value / x) / 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
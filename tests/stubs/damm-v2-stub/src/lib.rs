@@ -0,0 +1,530 @@
+// fixes unexpected `cfg` errors
+// check https://solana.stackexchange.com/questions/17777/unexpected-cfg-condition-value-solana
+#![allow(unexpected_cfgs)]
+// `#[program]` expands to a call to the deprecated `AccountInfo::realloc` in anchor 0.31
+#![allow(deprecated)]
+//! Local stand-in for Meteora DAMM v2 (cp-amm), loaded at the real program
+//! address by `anchor test`. It keeps the account order, PDA seeds and
+//! deposit rounding of the instructions coinfun calls into, and the offsets of
+//! the `Pool` fields coinfun reads, and nothing else: no fee schedules, swaps
+//! or NFT metadata, so positions never earn fees.
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{self, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked},
+};
+
+declare_id!("cpamdpZCGKUy5JxQXB4dcpGPiikHawvSWAd6mEn1sGG");
+
+const MIN_SQRT_PRICE: u128 = 4_295_048_016;
+const MAX_SQRT_PRICE: u128 = 79_226_673_521_066_979_257_578_248_091;
+
+#[program]
+pub mod damm_v2_stub {
+    use super::*;
+
+    pub fn initialize_pool(
+        ctx: Context<InitializePoolCtx>,
+        params: InitializePoolParameters,
+    ) -> Result<()> {
+        require!(
+            params.sqrt_price > MIN_SQRT_PRICE && params.sqrt_price < MAX_SQRT_PRICE,
+            StubError::InvalidPriceRange
+        );
+        require!(params.liquidity > 0, StubError::InvalidLiquidity);
+
+        let (amount_a, amount_b) = deposit_amounts(params.liquidity, params.sqrt_price)?;
+
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_a_program.to_account_info(),
+                TransferChecked {
+                    mint: ctx.accounts.token_a_mint.to_account_info(),
+                    from: ctx.accounts.payer_token_a.to_account_info(),
+                    to: ctx.accounts.token_a_vault.to_account_info(),
+                    authority: ctx.accounts.payer.to_account_info(),
+                },
+            ),
+            amount_a,
+            ctx.accounts.token_a_mint.decimals,
+        )?;
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_b_program.to_account_info(),
+                TransferChecked {
+                    mint: ctx.accounts.token_b_mint.to_account_info(),
+                    from: ctx.accounts.payer_token_b.to_account_info(),
+                    to: ctx.accounts.token_b_vault.to_account_info(),
+                    authority: ctx.accounts.payer.to_account_info(),
+                },
+            ),
+            amount_b,
+            ctx.accounts.token_b_mint.decimals,
+        )?;
+
+        mint_position_nft(
+            &ctx.accounts.token_2022_program,
+            &ctx.accounts.position_nft_mint,
+            &ctx.accounts.position_nft_account,
+            &ctx.accounts.pool_authority,
+            ctx.bumps.pool_authority,
+        )?;
+
+        ctx.accounts.pool.set_inner(Pool {
+            pool_fees: [0; 160],
+            token_a_mint: ctx.accounts.token_a_mint.key(),
+            token_b_mint: ctx.accounts.token_b_mint.key(),
+            token_a_vault: ctx.accounts.token_a_vault.key(),
+            token_b_vault: ctx.accounts.token_b_vault.key(),
+            whitelisted_vault: Pubkey::default(),
+            partner: Pubkey::default(),
+            liquidity: params.liquidity,
+            padding: 0,
+            protocol_and_partner_fees: [0; 4],
+            sqrt_min_price: MIN_SQRT_PRICE,
+            sqrt_max_price: MAX_SQRT_PRICE,
+            sqrt_price: params.sqrt_price,
+        });
+        ctx.accounts.position.set_inner(Position {
+            pool: ctx.accounts.pool.key(),
+            nft_mint: ctx.accounts.position_nft_mint.key(),
+            unlocked_liquidity: params.liquidity,
+            permanent_locked_liquidity: 0,
+        });
+
+        Ok(())
+    }
+
+    pub fn create_position(ctx: Context<CreatePositionCtx>) -> Result<()> {
+        mint_position_nft(
+            &ctx.accounts.token_program,
+            &ctx.accounts.position_nft_mint,
+            &ctx.accounts.position_nft_account,
+            &ctx.accounts.pool_authority,
+            ctx.bumps.pool_authority,
+        )?;
+        ctx.accounts.position.set_inner(Position {
+            pool: ctx.accounts.pool.key(),
+            nft_mint: ctx.accounts.position_nft_mint.key(),
+            unlocked_liquidity: 0,
+            permanent_locked_liquidity: 0,
+        });
+        Ok(())
+    }
+
+    // Deposits at the pool's current price, capped by the thresholds as in cp-amm
+    pub fn add_liquidity(
+        ctx: Context<AddLiquidityCtx>,
+        params: AddLiquidityParameters,
+    ) -> Result<()> {
+        require!(params.liquidity_delta > 0, StubError::InvalidLiquidity);
+        let (amount_a, amount_b) =
+            deposit_amounts(params.liquidity_delta, ctx.accounts.pool.sqrt_price)?;
+        require!(
+            amount_a <= params.token_a_amount_threshold
+                && amount_b <= params.token_b_amount_threshold,
+            StubError::ExceededSlippage
+        );
+
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_a_program.to_account_info(),
+                TransferChecked {
+                    mint: ctx.accounts.token_a_mint.to_account_info(),
+                    from: ctx.accounts.token_a_account.to_account_info(),
+                    to: ctx.accounts.token_a_vault.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            amount_a,
+            ctx.accounts.token_a_mint.decimals,
+        )?;
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_b_program.to_account_info(),
+                TransferChecked {
+                    mint: ctx.accounts.token_b_mint.to_account_info(),
+                    from: ctx.accounts.token_b_account.to_account_info(),
+                    to: ctx.accounts.token_b_vault.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            amount_b,
+            ctx.accounts.token_b_mint.decimals,
+        )?;
+
+        ctx.accounts.pool.liquidity += params.liquidity_delta;
+        ctx.accounts.position.unlocked_liquidity += params.liquidity_delta;
+        Ok(())
+    }
+
+    pub fn permanent_lock_position(
+        ctx: Context<PermanentLockPositionCtx>,
+        permanent_lock_liquidity: u128,
+    ) -> Result<()> {
+        let position = &mut ctx.accounts.position;
+        require!(
+            permanent_lock_liquidity > 0 && permanent_lock_liquidity <= position.unlocked_liquidity,
+            StubError::InvalidLiquidity
+        );
+        position.unlocked_liquidity -= permanent_lock_liquidity;
+        position.permanent_locked_liquidity += permanent_lock_liquidity;
+        Ok(())
+    }
+
+    // The stub has no swaps, so there is never anything to pay out; it only
+    // checks that the caller owns the position.
+    pub fn claim_position_fee(_ctx: Context<ClaimPositionFeeCtx>) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializePoolParameters {
+    pub liquidity: u128,
+    pub sqrt_price: u128,
+    pub activation_point: Option<u64>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AddLiquidityParameters {
+    pub liquidity_delta: u128,
+    pub token_a_amount_threshold: u64,
+    pub token_b_amount_threshold: u64,
+}
+
+// Laid out like cp-amm's `Pool` up to `sqrt_price`: coinfun reads `token_a_mint`
+// at offset 168 and `sqrt_price` at 456 of a pool created before `migrate`
+#[account]
+#[derive(InitSpace)]
+pub struct Pool {
+    pub pool_fees: [u8; 160],
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub token_a_vault: Pubkey,
+    pub token_b_vault: Pubkey,
+    pub whitelisted_vault: Pubkey,
+    pub partner: Pubkey,
+    pub liquidity: u128,
+    pub padding: u128,
+    pub protocol_and_partner_fees: [u64; 4],
+    pub sqrt_min_price: u128,
+    pub sqrt_max_price: u128,
+    pub sqrt_price: u128,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Position {
+    pub pool: Pubkey,
+    pub nft_mint: Pubkey,
+    pub unlocked_liquidity: u128,
+    pub permanent_locked_liquidity: u128,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializePoolCtx<'info> {
+    /// CHECK: Owner of the position NFT
+    pub creator: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = pool_authority,
+        mint::token_program = token_2022_program,
+    )]
+    pub position_nft_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"position_nft_account", position_nft_mint.key().as_ref()],
+        bump,
+        token::mint = position_nft_mint,
+        token::authority = creator,
+        token::token_program = token_2022_program,
+    )]
+    pub position_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: Pool config, not read by the stub
+    pub config: UncheckedAccount<'info>,
+    /// CHECK: Pool authority PDA
+    #[account(seeds = [b"pool_authority"], bump)]
+    pub pool_authority: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Pool::INIT_SPACE,
+        seeds = [
+            b"pool",
+            config.key().as_ref(),
+            max_key(&token_a_mint.key(), &token_b_mint.key()).as_ref(),
+            min_key(&token_a_mint.key(), &token_b_mint.key()).as_ref(),
+        ],
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Position::INIT_SPACE,
+        seeds = [b"position", position_nft_mint.key().as_ref()],
+        bump
+    )]
+    pub position: Box<Account<'info, Position>>,
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"token_vault", token_a_mint.key().as_ref(), pool.key().as_ref()],
+        bump,
+        token::mint = token_a_mint,
+        token::authority = pool_authority,
+        token::token_program = token_a_program,
+    )]
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"token_vault", token_b_mint.key().as_ref(), pool.key().as_ref()],
+        bump,
+        token::mint = token_b_mint,
+        token::authority = pool_authority,
+        token::token_program = token_b_program,
+    )]
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = token_a_mint,
+        token::token_program = token_a_program,
+    )]
+    pub payer_token_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = token_b_mint,
+        token::token_program = token_b_program,
+    )]
+    pub payer_token_b: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_a_program: Interface<'info, TokenInterface>,
+    pub token_b_program: Interface<'info, TokenInterface>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreatePositionCtx<'info> {
+    /// CHECK: Owner of the position NFT
+    pub owner: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = pool_authority,
+        mint::token_program = token_program,
+    )]
+    pub position_nft_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"position_nft_account", position_nft_mint.key().as_ref()],
+        bump,
+        token::mint = position_nft_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub position_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub pool: Box<Account<'info, Pool>>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Position::INIT_SPACE,
+        seeds = [b"position", position_nft_mint.key().as_ref()],
+        bump
+    )]
+    pub position: Box<Account<'info, Position>>,
+    /// CHECK: Pool authority PDA
+    #[account(seeds = [b"pool_authority"], bump)]
+    pub pool_authority: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddLiquidityCtx<'info> {
+    #[account(
+        mut,
+        has_one = token_a_vault,
+        has_one = token_b_vault,
+        has_one = token_a_mint,
+        has_one = token_b_mint,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    #[account(mut, has_one = pool)]
+    pub position: Box<Account<'info, Position>>,
+    #[account(mut, token::mint = token_a_mint)]
+    pub token_a_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = token_b_mint)]
+    pub token_b_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        constraint = position_nft_account.mint == position.nft_mint,
+        constraint = position_nft_account.amount == 1,
+        token::authority = owner,
+    )]
+    pub position_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub owner: Signer<'info>,
+    pub token_a_program: Interface<'info, TokenInterface>,
+    pub token_b_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PermanentLockPositionCtx<'info> {
+    #[account(mut)]
+    pub pool: Box<Account<'info, Pool>>,
+    #[account(mut, has_one = pool)]
+    pub position: Box<Account<'info, Position>>,
+    #[account(
+        constraint = position_nft_account.mint == position.nft_mint,
+        constraint = position_nft_account.amount == 1,
+        token::authority = owner,
+    )]
+    pub position_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub owner: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimPositionFeeCtx<'info> {
+    /// CHECK: Pool authority PDA
+    #[account(seeds = [b"pool_authority"], bump)]
+    pub pool_authority: UncheckedAccount<'info>,
+    #[account(
+        has_one = token_a_vault,
+        has_one = token_b_vault,
+        has_one = token_a_mint,
+        has_one = token_b_mint,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    #[account(mut, has_one = pool)]
+    pub position: Box<Account<'info, Position>>,
+    #[account(mut, token::mint = token_a_mint)]
+    pub token_a_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = token_b_mint)]
+    pub token_b_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        constraint = position_nft_account.mint == position.nft_mint,
+        constraint = position_nft_account.amount == 1,
+        token::authority = owner,
+    )]
+    pub position_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub owner: Signer<'info>,
+    pub token_a_program: Interface<'info, TokenInterface>,
+    pub token_b_program: Interface<'info, TokenInterface>,
+}
+
+#[error_code]
+pub enum StubError {
+    #[msg("Invalid price range")]
+    InvalidPriceRange,
+    #[msg("Invalid liquidity")]
+    InvalidLiquidity,
+    #[msg("Math overflow")]
+    MathOverflow,
+    #[msg("Exceeded slippage tolerance")]
+    ExceededSlippage,
+}
+
+/// Token A and token B a full-range `liquidity` takes at `sqrt_price`, rounded up
+/// as in cp-amm.
+fn deposit_amounts(liquidity: u128, sqrt_price: u128) -> Result<(u64, u64)> {
+    let amount_a = mul_div_ceil(liquidity, MAX_SQRT_PRICE - sqrt_price, sqrt_price)
+        .map(|res| res.div_ceil(MAX_SQRT_PRICE))
+        .and_then(|amount| u64::try_from(amount).ok())
+        .ok_or(StubError::MathOverflow)?;
+    let amount_b = mul_div_ceil(liquidity, sqrt_price - MIN_SQRT_PRICE, 1 << 64)
+        .map(|res| res.div_ceil(1 << 64))
+        .and_then(|amount| u64::try_from(amount).ok())
+        .ok_or(StubError::MathOverflow)?;
+    Ok((amount_a, amount_b))
+}
+
+fn mint_position_nft<'info>(
+    token_2022_program: &Program<'info, Token2022>,
+    position_nft_mint: &InterfaceAccount<'info, Mint>,
+    position_nft_account: &InterfaceAccount<'info, TokenAccount>,
+    pool_authority: &UncheckedAccount<'info>,
+    pool_authority_bump: u8,
+) -> Result<()> {
+    token_interface::mint_to(
+        CpiContext::new(
+            token_2022_program.to_account_info(),
+            MintTo {
+                mint: position_nft_mint.to_account_info(),
+                to: position_nft_account.to_account_info(),
+                authority: pool_authority.to_account_info(),
+            },
+        )
+        .with_signer(&[&[b"pool_authority", &[pool_authority_bump]]]),
+        1,
+    )
+}
+
+fn max_key(a: &Pubkey, b: &Pubkey) -> Pubkey {
+    *a.max(b)
+}
+
+fn min_key(a: &Pubkey, b: &Pubkey) -> Pubkey {
+    *a.min(b)
+}
+
+/// `a * b / denominator`, rounded up, with a 256-bit intermediate product.
+fn mul_div_ceil(a: u128, b: u128, denominator: u128) -> Option<u128> {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let cross = (lo_lo >> 64) + (hi_lo & MASK) + (lo_hi & MASK);
+    let lo = (cross << 64) | (lo_lo & MASK);
+    let hi = a_hi * b_hi + (hi_lo >> 64) + (lo_hi >> 64) + (cross >> 64);
+    if denominator == 0 || hi >= denominator {
+        return None;
+    }
+
+    let mut remainder = hi;
+    let mut quotient: u128 = 0;
+    for i in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((lo >> i) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= denominator {
+            remainder = remainder.wrapping_sub(denominator);
+            quotient |= 1;
+        }
+    }
+    if remainder > 0 {
+        quotient = quotient.checked_add(1)?;
+    }
+    Some(quotient)
+}
@@ -0,0 +1,437 @@
+// fixes unexpected `cfg` errors
+// check https://solana.stackexchange.com/questions/17777/unexpected-cfg-condition-value-solana
+#![allow(unexpected_cfgs)]
+// `#[program]` expands to a call to the deprecated `AccountInfo::realloc` in anchor 0.31
+#![allow(deprecated)]
+//! Local stand-in for Meteora DLMM (lb_clmm), loaded at the real program
+//! address by `anchor test`. It keeps the account order, signer and writable
+//! flags, PDA seeds and arguments of the instructions coinfun calls into, and
+//! the offsets of the `LbPair` fields coinfun reads, and nothing else: no bins,
+//! swaps or fee accounting, so deposits sit in the reserves as sent.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+declare_id!("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo");
+
+const BINS_PER_ARRAY: i32 = 70;
+const MAX_POSITION_WIDTH: i32 = 70;
+
+#[program]
+pub mod dlmm_stub {
+    use super::*;
+
+    // Permissionless here; lb_clmm keeps presets behind its admin
+    pub fn initialize_preset_parameter(
+        ctx: Context<InitializePresetParameterCtx>,
+        bin_step: u16,
+        base_factor: u16,
+    ) -> Result<()> {
+        ctx.accounts.preset_parameter.set_inner(PresetParameter { bin_step, base_factor });
+        Ok(())
+    }
+
+    pub fn initialize_lb_pair(
+        ctx: Context<InitializeLbPairCtx>,
+        active_id: i32,
+        bin_step: u16,
+    ) -> Result<()> {
+        require_eq!(bin_step, ctx.accounts.preset_parameter.bin_step, StubError::InvalidBinStep);
+        ctx.accounts.lb_pair.set_inner(LbPair {
+            base_factor: ctx.accounts.preset_parameter.base_factor,
+            parameters: [0; 62],
+            bump_and_pair_type: [0; 4],
+            active_id,
+            bin_step,
+            status_and_seeds: [0; 6],
+            token_x_mint: ctx.accounts.token_mint_x.key(),
+            token_y_mint: ctx.accounts.token_mint_y.key(),
+            reserve_x: ctx.accounts.reserve_x.key(),
+            reserve_y: ctx.accounts.reserve_y.key(),
+            oracle: ctx.accounts.oracle.key(),
+        });
+        ctx.accounts.oracle.lb_pair = ctx.accounts.lb_pair.key();
+        Ok(())
+    }
+
+    pub fn initialize_bin_array(ctx: Context<InitializeBinArrayCtx>, index: i64) -> Result<()> {
+        ctx.accounts.bin_array.set_inner(BinArray {
+            lb_pair: ctx.accounts.lb_pair.key(),
+            index,
+        });
+        Ok(())
+    }
+
+    pub fn initialize_position(
+        ctx: Context<InitializePositionCtx>,
+        lower_bin_id: i32,
+        width: i32,
+    ) -> Result<()> {
+        require!(
+            width > 0 && width <= MAX_POSITION_WIDTH,
+            StubError::InvalidPosition
+        );
+        ctx.accounts.position.set_inner(Position {
+            lb_pair: ctx.accounts.lb_pair.key(),
+            owner: ctx.accounts.owner.key(),
+            lower_bin_id,
+            upper_bin_id: lower_bin_id + width - 1,
+            amount_x: 0,
+            amount_y: 0,
+        });
+        Ok(())
+    }
+
+    // Takes both amounts whole into the reserves, after the checks lb_clmm makes on
+    // the active bin, the strategy range and the bin arrays
+    pub fn add_liquidity_by_strategy(
+        ctx: Context<AddLiquidityByStrategyCtx>,
+        liquidity_parameter: LiquidityParameterByStrategy,
+    ) -> Result<()> {
+        let params = &liquidity_parameter;
+        let strategy = &params.strategy_parameters;
+        require!(
+            params.active_id.abs_diff(ctx.accounts.lb_pair.active_id)
+                <= params.max_active_bin_slippage.unsigned_abs(),
+            StubError::ExceededBinSlippage
+        );
+        let position = &ctx.accounts.position;
+        require!(
+            strategy.min_bin_id >= position.lower_bin_id
+                && strategy.max_bin_id <= position.upper_bin_id
+                && strategy.min_bin_id <= ctx.accounts.lb_pair.active_id
+                && strategy.max_bin_id >= ctx.accounts.lb_pair.active_id,
+            StubError::InvalidStrategyParameters
+        );
+        require!(
+            ctx.accounts.bin_array_lower.index
+                == i64::from(position.lower_bin_id.div_euclid(BINS_PER_ARRAY))
+                && ctx.accounts.bin_array_upper.index
+                    == i64::from(position.upper_bin_id.div_euclid(BINS_PER_ARRAY)),
+            StubError::InvalidBinArray
+        );
+
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_x_program.to_account_info(),
+                TransferChecked {
+                    mint: ctx.accounts.token_x_mint.to_account_info(),
+                    from: ctx.accounts.user_token_x.to_account_info(),
+                    to: ctx.accounts.reserve_x.to_account_info(),
+                    authority: ctx.accounts.sender.to_account_info(),
+                },
+            ),
+            params.amount_x,
+            ctx.accounts.token_x_mint.decimals,
+        )?;
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_y_program.to_account_info(),
+                TransferChecked {
+                    mint: ctx.accounts.token_y_mint.to_account_info(),
+                    from: ctx.accounts.user_token_y.to_account_info(),
+                    to: ctx.accounts.reserve_y.to_account_info(),
+                    authority: ctx.accounts.sender.to_account_info(),
+                },
+            ),
+            params.amount_y,
+            ctx.accounts.token_y_mint.decimals,
+        )?;
+
+        let position = &mut ctx.accounts.position;
+        position.amount_x += params.amount_x;
+        position.amount_y += params.amount_y;
+        Ok(())
+    }
+
+    // The stub has no swaps, so there is never anything to pay out; it only
+    // checks that the caller owns the position.
+    pub fn claim_fee(_ctx: Context<ClaimFeeCtx>) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct StrategyParameters {
+    pub min_bin_id: i32,
+    pub max_bin_id: i32,
+    pub strategy_type: StrategyType,
+    pub parameteres: [u8; 64],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub enum StrategyType {
+    SpotOneSide,
+    CurveOneSide,
+    BidAskOneSide,
+    SpotBalanced,
+    CurveBalanced,
+    BidAskBalanced,
+    SpotImBalanced,
+    CurveImBalanced,
+    BidAskImBalanced,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LiquidityParameterByStrategy {
+    pub amount_x: u64,
+    pub amount_y: u64,
+    pub active_id: i32,
+    pub max_active_bin_slippage: i32,
+    pub strategy_parameters: StrategyParameters,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PresetParameter {
+    pub bin_step: u16,
+    pub base_factor: u16,
+}
+
+// Laid out like lb_clmm's `LbPair` up to `reserve_y`: coinfun reads `base_factor`
+// at offset 8, `active_id` at 76, `bin_step` at 80 and the mints at 88 and 120 of
+// a pair created before `migrate`
+#[account]
+#[derive(InitSpace)]
+pub struct LbPair {
+    pub base_factor: u16,
+    pub parameters: [u8; 62],
+    pub bump_and_pair_type: [u8; 4],
+    pub active_id: i32,
+    pub bin_step: u16,
+    pub status_and_seeds: [u8; 6],
+    pub token_x_mint: Pubkey,
+    pub token_y_mint: Pubkey,
+    pub reserve_x: Pubkey,
+    pub reserve_y: Pubkey,
+    pub oracle: Pubkey,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Oracle {
+    pub lb_pair: Pubkey,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BinArray {
+    pub lb_pair: Pubkey,
+    pub index: i64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Position {
+    pub lb_pair: Pubkey,
+    pub owner: Pubkey,
+    pub lower_bin_id: i32,
+    pub upper_bin_id: i32,
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(bin_step: u16, base_factor: u16)]
+pub struct InitializePresetParameterCtx<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PresetParameter::INIT_SPACE,
+        seeds = [
+            b"preset_parameter".as_ref(),
+            &bin_step.to_le_bytes(),
+            &base_factor.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub preset_parameter: Account<'info, PresetParameter>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeLbPairCtx<'info> {
+    #[account(
+        init,
+        payer = funder,
+        space = 8 + LbPair::INIT_SPACE,
+        seeds = [
+            min_key(&token_mint_x.key(), &token_mint_y.key()).as_ref(),
+            max_key(&token_mint_x.key(), &token_mint_y.key()).as_ref(),
+            &preset_parameter.bin_step.to_le_bytes(),
+            &preset_parameter.base_factor.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub lb_pair: Box<Account<'info, LbPair>>,
+    /// CHECK: Only needed for bins far from zero, which the stub does not track
+    #[account(mut)]
+    pub bin_array_bitmap_extension: Option<UncheckedAccount<'info>>,
+    pub token_mint_x: Box<InterfaceAccount<'info, Mint>>,
+    pub token_mint_y: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init,
+        payer = funder,
+        seeds = [lb_pair.key().as_ref(), token_mint_x.key().as_ref()],
+        bump,
+        token::mint = token_mint_x,
+        token::authority = lb_pair,
+        token::token_program = token_program,
+    )]
+    pub reserve_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = funder,
+        seeds = [lb_pair.key().as_ref(), token_mint_y.key().as_ref()],
+        bump,
+        token::mint = token_mint_y,
+        token::authority = lb_pair,
+        token::token_program = token_program,
+    )]
+    pub reserve_y: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = funder,
+        space = 8 + Oracle::INIT_SPACE,
+        seeds = [b"oracle", lb_pair.key().as_ref()],
+        bump
+    )]
+    pub oracle: Box<Account<'info, Oracle>>,
+    pub preset_parameter: Box<Account<'info, PresetParameter>>,
+    #[account(mut)]
+    pub funder: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(index: i64)]
+pub struct InitializeBinArrayCtx<'info> {
+    pub lb_pair: Box<Account<'info, LbPair>>,
+    #[account(
+        init,
+        payer = funder,
+        space = 8 + BinArray::INIT_SPACE,
+        seeds = [b"bin_array", lb_pair.key().as_ref(), &index.to_le_bytes()],
+        bump
+    )]
+    pub bin_array: Box<Account<'info, BinArray>>,
+    #[account(mut)]
+    pub funder: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializePositionCtx<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(init, payer = payer, space = 8 + Position::INIT_SPACE)]
+    pub position: Box<Account<'info, Position>>,
+    pub lb_pair: Box<Account<'info, LbPair>>,
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddLiquidityByStrategyCtx<'info> {
+    #[account(
+        mut,
+        has_one = lb_pair,
+        constraint = position.owner == sender.key() @ StubError::Unauthorized,
+    )]
+    pub position: Box<Account<'info, Position>>,
+    #[account(
+        mut,
+        has_one = reserve_x,
+        has_one = reserve_y,
+        has_one = token_x_mint,
+        has_one = token_y_mint,
+    )]
+    pub lb_pair: Box<Account<'info, LbPair>>,
+    /// CHECK: Only needed for bins far from zero, which the stub does not track
+    #[account(mut)]
+    pub bin_array_bitmap_extension: Option<UncheckedAccount<'info>>,
+    #[account(mut, token::mint = token_x_mint)]
+    pub user_token_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = token_y_mint)]
+    pub user_token_y: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub reserve_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub reserve_y: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_x_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_y_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut, has_one = lb_pair)]
+    pub bin_array_lower: Box<Account<'info, BinArray>>,
+    #[account(mut, has_one = lb_pair)]
+    pub bin_array_upper: Box<Account<'info, BinArray>>,
+    pub sender: Signer<'info>,
+    pub token_x_program: Interface<'info, TokenInterface>,
+    pub token_y_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimFeeCtx<'info> {
+    #[account(
+        mut,
+        has_one = reserve_x,
+        has_one = reserve_y,
+        has_one = token_x_mint,
+        has_one = token_y_mint,
+    )]
+    pub lb_pair: Box<Account<'info, LbPair>>,
+    #[account(
+        mut,
+        has_one = lb_pair,
+        constraint = position.owner == sender.key() @ StubError::Unauthorized,
+    )]
+    pub position: Box<Account<'info, Position>>,
+    #[account(mut, has_one = lb_pair)]
+    pub bin_array_lower: Box<Account<'info, BinArray>>,
+    #[account(mut, has_one = lb_pair)]
+    pub bin_array_upper: Box<Account<'info, BinArray>>,
+    pub sender: Signer<'info>,
+    #[account(mut)]
+    pub reserve_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub reserve_y: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = token_x_mint)]
+    pub user_token_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = token_y_mint)]
+    pub user_token_y: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_x_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_y_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[error_code]
+pub enum StubError {
+    #[msg("Bin step does not match the preset")]
+    InvalidBinStep,
+    #[msg("Invalid position width")]
+    InvalidPosition,
+    #[msg("Active bin moved past the allowed slippage")]
+    ExceededBinSlippage,
+    #[msg("Strategy range outside the position or the active bin")]
+    InvalidStrategyParameters,
+    #[msg("Bin arrays do not cover the position")]
+    InvalidBinArray,
+    #[msg("Signer does not own the position")]
+    Unauthorized,
+}
+
+fn max_key(a: &Pubkey, b: &Pubkey) -> Pubkey {
+    *a.max(b)
+}
+
+fn min_key(a: &Pubkey, b: &Pubkey) -> Pubkey {
+    *a.min(b)
+}